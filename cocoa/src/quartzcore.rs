@@ -24,7 +24,6 @@ use core_graphics::geometry::{CGAffineTransform, CGPoint, CGRect, CGSize};
 use core_graphics::path::{CGPath, SysCGPathRef};
 use foreign_types::ForeignType;
 use objc::{class, msg_send, sel, sel_impl};
-use std::ops::Mul;
 use std::ptr;
 
 use crate::appkit::CGLContextObj;
@@ -1471,118 +1470,7 @@ pub mod transaction {
 
 // CATransform3D.h
 
-#[repr(C)]
-#[derive(Clone, Copy)]
-pub struct CATransform3D {
-    pub m11: CGFloat,
-    pub m12: CGFloat,
-    pub m13: CGFloat,
-    pub m14: CGFloat,
-    pub m21: CGFloat,
-    pub m22: CGFloat,
-    pub m23: CGFloat,
-    pub m24: CGFloat,
-    pub m31: CGFloat,
-    pub m32: CGFloat,
-    pub m33: CGFloat,
-    pub m34: CGFloat,
-    pub m41: CGFloat,
-    pub m42: CGFloat,
-    pub m43: CGFloat,
-    pub m44: CGFloat,
-}
-
-impl PartialEq for CATransform3D {
-    #[inline]
-    fn eq(&self, other: &CATransform3D) -> bool {
-        unsafe { CATransform3DEqualToTransform(*self, *other) }
-    }
-}
-
-impl Mul<CATransform3D> for CATransform3D {
-    type Output = CATransform3D;
-
-    #[inline]
-    fn mul(self, other: CATransform3D) -> CATransform3D {
-        unsafe { CATransform3DConcat(self, other) }
-    }
-}
-
-impl CATransform3D {
-    pub const IDENTITY: CATransform3D = CATransform3D {
-        m11: 1.0,
-        m12: 0.0,
-        m13: 0.0,
-        m14: 0.0,
-        m21: 0.0,
-        m22: 1.0,
-        m23: 0.0,
-        m24: 0.0,
-        m31: 0.0,
-        m32: 0.0,
-        m33: 1.0,
-        m34: 0.0,
-        m41: 0.0,
-        m42: 0.0,
-        m43: 0.0,
-        m44: 1.0,
-    };
-
-    #[inline]
-    pub fn from_translation(tx: CGFloat, ty: CGFloat, tz: CGFloat) -> CATransform3D {
-        unsafe { CATransform3DMakeTranslation(tx, ty, tz) }
-    }
-
-    #[inline]
-    pub fn from_scale(sx: CGFloat, sy: CGFloat, sz: CGFloat) -> CATransform3D {
-        unsafe { CATransform3DMakeScale(sx, sy, sz) }
-    }
-
-    #[inline]
-    pub fn from_rotation(angle: CGFloat, x: CGFloat, y: CGFloat, z: CGFloat) -> CATransform3D {
-        unsafe { CATransform3DMakeRotation(angle, x, y, z) }
-    }
-
-    #[inline]
-    pub fn affine(affine_transform: CGAffineTransform) -> CATransform3D {
-        unsafe { CATransform3DMakeAffineTransform(affine_transform) }
-    }
-
-    #[inline]
-    pub fn is_identity(&self) -> bool {
-        unsafe { CATransform3DIsIdentity(*self) }
-    }
-
-    #[inline]
-    pub fn translate(&self, tx: CGFloat, ty: CGFloat, tz: CGFloat) -> CATransform3D {
-        unsafe { CATransform3DTranslate(*self, tx, ty, tz) }
-    }
-
-    #[inline]
-    pub fn scale(&self, sx: CGFloat, sy: CGFloat, sz: CGFloat) -> CATransform3D {
-        unsafe { CATransform3DScale(*self, sx, sy, sz) }
-    }
-
-    #[inline]
-    pub fn rotate(&self, angle: CGFloat, x: CGFloat, y: CGFloat, z: CGFloat) -> CATransform3D {
-        unsafe { CATransform3DRotate(*self, angle, x, y, z) }
-    }
-
-    #[inline]
-    pub fn invert(&self) -> CATransform3D {
-        unsafe { CATransform3DInvert(*self) }
-    }
-
-    #[inline]
-    pub fn is_affine(&self) -> bool {
-        unsafe { CATransform3DIsAffine(*self) }
-    }
-
-    #[inline]
-    pub fn to_affine(&self) -> CGAffineTransform {
-        unsafe { CATransform3DGetAffineTransform(*self) }
-    }
-}
+pub use core_graphics_types::transform3d::{CATransform3D, CATransform3DComponents, Quaternion};
 
 #[cfg_attr(feature = "link", link(name = "QuartzCore", kind = "framework"))]
 extern "C" {
@@ -1590,37 +1478,6 @@ extern "C" {
     static kCARendererMetalCommandQueue: CFStringRef;
 
     fn CACurrentMediaTime() -> CFTimeInterval;
-
-    fn CATransform3DIsIdentity(t: CATransform3D) -> bool;
-    fn CATransform3DEqualToTransform(a: CATransform3D, b: CATransform3D) -> bool;
-    fn CATransform3DMakeTranslation(tx: CGFloat, ty: CGFloat, tz: CGFloat) -> CATransform3D;
-    fn CATransform3DMakeScale(sx: CGFloat, sy: CGFloat, sz: CGFloat) -> CATransform3D;
-    fn CATransform3DMakeRotation(
-        angle: CGFloat,
-        x: CGFloat,
-        y: CGFloat,
-        z: CGFloat,
-    ) -> CATransform3D;
-    fn CATransform3DTranslate(
-        t: CATransform3D,
-        tx: CGFloat,
-        ty: CGFloat,
-        tz: CGFloat,
-    ) -> CATransform3D;
-    fn CATransform3DScale(t: CATransform3D, sx: CGFloat, sy: CGFloat, sz: CGFloat)
-        -> CATransform3D;
-    fn CATransform3DRotate(
-        t: CATransform3D,
-        angle: CGFloat,
-        x: CGFloat,
-        y: CGFloat,
-        z: CGFloat,
-    ) -> CATransform3D;
-    fn CATransform3DConcat(a: CATransform3D, b: CATransform3D) -> CATransform3D;
-    fn CATransform3DInvert(t: CATransform3D) -> CATransform3D;
-    fn CATransform3DMakeAffineTransform(m: CGAffineTransform) -> CATransform3D;
-    fn CATransform3DIsAffine(t: CATransform3D) -> bool;
-    fn CATransform3DGetAffineTransform(t: CATransform3D) -> CGAffineTransform;
 }
 
 // Miscellaneous structures in other frameworks.
@@ -0,0 +1,186 @@
+// Copyright 2026 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Four-character codes, as used for font table tags, pixel formats and `OSType`s.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// A four-character code, stored big-endian in a `u32` so that `b"glyf"` is `0x676C7966`.
+///
+/// Codes made of printable ASCII are displayed as text, and anything else as hexadecimal. Both
+/// forms parse back with [`FromStr`], and short tags are padded with spaces.
+///
+/// ```
+/// use core_foundation::four_char_code::FourCharCode;
+///
+/// const GLYF: FourCharCode = FourCharCode::new(b"glyf");
+/// assert_eq!(GLYF.to_u32(), 0x676C7966);
+/// assert_eq!(GLYF.to_string(), "glyf");
+/// assert_eq!("CFF".parse(), Ok(FourCharCode::new(b"CFF ")));
+/// assert_eq!(FourCharCode::from_u32(1).to_string(), "0x00000001");
+/// ```
+///
+/// [`FromStr`]: #impl-FromStr-for-FourCharCode
+#[repr(transparent)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FourCharCode(u32);
+
+impl FourCharCode {
+    #[inline]
+    pub const fn new(bytes: &[u8; 4]) -> FourCharCode {
+        FourCharCode(u32::from_be_bytes(*bytes))
+    }
+
+    #[inline]
+    pub const fn from_u32(code: u32) -> FourCharCode {
+        FourCharCode(code)
+    }
+
+    #[inline]
+    pub const fn to_u32(self) -> u32 {
+        self.0
+    }
+
+    #[inline]
+    pub const fn to_bytes(self) -> [u8; 4] {
+        self.0.to_be_bytes()
+    }
+
+    /// Returns whether every byte is printable ASCII, including space.
+    pub const fn is_printable(self) -> bool {
+        let bytes = self.to_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            if !matches!(bytes[i], b' '..=b'~') {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+}
+
+impl fmt::Display for FourCharCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_printable() {
+            // Printable ASCII is always valid UTF-8.
+            f.write_str(std::str::from_utf8(&self.to_bytes()).unwrap())
+        } else {
+            write!(f, "0x{:08X}", self.0)
+        }
+    }
+}
+
+impl fmt::Debug for FourCharCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_printable() {
+            write!(f, "FourCharCode({:?})", self.to_string())
+        } else {
+            write!(f, "FourCharCode(0x{:08X})", self.0)
+        }
+    }
+}
+
+/// An error parsing a [`FourCharCode`] from a string.
+///
+/// [`FourCharCode`]: struct.FourCharCode.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseFourCharCodeError {
+    Empty,
+    /// The string is longer than four bytes and is not a `0x` followed by eight hex digits.
+    TooLong,
+    /// The character at this byte offset is not printable ASCII.
+    InvalidCharacter(usize),
+}
+
+impl fmt::Display for ParseFourCharCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseFourCharCodeError::Empty => f.write_str("empty four-character code"),
+            ParseFourCharCodeError::TooLong => {
+                f.write_str("four-character code is longer than four characters")
+            }
+            ParseFourCharCodeError::InvalidCharacter(offset) => write!(
+                f,
+                "four-character code has a non-printable character at byte {}",
+                offset
+            ),
+        }
+    }
+}
+
+impl Error for ParseFourCharCodeError {}
+
+impl FromStr for FourCharCode {
+    type Err = ParseFourCharCodeError;
+
+    fn from_str(s: &str) -> Result<FourCharCode, ParseFourCharCodeError> {
+        if let Some(hex) = s.strip_prefix("0x").filter(|hex| hex.len() == 8) {
+            return u32::from_str_radix(hex, 16)
+                .map(FourCharCode)
+                .map_err(|_| ParseFourCharCodeError::TooLong);
+        }
+        if let Some(offset) = s.bytes().position(|b| !matches!(b, b' '..=b'~')) {
+            return Err(ParseFourCharCodeError::InvalidCharacter(offset));
+        }
+        match s.len() {
+            0 => Err(ParseFourCharCodeError::Empty),
+            len if len > 4 => Err(ParseFourCharCodeError::TooLong),
+            len => {
+                let mut bytes = [b' '; 4];
+                bytes[..len].copy_from_slice(s.as_bytes());
+                Ok(FourCharCode::new(&bytes))
+            }
+        }
+    }
+}
+
+impl From<u32> for FourCharCode {
+    #[inline]
+    fn from(code: u32) -> FourCharCode {
+        FourCharCode(code)
+    }
+}
+
+impl From<FourCharCode> for u32 {
+    #[inline]
+    fn from(code: FourCharCode) -> u32 {
+        code.0
+    }
+}
+
+impl From<[u8; 4]> for FourCharCode {
+    #[inline]
+    fn from(bytes: [u8; 4]) -> FourCharCode {
+        FourCharCode::new(&bytes)
+    }
+}
+
+impl From<&[u8; 4]> for FourCharCode {
+    #[inline]
+    fn from(bytes: &[u8; 4]) -> FourCharCode {
+        FourCharCode::new(bytes)
+    }
+}
+
+impl From<FourCharCode> for [u8; 4] {
+    #[inline]
+    fn from(code: FourCharCode) -> [u8; 4] {
+        code.to_bytes()
+    }
+}
+
+impl PartialEq<u32> for FourCharCode {
+    #[inline]
+    fn eq(&self, other: &u32) -> bool {
+        self.0 == *other
+    }
+}
@@ -248,6 +248,7 @@ pub mod date;
 pub mod dictionary;
 pub mod error;
 pub mod filedescriptor;
+pub mod four_char_code;
 pub mod mach_port;
 pub mod number;
 pub mod propertylist;
@@ -23,6 +23,19 @@ use core_foundation_sys::base::{
 use core_foundation_sys::error::CFErrorRef;
 pub use core_foundation_sys::propertylist::*;
 
+pub mod binary;
+pub mod openstep;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod value;
+pub mod xml;
+
+pub use self::value::{Dictionary, Value};
+
+/// The deepest nesting of arrays and dictionaries that the property list readers accept, so
+/// that hostile input cannot overflow the stack.
+pub const MAX_DEPTH: usize = 512;
+
 pub fn create_with_data(
     data: CFData,
     options: CFPropertyListMutabilityOptions,
@@ -281,6 +294,65 @@ pub mod test {
         }
     }
 
+    #[test]
+    #[cfg(any(
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "tvos",
+        target_os = "watchos",
+        target_os = "visionos"
+    ))]
+    fn value_to_CFPropertyList() {
+        let value = Value::Dictionary(
+            vec![
+                ("String", Value::from("Hello")),
+                ("Integer", Value::from(-42)),
+                ("Large", Value::from(u64::MAX)),
+                ("Real", Value::from(0.5)),
+                ("Boolean", Value::from(true)),
+                ("Data", Value::Data(vec![1, 2, 3])),
+                ("Date", Value::Date(123.0)),
+                (
+                    "Array",
+                    Value::Array(vec![Value::from("a"), Value::from(1)]),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+        );
+        let plist = value.to_CFPropertyList();
+        assert_eq!(Value::from_CFPropertyList(&plist), Some(value.clone()));
+
+        let data = create_data(plist.as_CFTypeRef(), kCFPropertyListXMLFormat_v1_0).unwrap();
+        assert_eq!(xml::from_bytes(data.bytes()).as_ref(), Ok(&value));
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "serde",
+        any(
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "tvos",
+            target_os = "watchos",
+            target_os = "visionos"
+        )
+    ))]
+    fn serde_to_CFPropertyList() {
+        use std::collections::BTreeMap;
+
+        let mut map = BTreeMap::new();
+        map.insert("one".to_owned(), vec![1u32]);
+        map.insert("two".to_owned(), vec![2, 2]);
+
+        let plist = super::serde::to_CFPropertyList(&map).unwrap();
+        let data = create_data(plist.as_CFTypeRef(), kCFPropertyListBinaryFormat_v1_0).unwrap();
+        let (plist, _) = create_with_data(data, kCFPropertyListImmutable).unwrap();
+        let plist = unsafe { CFPropertyList::wrap_under_create_rule(plist) };
+        let back: BTreeMap<String, Vec<u32>> = super::serde::from_CFPropertyList(&plist).unwrap();
+        assert_eq!(back, map);
+    }
+
     #[test]
     fn to_propertylist_retain_count() {
         let string = CFString::from_static_string("alongerstring");
@@ -0,0 +1,526 @@
+// Copyright 2026 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A pure-Rust reader and writer for `kCFPropertyListBinaryFormat_v1_0` (`bplist00`) data.
+//!
+//! Unlike [`create_with_data`] and [`create_data`], these functions do not call into
+//! CoreFoundation and work on any platform.
+//!
+//! [`create_with_data`]: ../fn.create_with_data.html
+//! [`create_data`]: ../fn.create_data.html
+
+use std::collections::HashMap;
+use std::error;
+use std::fmt;
+
+use super::value::{Dictionary, Value};
+use super::MAX_DEPTH;
+
+const MAGIC: &[u8; 8] = b"bplist00";
+const TRAILER_SIZE: usize = 32;
+
+const MARKER_FALSE: u8 = 0x08;
+const MARKER_TRUE: u8 = 0x09;
+const MARKER_INT: u8 = 0x10;
+const MARKER_REAL: u8 = 0x20;
+const MARKER_DATE: u8 = 0x33;
+const MARKER_DATA: u8 = 0x40;
+const MARKER_ASCII_STRING: u8 = 0x50;
+const MARKER_UTF16_STRING: u8 = 0x60;
+const MARKER_UID: u8 = 0x80;
+const MARKER_ARRAY: u8 = 0xA0;
+const MARKER_DICT: u8 = 0xD0;
+
+/// An error produced while reading or writing a binary property list.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The data does not start with `bplist00` or is too short to hold a trailer.
+    InvalidHeader,
+    /// The trailer describes an offset table or object count that does not fit in the data.
+    InvalidTrailer,
+    /// The object at `offset` is truncated or malformed.
+    InvalidObject { offset: usize },
+    /// An object reference points outside the object table.
+    InvalidReference { index: u64 },
+    /// The container at `offset` contains itself, directly or indirectly.
+    RecursiveObject { offset: usize },
+    /// The container at `offset` is nested more than [`MAX_DEPTH`] levels deep.
+    ///
+    /// [`MAX_DEPTH`]: ../constant.MAX_DEPTH.html
+    TooDeep { offset: usize },
+    /// Containers that are referenced many times would decode to more objects than the data
+    /// has object references.
+    TooManyObjects,
+    /// The dictionary key at `offset` is not a string.
+    InvalidKey { offset: usize },
+    /// The object at `offset` is valid in a `bplist00` file but has no [`Value`] equivalent,
+    /// such as a set or a null.
+    ///
+    /// [`Value`]: ../enum.Value.html
+    UnsupportedObject { offset: usize, marker: u8 },
+    /// An integer outside of `i64::MIN..=u64::MAX` cannot be written.
+    IntegerOutOfRange(i128),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::InvalidHeader => write!(f, "not a binary property list"),
+            Error::InvalidTrailer => write!(f, "invalid binary property list trailer"),
+            Error::InvalidObject { offset } => write!(f, "invalid object at offset {}", offset),
+            Error::InvalidReference { index } => write!(f, "invalid object reference {}", index),
+            Error::RecursiveObject { offset } => {
+                write!(f, "object at offset {} contains itself", offset)
+            }
+            Error::TooDeep { offset } => write!(
+                f,
+                "container at offset {} is nested more than {} levels deep",
+                offset, MAX_DEPTH
+            ),
+            Error::TooManyObjects => write!(f, "shared containers expand to too many objects"),
+            Error::InvalidKey { offset } => {
+                write!(f, "dictionary key at offset {} is not a string", offset)
+            }
+            Error::UnsupportedObject { offset, marker } => write!(
+                f,
+                "unsupported object with marker {:#04x} at offset {}",
+                marker, offset
+            ),
+            Error::IntegerOutOfRange(value) => {
+                write!(f, "integer {} cannot be stored in a property list", value)
+            }
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+/// Decodes a binary property list.
+pub fn from_bytes(bytes: &[u8]) -> Result<Value, Error> {
+    if bytes.len() < MAGIC.len() + TRAILER_SIZE || !bytes.starts_with(MAGIC) {
+        return Err(Error::InvalidHeader);
+    }
+
+    let trailer = &bytes[bytes.len() - TRAILER_SIZE..];
+    let offset_int_size = trailer[6] as usize;
+    let object_ref_size = trailer[7] as usize;
+    let num_objects = read_uint(&trailer[8..16]);
+    let top_object = read_uint(&trailer[16..24]);
+    let offset_table_offset = read_uint(&trailer[24..32]);
+
+    let table_end = bytes.len() - TRAILER_SIZE;
+    if !(1..=8).contains(&offset_int_size)
+        || !(1..=8).contains(&object_ref_size)
+        || num_objects == 0
+        || top_object >= num_objects
+        || offset_table_offset < MAGIC.len() as u64
+        || offset_table_offset > table_end as u64
+    {
+        return Err(Error::InvalidTrailer);
+    }
+    let num_objects = num_objects as usize;
+    let offset_table_offset = offset_table_offset as usize;
+    match num_objects.checked_mul(offset_int_size) {
+        Some(size) if size <= table_end - offset_table_offset => {}
+        _ => return Err(Error::InvalidTrailer),
+    }
+
+    let offsets = bytes[offset_table_offset..]
+        .chunks(offset_int_size)
+        .take(num_objects)
+        .map(|chunk| {
+            let offset = read_uint(chunk);
+            if offset < MAGIC.len() as u64 || offset >= offset_table_offset as u64 {
+                Err(Error::InvalidTrailer)
+            } else {
+                Ok(offset as usize)
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut reader = Reader {
+        bytes: &bytes[..offset_table_offset],
+        offsets,
+        object_ref_size,
+        stack: Vec::new(),
+        // Every object but the top one is decoded from a reference, which takes at least one
+        // byte, unless containers are shared.
+        remaining: offset_table_offset,
+    };
+    reader.read_object(top_object)
+}
+
+/// Encodes a value as a binary property list.
+///
+/// Equal strings, numbers, dates and data are written once and shared, as CoreFoundation does.
+pub fn to_bytes(value: &Value) -> Result<Vec<u8>, Error> {
+    let mut writer = Writer {
+        objects: Vec::new(),
+        uniques: HashMap::new(),
+    };
+    writer.flatten(value)?;
+
+    let object_ref_size = int_size(writer.objects.len() as u64 - 1);
+    let mut bytes = MAGIC.to_vec();
+    let mut offsets = Vec::with_capacity(writer.objects.len());
+    for object in &writer.objects {
+        offsets.push(bytes.len() as u64);
+        match object {
+            Object::Scalar(encoded) => bytes.extend_from_slice(encoded),
+            Object::Array(refs) => {
+                write_length(&mut bytes, MARKER_ARRAY, refs.len());
+                for &index in refs {
+                    write_uint(&mut bytes, index as u64, object_ref_size);
+                }
+            }
+            Object::Dictionary(keys, values) => {
+                write_length(&mut bytes, MARKER_DICT, keys.len());
+                for &index in keys.iter().chain(values) {
+                    write_uint(&mut bytes, index as u64, object_ref_size);
+                }
+            }
+        }
+    }
+
+    let offset_table_offset = bytes.len() as u64;
+    let offset_int_size = int_size(offsets.last().cloned().unwrap_or(0));
+    for &offset in &offsets {
+        write_uint(&mut bytes, offset, offset_int_size);
+    }
+
+    bytes.extend_from_slice(&[0; 6]);
+    bytes.push(offset_int_size as u8);
+    bytes.push(object_ref_size as u8);
+    bytes.extend_from_slice(&(offsets.len() as u64).to_be_bytes());
+    bytes.extend_from_slice(&0u64.to_be_bytes());
+    bytes.extend_from_slice(&offset_table_offset.to_be_bytes());
+    Ok(bytes)
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offsets: Vec<usize>,
+    object_ref_size: usize,
+    /// Indices of the containers currently being decoded, used to detect cycles.
+    stack: Vec<usize>,
+    /// How many more objects can be decoded, which bounds the work that containers referenced
+    /// from several places can cause.
+    remaining: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn read_object(&mut self, index: u64) -> Result<Value, Error> {
+        let offset = *self
+            .offsets
+            .get(index as usize)
+            .ok_or(Error::InvalidReference { index })?;
+        self.remaining = self.remaining.checked_sub(1).ok_or(Error::TooManyObjects)?;
+        // Containers are decoded in their own functions to keep the frames of this recursion
+        // small.
+        match self.bytes[offset] & 0xF0 {
+            MARKER_ARRAY => self.read_array(index, offset),
+            MARKER_DICT => self.read_dictionary(index, offset),
+            _ => self.read_scalar(offset),
+        }
+    }
+
+    fn read_array(&mut self, index: u64, offset: usize) -> Result<Value, Error> {
+        let refs = self.enter(index, offset, 1)?;
+        let array = refs
+            .into_iter()
+            .map(|index| self.read_object(index))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.stack.pop();
+        Ok(Value::Array(array))
+    }
+
+    fn read_dictionary(&mut self, index: u64, offset: usize) -> Result<Value, Error> {
+        let refs = self.enter(index, offset, 2)?;
+        let (keys, values) = refs.split_at(refs.len() / 2);
+        let dict = keys
+            .iter()
+            .zip(values)
+            .map(|(&key, &value)| match self.read_object(key)? {
+                Value::String(key) => Ok((key, self.read_object(value)?)),
+                _ => Err(Error::InvalidKey {
+                    offset: self.offsets[key as usize],
+                }),
+            })
+            .collect::<Result<Dictionary, _>>()?;
+        self.stack.pop();
+        Ok(Value::Dictionary(dict))
+    }
+
+    fn read_scalar(&self, offset: usize) -> Result<Value, Error> {
+        let marker = self.bytes[offset];
+        let invalid = Error::InvalidObject { offset };
+        let low = marker & 0x0F;
+
+        match marker & 0xF0 {
+            0x00 if marker == MARKER_FALSE => Ok(Value::Boolean(false)),
+            0x00 if marker == MARKER_TRUE => Ok(Value::Boolean(true)),
+            MARKER_INT if low <= 4 => {
+                let bytes = self.slice(offset + 1, 1 << low, offset)?;
+                let value = match bytes.len() {
+                    8 => read_uint(bytes) as i64 as i128,
+                    16 => {
+                        let value = i128::from_be_bytes(bytes.try_into().unwrap());
+                        if value < i64::MIN as i128 || value > u64::MAX as i128 {
+                            return Err(invalid);
+                        }
+                        value
+                    }
+                    _ => read_uint(bytes) as i128,
+                };
+                Ok(Value::Integer(value))
+            }
+            MARKER_REAL if low == 2 => {
+                let bytes = self.slice(offset + 1, 4, offset)?;
+                Ok(Value::Real(f32::from_bits(read_uint(bytes) as u32) as f64))
+            }
+            MARKER_REAL if low == 3 => {
+                let bytes = self.slice(offset + 1, 8, offset)?;
+                Ok(Value::Real(f64::from_bits(read_uint(bytes))))
+            }
+            0x30 if marker == MARKER_DATE => {
+                let bytes = self.slice(offset + 1, 8, offset)?;
+                Ok(Value::Date(f64::from_bits(read_uint(bytes))))
+            }
+            MARKER_DATA => {
+                let (len, start) = self.read_length(offset)?;
+                Ok(Value::Data(self.slice(start, len, offset)?.to_vec()))
+            }
+            MARKER_ASCII_STRING => {
+                let (len, start) = self.read_length(offset)?;
+                let bytes = self.slice(start, len, offset)?;
+                Ok(Value::String(bytes.iter().map(|&b| b as char).collect()))
+            }
+            MARKER_UTF16_STRING => {
+                let (len, start) = self.read_length(offset)?;
+                let len = len.checked_mul(2).ok_or(invalid)?;
+                let units = self
+                    .slice(start, len, offset)?
+                    .chunks(2)
+                    .map(|unit| u16::from_be_bytes([unit[0], unit[1]]))
+                    .collect::<Vec<_>>();
+                String::from_utf16(&units)
+                    .map(Value::String)
+                    .map_err(|_| invalid)
+            }
+            MARKER_UID if low <= 7 => {
+                let bytes = self.slice(offset + 1, low as usize + 1, offset)?;
+                Ok(Value::Uid(read_uint(bytes)))
+            }
+            _ => Err(Error::UnsupportedObject { offset, marker }),
+        }
+    }
+
+    /// Pushes the container at `index` onto the stack and reads its `count * per_entry` object
+    /// references.
+    fn enter(&mut self, index: u64, offset: usize, per_entry: usize) -> Result<Vec<u64>, Error> {
+        if self.stack.contains(&(index as usize)) {
+            return Err(Error::RecursiveObject { offset });
+        }
+        if self.stack.len() >= MAX_DEPTH {
+            return Err(Error::TooDeep { offset });
+        }
+        let (count, start) = self.read_length(offset)?;
+        let len = count
+            .checked_mul(per_entry * self.object_ref_size)
+            .ok_or(Error::InvalidObject { offset })?;
+        let refs = self
+            .slice(start, len, offset)?
+            .chunks(self.object_ref_size)
+            .map(read_uint)
+            .collect();
+        self.stack.push(index as usize);
+        Ok(refs)
+    }
+
+    /// Reads the element count of a data, string or container object, returning it with the
+    /// offset of the first byte after it.
+    fn read_length(&self, offset: usize) -> Result<(usize, usize), Error> {
+        let low = self.bytes[offset] & 0x0F;
+        if low != 0x0F {
+            return Ok((low as usize, offset + 1));
+        }
+        let invalid = Error::InvalidObject { offset };
+        let marker = *self.bytes.get(offset + 1).ok_or(invalid)?;
+        if marker & 0xF0 != MARKER_INT || marker & 0x0F > 3 {
+            return Err(invalid);
+        }
+        let size = 1 << (marker & 0x0F);
+        let len = read_uint(self.slice(offset + 2, size, offset)?);
+        if len > usize::MAX as u64 {
+            return Err(invalid);
+        }
+        Ok((len as usize, offset + 2 + size))
+    }
+
+    fn slice(&self, start: usize, len: usize, offset: usize) -> Result<&'a [u8], Error> {
+        start
+            .checked_add(len)
+            .and_then(|end| self.bytes.get(start..end))
+            .ok_or(Error::InvalidObject { offset })
+    }
+}
+
+enum Object {
+    Scalar(Vec<u8>),
+    Array(Vec<usize>),
+    Dictionary(Vec<usize>, Vec<usize>),
+}
+
+struct Writer {
+    objects: Vec<Object>,
+    /// Maps the encoding of each scalar written so far to its object index.
+    uniques: HashMap<Vec<u8>, usize>,
+}
+
+impl Writer {
+    fn flatten(&mut self, value: &Value) -> Result<usize, Error> {
+        match value {
+            Value::Array(array) => {
+                let index = self.reserve();
+                let refs = array
+                    .iter()
+                    .map(|value| self.flatten(value))
+                    .collect::<Result<_, _>>()?;
+                self.objects[index] = Object::Array(refs);
+                Ok(index)
+            }
+            Value::Dictionary(dict) => {
+                let index = self.reserve();
+                let mut keys = Vec::with_capacity(dict.len());
+                for key in dict.keys() {
+                    keys.push(self.scalar(encode_string(key)));
+                }
+                let values = dict
+                    .values()
+                    .map(|value| self.flatten(value))
+                    .collect::<Result<_, _>>()?;
+                self.objects[index] = Object::Dictionary(keys, values);
+                Ok(index)
+            }
+            Value::Boolean(false) => Ok(self.scalar(vec![MARKER_FALSE])),
+            Value::Boolean(true) => Ok(self.scalar(vec![MARKER_TRUE])),
+            Value::Data(data) => {
+                let mut encoded = Vec::with_capacity(data.len() + 1);
+                write_length(&mut encoded, MARKER_DATA, data.len());
+                encoded.extend_from_slice(data);
+                Ok(self.scalar(encoded))
+            }
+            Value::Date(date) => {
+                let mut encoded = vec![MARKER_DATE];
+                encoded.extend_from_slice(&date.to_be_bytes());
+                Ok(self.scalar(encoded))
+            }
+            Value::Integer(value) => {
+                let mut encoded = Vec::with_capacity(17);
+                write_int(&mut encoded, *value)?;
+                Ok(self.scalar(encoded))
+            }
+            Value::Real(value) => {
+                let mut encoded = vec![MARKER_REAL | 3];
+                encoded.extend_from_slice(&value.to_be_bytes());
+                Ok(self.scalar(encoded))
+            }
+            Value::String(string) => Ok(self.scalar(encode_string(string))),
+            Value::Uid(uid) => {
+                let size = int_size(*uid);
+                let mut encoded = vec![MARKER_UID | (size as u8 - 1)];
+                write_uint(&mut encoded, *uid, size);
+                Ok(self.scalar(encoded))
+            }
+        }
+    }
+
+    fn reserve(&mut self) -> usize {
+        self.objects.push(Object::Array(Vec::new()));
+        self.objects.len() - 1
+    }
+
+    fn scalar(&mut self, encoded: Vec<u8>) -> usize {
+        if let Some(&index) = self.uniques.get(&encoded) {
+            return index;
+        }
+        let index = self.objects.len();
+        self.objects.push(Object::Scalar(encoded.clone()));
+        self.uniques.insert(encoded, index);
+        index
+    }
+}
+
+fn encode_string(string: &str) -> Vec<u8> {
+    let mut encoded = Vec::with_capacity(string.len() + 1);
+    if string.is_ascii() {
+        write_length(&mut encoded, MARKER_ASCII_STRING, string.len());
+        encoded.extend_from_slice(string.as_bytes());
+    } else {
+        let units = string.encode_utf16().collect::<Vec<_>>();
+        write_length(&mut encoded, MARKER_UTF16_STRING, units.len());
+        for unit in units {
+            encoded.extend_from_slice(&unit.to_be_bytes());
+        }
+    }
+    encoded
+}
+
+fn write_length(bytes: &mut Vec<u8>, marker: u8, len: usize) {
+    if len < 0x0F {
+        bytes.push(marker | len as u8);
+    } else {
+        bytes.push(marker | 0x0F);
+        // Lengths always fit in an unsigned integer of at most eight bytes.
+        write_int(bytes, len as i128).unwrap();
+    }
+}
+
+fn write_int(bytes: &mut Vec<u8>, value: i128) -> Result<(), Error> {
+    if value < 0 {
+        if value < i64::MIN as i128 {
+            return Err(Error::IntegerOutOfRange(value));
+        }
+        bytes.push(MARKER_INT | 3);
+        bytes.extend_from_slice(&(value as i64).to_be_bytes());
+    } else if value > i64::MAX as i128 {
+        // CoreFoundation stores unsigned values that do not fit in an `i64` as 128-bit integers.
+        if value > u64::MAX as i128 {
+            return Err(Error::IntegerOutOfRange(value));
+        }
+        bytes.push(MARKER_INT | 4);
+        bytes.extend_from_slice(&value.to_be_bytes());
+    } else {
+        let size = int_size(value as u64);
+        bytes.push(MARKER_INT | size.trailing_zeros() as u8);
+        write_uint(bytes, value as u64, size);
+    }
+    Ok(())
+}
+
+/// Returns the number of bytes (1, 2, 4 or 8) needed to store `value`.
+fn int_size(value: u64) -> usize {
+    if value <= 0xFF {
+        1
+    } else if value <= 0xFFFF {
+        2
+    } else if value <= 0xFFFF_FFFF {
+        4
+    } else {
+        8
+    }
+}
+
+fn write_uint(bytes: &mut Vec<u8>, value: u64, size: usize) {
+    bytes.extend_from_slice(&value.to_be_bytes()[8 - size..]);
+}
+
+fn read_uint(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0, |acc, &b| (acc << 8) | b as u64)
+}
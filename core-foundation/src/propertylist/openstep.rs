@@ -0,0 +1,423 @@
+// Copyright 2026 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A pure-Rust reader and pretty-printer for `kCFPropertyListOpenStepFormat` data, the
+//! old-style `{ key = value; }` syntax used by `project.pbxproj` files and `.strings` files.
+//!
+//! The format only has strings, data, arrays and dictionaries. CoreFoundation can read it but
+//! not write it.
+
+use std::error;
+use std::fmt;
+
+use super::value::{Dictionary, Value};
+use super::MAX_DEPTH;
+
+/// An error produced while reading or writing an OpenStep property list.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The input is malformed. `line` and `column` are 1-based and count characters.
+    Syntax {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The value contains a boolean, number, date or UID, which the format cannot express.
+    UnsupportedValue,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Syntax {
+                line,
+                column,
+                message,
+            } => write!(f, "{} at line {}, column {}", message, line, column),
+            Error::UnsupportedValue => write!(
+                f,
+                "OpenStep property lists can only contain strings, data, arrays and dictionaries"
+            ),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+/// Decodes an OpenStep property list.
+///
+/// Like CoreFoundation, a top level made of `key = value;` pairs without enclosing braces is
+/// read as a dictionary, as found in `.strings` files.
+pub fn from_str(string: &str) -> Result<Value, Error> {
+    let mut parser = Parser {
+        input: string,
+        pos: 0,
+        depth: 0,
+    };
+    parser.skip_whitespace()?;
+    if parser.at_end() {
+        return Ok(Value::Dictionary(Dictionary::new()));
+    }
+
+    let start = parser.pos;
+    let value = parser.value()?;
+    parser.skip_whitespace()?;
+    if parser.at_end() {
+        return Ok(value);
+    }
+    if let Value::String(_) = value {
+        if parser.peek() == Some('=') || parser.peek() == Some(';') {
+            parser.pos = start;
+            return parser.entries(None).map(Value::Dictionary);
+        }
+    }
+    Err(parser.error("unexpected content after the top-level value"))
+}
+
+/// Pretty-prints a value in the OpenStep format.
+///
+/// The output is deterministic: dictionaries keep their order, nesting is indented with tabs,
+/// and strings are only quoted when they contain characters that cannot appear unquoted.
+pub fn to_string(value: &Value) -> Result<String, Error> {
+    let mut out = String::new();
+    write_value(&mut out, value, 0)?;
+    out.push('\n');
+    Ok(out)
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+    /// The number of arrays and dictionaries being parsed.
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn value(&mut self) -> Result<Value, Error> {
+        match self.peek() {
+            Some('{') | Some('(') => self.container(),
+            Some('<') => {
+                self.pos += 1;
+                self.data().map(Value::Data)
+            }
+            Some('"') | Some('\'') => self.quoted_string().map(Value::String),
+            Some(c) if is_unquoted(c) => Ok(Value::String(self.unquoted_string())),
+            Some(_) => Err(self.error("unexpected character")),
+            None => Err(self.error("unexpected end of input")),
+        }
+    }
+
+    fn container(&mut self) -> Result<Value, Error> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error("arrays and dictionaries nested too deeply"));
+        }
+        self.depth += 1;
+        let open = self.next_char();
+        let value = match open {
+            Some('{') => self.entries(Some('}')).map(Value::Dictionary),
+            _ => self.array().map(Value::Array),
+        };
+        self.depth -= 1;
+        value
+    }
+
+    /// Reads `key = value;` pairs up to `close`, or to the end of the input if `close` is
+    /// `None`.
+    fn entries(&mut self, close: Option<char>) -> Result<Dictionary, Error> {
+        // Collecting the entries at the end finds duplicate keys without a search for each key.
+        let mut entries = Vec::new();
+        loop {
+            self.skip_whitespace()?;
+            if close.is_some() && self.peek() == close {
+                self.pos += 1;
+                return Ok(entries.into_iter().collect());
+            }
+            if close.is_none() && self.at_end() {
+                return Ok(entries.into_iter().collect());
+            }
+
+            let key_pos = self.pos;
+            let key = match self.value()? {
+                Value::String(key) => key,
+                _ => return Err(self.error_at(key_pos, "dictionary keys must be strings")),
+            };
+            self.skip_whitespace()?;
+            let value = match self.peek() {
+                Some('=') => {
+                    self.pos += 1;
+                    self.skip_whitespace()?;
+                    self.value()?
+                }
+                // `.strings` files may list a key on its own, meaning it maps to itself.
+                Some(';') if close.is_none() => Value::String(key.clone()),
+                _ => return Err(self.error("expected `=`")),
+            };
+            self.skip_whitespace()?;
+            self.expect(';')?;
+            entries.push((key, value));
+        }
+    }
+
+    fn array(&mut self) -> Result<Vec<Value>, Error> {
+        let mut array = Vec::new();
+        loop {
+            self.skip_whitespace()?;
+            if self.peek() == Some(')') {
+                self.pos += 1;
+                return Ok(array);
+            }
+            array.push(self.value()?);
+            self.skip_whitespace()?;
+            match self.peek() {
+                Some(',') => self.pos += 1,
+                Some(')') => {}
+                _ => return Err(self.error("expected `,` or `)`")),
+            }
+        }
+    }
+
+    fn data(&mut self) -> Result<Vec<u8>, Error> {
+        let mut data = Vec::new();
+        let mut high = None;
+        loop {
+            let c = match self.peek() {
+                Some(c) => c,
+                None => return Err(self.error("unterminated data")),
+            };
+            if c == '>' {
+                if high.is_some() {
+                    return Err(self.error("odd number of hex digits in data"));
+                }
+                self.pos += 1;
+                return Ok(data);
+            }
+            if !c.is_whitespace() {
+                let digit = c
+                    .to_digit(16)
+                    .ok_or_else(|| self.error("invalid hex digit in data"))?
+                    as u8;
+                match high.take() {
+                    Some(high) => data.push(high << 4 | digit),
+                    None => high = Some(digit),
+                }
+            }
+            self.pos += c.len_utf8();
+        }
+    }
+
+    fn quoted_string(&mut self) -> Result<String, Error> {
+        let start = self.pos;
+        let quote = self.next_char().unwrap();
+        let mut string = String::new();
+        loop {
+            let c = match self.next_char() {
+                Some(c) => c,
+                None => return Err(self.error_at(start, "unterminated string")),
+            };
+            if c == quote {
+                return Ok(string);
+            }
+            if c != '\\' {
+                string.push(c);
+                continue;
+            }
+
+            let escape_pos = self.pos - 1;
+            let c = match self.next_char() {
+                Some('a') => '\x07',
+                Some('b') => '\x08',
+                Some('f') => '\x0C',
+                Some('n') => '\n',
+                Some('r') => '\r',
+                Some('t') => '\t',
+                Some('v') => '\x0B',
+                Some('U') => {
+                    let hex = self.input[self.pos..]
+                        .get(..4)
+                        .filter(|hex| hex.chars().all(|c| c.is_ascii_hexdigit()))
+                        .ok_or_else(|| self.error_at(escape_pos, "invalid \\U escape"))?;
+                    self.pos += 4;
+                    let unit = u16::from_str_radix(hex, 16).unwrap();
+                    self.utf16_escape(unit, escape_pos)?
+                }
+                Some(c @ '0'..='7') => {
+                    let mut code = c.to_digit(8).unwrap();
+                    for _ in 0..2 {
+                        match self.peek().and_then(|c| c.to_digit(8)) {
+                            Some(digit) => {
+                                code = code * 8 + digit;
+                                self.pos += 1;
+                            }
+                            None => break,
+                        }
+                    }
+                    char::from_u32(code).unwrap()
+                }
+                Some(c) => c,
+                None => return Err(self.error_at(start, "unterminated string")),
+            };
+            string.push(c);
+        }
+    }
+
+    /// Decodes a `\U` escape, combining a high surrogate with a following `\U` low surrogate.
+    fn utf16_escape(&mut self, unit: u16, escape_pos: usize) -> Result<char, Error> {
+        if let Some(c) = char::from_u32(unit as u32) {
+            return Ok(c);
+        }
+        let rest = &self.input[self.pos..];
+        let low = rest
+            .strip_prefix("\\U")
+            .and_then(|rest| rest.get(..4))
+            .and_then(|hex| u16::from_str_radix(hex, 16).ok());
+        match low.map(|low| char::decode_utf16([unit, low]).next().unwrap()) {
+            Some(Ok(c)) => {
+                self.pos += 6;
+                Ok(c)
+            }
+            _ => Err(self.error_at(escape_pos, "unpaired surrogate in \\U escape")),
+        }
+    }
+
+    fn unquoted_string(&mut self) -> String {
+        let rest = &self.input[self.pos..];
+        let end = rest.find(|c| !is_unquoted(c)).unwrap_or(rest.len());
+        self.pos += end;
+        rest[..end].to_owned()
+    }
+
+    /// Skips whitespace and `//` and `/* */` comments.
+    fn skip_whitespace(&mut self) -> Result<(), Error> {
+        loop {
+            let rest = &self.input[self.pos..];
+            let trimmed = rest.trim_start();
+            self.pos += rest.len() - trimmed.len();
+            if trimmed.starts_with("//") {
+                self.pos += trimmed.find('\n').unwrap_or(trimmed.len());
+            } else if let Some(comment) = trimmed.strip_prefix("/*") {
+                let end = comment
+                    .find("*/")
+                    .ok_or_else(|| self.error("unterminated comment"))?;
+                self.pos += end + 4;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), Error> {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected `{}`", c)))
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn next_char(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn at_end(&self) -> bool {
+        self.pos == self.input.len()
+    }
+
+    fn error(&self, message: &str) -> Error {
+        self.error_at(self.pos, message)
+    }
+
+    fn error_at(&self, pos: usize, message: &str) -> Error {
+        let before = &self.input[..pos];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Error::Syntax {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            message: message.to_owned(),
+        }
+    }
+}
+
+/// The characters CoreFoundation allows in unquoted strings.
+fn is_unquoted(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "_$/:.-".contains(c)
+}
+
+fn write_value(out: &mut String, value: &Value, depth: usize) -> Result<(), Error> {
+    match value {
+        Value::Array(array) if array.is_empty() => out.push_str("()"),
+        Value::Array(array) => {
+            out.push_str("(\n");
+            for value in array {
+                indent(out, depth + 1);
+                write_value(out, value, depth + 1)?;
+                out.push_str(",\n");
+            }
+            indent(out, depth);
+            out.push(')');
+        }
+        Value::Dictionary(dict) if dict.is_empty() => out.push_str("{}"),
+        Value::Dictionary(dict) => {
+            out.push_str("{\n");
+            for (key, value) in dict {
+                indent(out, depth + 1);
+                write_string(out, key);
+                out.push_str(" = ");
+                write_value(out, value, depth + 1)?;
+                out.push_str(";\n");
+            }
+            indent(out, depth);
+            out.push('}');
+        }
+        Value::Data(data) => {
+            out.push('<');
+            for (i, byte) in data.iter().enumerate() {
+                if i > 0 && i % 4 == 0 {
+                    out.push(' ');
+                }
+                out.push_str(&format!("{:02x}", byte));
+            }
+            out.push('>');
+        }
+        Value::String(string) => write_string(out, string),
+        Value::Boolean(_) | Value::Date(_) | Value::Integer(_) | Value::Real(_) | Value::Uid(_) => {
+            return Err(Error::UnsupportedValue)
+        }
+    }
+    Ok(())
+}
+
+fn write_string(out: &mut String, string: &str) {
+    if !string.is_empty() && string.chars().all(is_unquoted) {
+        out.push_str(string);
+        return;
+    }
+    out.push('"');
+    for c in string.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            c if c.is_control() => out.push_str(&format!("\\U{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+fn indent(out: &mut String, depth: usize) {
+    out.extend(std::iter::repeat('\t').take(depth));
+}
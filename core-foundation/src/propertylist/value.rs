@@ -0,0 +1,465 @@
+// Copyright 2026 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! An owned property list value tree that does not depend on CoreFoundation.
+
+use std::collections::hash_map::{Entry, HashMap};
+use std::slice;
+use std::vec;
+
+use core_foundation_sys::date::CFAbsoluteTime;
+
+/// An owned property list value.
+///
+/// Each variant mirrors one of the [`CFPropertyListSubClass`] types, with [`CFNumber`] split into
+/// `Integer` and `Real`. `Uid` only appears in binary property lists written by
+/// `NSKeyedArchiver`.
+///
+/// [`CFPropertyListSubClass`]: ../trait.CFPropertyListSubClass.html
+/// [`CFNumber`]: ../../number/struct.CFNumber.html
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Array(Vec<Value>),
+    Dictionary(Dictionary),
+    Boolean(bool),
+    Data(Vec<u8>),
+    /// Seconds relative to the CoreFoundation reference date, 2001-01-01T00:00:00Z.
+    Date(CFAbsoluteTime),
+    /// Integers in the range `i64::MIN..=u64::MAX`; property lists cannot encode anything larger.
+    Integer(i128),
+    Real(f64),
+    String(String),
+    Uid(u64),
+}
+
+impl Value {
+    pub fn as_array(&self) -> Option<&Vec<Value>> {
+        match self {
+            Value::Array(array) => Some(array),
+            _ => None,
+        }
+    }
+
+    pub fn as_dictionary(&self) -> Option<&Dictionary> {
+        match self {
+            Value::Dictionary(dict) => Some(dict),
+            _ => None,
+        }
+    }
+
+    pub fn as_boolean(&self) -> Option<bool> {
+        match *self {
+            Value::Boolean(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_data(&self) -> Option<&[u8]> {
+        match self {
+            Value::Data(data) => Some(data),
+            _ => None,
+        }
+    }
+
+    pub fn as_date(&self) -> Option<CFAbsoluteTime> {
+        match *self {
+            Value::Date(date) => Some(date),
+            _ => None,
+        }
+    }
+
+    pub fn as_integer(&self) -> Option<i128> {
+        match *self {
+            Value::Integer(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a float, converting integers the same way `CFNumberGetValue` does.
+    pub fn as_real(&self) -> Option<f64> {
+        match *self {
+            Value::Real(value) => Some(value),
+            Value::Integer(value) => Some(value as f64),
+            _ => None,
+        }
+    }
+
+    pub fn as_string(&self) -> Option<&str> {
+        match self {
+            Value::String(string) => Some(string),
+            _ => None,
+        }
+    }
+
+    pub fn as_uid(&self) -> Option<u64> {
+        match *self {
+            Value::Uid(uid) => Some(uid),
+            _ => None,
+        }
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Value {
+        Value::Boolean(value)
+    }
+}
+
+impl From<i32> for Value {
+    fn from(value: i32) -> Value {
+        Value::Integer(value.into())
+    }
+}
+
+impl From<i64> for Value {
+    fn from(value: i64) -> Value {
+        Value::Integer(value.into())
+    }
+}
+
+impl From<u64> for Value {
+    fn from(value: u64) -> Value {
+        Value::Integer(value.into())
+    }
+}
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Value {
+        Value::Real(value)
+    }
+}
+
+impl<'a> From<&'a str> for Value {
+    fn from(value: &'a str) -> Value {
+        Value::String(value.to_owned())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Value {
+        Value::String(value)
+    }
+}
+
+impl From<Vec<u8>> for Value {
+    fn from(value: Vec<u8>) -> Value {
+        Value::Data(value)
+    }
+}
+
+impl From<Vec<Value>> for Value {
+    fn from(value: Vec<Value>) -> Value {
+        Value::Array(value)
+    }
+}
+
+impl From<Dictionary> for Value {
+    fn from(value: Dictionary) -> Value {
+        Value::Dictionary(value)
+    }
+}
+
+/// A property list dictionary with string keys.
+///
+/// Unlike `CFDictionary`, entries keep the order they were inserted or decoded in, so that
+/// reading and writing a file does not shuffle its keys.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Dictionary {
+    entries: Vec<(String, Value)>,
+}
+
+impl Dictionary {
+    #[inline]
+    pub fn new() -> Dictionary {
+        Dictionary::default()
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.entries.iter().find(|e| e.0 == key).map(|e| &e.1)
+    }
+
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut Value> {
+        self.entries
+            .iter_mut()
+            .find(|e| e.0 == key)
+            .map(|e| &mut e.1)
+    }
+
+    #[inline]
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Inserts a value, returning the previous value for `key`. Replacing a value keeps the
+    /// key's original position.
+    pub fn insert<K: Into<String>>(&mut self, key: K, value: Value) -> Option<Value> {
+        let key = key.into();
+        match self.get_mut(&key) {
+            Some(slot) => Some(std::mem::replace(slot, value)),
+            None => {
+                self.entries.push((key, value));
+                None
+            }
+        }
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<Value> {
+        let index = self.entries.iter().position(|e| e.0 == key)?;
+        Some(self.entries.remove(index).1)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> Iter<'_> {
+        Iter(self.entries.iter())
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(|e| &*e.0)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &Value> {
+        self.entries.iter().map(|e| &e.1)
+    }
+}
+
+impl<K: Into<String>> FromIterator<(K, Value)> for Dictionary {
+    fn from_iter<I: IntoIterator<Item = (K, Value)>>(iter: I) -> Dictionary {
+        let mut dict = Dictionary::new();
+        dict.extend(iter);
+        dict
+    }
+}
+
+impl<K: Into<String>> Extend<(K, Value)> for Dictionary {
+    /// Inserts every entry, as [`insert`](Dictionary::insert) does, using an index of the keys
+    /// rather than searching the entries for each one.
+    fn extend<I: IntoIterator<Item = (K, Value)>>(&mut self, iter: I) {
+        let mut positions: HashMap<String, usize> = self
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(i, e)| (e.0.clone(), i))
+            .collect();
+        for (key, value) in iter {
+            match positions.entry(key.into()) {
+                Entry::Occupied(entry) => self.entries[*entry.get()].1 = value,
+                Entry::Vacant(entry) => {
+                    self.entries.push((entry.key().clone(), value));
+                    entry.insert(self.entries.len() - 1);
+                }
+            }
+        }
+    }
+}
+
+pub struct Iter<'a>(slice::Iter<'a, (String, Value)>);
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = (&'a str, &'a Value);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|e| (&*e.0, &e.1))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for Iter<'a> {}
+
+impl<'a> IntoIterator for &'a Dictionary {
+    type Item = (&'a str, &'a Value);
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+impl IntoIterator for Dictionary {
+    type Item = (String, Value);
+    type IntoIter = vec::IntoIter<(String, Value)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+#[cfg(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "tvos",
+    target_os = "watchos",
+    target_os = "visionos"
+))]
+mod cf {
+    use core::ffi::c_void;
+
+    use core_foundation_sys::base::kCFAllocatorDefault;
+    use core_foundation_sys::number::{
+        CFNumberCreate, CFNumberGetValue, CFNumberIsFloatType, CFNumberType,
+    };
+
+    use super::{Dictionary, Value};
+    use crate::array::CFArray;
+    use crate::base::{CFType, TCFType};
+    use crate::boolean::CFBoolean;
+    use crate::data::CFData;
+    use crate::date::CFDate;
+    use crate::dictionary::CFDictionary;
+    use crate::number::CFNumber;
+    use crate::propertylist::{CFPropertyList, CFPropertyListSubClass};
+    use crate::string::CFString;
+
+    /// `kCFNumberSInt128Type`, which CoreFoundation uses for integers above `i64::MAX` in
+    /// property lists but does not declare publicly.
+    #[allow(non_upper_case_globals)]
+    const kCFNumberSInt128Type: CFNumberType = 17;
+
+    /// The layout of `CFSInt128Struct`.
+    #[repr(C)]
+    #[derive(Default)]
+    struct SInt128 {
+        high: i64,
+        low: u64,
+    }
+
+    impl Value {
+        /// Creates the equivalent CoreFoundation property list.
+        ///
+        /// `Uid` values become `{ "CF$UID" = n; }` dictionaries, which is how CoreFoundation
+        /// represents them outside of binary property lists.
+        pub fn to_CFPropertyList(&self) -> CFPropertyList {
+            match self {
+                Value::Array(array) => {
+                    let values: Vec<CFType> = array
+                        .iter()
+                        .map(|value| value.to_CFPropertyList().into_CFType())
+                        .collect();
+                    CFArray::from_CFTypes(&values)
+                        .into_untyped()
+                        .into_CFPropertyList()
+                }
+                Value::Dictionary(dict) => {
+                    let pairs: Vec<(CFType, CFType)> = dict
+                        .iter()
+                        .map(|(key, value)| {
+                            (
+                                CFString::new(key).into_CFType(),
+                                value.to_CFPropertyList().into_CFType(),
+                            )
+                        })
+                        .collect();
+                    CFDictionary::from_CFType_pairs(&pairs)
+                        .into_untyped()
+                        .into_CFPropertyList()
+                }
+                Value::Boolean(value) => CFBoolean::from(*value).into_CFPropertyList(),
+                Value::Data(data) => CFData::from_buffer(data).into_CFPropertyList(),
+                Value::Date(date) => CFDate::new(*date).into_CFPropertyList(),
+                Value::Integer(value) => {
+                    if let Ok(value) = i64::try_from(*value) {
+                        return CFNumber::from(value).into_CFPropertyList();
+                    }
+                    let value = SInt128 {
+                        high: (*value >> 64) as i64,
+                        low: *value as u64,
+                    };
+                    unsafe {
+                        let number_ref = CFNumberCreate(
+                            kCFAllocatorDefault,
+                            kCFNumberSInt128Type,
+                            &value as *const SInt128 as *const c_void,
+                        );
+                        CFNumber::wrap_under_create_rule(number_ref).into_CFPropertyList()
+                    }
+                }
+                Value::Real(value) => CFNumber::from(*value).into_CFPropertyList(),
+                Value::String(string) => CFString::new(string).into_CFPropertyList(),
+                Value::Uid(uid) => {
+                    let dict = Dictionary::from_iter(vec![(
+                        crate::propertylist::xml::UID_KEY,
+                        Value::Integer(*uid as i128),
+                    )]);
+                    Value::Dictionary(dict).to_CFPropertyList()
+                }
+            }
+        }
+
+        /// Copies a CoreFoundation property list into an owned value.
+        ///
+        /// Returns `None` if the property list contains an object that is not one of the
+        /// [`CFPropertyListSubClass`] types, or a dictionary key that is not a string.
+        ///
+        /// [`CFPropertyListSubClass`]: ../trait.CFPropertyListSubClass.html
+        pub fn from_CFPropertyList(plist: &CFPropertyList) -> Option<Value> {
+            if let Some(string) = plist.downcast::<CFString>() {
+                Some(Value::String(string.to_string()))
+            } else if let Some(number) = plist.downcast::<CFNumber>() {
+                if unsafe { CFNumberIsFloatType(number.as_concrete_TypeRef()) } != 0 {
+                    return number.to_f64().map(Value::Real);
+                }
+                let mut value = SInt128::default();
+                let ok = unsafe {
+                    CFNumberGetValue(
+                        number.as_concrete_TypeRef(),
+                        kCFNumberSInt128Type,
+                        &mut value as *mut SInt128 as *mut c_void,
+                    )
+                };
+                if ok {
+                    Some(Value::Integer(
+                        (value.high as i128) << 64 | value.low as i128,
+                    ))
+                } else {
+                    number.to_i64().map(Value::from)
+                }
+            } else if let Some(boolean) = plist.downcast::<CFBoolean>() {
+                Some(Value::Boolean(boolean.into()))
+            } else if let Some(date) = plist.downcast::<CFDate>() {
+                Some(Value::Date(date.abs_time()))
+            } else if let Some(data) = plist.downcast::<CFData>() {
+                Some(Value::Data(data.bytes().to_vec()))
+            } else if let Some(array) = plist.downcast::<CFArray>() {
+                array
+                    .get_all_values()
+                    .into_iter()
+                    .map(|value| unsafe {
+                        Value::from_CFPropertyList(&CFPropertyList::wrap_under_get_rule(value))
+                    })
+                    .collect::<Option<Vec<_>>>()
+                    .map(Value::Array)
+            } else if let Some(dict) = plist.downcast::<CFDictionary>() {
+                let (keys, values) = dict.get_keys_and_values();
+                let mut result = Dictionary::new();
+                for (key, value) in keys.into_iter().zip(values) {
+                    let key = unsafe { CFType::wrap_under_get_rule(key) }.downcast::<CFString>()?;
+                    let value = unsafe { CFPropertyList::wrap_under_get_rule(value) };
+                    result.insert(key.to_string(), Value::from_CFPropertyList(&value)?);
+                }
+                Some(Value::Dictionary(result))
+            } else {
+                None
+            }
+        }
+    }
+}
@@ -0,0 +1,721 @@
+// Copyright 2026 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A pure-Rust reader and writer for `kCFPropertyListXMLFormat_v1_0` data.
+//!
+//! The output matches what `CFPropertyListCreateData` produces: tab indentation, base64 data
+//! wrapped in lines, and dates in whole UTC seconds.
+
+use std::cmp;
+use std::error;
+use std::fmt;
+
+use core_foundation_sys::date::CFAbsoluteTime;
+
+use super::value::{Dictionary, Value};
+use super::MAX_DEPTH;
+
+const HEADER: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+<!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \
+\"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n\
+<plist version=\"1.0\">\n";
+const FOOTER: &str = "</plist>\n";
+
+/// The dictionary key CoreFoundation uses to represent a [`Value::Uid`] in XML.
+///
+/// [`Value::Uid`]: ../enum.Value.html#variant.Uid
+pub const UID_KEY: &str = "CF$UID";
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// An error produced while reading or writing an XML property list.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The document is not well-formed, or contains an element that is not valid in a property
+    /// list. `line` and `column` are 1-based and count characters.
+    Syntax {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// An integer outside of `i64::MIN..=u64::MAX` cannot be written.
+    IntegerOutOfRange(i128),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Syntax {
+                line,
+                column,
+                message,
+            } => write!(f, "{} at line {}, column {}", message, line, column),
+            Error::IntegerOutOfRange(value) => {
+                write!(f, "integer {} cannot be stored in a property list", value)
+            }
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+/// Decodes an XML property list from UTF-8 encoded bytes.
+pub fn from_bytes(bytes: &[u8]) -> Result<Value, Error> {
+    let bytes = bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(bytes);
+    match std::str::from_utf8(bytes) {
+        Ok(string) => from_str(string),
+        Err(err) => Err(syntax_error(bytes, err.valid_up_to(), "invalid UTF-8")),
+    }
+}
+
+/// Decodes an XML property list.
+///
+/// A top-level `<plist>` element is optional. Dictionaries of the form
+/// `<dict><key>CF$UID</key><integer>N</integer></dict>` decode to [`Value::Uid`], like
+/// CoreFoundation does.
+///
+/// [`Value::Uid`]: ../enum.Value.html#variant.Uid
+pub fn from_str(string: &str) -> Result<Value, Error> {
+    let mut parser = Parser {
+        input: string,
+        pos: 0,
+        depth: 0,
+    };
+    parser.skip_misc()?;
+    let tag = parser.start_tag()?;
+    let value = if tag.name == "plist" {
+        if tag.empty {
+            return Err(parser.error_at(tag.pos, "empty plist element"));
+        }
+        parser.skip_misc()?;
+        let value_tag = parser.start_tag()?;
+        let value = parser.value(value_tag)?;
+        parser.skip_misc()?;
+        parser.end_tag("plist")?;
+        value
+    } else {
+        parser.value(tag)?
+    };
+    parser.skip_misc()?;
+    if parser.pos != parser.input.len() {
+        return Err(parser.error("unexpected content after the root element"));
+    }
+    Ok(value)
+}
+
+/// Encodes a value as an XML property list.
+pub fn to_string(value: &Value) -> Result<String, Error> {
+    let mut out = String::from(HEADER);
+    write_value(&mut out, value, 0)?;
+    out.push_str(FOOTER);
+    Ok(out)
+}
+
+/// Encodes a value as an XML property list in UTF-8.
+pub fn to_bytes(value: &Value) -> Result<Vec<u8>, Error> {
+    to_string(value).map(String::into_bytes)
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+    /// The number of arrays and dictionaries being parsed.
+    depth: usize,
+}
+
+struct StartTag<'a> {
+    name: &'a str,
+    /// Whether the element was written as `<name/>`.
+    empty: bool,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn value(&mut self, tag: StartTag<'a>) -> Result<Value, Error> {
+        match tag.name {
+            "dict" | "array" => self.container(tag),
+            "true" | "false" => {
+                if !tag.empty {
+                    self.skip_misc()?;
+                    self.end_tag(tag.name)?;
+                }
+                Ok(Value::Boolean(tag.name == "true"))
+            }
+            "string" => self.text(&tag).map(Value::String),
+            "integer" => {
+                let text = self.text(&tag)?;
+                parse_integer(text.trim())
+                    .map(Value::Integer)
+                    .ok_or_else(|| self.error_at(tag.pos, "invalid integer"))
+            }
+            "real" => {
+                let text = self.text(&tag)?;
+                text.trim()
+                    .parse()
+                    .ok()
+                    .map(Value::Real)
+                    .ok_or_else(|| self.error_at(tag.pos, "invalid real"))
+            }
+            "date" => {
+                let text = self.text(&tag)?;
+                parse_date(text.trim())
+                    .map(Value::Date)
+                    .ok_or_else(|| self.error_at(tag.pos, "invalid date"))
+            }
+            "data" => {
+                let text = self.text(&tag)?;
+                decode_base64(&text)
+                    .map(Value::Data)
+                    .ok_or_else(|| self.error_at(tag.pos, "invalid base64 data"))
+            }
+            name => Err(self.error_at(tag.pos, &format!("unexpected element <{}>", name))),
+        }
+    }
+
+    fn container(&mut self, tag: StartTag<'a>) -> Result<Value, Error> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error_at(tag.pos, "arrays and dictionaries nested too deeply"));
+        }
+        self.depth += 1;
+        let value = match tag.name {
+            "dict" => self.dictionary(tag),
+            _ => self.array(tag),
+        };
+        self.depth -= 1;
+        value
+    }
+
+    fn array(&mut self, tag: StartTag<'a>) -> Result<Value, Error> {
+        let mut array = Vec::new();
+        if !tag.empty {
+            loop {
+                self.skip_misc()?;
+                if self.rest().starts_with("</") {
+                    break;
+                }
+                let tag = self.start_tag()?;
+                array.push(self.value(tag)?);
+            }
+            self.end_tag("array")?;
+        }
+        Ok(Value::Array(array))
+    }
+
+    fn dictionary(&mut self, tag: StartTag<'a>) -> Result<Value, Error> {
+        if tag.empty {
+            return Ok(Value::Dictionary(Dictionary::new()));
+        }
+        // Collecting the entries at the end finds duplicate keys without a search for each key.
+        let mut entries = Vec::new();
+        loop {
+            self.skip_misc()?;
+            if self.rest().starts_with("</") {
+                break;
+            }
+            let key_tag = self.start_tag()?;
+            if key_tag.name != "key" {
+                return Err(self.error_at(key_tag.pos, "expected <key>"));
+            }
+            let key = self.text(&key_tag)?;
+            self.skip_misc()?;
+            let value_tag = self.start_tag()?;
+            let value = self.value(value_tag)?;
+            entries.push((key, value));
+        }
+        self.end_tag("dict")?;
+        let dict: Dictionary = entries.into_iter().collect();
+
+        if dict.len() == 1 {
+            if let Some(&Value::Integer(uid)) = dict.get(UID_KEY) {
+                // CoreFoundation's keyed archiver UIDs are 32-bit.
+                if uid >= 0 && uid <= u32::MAX as i128 {
+                    return Ok(Value::Uid(uid as u64));
+                }
+            }
+        }
+        Ok(Value::Dictionary(dict))
+    }
+
+    /// Reads the character data of a leaf element up to and including its end tag.
+    fn text(&mut self, tag: &StartTag) -> Result<String, Error> {
+        let mut text = String::new();
+        if tag.empty {
+            return Ok(text);
+        }
+        loop {
+            let rest = self.rest();
+            if rest.starts_with("</") {
+                break;
+            } else if rest.starts_with("<!--") {
+                self.skip_comment()?;
+            } else if let Some(cdata) = rest.strip_prefix("<![CDATA[") {
+                let end = cdata
+                    .find("]]>")
+                    .ok_or_else(|| self.error("unterminated CDATA section"))?;
+                text.push_str(&cdata[..end]);
+                self.pos += "<![CDATA[".len() + end + "]]>".len();
+            } else if rest.starts_with('<') {
+                return Err(self.error(&format!("unexpected element inside <{}>", tag.name)));
+            } else if rest.starts_with('&') {
+                text.push(self.entity()?);
+            } else if rest.is_empty() {
+                return Err(self.error(&format!("unterminated <{}>", tag.name)));
+            } else {
+                let end = rest.find(['<', '&']).unwrap_or(rest.len());
+                text.push_str(&rest[..end]);
+                self.pos += end;
+            }
+        }
+        self.end_tag(tag.name)?;
+        Ok(text)
+    }
+
+    fn entity(&mut self) -> Result<char, Error> {
+        let rest = self.rest();
+        let end = rest
+            .find(';')
+            .filter(|&end| end <= 10)
+            .ok_or_else(|| self.error("unterminated entity reference"))?;
+        let c = match &rest[1..end] {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            name => {
+                let code = if let Some(hex) = name.strip_prefix("#x") {
+                    u32::from_str_radix(hex, 16).ok()
+                } else if let Some(decimal) = name.strip_prefix('#') {
+                    decimal.parse().ok()
+                } else {
+                    None
+                };
+                code.and_then(char::from_u32)
+            }
+        };
+        let c = c.ok_or_else(|| self.error("unknown entity reference"))?;
+        self.pos += end + 1;
+        Ok(c)
+    }
+
+    fn start_tag(&mut self) -> Result<StartTag<'a>, Error> {
+        let pos = self.pos;
+        if !self.rest().starts_with('<') || self.rest().starts_with("</") {
+            return Err(self.error("expected an element"));
+        }
+        self.pos += 1;
+        let name = self.name()?;
+        loop {
+            self.skip_whitespace();
+            let rest = self.rest();
+            if rest.starts_with("/>") {
+                self.pos += 2;
+                return Ok(StartTag {
+                    name,
+                    empty: true,
+                    pos,
+                });
+            } else if rest.starts_with('>') {
+                self.pos += 1;
+                return Ok(StartTag {
+                    name,
+                    empty: false,
+                    pos,
+                });
+            }
+            // Attributes are allowed but ignored, as CoreFoundation does with `version`.
+            self.name()?;
+            self.skip_whitespace();
+            self.expect("=")?;
+            self.skip_whitespace();
+            let quote = match self.rest().chars().next() {
+                Some(quote @ '"') | Some(quote @ '\'') => quote,
+                _ => return Err(self.error("expected a quoted attribute value")),
+            };
+            let end = self.rest()[1..]
+                .find(quote)
+                .ok_or_else(|| self.error("unterminated attribute value"))?;
+            self.pos += end + 2;
+        }
+    }
+
+    fn end_tag(&mut self, name: &str) -> Result<(), Error> {
+        let pos = self.pos;
+        self.expect("</")?;
+        let found = self.name()?;
+        self.skip_whitespace();
+        if found != name || !self.rest().starts_with('>') {
+            return Err(self.error_at(pos, &format!("expected </{}>", name)));
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    fn name(&mut self) -> Result<&'a str, Error> {
+        let rest = self.rest();
+        let end = rest
+            .find(|c: char| !(c.is_alphanumeric() || "_-.:".contains(c)))
+            .unwrap_or(rest.len());
+        if end == 0 {
+            return Err(self.error("expected a name"));
+        }
+        self.pos += end;
+        Ok(&rest[..end])
+    }
+
+    /// Skips whitespace, comments, processing instructions and document type declarations.
+    fn skip_misc(&mut self) -> Result<(), Error> {
+        loop {
+            self.skip_whitespace();
+            let rest = self.rest();
+            if rest.starts_with("<!--") {
+                self.skip_comment()?;
+            } else if rest.starts_with("<?") {
+                let end = rest
+                    .find("?>")
+                    .ok_or_else(|| self.error("unterminated processing instruction"))?;
+                self.pos += end + 2;
+            } else if rest.starts_with("<!DOCTYPE") {
+                // The internal subset, if any, may itself contain `>` characters.
+                let mut depth = 0;
+                let end = rest
+                    .char_indices()
+                    .find(|&(_, c)| {
+                        match c {
+                            '[' => depth += 1,
+                            ']' => depth -= 1,
+                            '>' if depth == 0 => return true,
+                            _ => {}
+                        }
+                        false
+                    })
+                    .ok_or_else(|| self.error("unterminated document type declaration"))?;
+                self.pos += end.0 + 1;
+            } else if !rest.is_empty() && !rest.starts_with('<') {
+                return Err(self.error("unexpected character data"));
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn skip_comment(&mut self) -> Result<(), Error> {
+        let end = self.rest()[4..]
+            .find("-->")
+            .ok_or_else(|| self.error("unterminated comment"))?;
+        self.pos += 4 + end + 3;
+        Ok(())
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), Error> {
+        if self.rest().starts_with(token) {
+            self.pos += token.len();
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected `{}`", token)))
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn error(&self, message: &str) -> Error {
+        self.error_at(self.pos, message)
+    }
+
+    fn error_at(&self, pos: usize, message: &str) -> Error {
+        syntax_error(self.input.as_bytes(), pos, message)
+    }
+}
+
+fn syntax_error(input: &[u8], pos: usize, message: &str) -> Error {
+    let before = String::from_utf8_lossy(&input[..pos]);
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    Error::Syntax {
+        line: before.matches('\n').count() + 1,
+        column: before[line_start..].chars().count() + 1,
+        message: message.to_owned(),
+    }
+}
+
+fn write_value(out: &mut String, value: &Value, depth: usize) -> Result<(), Error> {
+    indent(out, depth);
+    match value {
+        Value::Array(array) if array.is_empty() => out.push_str("<array/>\n"),
+        Value::Array(array) => {
+            out.push_str("<array>\n");
+            for value in array {
+                write_value(out, value, depth + 1)?;
+            }
+            indent(out, depth);
+            out.push_str("</array>\n");
+        }
+        Value::Dictionary(dict) if dict.is_empty() => out.push_str("<dict/>\n"),
+        Value::Dictionary(dict) => {
+            out.push_str("<dict>\n");
+            for (key, value) in dict {
+                indent(out, depth + 1);
+                write_element(out, "key", &escape(key));
+                write_value(out, value, depth + 1)?;
+            }
+            indent(out, depth);
+            out.push_str("</dict>\n");
+        }
+        Value::Boolean(true) => out.push_str("<true/>\n"),
+        Value::Boolean(false) => out.push_str("<false/>\n"),
+        Value::Data(data) => {
+            out.push_str("<data>\n");
+            // Like CoreFoundation, count tabs as eight columns and keep lines within 76.
+            let line_length = cmp::max(16, 76 - 8 * depth as isize) as usize / 4 * 3;
+            for line in data.chunks(line_length) {
+                indent(out, depth);
+                out.push_str(&encode_base64(line));
+                out.push('\n');
+            }
+            indent(out, depth);
+            out.push_str("</data>\n");
+        }
+        Value::Date(date) => write_element(out, "date", &format_date(*date)),
+        Value::Integer(value) => {
+            if *value < i64::MIN as i128 || *value > u64::MAX as i128 {
+                return Err(Error::IntegerOutOfRange(*value));
+            }
+            write_element(out, "integer", &value.to_string());
+        }
+        Value::Real(value) => write_element(out, "real", &format_real(*value)),
+        Value::String(string) => write_element(out, "string", &escape(string)),
+        Value::Uid(uid) => {
+            out.push_str("<dict>\n");
+            indent(out, depth + 1);
+            write_element(out, "key", UID_KEY);
+            indent(out, depth + 1);
+            write_element(out, "integer", &uid.to_string());
+            indent(out, depth);
+            out.push_str("</dict>\n");
+        }
+    }
+    Ok(())
+}
+
+fn write_element(out: &mut String, name: &str, content: &str) {
+    out.push('<');
+    out.push_str(name);
+    out.push('>');
+    out.push_str(content);
+    out.push_str("</");
+    out.push_str(name);
+    out.push_str(">\n");
+}
+
+fn indent(out: &mut String, depth: usize) {
+    out.extend(std::iter::repeat('\t').take(depth));
+}
+
+fn escape(string: &str) -> String {
+    let mut escaped = String::with_capacity(string.len());
+    for c in string.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn parse_integer(text: &str) -> Option<i128> {
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, text.strip_prefix('+').unwrap_or(text)),
+    };
+    let magnitude = match digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+    {
+        Some(hex) => u64::from_str_radix(hex, 16).ok()?,
+        None if digits.bytes().all(|b| b.is_ascii_digit()) => digits.parse().ok()?,
+        None => return None,
+    };
+    let value = if negative {
+        -(magnitude as i128)
+    } else {
+        magnitude as i128
+    };
+    if value < i64::MIN as i128 {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+fn format_real(value: f64) -> String {
+    if value.is_nan() {
+        "nan".to_owned()
+    } else if value.is_infinite() {
+        if value > 0.0 {
+            "+infinity"
+        } else {
+            "-infinity"
+        }
+        .to_owned()
+    } else {
+        format!("{:?}", value)
+    }
+}
+
+/// The number of days between 1970-01-01 and the CoreFoundation reference date, 2001-01-01.
+const REFERENCE_DATE_DAYS: i64 = 11323;
+
+/// Parses an ISO-8601 date in the `YYYY-MM-DDTHH:MM:SSZ` form CoreFoundation writes, with
+/// optional fractional seconds.
+fn parse_date(text: &str) -> Option<CFAbsoluteTime> {
+    let text = text.strip_suffix('Z')?;
+    let (date, time) = text.split_once('T')?;
+    let mut date_fields = date.splitn(3, '-');
+    let year: i64 = date_fields.next()?.parse().ok()?;
+    let month: u32 = date_fields.next()?.parse().ok()?;
+    let day: u32 = date_fields.next()?.parse().ok()?;
+    let mut time_fields = time.splitn(3, ':');
+    let hour: u32 = time_fields.next()?.parse().ok()?;
+    let minute: u32 = time_fields.next()?.parse().ok()?;
+    let seconds: f64 = time_fields.next()?.parse().ok()?;
+    if !(1..=12).contains(&month)
+        || day < 1
+        || day > days_in_month(year, month)
+        || hour > 23
+        || minute > 59
+        || !(0.0..60.0).contains(&seconds)
+    {
+        return None;
+    }
+    let days = days_from_civil(year, month, day) - REFERENCE_DATE_DAYS;
+    Some((days * 86400 + hour as i64 * 3600 + minute as i64 * 60) as f64 + seconds)
+}
+
+/// Formats a date the way CoreFoundation does, truncating to whole seconds.
+fn format_date(date: CFAbsoluteTime) -> String {
+    let seconds = date.floor() as i64;
+    let days = seconds.div_euclid(86400);
+    let time = seconds.rem_euclid(86400);
+    let (year, month, day) = civil_from_days(days + REFERENCE_DATE_DAYS);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        time / 3600,
+        time / 60 % 60,
+        time % 60
+    )
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Returns the number of days since 1970-01-01 in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = month as i64;
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// The inverse of `days_from_civil`.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+fn encode_base64(data: &[u8]) -> String {
+    let mut encoded = String::with_capacity((data.len() + 2) / 3 * 4);
+    for chunk in data.chunks(3) {
+        let bits = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |acc, (i, &b)| acc | (b as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(BASE64_ALPHABET[(bits >> (18 - 6 * i) & 0x3F) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// Decodes base64, ignoring whitespace anywhere in the input.
+fn decode_base64(text: &str) -> Option<Vec<u8>> {
+    let mut data = Vec::with_capacity(text.len() / 4 * 3);
+    let mut bits = 0u32;
+    let mut count = 0;
+    let mut padding = 0;
+    for b in text.bytes() {
+        let sextet = match b {
+            b'A'..=b'Z' => b - b'A',
+            b'a'..=b'z' => b - b'a' + 26,
+            b'0'..=b'9' => b - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            b'=' => {
+                padding += 1;
+                continue;
+            }
+            b if b.is_ascii_whitespace() => continue,
+            _ => return None,
+        };
+        if padding > 0 {
+            return None;
+        }
+        bits = bits << 6 | sextet as u32;
+        count += 1;
+        if count == 4 {
+            data.extend_from_slice(&bits.to_be_bytes()[1..]);
+            bits = 0;
+            count = 0;
+        }
+    }
+    match count {
+        0 => {}
+        2 => data.push((bits >> 4) as u8),
+        3 => data.extend_from_slice(&((bits >> 2) as u16).to_be_bytes()),
+        _ => return None,
+    }
+    if padding > 2 {
+        return None;
+    }
+    Some(data)
+}
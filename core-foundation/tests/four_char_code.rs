@@ -0,0 +1,63 @@
+use core_foundation::four_char_code::{FourCharCode, ParseFourCharCodeError};
+
+#[test]
+fn conversions() {
+    const HEAD: FourCharCode = FourCharCode::new(b"head");
+    assert_eq!(HEAD.to_u32(), 0x68656164);
+    assert_eq!(FourCharCode::from(0x68656164), HEAD);
+    assert_eq!(u32::from(HEAD), 0x68656164);
+    assert_eq!(FourCharCode::from(*b"head"), HEAD);
+    assert_eq!(<[u8; 4]>::from(HEAD), *b"head");
+    assert_eq!(HEAD, 0x68656164);
+    assert!(FourCharCode::new(b"OS/2") < FourCharCode::new(b"cmap"));
+}
+
+#[test]
+fn formatting() {
+    let tags = [
+        (FourCharCode::new(b"glyf"), "glyf", "FourCharCode(\"glyf\")"),
+        (FourCharCode::new(b"CFF "), "CFF ", "FourCharCode(\"CFF \")"),
+        (
+            FourCharCode::from_u32(1),
+            "0x00000001",
+            "FourCharCode(0x00000001)",
+        ),
+        (
+            FourCharCode::new(b"\xFFabc"),
+            "0xFF616263",
+            "FourCharCode(0xFF616263)",
+        ),
+    ];
+    for (tag, display, debug) in tags {
+        assert_eq!(tag.to_string(), display);
+        assert_eq!(format!("{:?}", tag), debug);
+        assert_eq!(display.parse(), Ok(tag));
+    }
+}
+
+#[test]
+fn parsing() {
+    assert_eq!("cvt".parse(), Ok(FourCharCode::new(b"cvt ")));
+    assert_eq!("a".parse(), Ok(FourCharCode::new(b"a   ")));
+    assert_eq!("0x".parse(), Ok(FourCharCode::new(b"0x  ")));
+    assert_eq!(
+        "".parse::<FourCharCode>(),
+        Err(ParseFourCharCodeError::Empty)
+    );
+    assert_eq!(
+        "glyph".parse::<FourCharCode>(),
+        Err(ParseFourCharCodeError::TooLong)
+    );
+    assert_eq!(
+        "0x1234567".parse::<FourCharCode>(),
+        Err(ParseFourCharCodeError::TooLong)
+    );
+    assert_eq!(
+        "ab\tc".parse::<FourCharCode>(),
+        Err(ParseFourCharCodeError::InvalidCharacter(2))
+    );
+    assert_eq!(
+        "é".parse::<FourCharCode>(),
+        Err(ParseFourCharCodeError::InvalidCharacter(0))
+    );
+}
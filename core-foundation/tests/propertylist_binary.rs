@@ -0,0 +1,255 @@
+use core_foundation::propertylist::binary::{self, Error};
+use core_foundation::propertylist::{Dictionary, Value, MAX_DEPTH};
+
+const FIXTURE: &[u8] = include_bytes!("fixtures/binary.plist");
+
+fn fixture_value() -> Value {
+    let inner: Dictionary = vec![("Key", Value::from("Value"))].into_iter().collect();
+    let nested: Dictionary = vec![
+        ("Inner", Value::Dictionary(inner)),
+        ("List", Value::Array(vec![])),
+    ]
+    .into_iter()
+    .collect();
+
+    let dict: Dictionary = vec![
+        ("String", Value::from("Hello")),
+        ("Unicode", Value::from("Grüße, 世界 🎉")),
+        ("Empty", Value::from("")),
+        ("LongString", Value::from("abcdefghijklmnopqrstuvwxyz")),
+        ("Integer", Value::from(42)),
+        ("Negative", Value::from(-7)),
+        ("Large", Value::from(1i64 << 40)),
+        ("Unsigned", Value::from(u64::MAX)),
+        ("Real", Value::from(3.25)),
+        ("True", Value::from(true)),
+        ("False", Value::from(false)),
+        ("Date", Value::Date(613917045.0)),
+        ("Data", Value::Data((0..20).collect())),
+        (
+            "Array",
+            Value::Array(vec![
+                Value::from(1),
+                Value::from("two"),
+                Value::Array(vec![Value::from(3.5)]),
+                Value::Dictionary(Dictionary::new()),
+            ]),
+        ),
+        ("Nested", Value::Dictionary(nested)),
+        ("Uid", Value::Uid(7)),
+    ]
+    .into_iter()
+    .collect();
+    Value::Dictionary(dict)
+}
+
+#[test]
+fn read_fixture() {
+    let value = binary::from_bytes(FIXTURE).unwrap();
+    assert_eq!(value, fixture_value());
+
+    let dict = value.as_dictionary().unwrap();
+    let keys: Vec<_> = dict.keys().collect();
+    assert_eq!(&keys[..3], ["String", "Unicode", "Empty"]);
+}
+
+#[test]
+fn round_trip_fixture() {
+    let value = binary::from_bytes(FIXTURE).unwrap();
+    let bytes = binary::to_bytes(&value).unwrap();
+    assert!(bytes.starts_with(b"bplist00"));
+    assert_eq!(binary::from_bytes(&bytes).unwrap(), value);
+}
+
+#[test]
+fn round_trip_scalars() {
+    let values = [
+        Value::from(0),
+        Value::from(255),
+        Value::from(256),
+        Value::from(65536),
+        Value::from(i64::MAX),
+        Value::from(i64::MIN),
+        Value::from(u64::MAX),
+        Value::from(-0.0),
+        Value::from(f64::INFINITY),
+        Value::Date(-978307200.0),
+        Value::Uid(0),
+        Value::Uid(u64::MAX),
+        Value::Data(vec![0xAB; 1000]),
+        Value::from("x".repeat(70000)),
+    ];
+    for value in values.iter() {
+        let bytes = binary::to_bytes(value).unwrap();
+        assert_eq!(&binary::from_bytes(&bytes).unwrap(), value);
+    }
+}
+
+#[test]
+fn shares_equal_scalars() {
+    let value = Value::Array(vec![Value::from("same"); 100]);
+    let bytes = binary::to_bytes(&value).unwrap();
+    // One array with 100 one-byte references to a single string.
+    assert!(bytes.len() < 200);
+    assert_eq!(binary::from_bytes(&bytes).unwrap(), value);
+}
+
+#[test]
+fn write_out_of_range_integer() {
+    let value = Value::Integer(u64::MAX as i128 + 1);
+    assert_eq!(
+        binary::to_bytes(&value),
+        Err(Error::IntegerOutOfRange(u64::MAX as i128 + 1))
+    );
+}
+
+#[test]
+fn reject_bad_header() {
+    assert_eq!(binary::from_bytes(b"bplist00"), Err(Error::InvalidHeader));
+    let mut bytes = FIXTURE.to_vec();
+    bytes[7] = b'1';
+    assert_eq!(binary::from_bytes(&bytes), Err(Error::InvalidHeader));
+}
+
+#[test]
+fn reject_truncated_data() {
+    for len in 0..FIXTURE.len() {
+        assert!(binary::from_bytes(&FIXTURE[..len]).is_err());
+    }
+}
+
+/// Builds a file from raw objects, each referencing others with one-byte references.
+fn build(objects: &[&[u8]]) -> Vec<u8> {
+    let mut bytes = b"bplist00".to_vec();
+    let mut offsets = vec![];
+    for object in objects {
+        offsets.push(bytes.len() as u8);
+        bytes.extend_from_slice(object);
+    }
+    let table = bytes.len() as u64;
+    bytes.extend_from_slice(&offsets);
+    bytes.extend_from_slice(&[0, 0, 0, 0, 0, 0, 1, 1]);
+    bytes.extend_from_slice(&(objects.len() as u64).to_be_bytes());
+    bytes.extend_from_slice(&0u64.to_be_bytes());
+    bytes.extend_from_slice(&table.to_be_bytes());
+    bytes
+}
+
+#[test]
+fn reject_recursive_container() {
+    let bytes = build(&[&[0xA2, 1, 0], &[0x09]]);
+    assert_eq!(
+        binary::from_bytes(&bytes),
+        Err(Error::RecursiveObject { offset: 8 })
+    );
+}
+
+#[test]
+fn reject_non_string_key() {
+    let bytes = build(&[&[0xD1, 1, 1], &[0x10, 1]]);
+    assert_eq!(
+        binary::from_bytes(&bytes),
+        Err(Error::InvalidKey { offset: 11 })
+    );
+}
+
+#[test]
+fn reject_bad_reference() {
+    let bytes = build(&[&[0xA1, 9]]);
+    assert_eq!(
+        binary::from_bytes(&bytes),
+        Err(Error::InvalidReference { index: 9 })
+    );
+}
+
+#[test]
+fn reject_set() {
+    let bytes = build(&[&[0xC0]]);
+    assert_eq!(
+        binary::from_bytes(&bytes),
+        Err(Error::UnsupportedObject {
+            offset: 8,
+            marker: 0xC0
+        })
+    );
+}
+
+#[test]
+fn shared_children_are_not_recursive() {
+    let bytes = build(&[&[0xA2, 1, 1], &[0xA1, 2], &[0x08]]);
+    let inner = Value::Array(vec![Value::from(false)]);
+    assert_eq!(
+        binary::from_bytes(&bytes).unwrap(),
+        Value::Array(vec![inner.clone(), inner])
+    );
+}
+
+#[test]
+fn invalid_utf16() {
+    let bytes = build(&[&[0x61, 0xD8, 0x00]]);
+    assert_eq!(
+        binary::from_bytes(&bytes),
+        Err(Error::InvalidObject { offset: 8 })
+    );
+}
+
+/// Builds a file of `depth` arrays nested inside each other, each holding `refs` references to
+/// the next one, with two-byte offsets and references.
+fn nested(depth: usize, refs: u8) -> Vec<u8> {
+    let mut bytes = b"bplist00".to_vec();
+    let mut offsets = vec![];
+    for index in 1..=depth {
+        offsets.extend_from_slice(&(bytes.len() as u16).to_be_bytes());
+        if index < depth {
+            bytes.push(0xA0 | refs);
+            for _ in 0..refs {
+                bytes.extend_from_slice(&(index as u16).to_be_bytes());
+            }
+        } else {
+            bytes.push(0xA0);
+        }
+    }
+    let table = bytes.len() as u64;
+    bytes.extend_from_slice(&offsets);
+    bytes.extend_from_slice(&[0, 0, 0, 0, 0, 0, 2, 2]);
+    bytes.extend_from_slice(&(depth as u64).to_be_bytes());
+    bytes.extend_from_slice(&0u64.to_be_bytes());
+    bytes.extend_from_slice(&table.to_be_bytes());
+    bytes
+}
+
+#[test]
+fn reject_deep_nesting() {
+    assert!(binary::from_bytes(&nested(MAX_DEPTH, 1)).is_ok());
+    assert_eq!(
+        binary::from_bytes(&nested(MAX_DEPTH + 1, 1)),
+        Err(Error::TooDeep {
+            offset: 8 + 3 * MAX_DEPTH
+        })
+    );
+}
+
+#[test]
+fn reject_shared_container_expansion() {
+    // Each array holds the next one twice, which would expand to 2^40 arrays.
+    assert_eq!(
+        binary::from_bytes(&nested(40, 2)),
+        Err(Error::TooManyObjects)
+    );
+    // Sharing that stays within the size of the data is decoded.
+    let value = binary::from_bytes(&nested(4, 2)).unwrap();
+    assert!(matches!(value, Value::Array(ref array) if array.len() == 2));
+}
+
+#[test]
+fn large_dictionary() {
+    let dict: Dictionary = (0..20000)
+        .map(|i| (format!("key{}", i), Value::Integer(i)))
+        .chain([("key0".to_owned(), Value::Boolean(true))])
+        .collect();
+    assert_eq!(dict.len(), 20000);
+    assert_eq!(dict.keys().next(), Some("key0"));
+    assert_eq!(dict.get("key0"), Some(&Value::Boolean(true)));
+    let bytes = binary::to_bytes(&Value::Dictionary(dict.clone())).unwrap();
+    assert_eq!(binary::from_bytes(&bytes), Ok(Value::Dictionary(dict)));
+}
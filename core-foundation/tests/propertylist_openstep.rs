@@ -0,0 +1,194 @@
+use core_foundation::propertylist::openstep::{self, Error};
+use core_foundation::propertylist::{Dictionary, Value, MAX_DEPTH};
+
+const PROJECT: &str = include_str!("fixtures/project.pbxproj");
+
+fn dict(entries: Vec<(&str, Value)>) -> Value {
+    Value::Dictionary(entries.into_iter().collect())
+}
+
+#[test]
+fn read_project() {
+    let project = openstep::from_str(PROJECT).unwrap();
+    let project = project.as_dictionary().unwrap();
+    assert_eq!(project.get("archiveVersion"), Some(&Value::from("1")));
+    assert_eq!(
+        project.get("classes"),
+        Some(&Value::Dictionary(Dictionary::new()))
+    );
+    assert_eq!(
+        project.get("signature"),
+        Some(&Value::Data(vec![0x0f, 0xbd, 0x77, 0x69, 0x2f]))
+    );
+
+    let objects = project.get("objects").unwrap().as_dictionary().unwrap();
+    let keys: Vec<_> = objects.keys().collect();
+    assert_eq!(
+        keys,
+        [
+            "8A1F2B3C2B0000010000AAAA",
+            "8A1F2B3C2B0000020000AAAA",
+            "8A1F2B3C2B0000030000AAAA",
+            "8A1F2B3C2B0000040000AAAA",
+            "8A1F2B3C2B0000050000AAAA",
+        ]
+    );
+
+    let group = objects
+        .get("8A1F2B3C2B0000040000AAAA")
+        .and_then(Value::as_dictionary)
+        .unwrap();
+    assert_eq!(group.get("name"), Some(&Value::from("Example \"App\"")));
+    assert_eq!(
+        group.get("children"),
+        Some(&Value::Array(vec![
+            Value::from("8A1F2B3C2B0000020000AAAA"),
+            Value::from("8A1F2B3C2B0000030000AAAA"),
+        ]))
+    );
+
+    let settings = objects
+        .get("8A1F2B3C2B0000050000AAAA")
+        .and_then(Value::as_dictionary)
+        .and_then(|config| config.get("buildSettings"))
+        .and_then(Value::as_dictionary)
+        .unwrap();
+    assert_eq!(
+        settings.get("INFOPLIST_KEY_NSHumanReadableCopyright"),
+        Some(&Value::from("© 2026\tServo"))
+    );
+}
+
+#[test]
+fn round_trip_project() {
+    let project = openstep::from_str(PROJECT).unwrap();
+    let string = openstep::to_string(&project).unwrap();
+    assert_eq!(openstep::from_str(&string).unwrap(), project);
+    assert_eq!(openstep::to_string(&project).unwrap(), string);
+}
+
+#[test]
+fn pretty_print() {
+    let value = dict(vec![
+        ("name", Value::from("Example App")),
+        ("path", Value::from("src/main.m")),
+        ("empty", Value::from("")),
+        (
+            "list",
+            Value::Array(vec![Value::from("a"), Value::from("b\n")]),
+        ),
+        ("nothing", Value::Array(vec![])),
+        (
+            "nested",
+            dict(vec![("data", Value::Data((0..6).collect()))]),
+        ),
+        ("none", Value::Dictionary(Dictionary::new())),
+    ]);
+    assert_eq!(
+        openstep::to_string(&value).unwrap(),
+        "{\n\
+         \tname = \"Example App\";\n\
+         \tpath = src/main.m;\n\
+         \tempty = \"\";\n\
+         \tlist = (\n\
+         \t\ta,\n\
+         \t\t\"b\\n\",\n\
+         \t);\n\
+         \tnothing = ();\n\
+         \tnested = {\n\
+         \t\tdata = <00010203 0405>;\n\
+         \t};\n\
+         \tnone = {};\n\
+         }\n"
+    );
+}
+
+#[test]
+fn read_escapes() {
+    let value = openstep::from_str(
+        r#"("\a\b\f\n\r\t\v\"\'\\", 'single "quoted"', "\101\U00e9\Ud83d\Ude00")"#,
+    )
+    .unwrap();
+    assert_eq!(
+        value,
+        Value::Array(vec![
+            Value::from("\x07\x08\x0C\n\r\t\x0B\"'\\"),
+            Value::from("single \"quoted\""),
+            Value::from("Aé😀"),
+        ])
+    );
+}
+
+#[test]
+fn read_strings_file() {
+    let value = openstep::from_str(
+        "/* Title */\n\"Hello\" = \"Bonjour\";\nGoodbye = \"Au revoir\"; // farewell\nOK;\n",
+    )
+    .unwrap();
+    assert_eq!(
+        value,
+        dict(vec![
+            ("Hello", Value::from("Bonjour")),
+            ("Goodbye", Value::from("Au revoir")),
+            ("OK", Value::from("OK")),
+        ])
+    );
+    assert_eq!(
+        openstep::from_str("  // nothing\n").unwrap(),
+        Value::Dictionary(Dictionary::new())
+    );
+}
+
+#[test]
+fn write_unsupported_values() {
+    for value in [
+        Value::from(true),
+        Value::from(1),
+        Value::from(1.5),
+        Value::Date(0.0),
+        Value::Uid(1),
+    ]
+    .iter()
+    {
+        assert_eq!(
+            openstep::to_string(&Value::Array(vec![value.clone()])),
+            Err(Error::UnsupportedValue)
+        );
+    }
+}
+
+fn syntax_error_position(input: &str) -> (usize, usize) {
+    match openstep::from_str(input) {
+        Err(Error::Syntax { line, column, .. }) => (line, column),
+        result => panic!("expected a syntax error, got {:?}", result),
+    }
+}
+
+#[test]
+fn error_positions() {
+    assert_eq!(syntax_error_position("{\n\ta = b\n}"), (3, 1));
+    assert_eq!(syntax_error_position("{\n\ta b;\n}"), (2, 4));
+    assert_eq!(syntax_error_position("(a b)"), (1, 4));
+    assert_eq!(syntax_error_position("{ é = 1; }"), (1, 3));
+    assert_eq!(syntax_error_position("<0fz>"), (1, 4));
+    assert_eq!(syntax_error_position("<0f 1>"), (1, 6));
+    assert_eq!(syntax_error_position("(\n  \"abc"), (2, 3));
+    assert_eq!(syntax_error_position("{ a = (1); } x"), (1, 14));
+    assert_eq!(syntax_error_position("{ (a) = b; }"), (1, 3));
+    assert_eq!(syntax_error_position("/* open"), (1, 1));
+    assert_eq!(syntax_error_position("\"\\Ud800\""), (1, 2));
+}
+
+#[test]
+fn reject_deep_nesting() {
+    let nested = |depth| "(".repeat(depth) + &")".repeat(depth);
+    assert!(openstep::from_str(&nested(MAX_DEPTH)).is_ok());
+    assert_eq!(
+        syntax_error_position(&nested(MAX_DEPTH + 1)),
+        (1, 1 + MAX_DEPTH)
+    );
+    assert_eq!(
+        syntax_error_position(&"{a=".repeat(200_000)),
+        (1, 1 + 3 * MAX_DEPTH)
+    );
+}
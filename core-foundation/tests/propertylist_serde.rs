@@ -0,0 +1,143 @@
+use std::collections::BTreeMap;
+
+use core_foundation::propertylist::serde::{from_value, to_value, Date, Uid};
+use core_foundation::propertylist::{binary, xml, Dictionary, Value};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Info {
+    bundle_identifier: String,
+    version: (u32, u32, u32),
+    enabled: bool,
+    scale: f64,
+    icon: Option<String>,
+    #[serde(with = "bytes")]
+    signature: Vec<u8>,
+    modified: Date,
+    object: Uid,
+    kinds: Vec<Kind>,
+    extra: BTreeMap<String, i64>,
+    large: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+enum Kind {
+    Plain,
+    Named(String),
+    Pair(i32, i32),
+    Point { x: f64, y: f64 },
+}
+
+mod bytes {
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(bytes)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        struct Visitor;
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = Vec<u8>;
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("data")
+            }
+            fn visit_bytes<E>(self, bytes: &[u8]) -> Result<Vec<u8>, E> {
+                Ok(bytes.to_vec())
+            }
+        }
+        deserializer.deserialize_bytes(Visitor)
+    }
+}
+
+fn info() -> Info {
+    Info {
+        bundle_identifier: "org.servo.Example".to_owned(),
+        version: (1, 2, 3),
+        enabled: true,
+        scale: 2.5,
+        icon: None,
+        signature: vec![0, 1, 2, 255],
+        modified: Date(613917045.0),
+        object: Uid(7),
+        kinds: vec![
+            Kind::Plain,
+            Kind::Named("n".to_owned()),
+            Kind::Pair(-1, 2),
+            Kind::Point { x: 0.5, y: -0.5 },
+        ],
+        extra: vec![("a".to_owned(), 1), ("b".to_owned(), -2)]
+            .into_iter()
+            .collect(),
+        large: u64::MAX,
+    }
+}
+
+#[test]
+fn to_value_shape() {
+    let value = to_value(&info()).unwrap();
+    let dict = value.as_dictionary().unwrap();
+    assert_eq!(
+        dict.get("BundleIdentifier"),
+        Some(&Value::from("org.servo.Example"))
+    );
+    assert!(!dict.contains_key("Icon"));
+    assert_eq!(
+        dict.get("Signature"),
+        Some(&Value::Data(vec![0, 1, 2, 255]))
+    );
+    assert_eq!(dict.get("Modified"), Some(&Value::Date(613917045.0)));
+    assert_eq!(dict.get("Object"), Some(&Value::Uid(7)));
+    assert_eq!(dict.get("Large"), Some(&Value::from(u64::MAX)));
+
+    let kinds = dict.get("Kinds").and_then(Value::as_array).unwrap();
+    assert_eq!(kinds[0], Value::from("Plain"));
+    let named: Dictionary = vec![("Named", Value::from("n"))].into_iter().collect();
+    assert_eq!(kinds[1], Value::Dictionary(named));
+}
+
+#[test]
+fn round_trip_binary() {
+    let info = info();
+    let bytes = binary::to_bytes(&to_value(&info).unwrap()).unwrap();
+    let back: Info = from_value(binary::from_bytes(&bytes).unwrap()).unwrap();
+    assert_eq!(back, info);
+}
+
+#[test]
+fn round_trip_xml() {
+    let mut info = info();
+    info.icon = Some("icon.png".to_owned());
+    let string = xml::to_string(&to_value(&info).unwrap()).unwrap();
+    assert!(string.contains("<date>2020-06-15T12:30:45Z</date>"));
+    let back: Info = from_value(xml::from_str(&string).unwrap()).unwrap();
+    assert_eq!(back, info);
+}
+
+#[test]
+fn value_round_trips_through_serde() {
+    let value = binary::from_bytes(include_bytes!("fixtures/binary.plist")).unwrap();
+    let back: Value = from_value(to_value(&value).unwrap()).unwrap();
+    assert_eq!(back, value);
+}
+
+#[test]
+fn none_outside_fields_is_an_error() {
+    assert!(to_value(&None::<i32>).is_err());
+    assert!(to_value(&vec![Some(1), None]).is_err());
+    let map: BTreeMap<&str, Option<i32>> = vec![("a", Some(1)), ("b", None)].into_iter().collect();
+    let value = to_value(&map).unwrap();
+    assert_eq!(value.as_dictionary().unwrap().len(), 1);
+}
+
+#[test]
+fn rejects_unrepresentable_values() {
+    assert!(to_value(&u128::MAX).is_err());
+    assert!(to_value(&(i64::MIN as i128 - 1)).is_err());
+    let map: BTreeMap<Vec<u8>, i32> = vec![(vec![1], 1)].into_iter().collect();
+    assert!(to_value(&map).is_err());
+
+    let result: Result<Info, _> = from_value(Value::from("nope"));
+    assert!(result.is_err());
+}
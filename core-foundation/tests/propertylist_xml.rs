@@ -0,0 +1,200 @@
+use core_foundation::propertylist::xml::{self, Error};
+use core_foundation::propertylist::{binary, Dictionary, Value, MAX_DEPTH};
+
+const FIXTURE: &str = include_str!("fixtures/xml.plist");
+
+fn dict(entries: Vec<(&str, Value)>) -> Value {
+    Value::Dictionary(entries.into_iter().collect())
+}
+
+#[test]
+fn read_fixture() {
+    let value = xml::from_str(FIXTURE).unwrap();
+    let expected = dict(vec![
+        ("CFBundleIdentifier", Value::from("org.servo.Example")),
+        ("Escaped", Value::from("<a & b>")),
+        ("Unicode", Value::from("Grüße, 世界 🎉")),
+        ("Empty", Value::from("")),
+        ("Integer", Value::from(42)),
+        ("Negative", Value::from(-7)),
+        ("Unsigned", Value::from(u64::MAX)),
+        ("Real", Value::from(3.25)),
+        ("True", Value::from(true)),
+        ("False", Value::from(false)),
+        ("Date", Value::Date(613917045.0)),
+        ("Data", Value::Data((0..60).collect())),
+        (
+            "Array",
+            Value::Array(vec![
+                Value::from(1),
+                Value::from("two"),
+                Value::Array(vec![Value::from(3.5)]),
+                Value::Dictionary(Dictionary::new()),
+                Value::Array(vec![]),
+            ]),
+        ),
+        (
+            "Nested",
+            dict(vec![(
+                "Inner",
+                dict(vec![
+                    ("Key", Value::from("Value")),
+                    ("Data", Value::Data(b"hi".to_vec())),
+                ]),
+            )]),
+        ),
+        ("Uid", Value::Uid(7)),
+    ]);
+    assert_eq!(value, expected);
+}
+
+#[test]
+fn write_matches_fixture() {
+    let value = xml::from_str(FIXTURE).unwrap();
+    assert_eq!(xml::to_string(&value).unwrap(), FIXTURE);
+}
+
+#[test]
+fn binary_and_xml_agree() {
+    let value = xml::from_str(FIXTURE).unwrap();
+    let bytes = binary::to_bytes(&value).unwrap();
+    assert_eq!(binary::from_bytes(&bytes).unwrap(), value);
+}
+
+#[test]
+fn read_without_prolog() {
+    let value = xml::from_str("<array><integer>0x1F</integer><real>1e3</real></array>").unwrap();
+    assert_eq!(
+        value,
+        Value::Array(vec![Value::from(31), Value::from(1000.0)])
+    );
+}
+
+#[test]
+fn read_entities_cdata_and_comments() {
+    let value = xml::from_str(
+        "<plist><!-- note --><string>&#65;&#x42;&quot;<!-- x --><![CDATA[<&>]]>&apos;</string></plist>",
+    )
+    .unwrap();
+    assert_eq!(value, Value::from("AB\"<&>'"));
+}
+
+#[test]
+fn read_data_with_whitespace() {
+    let value = xml::from_str("<data>\n\tAAEC\n\tAw==\n</data>").unwrap();
+    assert_eq!(value, Value::Data(vec![0, 1, 2, 3]));
+}
+
+#[test]
+fn round_trip_special_reals() {
+    let value = Value::Array(vec![
+        Value::from(f64::INFINITY),
+        Value::from(f64::NEG_INFINITY),
+        Value::from(1e300),
+        Value::from(-0.5),
+    ]);
+    let string = xml::to_string(&value).unwrap();
+    assert!(string.contains("<real>+infinity</real>"));
+    assert_eq!(xml::from_str(&string).unwrap(), value);
+
+    match xml::from_str("<real>nan</real>").unwrap() {
+        Value::Real(value) => assert!(value.is_nan()),
+        value => panic!("unexpected {:?}", value),
+    }
+}
+
+#[test]
+fn dates() {
+    let cases = [
+        ("2001-01-01T00:00:00Z", 0.0),
+        ("2000-12-31T23:59:59Z", -1.0),
+        ("1970-01-01T00:00:00Z", -978307200.0),
+        ("2024-02-29T12:00:00Z", 730900800.0),
+    ];
+    for &(text, time) in cases.iter() {
+        let value = xml::from_str(&format!("<date>{}</date>", text)).unwrap();
+        assert_eq!(value, Value::Date(time));
+        assert!(xml::to_string(&value).unwrap().contains(text));
+    }
+
+    // Fractional seconds are read, but written truncated like CoreFoundation does.
+    let value = xml::from_str("<date>2001-01-01T00:00:00.5Z</date>").unwrap();
+    assert_eq!(value, Value::Date(0.5));
+    assert!(xml::to_string(&value)
+        .unwrap()
+        .contains("2001-01-01T00:00:00Z"));
+
+    assert!(xml::from_str("<date>2023-02-29T00:00:00Z</date>").is_err());
+    assert!(xml::from_str("<date>2023-01-01T00:00:00</date>").is_err());
+}
+
+#[test]
+fn write_uid_and_empty_containers() {
+    let value = Value::Array(vec![
+        Value::Uid(3),
+        Value::Array(vec![]),
+        Value::Dictionary(Dictionary::new()),
+    ]);
+    let string = xml::to_string(&value).unwrap();
+    assert!(string.contains(
+        "\t<dict>\n\t\t<key>CF$UID</key>\n\t\t<integer>3</integer>\n\t</dict>\n\t<array/>\n\t<dict/>\n"
+    ));
+    assert_eq!(xml::from_str(&string).unwrap(), value);
+}
+
+#[test]
+fn write_out_of_range_integer() {
+    let value = Value::Integer(i64::MIN as i128 - 1);
+    assert_eq!(
+        xml::to_string(&value),
+        Err(Error::IntegerOutOfRange(i64::MIN as i128 - 1))
+    );
+}
+
+fn syntax_error_position(input: &str) -> (usize, usize) {
+    match xml::from_str(input) {
+        Err(Error::Syntax { line, column, .. }) => (line, column),
+        result => panic!("expected a syntax error, got {:?}", result),
+    }
+}
+
+#[test]
+fn error_positions() {
+    assert_eq!(
+        syntax_error_position("<dict>\n  <string/>\n</dict>"),
+        (2, 3)
+    );
+    assert_eq!(syntax_error_position("<array>\n\t<foo/>\n</array>"), (2, 2));
+    assert_eq!(syntax_error_position("<integer>12a</integer>"), (1, 1));
+    assert_eq!(syntax_error_position("<string>é</strin>"), (1, 10));
+    assert_eq!(syntax_error_position("<true/><true/>"), (1, 8));
+    assert_eq!(syntax_error_position("<data>!!</data>"), (1, 1));
+    assert_eq!(syntax_error_position("<string>&bogus;</string>"), (1, 9));
+    assert_eq!(syntax_error_position("<array>"), (1, 8));
+}
+
+#[test]
+fn invalid_utf8() {
+    assert_eq!(
+        xml::from_bytes(b"<string>\n\xFF</string>"),
+        Err(Error::Syntax {
+            line: 2,
+            column: 1,
+            message: "invalid UTF-8".to_owned()
+        })
+    );
+}
+
+#[test]
+fn reject_deep_nesting() {
+    let nested = |depth| "<array>".repeat(depth) + &"</array>".repeat(depth);
+    assert!(xml::from_str(&nested(MAX_DEPTH)).is_ok());
+    assert_eq!(
+        syntax_error_position(&nested(MAX_DEPTH + 1)),
+        (1, 1 + 7 * MAX_DEPTH)
+    );
+    assert_eq!(
+        syntax_error_position(&"<array>".repeat(200_000)),
+        (1, 1 + 7 * MAX_DEPTH)
+    );
+}
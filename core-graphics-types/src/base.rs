@@ -12,6 +12,10 @@
 #![allow(non_camel_case_types)]
 #![allow(non_upper_case_globals)]
 
+use std::error;
+use std::fmt;
+use std::io;
+
 #[cfg(any(target_arch = "x86", target_arch = "arm", target_arch = "aarch64"))]
 pub type boolean_t = core::ffi::c_int;
 #[cfg(target_arch = "x86_64")]
@@ -22,18 +26,141 @@ pub type CGFloat = core::ffi::c_double;
 #[cfg(not(target_pointer_width = "64"))]
 pub type CGFloat = core::ffi::c_float;
 
-pub type CGError = i32;
+/// The result code returned by CoreGraphics functions, `kCGErrorSuccess` on success. Use
+/// [`CGError::check`] to turn it into a `Result`.
+///
+/// [`CGError::check`]: enum.CGError.html#method.check
+pub type CGErrorCode = i32;
 
 pub type CGGlyph = core::ffi::c_ushort;
 
-pub const kCGErrorSuccess: CGError = 0;
-pub const kCGErrorFailure: CGError = 1000;
-pub const kCGErrorIllegalArgument: CGError = 1001;
-pub const kCGErrorInvalidConnection: CGError = 1002;
-pub const kCGErrorInvalidContext: CGError = 1003;
-pub const kCGErrorCannotComplete: CGError = 1004;
-pub const kCGErrorNotImplemented: CGError = 1006;
-pub const kCGErrorRangeCheck: CGError = 1007;
-pub const kCGErrorTypeCheck: CGError = 1008;
-pub const kCGErrorInvalidOperation: CGError = 1010;
-pub const kCGErrorNoneAvailable: CGError = 1011;
+pub const kCGErrorSuccess: CGErrorCode = 0;
+pub const kCGErrorFailure: CGErrorCode = 1000;
+pub const kCGErrorIllegalArgument: CGErrorCode = 1001;
+pub const kCGErrorInvalidConnection: CGErrorCode = 1002;
+pub const kCGErrorInvalidContext: CGErrorCode = 1003;
+pub const kCGErrorCannotComplete: CGErrorCode = 1004;
+pub const kCGErrorNotImplemented: CGErrorCode = 1006;
+pub const kCGErrorRangeCheck: CGErrorCode = 1007;
+pub const kCGErrorTypeCheck: CGErrorCode = 1008;
+pub const kCGErrorInvalidOperation: CGErrorCode = 1010;
+pub const kCGErrorNoneAvailable: CGErrorCode = 1011;
+
+/// An error reported by a CoreGraphics function.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CGError {
+    /// `kCGErrorFailure`
+    Failure,
+    /// `kCGErrorIllegalArgument`
+    IllegalArgument,
+    /// `kCGErrorInvalidConnection`
+    InvalidConnection,
+    /// `kCGErrorInvalidContext`
+    InvalidContext,
+    /// `kCGErrorCannotComplete`
+    CannotComplete,
+    /// `kCGErrorNotImplemented`
+    NotImplemented,
+    /// `kCGErrorRangeCheck`
+    RangeCheck,
+    /// `kCGErrorTypeCheck`
+    TypeCheck,
+    /// `kCGErrorInvalidOperation`
+    InvalidOperation,
+    /// `kCGErrorNoneAvailable`
+    NoneAvailable,
+    /// An undocumented error code. This is never `kCGErrorSuccess`.
+    Unknown(CGErrorCode),
+}
+
+impl CGError {
+    /// Returns the error for `code`, or `None` if it is `kCGErrorSuccess`.
+    pub fn from_code(code: CGErrorCode) -> Option<CGError> {
+        Some(match code {
+            kCGErrorSuccess => return None,
+            kCGErrorFailure => CGError::Failure,
+            kCGErrorIllegalArgument => CGError::IllegalArgument,
+            kCGErrorInvalidConnection => CGError::InvalidConnection,
+            kCGErrorInvalidContext => CGError::InvalidContext,
+            kCGErrorCannotComplete => CGError::CannotComplete,
+            kCGErrorNotImplemented => CGError::NotImplemented,
+            kCGErrorRangeCheck => CGError::RangeCheck,
+            kCGErrorTypeCheck => CGError::TypeCheck,
+            kCGErrorInvalidOperation => CGError::InvalidOperation,
+            kCGErrorNoneAvailable => CGError::NoneAvailable,
+            code => CGError::Unknown(code),
+        })
+    }
+
+    /// Returns `Ok` for `kCGErrorSuccess` and the error for any other code.
+    #[inline]
+    pub fn check(code: CGErrorCode) -> Result<(), CGError> {
+        match CGError::from_code(code) {
+            None => Ok(()),
+            Some(err) => Err(err),
+        }
+    }
+
+    pub fn code(self) -> CGErrorCode {
+        match self {
+            CGError::Failure => kCGErrorFailure,
+            CGError::IllegalArgument => kCGErrorIllegalArgument,
+            CGError::InvalidConnection => kCGErrorInvalidConnection,
+            CGError::InvalidContext => kCGErrorInvalidContext,
+            CGError::CannotComplete => kCGErrorCannotComplete,
+            CGError::NotImplemented => kCGErrorNotImplemented,
+            CGError::RangeCheck => kCGErrorRangeCheck,
+            CGError::TypeCheck => kCGErrorTypeCheck,
+            CGError::InvalidOperation => kCGErrorInvalidOperation,
+            CGError::NoneAvailable => kCGErrorNoneAvailable,
+            CGError::Unknown(code) => code,
+        }
+    }
+
+    /// Returns the closest `io::ErrorKind`.
+    pub fn io_error_kind(self) -> io::ErrorKind {
+        match self {
+            CGError::IllegalArgument | CGError::RangeCheck | CGError::TypeCheck => {
+                io::ErrorKind::InvalidInput
+            }
+            CGError::InvalidConnection => io::ErrorKind::NotConnected,
+            CGError::NotImplemented => io::ErrorKind::Unsupported,
+            CGError::NoneAvailable => io::ErrorKind::NotFound,
+            _ => io::ErrorKind::Other,
+        }
+    }
+}
+
+impl fmt::Display for CGError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = match self {
+            CGError::Failure => "a general failure occurred",
+            CGError::IllegalArgument => "one or more parameters are not valid",
+            CGError::InvalidConnection => "the connection to the window server is not valid",
+            CGError::InvalidContext => "the graphics context is not valid",
+            CGError::CannotComplete => "the requested operation cannot be completed",
+            CGError::NotImplemented => "the requested function is not implemented",
+            CGError::RangeCheck => "a parameter is outside the accepted range",
+            CGError::TypeCheck => "a data type or token was encountered out of sequence",
+            CGError::InvalidOperation => "the requested operation is not valid",
+            CGError::NoneAvailable => "the requested resource is not available",
+            CGError::Unknown(code) => return write!(f, "unknown CoreGraphics error {}", code),
+        };
+        f.write_str(message)
+    }
+}
+
+impl error::Error for CGError {}
+
+impl From<CGError> for CGErrorCode {
+    #[inline]
+    fn from(err: CGError) -> CGErrorCode {
+        err.code()
+    }
+}
+
+impl From<CGError> for io::Error {
+    fn from(err: CGError) -> io::Error {
+        io::Error::new(err.io_error_kind(), err)
+    }
+}
@@ -10,6 +10,7 @@
 use crate::base::CGFloat;
 use core_foundation::base::TCFType;
 use core_foundation::dictionary::CFDictionary;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 pub const CG_ZERO_POINT: CGPoint = CGPoint { x: 0.0, y: 0.0 };
 
@@ -23,6 +24,28 @@ pub const CG_ZERO_RECT: CGRect = CGRect {
     size: CG_ZERO_SIZE,
 };
 
+/// The null rectangle, equivalent to `CGRectNull`. It is returned, for example, as the
+/// intersection of two disjoint rectangles.
+pub const CG_RECT_NULL: CGRect = CGRect {
+    origin: CGPoint {
+        x: CGFloat::INFINITY,
+        y: CGFloat::INFINITY,
+    },
+    size: CG_ZERO_SIZE,
+};
+
+/// The infinite rectangle, equivalent to `CGRectInfinite`.
+pub const CG_RECT_INFINITE: CGRect = CGRect {
+    origin: CGPoint {
+        x: -CGFloat::MAX / 2.0,
+        y: -CGFloat::MAX / 2.0,
+    },
+    size: CGSize {
+        width: CGFloat::MAX,
+        height: CGFloat::MAX,
+    },
+};
+
 pub const CG_AFFINE_TRANSFORM_IDENTITY: CGAffineTransform = CGAffineTransform {
     a: 1.0,
     b: 0.0,
@@ -33,7 +56,7 @@ pub const CG_AFFINE_TRANSFORM_IDENTITY: CGAffineTransform = CGAffineTransform {
 };
 
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct CGSize {
     pub width: CGFloat,
     pub height: CGFloat,
@@ -47,12 +70,15 @@ impl CGSize {
 
     #[inline]
     pub fn apply_transform(&self, t: &CGAffineTransform) -> CGSize {
-        unsafe { ffi::CGSizeApplyAffineTransform(*self, *t) }
+        CGSize {
+            width: t.a * self.width + t.c * self.height,
+            height: t.b * self.width + t.d * self.height,
+        }
     }
 }
 
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct CGPoint {
     pub x: CGFloat,
     pub y: CGFloat,
@@ -66,17 +92,66 @@ impl CGPoint {
 
     #[inline]
     pub fn apply_transform(&self, t: &CGAffineTransform) -> CGPoint {
-        unsafe { ffi::CGPointApplyAffineTransform(*self, *t) }
+        CGPoint {
+            x: t.a * self.x + t.c * self.y + t.tx,
+            y: t.b * self.x + t.d * self.y + t.ty,
+        }
     }
 }
 
+/// The difference between two points.
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CGVector {
+    pub dx: CGFloat,
+    pub dy: CGFloat,
+}
+
+impl CGVector {
+    #[inline]
+    pub fn new(dx: CGFloat, dy: CGFloat) -> CGVector {
+        CGVector { dx, dy }
+    }
+
+    /// Returns the vector that moves `from` to `to`.
+    #[inline]
+    pub fn between(from: CGPoint, to: CGPoint) -> CGVector {
+        CGVector::new(to.x - from.x, to.y - from.y)
+    }
+
+    /// Like [`CGPoint::apply_transform`], but ignoring the translation.
+    ///
+    /// [`CGPoint::apply_transform`]: struct.CGPoint.html#method.apply_transform
+    #[inline]
+    pub fn apply_transform(&self, t: &CGAffineTransform) -> CGVector {
+        CGVector {
+            dx: t.a * self.dx + t.c * self.dy,
+            dy: t.b * self.dx + t.d * self.dy,
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct CGRect {
     pub origin: CGPoint,
     pub size: CGSize,
 }
 
+/// The edges of a rectangle, used by [`CGRect::divide`].
+///
+/// [`CGRect::divide`]: struct.CGRect.html#method.divide
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CGRectEdge {
+    MinX = 0,
+    MinY = 1,
+    MaxX = 2,
+    MaxY = 3,
+}
+
+// As in CoreGraphics, every operation below treats a rectangle with a negative width or height
+// as its standardized equivalent, and the null rectangle as the empty set.
 impl CGRect {
     #[inline]
     pub fn new(origin: &CGPoint, size: &CGSize) -> CGRect {
@@ -86,9 +161,222 @@ impl CGRect {
         }
     }
 
+    /// Equivalent to `CGRectNull`.
+    #[inline]
+    pub fn null() -> CGRect {
+        CG_RECT_NULL
+    }
+
+    /// Equivalent to `CGRectInfinite`.
+    #[inline]
+    pub fn infinite() -> CGRect {
+        CG_RECT_INFINITE
+    }
+
+    #[inline]
+    pub fn is_null(&self) -> bool {
+        self.origin.x == CGFloat::INFINITY || self.origin.y == CGFloat::INFINITY
+    }
+
+    #[inline]
+    pub fn is_infinite(&self) -> bool {
+        *self == CG_RECT_INFINITE
+    }
+
+    /// Returns `true` if this rectangle is null or has a zero width or height.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.is_null() || self.size.width == 0.0 || self.size.height == 0.0
+    }
+
+    /// Returns an equivalent rectangle with a non-negative width and height.
+    pub fn standardize(&self) -> CGRect {
+        if self.is_null() {
+            return CG_RECT_NULL;
+        }
+        let mut rect = *self;
+        if rect.size.width < 0.0 {
+            rect.origin.x += rect.size.width;
+            rect.size.width = -rect.size.width;
+        }
+        if rect.size.height < 0.0 {
+            rect.origin.y += rect.size.height;
+            rect.size.height = -rect.size.height;
+        }
+        rect
+    }
+
+    #[inline]
+    pub fn min_x(&self) -> CGFloat {
+        self.standardize().origin.x
+    }
+
+    #[inline]
+    pub fn mid_x(&self) -> CGFloat {
+        let rect = self.standardize();
+        rect.origin.x + rect.size.width / 2.0
+    }
+
+    #[inline]
+    pub fn max_x(&self) -> CGFloat {
+        let rect = self.standardize();
+        rect.origin.x + rect.size.width
+    }
+
+    #[inline]
+    pub fn min_y(&self) -> CGFloat {
+        self.standardize().origin.y
+    }
+
+    #[inline]
+    pub fn mid_y(&self) -> CGFloat {
+        let rect = self.standardize();
+        rect.origin.y + rect.size.height / 2.0
+    }
+
+    #[inline]
+    pub fn max_y(&self) -> CGFloat {
+        let rect = self.standardize();
+        rect.origin.y + rect.size.height
+    }
+
+    #[inline]
+    pub fn width(&self) -> CGFloat {
+        self.size.width.abs()
+    }
+
     #[inline]
+    pub fn height(&self) -> CGFloat {
+        self.size.height.abs()
+    }
+
+    /// Builds a standardized rectangle from its edges, or the null rectangle if they cross.
+    fn from_edges(min_x: CGFloat, min_y: CGFloat, max_x: CGFloat, max_y: CGFloat) -> CGRect {
+        if max_x < min_x || max_y < min_y {
+            return CG_RECT_NULL;
+        }
+        CGRect::new(
+            &CGPoint::new(min_x, min_y),
+            &CGSize::new(max_x - min_x, max_y - min_y),
+        )
+    }
+
+    /// Moves each edge `dx` and `dy` inwards, or outwards for negative values. Returns the null
+    /// rectangle if the result would have a negative width or height.
     pub fn inset(&self, size: &CGSize) -> CGRect {
-        unsafe { ffi::CGRectInset(*self, size.width, size.height) }
+        if self.is_null() {
+            return CG_RECT_NULL;
+        }
+        CGRect::from_edges(
+            self.min_x() + size.width,
+            self.min_y() + size.height,
+            self.max_x() - size.width,
+            self.max_y() - size.height,
+        )
+    }
+
+    pub fn offset(&self, dx: CGFloat, dy: CGFloat) -> CGRect {
+        if self.is_null() {
+            return CG_RECT_NULL;
+        }
+        let mut rect = self.standardize();
+        rect.origin.x += dx;
+        rect.origin.y += dy;
+        rect
+    }
+
+    /// Returns the smallest rectangle with integral coordinates that contains this one.
+    pub fn integral(&self) -> CGRect {
+        if self.is_null() {
+            return CG_RECT_NULL;
+        }
+        CGRect::from_edges(
+            self.min_x().floor(),
+            self.min_y().floor(),
+            self.max_x().ceil(),
+            self.max_y().ceil(),
+        )
+    }
+
+    /// Returns the smallest rectangle that contains both rectangles. A null rectangle is
+    /// ignored.
+    pub fn union(&self, other: &CGRect) -> CGRect {
+        if self.is_null() {
+            return other.standardize();
+        }
+        if other.is_null() {
+            return self.standardize();
+        }
+        CGRect::from_edges(
+            self.min_x().min(other.min_x()),
+            self.min_y().min(other.min_y()),
+            self.max_x().max(other.max_x()),
+            self.max_y().max(other.max_y()),
+        )
+    }
+
+    /// Returns the overlap of both rectangles, or the null rectangle if they do not overlap.
+    ///
+    /// Rectangles that only share an edge produce a zero-width or zero-height rectangle.
+    pub fn intersection(&self, other: &CGRect) -> CGRect {
+        if self.is_null() || other.is_null() {
+            return CG_RECT_NULL;
+        }
+        CGRect::from_edges(
+            self.min_x().max(other.min_x()),
+            self.min_y().max(other.min_y()),
+            self.max_x().min(other.max_x()),
+            self.max_y().min(other.max_y()),
+        )
+    }
+
+    /// Returns `true` if the rectangles overlap by a non-zero area.
+    #[inline]
+    pub fn is_intersects(&self, other: &CGRect) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// Splits this rectangle in two along a line parallel to `edge`.
+    ///
+    /// Returns `(slice, remainder)`, where `slice` is the part within `amount` of `edge`.
+    /// `amount` is clamped to the size of the rectangle.
+    pub fn divide(&self, amount: CGFloat, edge: CGRectEdge) -> (CGRect, CGRect) {
+        if self.is_null() {
+            return (CG_RECT_NULL, CG_RECT_NULL);
+        }
+        let rect = self.standardize();
+        let (min_x, min_y, max_x, max_y) = (rect.min_x(), rect.min_y(), rect.max_x(), rect.max_y());
+        let amount = amount.max(0.0);
+        match edge {
+            CGRectEdge::MinX => {
+                let split = (min_x + amount).min(max_x);
+                (
+                    CGRect::from_edges(min_x, min_y, split, max_y),
+                    CGRect::from_edges(split, min_y, max_x, max_y),
+                )
+            }
+            CGRectEdge::MinY => {
+                let split = (min_y + amount).min(max_y);
+                (
+                    CGRect::from_edges(min_x, min_y, max_x, split),
+                    CGRect::from_edges(min_x, split, max_x, max_y),
+                )
+            }
+            CGRectEdge::MaxX => {
+                let split = (max_x - amount).max(min_x);
+                (
+                    CGRect::from_edges(split, min_y, max_x, max_y),
+                    CGRect::from_edges(min_x, min_y, split, max_y),
+                )
+            }
+            CGRectEdge::MaxY => {
+                let split = (max_y - amount).max(min_y);
+                (
+                    CGRect::from_edges(min_x, split, max_x, max_y),
+                    CGRect::from_edges(min_x, min_y, max_x, split),
+                )
+            }
+        }
     }
 
     #[inline]
@@ -104,35 +392,59 @@ impl CGRect {
         }
     }
 
-    #[inline]
-    pub fn is_empty(&self) -> bool {
-        unsafe {
-            // I use one, as it seems that `YES` is not available from this crate.
-            ffi::CGRectIsEmpty(*self) == 1
+    /// Returns the smallest rectangle containing the four transformed corners of this rectangle.
+    pub fn apply_transform(&self, t: &CGAffineTransform) -> CGRect {
+        if self.is_null() {
+            return CG_RECT_NULL;
         }
+        let (x0, x1) = (self.origin.x, self.origin.x + self.size.width);
+        let (y0, y1) = (self.origin.y, self.origin.y + self.size.height);
+        let corners = [
+            CGPoint::new(x0, y0).apply_transform(t),
+            CGPoint::new(x1, y0).apply_transform(t),
+            CGPoint::new(x0, y1).apply_transform(t),
+            CGPoint::new(x1, y1).apply_transform(t),
+        ];
+        let (mut min, mut max) = (corners[0], corners[0]);
+        for corner in &corners[1..] {
+            min.x = min.x.min(corner.x);
+            min.y = min.y.min(corner.y);
+            max.x = max.x.max(corner.x);
+            max.y = max.y.max(corner.y);
+        }
+        CGRect::new(&min, &CGSize::new(max.x - min.x, max.y - min.y))
     }
 
-    #[inline]
-    pub fn is_intersects(&self, other: &CGRect) -> bool {
-        unsafe {
-            // I use one, as it seems that `YES` is not available from this crate.
-            ffi::CGRectIntersectsRect(*self, *other) == 1
-        }
+    /// Returns `true` if `point` lies inside this rectangle. Points on the minimum edges are
+    /// inside, points on the maximum edges are not.
+    pub fn contains(&self, point: &CGPoint) -> bool {
+        !self.is_null()
+            && point.x >= self.min_x()
+            && point.x < self.max_x()
+            && point.y >= self.min_y()
+            && point.y < self.max_y()
     }
 
+    /// Returns `true` if `other` lies entirely inside this rectangle, that is, if the union of
+    /// both is this rectangle.
     #[inline]
-    pub fn apply_transform(&self, t: &CGAffineTransform) -> CGRect {
-        unsafe { ffi::CGRectApplyAffineTransform(*self, *t) }
+    pub fn contains_rect(&self, other: &CGRect) -> bool {
+        self.union(other).equal_to_rect(self)
     }
 
+    /// Compares the standardized forms of both rectangles, as `CGRectEqualToRect` does.
     #[inline]
-    pub fn contains(&self, point: &CGPoint) -> bool {
-        unsafe { ffi::CGRectContainsPoint(*self, *point) == 1 }
+    pub fn equal_to_rect(&self, other: &CGRect) -> bool {
+        self.standardize() == other.standardize()
     }
 }
 
+/// An affine transformation matrix.
+///
+/// Points are treated as row vectors, so a point `(x, y)` is mapped to
+/// `(a * x + c * y + tx, b * x + d * y + ty)`, as in CoreGraphics.
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct CGAffineTransform {
     pub a: CGFloat,
     pub b: CGFloat,
@@ -156,32 +468,469 @@ impl CGAffineTransform {
     }
 
     #[inline]
-    pub fn invert(&self) -> CGAffineTransform {
-        unsafe { ffi::CGAffineTransformInvert(*self) }
+    pub fn identity() -> CGAffineTransform {
+        CG_AFFINE_TRANSFORM_IDENTITY
+    }
+
+    /// Equivalent to `CGAffineTransformMakeTranslation`.
+    #[inline]
+    pub fn translation(tx: CGFloat, ty: CGFloat) -> CGAffineTransform {
+        CGAffineTransform::new(1.0, 0.0, 0.0, 1.0, tx, ty)
+    }
+
+    /// Equivalent to `CGAffineTransformMakeScale`.
+    #[inline]
+    pub fn scale(sx: CGFloat, sy: CGFloat) -> CGAffineTransform {
+        CGAffineTransform::new(sx, 0.0, 0.0, sy, 0.0, 0.0)
+    }
+
+    /// Equivalent to `CGAffineTransformMakeRotation`. The angle is in radians; positive angles
+    /// rotate counterclockwise in a y-up coordinate system.
+    #[inline]
+    pub fn rotation(angle: CGFloat) -> CGAffineTransform {
+        let (sin, cos) = angle.sin_cos();
+        CGAffineTransform::new(cos, sin, -sin, cos, 0.0, 0.0)
+    }
+
+    /// A shear that maps `(x, y)` to `(x + sx * y, y + sy * x)`.
+    #[inline]
+    pub fn shear(sx: CGFloat, sy: CGFloat) -> CGAffineTransform {
+        CGAffineTransform::new(1.0, sy, sx, 1.0, 0.0, 0.0)
+    }
+
+    /// Returns the transform that applies `self` and then `other`.
+    ///
+    /// Equivalent to `CGAffineTransformConcat(self, other)`, and to `self * other`.
+    #[inline]
+    pub fn concat(&self, other: &CGAffineTransform) -> CGAffineTransform {
+        CGAffineTransform {
+            a: self.a * other.a + self.b * other.c,
+            b: self.a * other.b + self.b * other.d,
+            c: self.c * other.a + self.d * other.c,
+            d: self.c * other.b + self.d * other.d,
+            tx: self.tx * other.a + self.ty * other.c + other.tx,
+            ty: self.tx * other.b + self.ty * other.d + other.ty,
+        }
+    }
+
+    #[inline]
+    pub fn determinant(&self) -> CGFloat {
+        self.a * self.d - self.b * self.c
+    }
+
+    #[inline]
+    pub fn is_identity(&self) -> bool {
+        *self == CG_AFFINE_TRANSFORM_IDENTITY
+    }
+
+    /// Returns the inverse of this transform, or `None` if it is singular.
+    ///
+    /// Unlike `CGAffineTransformInvert`, which returns its argument unchanged when it cannot be
+    /// inverted, a singular transform is reported to the caller.
+    pub fn invert(&self) -> Option<CGAffineTransform> {
+        let det = self.determinant();
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        Some(CGAffineTransform {
+            a: self.d / det,
+            b: -self.b / det,
+            c: -self.c / det,
+            d: self.a / det,
+            tx: (self.c * self.ty - self.d * self.tx) / det,
+            ty: (self.b * self.tx - self.a * self.ty) / det,
+        })
+    }
+
+    /// Splits this transform into scale, horizontal shear, rotation and translation, in the
+    /// order in which they are applied.
+    ///
+    /// The x scale is never negative; a reflection is carried by a negative y scale.
+    pub fn decompose(&self) -> CGAffineTransformComponents {
+        let sx = self.a.hypot(self.b);
+        let rotation = if sx == 0.0 { 0.0 } else { self.b.atan2(self.a) };
+        let (sin, cos) = rotation.sin_cos();
+        // Undo the rotation on the second row, which leaves `(sy * shear, sy)`.
+        let sy = self.d * cos - self.c * sin;
+        let sheared = self.c * cos + self.d * sin;
+        let horizontal_shear = if sy == 0.0 { 0.0 } else { sheared / sy };
+        CGAffineTransformComponents {
+            scale: CGSize::new(sx, sy),
+            horizontal_shear,
+            rotation,
+            translation: CGPoint::new(self.tx, self.ty),
+        }
+    }
+
+    /// Builds a transform from the result of [`decompose`].
+    ///
+    /// [`decompose`]: #method.decompose
+    pub fn from_components(components: &CGAffineTransformComponents) -> CGAffineTransform {
+        CGAffineTransform::scale(components.scale.width, components.scale.height)
+            * CGAffineTransform::shear(components.horizontal_shear, 0.0)
+            * CGAffineTransform::rotation(components.rotation)
+            * CGAffineTransform::translation(components.translation.x, components.translation.y)
+    }
+}
+
+impl Mul for CGAffineTransform {
+    type Output = CGAffineTransform;
+
+    /// Returns the transform that applies `self` and then `other`.
+    #[inline]
+    fn mul(self, other: CGAffineTransform) -> CGAffineTransform {
+        self.concat(&other)
+    }
+}
+
+impl MulAssign for CGAffineTransform {
+    #[inline]
+    fn mul_assign(&mut self, other: CGAffineTransform) {
+        *self = self.concat(&other);
+    }
+}
+
+/// The parts of a [`CGAffineTransform`], as returned by [`CGAffineTransform::decompose`].
+///
+/// The transform is the scale, followed by the horizontal shear, the rotation (in radians) and
+/// finally the translation.
+///
+/// [`CGAffineTransform`]: struct.CGAffineTransform.html
+/// [`CGAffineTransform::decompose`]: struct.CGAffineTransform.html#method.decompose
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CGAffineTransformComponents {
+    pub scale: CGSize,
+    pub horizontal_shear: CGFloat,
+    pub rotation: CGFloat,
+    pub translation: CGPoint,
+}
+
+// Arithmetic. Points, vectors and sizes add and subtract componentwise, and a point can also be
+// moved by a vector. `CGVector::between` gives the vector from one point to another.
+
+macro_rules! impl_vector_ops {
+    ($ty:ident, $x:ident, $y:ident) => {
+        impl Add for $ty {
+            type Output = $ty;
+
+            #[inline]
+            fn add(self, other: $ty) -> $ty {
+                $ty::new(self.$x + other.$x, self.$y + other.$y)
+            }
+        }
+
+        impl AddAssign for $ty {
+            #[inline]
+            fn add_assign(&mut self, other: $ty) {
+                *self = *self + other;
+            }
+        }
+
+        impl Sub for $ty {
+            type Output = $ty;
+
+            #[inline]
+            fn sub(self, other: $ty) -> $ty {
+                $ty::new(self.$x - other.$x, self.$y - other.$y)
+            }
+        }
+
+        impl SubAssign for $ty {
+            #[inline]
+            fn sub_assign(&mut self, other: $ty) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+macro_rules! impl_scale_ops {
+    ($ty:ident, $x:ident, $y:ident) => {
+        impl Mul<CGFloat> for $ty {
+            type Output = $ty;
+
+            #[inline]
+            fn mul(self, scale: CGFloat) -> $ty {
+                $ty::new(self.$x * scale, self.$y * scale)
+            }
+        }
+
+        impl MulAssign<CGFloat> for $ty {
+            #[inline]
+            fn mul_assign(&mut self, scale: CGFloat) {
+                *self = *self * scale;
+            }
+        }
+
+        impl Neg for $ty {
+            type Output = $ty;
+
+            #[inline]
+            fn neg(self) -> $ty {
+                $ty::new(-self.$x, -self.$y)
+            }
+        }
+    };
+}
+
+impl_vector_ops!(CGPoint, x, y);
+impl_vector_ops!(CGVector, dx, dy);
+impl_vector_ops!(CGSize, width, height);
+impl_scale_ops!(CGVector, dx, dy);
+impl_scale_ops!(CGSize, width, height);
+impl_scale_ops!(CGPoint, x, y);
+
+impl Add<CGVector> for CGPoint {
+    type Output = CGPoint;
+
+    #[inline]
+    fn add(self, offset: CGVector) -> CGPoint {
+        CGPoint::new(self.x + offset.dx, self.y + offset.dy)
+    }
+}
+
+impl AddAssign<CGVector> for CGPoint {
+    #[inline]
+    fn add_assign(&mut self, offset: CGVector) {
+        *self = *self + offset;
+    }
+}
+
+impl Sub<CGVector> for CGPoint {
+    type Output = CGPoint;
+
+    #[inline]
+    fn sub(self, offset: CGVector) -> CGPoint {
+        CGPoint::new(self.x - offset.dx, self.y - offset.dy)
+    }
+}
+
+impl SubAssign<CGVector> for CGPoint {
+    #[inline]
+    fn sub_assign(&mut self, offset: CGVector) {
+        *self = *self - offset;
+    }
+}
+
+// Conversions from and to `(x, y)` tuples and `[x, y]` arrays.
+
+macro_rules! impl_pair_conversions {
+    ($ty:ident, $x:ident, $y:ident) => {
+        impl From<(CGFloat, CGFloat)> for $ty {
+            #[inline]
+            fn from(($x, $y): (CGFloat, CGFloat)) -> $ty {
+                $ty { $x, $y }
+            }
+        }
+
+        impl From<[CGFloat; 2]> for $ty {
+            #[inline]
+            fn from([$x, $y]: [CGFloat; 2]) -> $ty {
+                $ty { $x, $y }
+            }
+        }
+
+        impl From<$ty> for (CGFloat, CGFloat) {
+            #[inline]
+            fn from(value: $ty) -> (CGFloat, CGFloat) {
+                (value.$x, value.$y)
+            }
+        }
+
+        impl From<$ty> for [CGFloat; 2] {
+            #[inline]
+            fn from(value: $ty) -> [CGFloat; 2] {
+                [value.$x, value.$y]
+            }
+        }
+    };
+}
+
+impl_pair_conversions!(CGPoint, x, y);
+impl_pair_conversions!(CGSize, width, height);
+impl_pair_conversions!(CGVector, dx, dy);
+
+impl From<(CGPoint, CGSize)> for CGRect {
+    #[inline]
+    fn from((origin, size): (CGPoint, CGSize)) -> CGRect {
+        CGRect { origin, size }
+    }
+}
+
+impl From<CGRect> for (CGPoint, CGSize) {
+    #[inline]
+    fn from(rect: CGRect) -> (CGPoint, CGSize) {
+        (rect.origin, rect.size)
+    }
+}
+
+/// `[x, y, width, height]`.
+impl From<[CGFloat; 4]> for CGRect {
+    #[inline]
+    fn from([x, y, width, height]: [CGFloat; 4]) -> CGRect {
+        CGRect::new(&CGPoint::new(x, y), &CGSize::new(width, height))
+    }
+}
+
+/// `[x, y, width, height]`.
+impl From<CGRect> for [CGFloat; 4] {
+    #[inline]
+    fn from(rect: CGRect) -> [CGFloat; 4] {
+        [
+            rect.origin.x,
+            rect.origin.y,
+            rect.size.width,
+            rect.size.height,
+        ]
+    }
+}
+
+#[cfg(feature = "mint")]
+mod mint_conversions {
+    use super::{CGPoint, CGSize, CGVector};
+    use crate::base::CGFloat;
+
+    impl From<mint::Point2<CGFloat>> for CGPoint {
+        #[inline]
+        fn from(point: mint::Point2<CGFloat>) -> CGPoint {
+            CGPoint::new(point.x, point.y)
+        }
+    }
+
+    impl From<CGPoint> for mint::Point2<CGFloat> {
+        #[inline]
+        fn from(point: CGPoint) -> mint::Point2<CGFloat> {
+            mint::Point2 {
+                x: point.x,
+                y: point.y,
+            }
+        }
+    }
+
+    impl From<mint::Vector2<CGFloat>> for CGVector {
+        #[inline]
+        fn from(vector: mint::Vector2<CGFloat>) -> CGVector {
+            CGVector::new(vector.x, vector.y)
+        }
+    }
+
+    impl From<CGVector> for mint::Vector2<CGFloat> {
+        #[inline]
+        fn from(vector: CGVector) -> mint::Vector2<CGFloat> {
+            mint::Vector2 {
+                x: vector.dx,
+                y: vector.dy,
+            }
+        }
+    }
+
+    /// mint has no size type; sizes convert to and from `(width, height)` vectors.
+    impl From<mint::Vector2<CGFloat>> for CGSize {
+        #[inline]
+        fn from(size: mint::Vector2<CGFloat>) -> CGSize {
+            CGSize::new(size.x, size.y)
+        }
+    }
+
+    impl From<CGSize> for mint::Vector2<CGFloat> {
+        #[inline]
+        fn from(size: CGSize) -> mint::Vector2<CGFloat> {
+            mint::Vector2 {
+                x: size.width,
+                y: size.height,
+            }
+        }
+    }
+}
+
+#[cfg(feature = "euclid")]
+mod euclid_conversions {
+    use super::{CGAffineTransform, CGPoint, CGRect, CGSize, CGVector};
+    use crate::base::CGFloat;
+    use euclid::{Point2D, Rect, Size2D, Transform2D, Vector2D};
+
+    impl<U> From<Point2D<CGFloat, U>> for CGPoint {
+        #[inline]
+        fn from(point: Point2D<CGFloat, U>) -> CGPoint {
+            CGPoint::new(point.x, point.y)
+        }
+    }
+
+    impl<U> From<CGPoint> for Point2D<CGFloat, U> {
+        #[inline]
+        fn from(point: CGPoint) -> Point2D<CGFloat, U> {
+            Point2D::new(point.x, point.y)
+        }
+    }
+
+    impl<U> From<Vector2D<CGFloat, U>> for CGVector {
+        #[inline]
+        fn from(vector: Vector2D<CGFloat, U>) -> CGVector {
+            CGVector::new(vector.x, vector.y)
+        }
+    }
+
+    impl<U> From<CGVector> for Vector2D<CGFloat, U> {
+        #[inline]
+        fn from(vector: CGVector) -> Vector2D<CGFloat, U> {
+            Vector2D::new(vector.dx, vector.dy)
+        }
+    }
+
+    impl<U> From<Size2D<CGFloat, U>> for CGSize {
+        #[inline]
+        fn from(size: Size2D<CGFloat, U>) -> CGSize {
+            CGSize::new(size.width, size.height)
+        }
+    }
+
+    impl<U> From<CGSize> for Size2D<CGFloat, U> {
+        #[inline]
+        fn from(size: CGSize) -> Size2D<CGFloat, U> {
+            Size2D::new(size.width, size.height)
+        }
+    }
+
+    impl<U> From<Rect<CGFloat, U>> for CGRect {
+        #[inline]
+        fn from(rect: Rect<CGFloat, U>) -> CGRect {
+            CGRect::new(&rect.origin.into(), &rect.size.into())
+        }
+    }
+
+    impl<U> From<CGRect> for Rect<CGFloat, U> {
+        #[inline]
+        fn from(rect: CGRect) -> Rect<CGFloat, U> {
+            Rect::new(rect.origin.into(), rect.size.into())
+        }
+    }
+
+    /// Both use row vectors, so the matrix entries map one to one.
+    impl<Src, Dst> From<Transform2D<CGFloat, Src, Dst>> for CGAffineTransform {
+        #[inline]
+        fn from(t: Transform2D<CGFloat, Src, Dst>) -> CGAffineTransform {
+            CGAffineTransform::new(t.m11, t.m12, t.m21, t.m22, t.m31, t.m32)
+        }
+    }
+
+    impl<Src, Dst> From<CGAffineTransform> for Transform2D<CGFloat, Src, Dst> {
+        #[inline]
+        fn from(t: CGAffineTransform) -> Transform2D<CGFloat, Src, Dst> {
+            Transform2D::new(t.a, t.b, t.c, t.d, t.tx, t.ty)
+        }
     }
 }
 
 mod ffi {
-    use crate::base::{boolean_t, CGFloat};
-    use crate::geometry::{CGAffineTransform, CGPoint, CGRect, CGSize};
+    use crate::base::boolean_t;
+    use crate::geometry::CGRect;
     use core_foundation::dictionary::CFDictionaryRef;
 
     #[cfg_attr(feature = "link", link(name = "CoreGraphics", kind = "framework"))]
     extern "C" {
-        pub fn CGRectInset(rect: CGRect, dx: CGFloat, dy: CGFloat) -> CGRect;
         pub fn CGRectMakeWithDictionaryRepresentation(
             dict: CFDictionaryRef,
             rect: *mut CGRect,
         ) -> boolean_t;
-        pub fn CGRectIsEmpty(rect: CGRect) -> boolean_t;
-        pub fn CGRectIntersectsRect(rect1: CGRect, rect2: CGRect) -> boolean_t;
-
-        pub fn CGAffineTransformInvert(t: CGAffineTransform) -> CGAffineTransform;
-
-        pub fn CGPointApplyAffineTransform(point: CGPoint, t: CGAffineTransform) -> CGPoint;
-        pub fn CGRectApplyAffineTransform(rect: CGRect, t: CGAffineTransform) -> CGRect;
-        pub fn CGSizeApplyAffineTransform(size: CGSize, t: CGAffineTransform) -> CGSize;
-
-        pub fn CGRectContainsPoint(rect: CGRect, point: CGPoint) -> boolean_t;
     }
 }
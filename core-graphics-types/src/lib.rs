@@ -9,3 +9,4 @@
 
 pub mod base;
 pub mod geometry;
+pub mod transform3d;
@@ -0,0 +1,460 @@
+// Copyright 2026 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Core Animation `CATransform3D` matrix, implemented without calling into QuartzCore so
+//! that it can be used on any platform.
+
+use crate::base::CGFloat;
+use crate::geometry::CGAffineTransform;
+use std::ops::{Mul, MulAssign};
+
+/// A 4x4 homogeneous transform, laid out like QuartzCore's `CATransform3D`.
+///
+/// As with [`CGAffineTransform`], points are row vectors: `(x, y, z, 1)` is multiplied by the
+/// matrix on the right, so the translation lives in `m41`, `m42` and `m43`, and `a * b` applies
+/// `a` first.
+///
+/// [`CGAffineTransform`]: ../geometry/struct.CGAffineTransform.html
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CATransform3D {
+    pub m11: CGFloat,
+    pub m12: CGFloat,
+    pub m13: CGFloat,
+    pub m14: CGFloat,
+    pub m21: CGFloat,
+    pub m22: CGFloat,
+    pub m23: CGFloat,
+    pub m24: CGFloat,
+    pub m31: CGFloat,
+    pub m32: CGFloat,
+    pub m33: CGFloat,
+    pub m34: CGFloat,
+    pub m41: CGFloat,
+    pub m42: CGFloat,
+    pub m43: CGFloat,
+    pub m44: CGFloat,
+}
+
+/// A rotation, stored as a unit quaternion.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Quaternion {
+    pub x: CGFloat,
+    pub y: CGFloat,
+    pub z: CGFloat,
+    pub w: CGFloat,
+}
+
+impl Quaternion {
+    pub const IDENTITY: Quaternion = Quaternion {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+        w: 1.0,
+    };
+
+    /// A rotation of `angle` radians around the axis `(x, y, z)`, in the same direction as
+    /// [`CATransform3D::from_rotation`]. A zero axis gives the identity.
+    ///
+    /// [`CATransform3D::from_rotation`]: struct.CATransform3D.html#method.from_rotation
+    pub fn from_axis_angle(angle: CGFloat, x: CGFloat, y: CGFloat, z: CGFloat) -> Quaternion {
+        let length = (x * x + y * y + z * z).sqrt();
+        if length == 0.0 {
+            return Quaternion::IDENTITY;
+        }
+        let (sin, cos) = (angle / 2.0).sin_cos();
+        let factor = sin / length;
+        Quaternion {
+            x: x * factor,
+            y: y * factor,
+            z: z * factor,
+            w: cos,
+        }
+    }
+}
+
+/// The parts of a [`CATransform3D`], as returned by [`CATransform3D::decompose`].
+///
+/// The transform is the scale, followed by the rotation and then the translation.
+///
+/// [`CATransform3D`]: struct.CATransform3D.html
+/// [`CATransform3D::decompose`]: struct.CATransform3D.html#method.decompose
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CATransform3DComponents {
+    pub scale: [CGFloat; 3],
+    pub rotation: Quaternion,
+    pub translation: [CGFloat; 3],
+}
+
+impl CATransform3D {
+    pub const IDENTITY: CATransform3D = CATransform3D {
+        m11: 1.0,
+        m12: 0.0,
+        m13: 0.0,
+        m14: 0.0,
+        m21: 0.0,
+        m22: 1.0,
+        m23: 0.0,
+        m24: 0.0,
+        m31: 0.0,
+        m32: 0.0,
+        m33: 1.0,
+        m34: 0.0,
+        m41: 0.0,
+        m42: 0.0,
+        m43: 0.0,
+        m44: 1.0,
+    };
+
+    #[inline]
+    fn from_rows(rows: [[CGFloat; 4]; 4]) -> CATransform3D {
+        let [[m11, m12, m13, m14], [m21, m22, m23, m24], [m31, m32, m33, m34], [m41, m42, m43, m44]] =
+            rows;
+        CATransform3D {
+            m11,
+            m12,
+            m13,
+            m14,
+            m21,
+            m22,
+            m23,
+            m24,
+            m31,
+            m32,
+            m33,
+            m34,
+            m41,
+            m42,
+            m43,
+            m44,
+        }
+    }
+
+    #[inline]
+    fn rows(&self) -> [[CGFloat; 4]; 4] {
+        [
+            [self.m11, self.m12, self.m13, self.m14],
+            [self.m21, self.m22, self.m23, self.m24],
+            [self.m31, self.m32, self.m33, self.m34],
+            [self.m41, self.m42, self.m43, self.m44],
+        ]
+    }
+
+    /// Equivalent to `CATransform3DMakeTranslation`.
+    #[inline]
+    pub fn from_translation(tx: CGFloat, ty: CGFloat, tz: CGFloat) -> CATransform3D {
+        CATransform3D {
+            m41: tx,
+            m42: ty,
+            m43: tz,
+            ..CATransform3D::IDENTITY
+        }
+    }
+
+    /// Equivalent to `CATransform3DMakeScale`.
+    #[inline]
+    pub fn from_scale(sx: CGFloat, sy: CGFloat, sz: CGFloat) -> CATransform3D {
+        CATransform3D {
+            m11: sx,
+            m22: sy,
+            m33: sz,
+            ..CATransform3D::IDENTITY
+        }
+    }
+
+    /// Equivalent to `CATransform3DMakeRotation`: a rotation of `angle` radians around the axis
+    /// `(x, y, z)`, which need not be normalized. A zero axis gives the identity.
+    pub fn from_rotation(angle: CGFloat, x: CGFloat, y: CGFloat, z: CGFloat) -> CATransform3D {
+        CATransform3D::from_quaternion(&Quaternion::from_axis_angle(angle, x, y, z))
+    }
+
+    /// Builds the rotation described by a unit quaternion.
+    pub fn from_quaternion(q: &Quaternion) -> CATransform3D {
+        let Quaternion { x, y, z, w } = *q;
+        CATransform3D::from_rows([
+            [
+                1.0 - 2.0 * (y * y + z * z),
+                2.0 * (x * y + z * w),
+                2.0 * (x * z - y * w),
+                0.0,
+            ],
+            [
+                2.0 * (x * y - z * w),
+                1.0 - 2.0 * (x * x + z * z),
+                2.0 * (y * z + x * w),
+                0.0,
+            ],
+            [
+                2.0 * (x * z + y * w),
+                2.0 * (y * z - x * w),
+                1.0 - 2.0 * (x * x + y * y),
+                0.0,
+            ],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// A perspective projection as seen from `distance` units in front of the `z = 0` plane,
+    /// that is, the identity with `m34` set to `-1 / distance`.
+    #[inline]
+    pub fn from_perspective(distance: CGFloat) -> CATransform3D {
+        CATransform3D {
+            m34: -1.0 / distance,
+            ..CATransform3D::IDENTITY
+        }
+    }
+
+    /// Equivalent to `CATransform3DMakeAffineTransform`.
+    #[inline]
+    pub fn affine(affine_transform: CGAffineTransform) -> CATransform3D {
+        CATransform3D {
+            m11: affine_transform.a,
+            m12: affine_transform.b,
+            m21: affine_transform.c,
+            m22: affine_transform.d,
+            m41: affine_transform.tx,
+            m42: affine_transform.ty,
+            ..CATransform3D::IDENTITY
+        }
+    }
+
+    /// Builds a transform from the result of [`decompose`].
+    ///
+    /// [`decompose`]: #method.decompose
+    pub fn from_components(components: &CATransform3DComponents) -> CATransform3D {
+        let [sx, sy, sz] = components.scale;
+        let [tx, ty, tz] = components.translation;
+        CATransform3D::from_scale(sx, sy, sz)
+            * CATransform3D::from_quaternion(&components.rotation)
+            * CATransform3D::from_translation(tx, ty, tz)
+    }
+
+    #[inline]
+    pub fn is_identity(&self) -> bool {
+        *self == CATransform3D::IDENTITY
+    }
+
+    /// Returns the transform that applies `self` and then `other`.
+    ///
+    /// Equivalent to `CATransform3DConcat(self, other)`, and to `self * other`.
+    pub fn concat(&self, other: &CATransform3D) -> CATransform3D {
+        let (a, b) = (self.rows(), other.rows());
+        let mut rows = [[0.0; 4]; 4];
+        for (i, row) in rows.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = (0..4).map(|k| a[i][k] * b[k][j]).sum();
+            }
+        }
+        CATransform3D::from_rows(rows)
+    }
+
+    /// Equivalent to `CATransform3DTranslate`: translates, then applies `self`.
+    #[inline]
+    pub fn translate(&self, tx: CGFloat, ty: CGFloat, tz: CGFloat) -> CATransform3D {
+        CATransform3D::from_translation(tx, ty, tz).concat(self)
+    }
+
+    /// Equivalent to `CATransform3DScale`: scales, then applies `self`.
+    #[inline]
+    pub fn scale(&self, sx: CGFloat, sy: CGFloat, sz: CGFloat) -> CATransform3D {
+        CATransform3D::from_scale(sx, sy, sz).concat(self)
+    }
+
+    /// Equivalent to `CATransform3DRotate`: rotates, then applies `self`.
+    #[inline]
+    pub fn rotate(&self, angle: CGFloat, x: CGFloat, y: CGFloat, z: CGFloat) -> CATransform3D {
+        CATransform3D::from_rotation(angle, x, y, z).concat(self)
+    }
+
+    pub fn determinant(&self) -> CGFloat {
+        let m = self.rows();
+        let minor = |c0: usize, c1: usize| m[2][c0] * m[3][c1] - m[2][c1] * m[3][c0];
+        let cofactor = |c: [usize; 3]| {
+            m[1][c[0]] * minor(c[1], c[2]) - m[1][c[1]] * minor(c[0], c[2])
+                + m[1][c[2]] * minor(c[0], c[1])
+        };
+        m[0][0] * cofactor([1, 2, 3]) - m[0][1] * cofactor([0, 2, 3])
+            + m[0][2] * cofactor([0, 1, 3])
+            - m[0][3] * cofactor([0, 1, 2])
+    }
+
+    /// Returns the inverse of this transform, or `None` if it is singular.
+    ///
+    /// Unlike `CATransform3DInvert`, which returns its argument unchanged when it cannot be
+    /// inverted, a singular transform is reported to the caller.
+    pub fn invert(&self) -> Option<CATransform3D> {
+        // Gauss-Jordan elimination with partial pivoting on `[self | identity]`.
+        let mut m = self.rows();
+        let mut inverse = CATransform3D::IDENTITY.rows();
+        for column in 0..4 {
+            let pivot = (column..4)
+                .max_by(|&i, &j| m[i][column].abs().total_cmp(&m[j][column].abs()))
+                .unwrap();
+            let pivot_value = m[pivot][column];
+            if pivot_value == 0.0 || !pivot_value.is_finite() {
+                return None;
+            }
+            m.swap(column, pivot);
+            inverse.swap(column, pivot);
+            for j in 0..4 {
+                m[column][j] /= pivot_value;
+                inverse[column][j] /= pivot_value;
+            }
+            for row in 0..4 {
+                let factor = m[row][column];
+                if row == column || factor == 0.0 {
+                    continue;
+                }
+                for j in 0..4 {
+                    m[row][j] -= factor * m[column][j];
+                    inverse[row][j] -= factor * inverse[column][j];
+                }
+            }
+        }
+        Some(CATransform3D::from_rows(inverse))
+    }
+
+    /// Returns `true` if this transform only acts in the x-y plane and can be represented exactly
+    /// by a `CGAffineTransform`, as `CATransform3DIsAffine` does.
+    pub fn is_affine(&self) -> bool {
+        self.m13 == 0.0
+            && self.m14 == 0.0
+            && self.m23 == 0.0
+            && self.m24 == 0.0
+            && self.m31 == 0.0
+            && self.m32 == 0.0
+            && self.m33 == 1.0
+            && self.m34 == 0.0
+            && self.m43 == 0.0
+            && self.m44 == 1.0
+    }
+
+    /// Returns the affine part of this transform, dropping everything that involves the z axis
+    /// or perspective, as `CATransform3DGetAffineTransform` does.
+    #[inline]
+    pub fn to_affine(&self) -> CGAffineTransform {
+        CGAffineTransform::new(self.m11, self.m12, self.m21, self.m22, self.m41, self.m42)
+    }
+
+    /// Transforms the point `(x, y, z)`, including the perspective divide.
+    pub fn transform_point(
+        &self,
+        x: CGFloat,
+        y: CGFloat,
+        z: CGFloat,
+    ) -> (CGFloat, CGFloat, CGFloat) {
+        let w = x * self.m14 + y * self.m24 + z * self.m34 + self.m44;
+        (
+            (x * self.m11 + y * self.m21 + z * self.m31 + self.m41) / w,
+            (x * self.m12 + y * self.m22 + z * self.m32 + self.m42) / w,
+            (x * self.m13 + y * self.m23 + z * self.m33 + self.m43) / w,
+        )
+    }
+
+    /// Splits this transform into scale, rotation and translation.
+    ///
+    /// Returns `None` if the transform has a perspective component or a zero scale along any
+    /// axis. A reflection is carried by a negative x scale.
+    pub fn decompose(&self) -> Option<CATransform3DComponents> {
+        if self.m14 != 0.0 || self.m24 != 0.0 || self.m34 != 0.0 || self.m44 == 0.0 {
+            return None;
+        }
+        let m = self.rows();
+        let w = self.m44;
+        let mut rows = [[0.0; 3]; 3];
+        let mut scale = [0.0; 3];
+        for i in 0..3 {
+            let row = [m[i][0] / w, m[i][1] / w, m[i][2] / w];
+            scale[i] = (row[0] * row[0] + row[1] * row[1] + row[2] * row[2]).sqrt();
+            if scale[i] == 0.0 || !scale[i].is_finite() {
+                return None;
+            }
+            rows[i] = [row[0] / scale[i], row[1] / scale[i], row[2] / scale[i]];
+        }
+        let det = rows[0][0] * (rows[1][1] * rows[2][2] - rows[1][2] * rows[2][1])
+            - rows[0][1] * (rows[1][0] * rows[2][2] - rows[1][2] * rows[2][0])
+            + rows[0][2] * (rows[1][0] * rows[2][1] - rows[1][1] * rows[2][0]);
+        if det < 0.0 {
+            scale[0] = -scale[0];
+            rows[0] = [-rows[0][0], -rows[0][1], -rows[0][2]];
+        }
+
+        Some(CATransform3DComponents {
+            scale,
+            rotation: quaternion_from_rows(&rows),
+            translation: [self.m41 / w, self.m42 / w, self.m43 / w],
+        })
+    }
+}
+
+/// Converts the rows of a rotation matrix, in the layout produced by
+/// [`CATransform3D::from_quaternion`], back into a quaternion.
+fn quaternion_from_rows(r: &[[CGFloat; 3]; 3]) -> Quaternion {
+    let trace = r[0][0] + r[1][1] + r[2][2];
+    let q = if trace > 0.0 {
+        let s = (trace + 1.0).sqrt() * 2.0;
+        Quaternion {
+            x: (r[1][2] - r[2][1]) / s,
+            y: (r[2][0] - r[0][2]) / s,
+            z: (r[0][1] - r[1][0]) / s,
+            w: s / 4.0,
+        }
+    } else if r[0][0] > r[1][1] && r[0][0] > r[2][2] {
+        let s = (1.0 + r[0][0] - r[1][1] - r[2][2]).sqrt() * 2.0;
+        Quaternion {
+            x: s / 4.0,
+            y: (r[0][1] + r[1][0]) / s,
+            z: (r[0][2] + r[2][0]) / s,
+            w: (r[1][2] - r[2][1]) / s,
+        }
+    } else if r[1][1] > r[2][2] {
+        let s = (1.0 + r[1][1] - r[0][0] - r[2][2]).sqrt() * 2.0;
+        Quaternion {
+            x: (r[0][1] + r[1][0]) / s,
+            y: s / 4.0,
+            z: (r[1][2] + r[2][1]) / s,
+            w: (r[2][0] - r[0][2]) / s,
+        }
+    } else {
+        let s = (1.0 + r[2][2] - r[0][0] - r[1][1]).sqrt() * 2.0;
+        Quaternion {
+            x: (r[0][2] + r[2][0]) / s,
+            y: (r[1][2] + r[2][1]) / s,
+            z: s / 4.0,
+            w: (r[0][1] - r[1][0]) / s,
+        }
+    };
+    // Keep `w` non-negative so that equal rotations decompose the same way.
+    if q.w < 0.0 {
+        Quaternion {
+            x: -q.x,
+            y: -q.y,
+            z: -q.z,
+            w: -q.w,
+        }
+    } else {
+        q
+    }
+}
+
+impl Mul for CATransform3D {
+    type Output = CATransform3D;
+
+    #[inline]
+    fn mul(self, other: CATransform3D) -> CATransform3D {
+        self.concat(&other)
+    }
+}
+
+impl MulAssign for CATransform3D {
+    #[inline]
+    fn mul_assign(&mut self, other: CATransform3D) {
+        *self = self.concat(&other);
+    }
+}
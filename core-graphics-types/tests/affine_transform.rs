@@ -0,0 +1,161 @@
+use core_graphics_types::base::CGFloat;
+use core_graphics_types::geometry::{
+    CGAffineTransform, CGPoint, CGRect, CGSize, CG_AFFINE_TRANSFORM_IDENTITY,
+};
+use std::f64::consts::FRAC_PI_2;
+
+const EPSILON: CGFloat = 1e-9;
+
+fn assert_close(actual: &CGAffineTransform, expected: &CGAffineTransform) {
+    let pairs = [
+        (actual.a, expected.a),
+        (actual.b, expected.b),
+        (actual.c, expected.c),
+        (actual.d, expected.d),
+        (actual.tx, expected.tx),
+        (actual.ty, expected.ty),
+    ];
+    for &(actual_value, expected_value) in pairs.iter() {
+        assert!(
+            (actual_value - expected_value).abs() < EPSILON,
+            "{:?} != {:?}",
+            actual,
+            expected
+        );
+    }
+}
+
+fn assert_point(point: CGPoint, x: CGFloat, y: CGFloat) {
+    assert!(
+        (point.x - x).abs() < EPSILON && (point.y - y).abs() < EPSILON,
+        "{:?} != ({}, {})",
+        point,
+        x,
+        y
+    );
+}
+
+#[test]
+fn constructors() {
+    assert!(CGAffineTransform::identity().is_identity());
+    assert_eq!(CGAffineTransform::default().determinant(), 0.0);
+    assert!(!CGAffineTransform::translation(1.0, 0.0).is_identity());
+
+    let point = CGPoint::new(2.0, 3.0);
+    assert_point(
+        point.apply_transform(&CGAffineTransform::translation(1.0, -1.0)),
+        3.0,
+        2.0,
+    );
+    assert_point(
+        point.apply_transform(&CGAffineTransform::scale(2.0, -1.0)),
+        4.0,
+        -3.0,
+    );
+    assert_point(
+        point.apply_transform(&CGAffineTransform::rotation(FRAC_PI_2)),
+        -3.0,
+        2.0,
+    );
+    assert_point(
+        point.apply_transform(&CGAffineTransform::shear(1.0, 0.5)),
+        5.0,
+        4.0,
+    );
+}
+
+#[test]
+fn concat_applies_left_first() {
+    let scale = CGAffineTransform::scale(2.0, 2.0);
+    let translate = CGAffineTransform::translation(10.0, 0.0);
+    let point = CGPoint::new(1.0, 1.0);
+
+    assert_point(point.apply_transform(&(scale * translate)), 12.0, 2.0);
+    assert_point(point.apply_transform(&(translate * scale)), 22.0, 2.0);
+    assert_eq!(scale.concat(&translate), scale * translate);
+
+    let mut transform = scale;
+    transform *= translate;
+    assert_eq!(transform, scale * translate);
+    assert_eq!(transform * CG_AFFINE_TRANSFORM_IDENTITY, transform);
+}
+
+#[test]
+fn determinant() {
+    assert_eq!(CGAffineTransform::scale(2.0, 3.0).determinant(), 6.0);
+    assert!((CGAffineTransform::rotation(1.0).determinant() - 1.0).abs() < EPSILON);
+    assert_eq!(CGAffineTransform::shear(2.0, 0.0).determinant(), 1.0);
+}
+
+#[test]
+fn invert() {
+    let transform = CGAffineTransform::scale(2.0, 4.0)
+        * CGAffineTransform::rotation(0.3)
+        * CGAffineTransform::translation(5.0, -7.0);
+    let inverse = transform.invert().unwrap();
+    assert_close(&(transform * inverse), &CG_AFFINE_TRANSFORM_IDENTITY);
+    assert_close(&(inverse * transform), &CG_AFFINE_TRANSFORM_IDENTITY);
+
+    // Exact for simple transforms.
+    assert_eq!(
+        CGAffineTransform::new(2.0, 0.0, 0.0, 4.0, 1.0, 2.0).invert(),
+        Some(CGAffineTransform::new(0.5, 0.0, 0.0, 0.25, -0.5, -0.5))
+    );
+
+    assert_eq!(CGAffineTransform::scale(0.0, 1.0).invert(), None);
+    assert_eq!(
+        CGAffineTransform::new(1.0, 2.0, 2.0, 4.0, 3.0, 3.0).invert(),
+        None
+    );
+    assert_eq!(CGAffineTransform::scale(CGFloat::NAN, 1.0).invert(), None);
+}
+
+#[test]
+fn decompose_round_trips() {
+    let transforms = [
+        CG_AFFINE_TRANSFORM_IDENTITY,
+        CGAffineTransform::translation(3.0, 4.0),
+        CGAffineTransform::rotation(2.5),
+        CGAffineTransform::scale(-2.0, 3.0),
+        CGAffineTransform::scale(2.0, 3.0)
+            * CGAffineTransform::shear(0.5, 0.0)
+            * CGAffineTransform::rotation(-1.0)
+            * CGAffineTransform::translation(-1.0, 8.0),
+        CGAffineTransform::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0),
+    ];
+    for transform in transforms.iter() {
+        let components = transform.decompose();
+        assert!(components.scale.width >= 0.0);
+        assert_close(&CGAffineTransform::from_components(&components), transform);
+    }
+
+    let components = (CGAffineTransform::scale(2.0, 3.0)
+        * CGAffineTransform::shear(0.5, 0.0)
+        * CGAffineTransform::rotation(-1.0)
+        * CGAffineTransform::translation(-1.0, 8.0))
+    .decompose();
+    assert!((components.scale.width - 2.0).abs() < EPSILON);
+    assert!((components.scale.height - 3.0).abs() < EPSILON);
+    assert!((components.horizontal_shear - 0.5).abs() < EPSILON);
+    assert!((components.rotation + 1.0).abs() < EPSILON);
+    assert_point(components.translation, -1.0, 8.0);
+}
+
+#[test]
+fn apply_to_size_and_rect() {
+    let size = CGSize::new(2.0, 1.0).apply_transform(&CGAffineTransform::rotation(FRAC_PI_2));
+    assert!((size.width + 1.0).abs() < EPSILON && (size.height - 2.0).abs() < EPSILON);
+
+    let rect = CGRect::new(&CGPoint::new(1.0, 1.0), &CGSize::new(2.0, 1.0));
+    let rotated = rect.apply_transform(&CGAffineTransform::rotation(FRAC_PI_2));
+    assert_point(rotated.origin, -2.0, 1.0);
+    assert_point(
+        CGPoint::new(rotated.size.width, rotated.size.height),
+        1.0,
+        2.0,
+    );
+
+    let flipped = rect.apply_transform(&CGAffineTransform::scale(-1.0, 1.0));
+    assert_point(flipped.origin, -3.0, 1.0);
+    assert_eq!(flipped.size.width, 2.0);
+}
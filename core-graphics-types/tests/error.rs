@@ -0,0 +1,55 @@
+use core_graphics_types::base::{
+    kCGErrorFailure, kCGErrorIllegalArgument, kCGErrorNoneAvailable, kCGErrorSuccess, CGError,
+};
+use std::error::Error;
+use std::io;
+
+#[test]
+fn codes() {
+    assert_eq!(CGError::from_code(kCGErrorSuccess), None);
+    assert_eq!(CGError::check(kCGErrorSuccess), Ok(()));
+    assert_eq!(CGError::check(kCGErrorFailure), Err(CGError::Failure));
+    assert_eq!(CGError::check(1005), Err(CGError::Unknown(1005)));
+    for code in (1000..=1012).chain([-1, 42]) {
+        let err = CGError::from_code(code).unwrap();
+        assert_eq!(err.code(), code);
+        assert_eq!(i32::from(err), code);
+        assert_eq!(
+            matches!(err, CGError::Unknown(_)),
+            [1005, 1009, 1012, -1, 42].contains(&code)
+        );
+    }
+}
+
+#[test]
+fn display() {
+    assert_eq!(
+        CGError::IllegalArgument.to_string(),
+        "one or more parameters are not valid"
+    );
+    assert_eq!(
+        CGError::Unknown(-42).to_string(),
+        "unknown CoreGraphics error -42"
+    );
+}
+
+#[test]
+fn io_error() {
+    let err = io::Error::from(CGError::from_code(kCGErrorIllegalArgument).unwrap());
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    assert_eq!(err.to_string(), CGError::IllegalArgument.to_string());
+    let inner = err.get_ref().unwrap().downcast_ref::<CGError>();
+    assert_eq!(inner, Some(&CGError::IllegalArgument));
+
+    let err: io::Error = CGError::from_code(kCGErrorNoneAvailable).unwrap().into();
+    assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    assert_eq!(
+        io::Error::from(CGError::NotImplemented).kind(),
+        io::ErrorKind::Unsupported
+    );
+    assert_eq!(
+        io::Error::from(CGError::Unknown(7)).kind(),
+        io::ErrorKind::Other
+    );
+    assert!(CGError::Failure.source().is_none());
+}
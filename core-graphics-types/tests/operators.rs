@@ -0,0 +1,124 @@
+use core_graphics_types::base::CGFloat;
+use core_graphics_types::geometry::{
+    CGAffineTransform, CGPoint, CGRect, CGSize, CGVector, CG_ZERO_POINT,
+};
+
+#[test]
+fn point_arithmetic() {
+    let a = CGPoint::new(1.0, 2.0);
+    let b = CGPoint::new(4.0, 6.0);
+    assert_eq!(a + b, CGPoint::new(5.0, 8.0));
+    assert_eq!(b - a, CGPoint::new(3.0, 4.0));
+    let offset = CGVector::between(a, b);
+    assert_eq!(offset, CGVector::new(3.0, 4.0));
+    assert_eq!(a + offset, b);
+    assert_eq!(b - offset, a);
+    assert_eq!(a * 2.0, CGPoint::new(2.0, 4.0));
+    assert_eq!(-a, CGPoint::new(-1.0, -2.0));
+
+    let mut point = a;
+    point += offset;
+    point -= CGVector::new(1.0, 1.0);
+    point *= 0.5;
+    assert_eq!(point, CGPoint::new(1.5, 2.5));
+
+    point += a;
+    assert_eq!(point, CGPoint::new(2.5, 4.5));
+    point -= b;
+    assert_eq!(point, CGPoint::new(-1.5, -1.5));
+}
+
+#[test]
+fn vector_and_size_arithmetic() {
+    let v = CGVector::new(1.0, -1.0);
+    assert_eq!(v + v, v * 2.0);
+    assert_eq!(v - v, CGVector::default());
+    assert_eq!(-v, CGVector::new(-1.0, 1.0));
+    assert_eq!(
+        v.apply_transform(&CGAffineTransform::translation(5.0, 5.0)),
+        v
+    );
+
+    let s = CGSize::new(3.0, 4.0);
+    assert_eq!(s + CGSize::new(1.0, 1.0), CGSize::new(4.0, 5.0));
+    assert_eq!(s - s, CGSize::default());
+    assert_eq!(s * 0.5, CGSize::new(1.5, 2.0));
+    assert_eq!(-s, CGSize::new(-3.0, -4.0));
+
+    let mut size = s;
+    size += s;
+    size -= CGSize::new(1.0, 0.0);
+    size *= 2.0;
+    assert_eq!(size, CGSize::new(10.0, 16.0));
+}
+
+#[test]
+fn conversions() {
+    assert_eq!(CGPoint::from((1.0, 2.0)), CGPoint::new(1.0, 2.0));
+    assert_eq!(CGPoint::from([1.0, 2.0]), CGPoint::new(1.0, 2.0));
+    assert_eq!(CGSize::from((3.0, 4.0)), CGSize::new(3.0, 4.0));
+    assert_eq!(CGVector::from([5.0, 6.0]), CGVector::new(5.0, 6.0));
+
+    let tuple: (CGFloat, CGFloat) = CGSize::new(3.0, 4.0).into();
+    assert_eq!(tuple, (3.0, 4.0));
+    let array: [CGFloat; 2] = CGPoint::new(1.0, 2.0).into();
+    assert_eq!(array, [1.0, 2.0]);
+
+    let rect = CGRect::from([1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(
+        rect,
+        CGRect::from((CGPoint::new(1.0, 2.0), CGSize::new(3.0, 4.0)))
+    );
+    let array: [CGFloat; 4] = rect.into();
+    assert_eq!(array, [1.0, 2.0, 3.0, 4.0]);
+    let (origin, size): (CGPoint, CGSize) = rect.into();
+    assert_eq!((origin, size), (rect.origin, rect.size));
+
+    assert_eq!(CGPoint::default(), CG_ZERO_POINT);
+    assert_eq!(CGRect::default().size, CGSize::default());
+}
+
+#[cfg(feature = "mint")]
+#[test]
+fn mint_conversions() {
+    let point: mint::Point2<CGFloat> = CGPoint::new(1.0, 2.0).into();
+    assert_eq!(point, mint::Point2 { x: 1.0, y: 2.0 });
+    assert_eq!(CGPoint::from(point), CGPoint::new(1.0, 2.0));
+
+    let vector: mint::Vector2<CGFloat> = CGVector::new(3.0, 4.0).into();
+    assert_eq!(CGVector::from(vector), CGVector::new(3.0, 4.0));
+    assert_eq!(CGSize::from(vector), CGSize::new(3.0, 4.0));
+}
+
+#[cfg(feature = "euclid")]
+#[test]
+fn euclid_conversions() {
+    use euclid::default::{Point2D, Rect, Size2D, Transform2D, Vector2D};
+
+    let point: Point2D<CGFloat> = CGPoint::new(1.0, 2.0).into();
+    assert_eq!(point, Point2D::new(1.0, 2.0));
+    assert_eq!(CGPoint::from(point), CGPoint::new(1.0, 2.0));
+
+    let vector: Vector2D<CGFloat> = CGVector::new(3.0, 4.0).into();
+    assert_eq!(CGVector::from(vector), CGVector::new(3.0, 4.0));
+    let size: Size2D<CGFloat> = CGSize::new(5.0, 6.0).into();
+    assert_eq!(CGSize::from(size), CGSize::new(5.0, 6.0));
+
+    let rect = CGRect::from([1.0, 2.0, 3.0, 4.0]);
+    let euclid_rect: Rect<CGFloat> = rect.into();
+    assert_eq!(euclid_rect, Rect::new(point, Size2D::new(3.0, 4.0)));
+    assert_eq!(CGRect::from(euclid_rect), rect);
+
+    // Both libraries apply the left-hand transform first.
+    let transform = Transform2D::scale(2.0, 3.0).then_translate(Vector2D::new(1.0, 1.0));
+    let cg = CGAffineTransform::from(transform);
+    assert_eq!(
+        cg,
+        CGAffineTransform::scale(2.0, 3.0) * CGAffineTransform::translation(1.0, 1.0)
+    );
+    assert_eq!(
+        CGPoint::from(transform.transform_point(point)),
+        CGPoint::new(1.0, 2.0).apply_transform(&cg)
+    );
+    assert_eq!(Transform2D::from(cg), transform);
+}
@@ -0,0 +1,245 @@
+use core_graphics_types::base::CGFloat;
+use core_graphics_types::geometry::{
+    CGAffineTransform, CGPoint, CGRect, CGRectEdge, CGSize, CG_RECT_INFINITE, CG_RECT_NULL,
+    CG_ZERO_RECT,
+};
+
+fn rect(x: CGFloat, y: CGFloat, width: CGFloat, height: CGFloat) -> CGRect {
+    CGRect::new(&CGPoint::new(x, y), &CGSize::new(width, height))
+}
+
+fn null() -> CGRect {
+    CG_RECT_NULL
+}
+
+#[test]
+fn predicates() {
+    // (rect, is_null, is_empty, is_infinite)
+    let cases = [
+        (rect(0.0, 0.0, 1.0, 1.0), false, false, false),
+        (rect(0.0, 0.0, -1.0, -1.0), false, false, false),
+        (rect(5.0, 5.0, 0.0, 1.0), false, true, false),
+        (rect(5.0, 5.0, 1.0, 0.0), false, true, false),
+        (CG_ZERO_RECT, false, true, false),
+        (null(), true, true, false),
+        (rect(CGFloat::INFINITY, 0.0, 1.0, 1.0), true, true, false),
+        (CG_RECT_INFINITE, false, false, true),
+    ];
+    for &(r, is_null, is_empty, is_infinite) in cases.iter() {
+        assert_eq!(r.is_null(), is_null, "{:?}", r);
+        assert_eq!(r.is_empty(), is_empty, "{:?}", r);
+        assert_eq!(r.is_infinite(), is_infinite, "{:?}", r);
+    }
+    assert!(CGRect::null().is_null());
+    assert!(CGRect::infinite().is_infinite());
+}
+
+#[test]
+fn standardize_and_accessors() {
+    let r = rect(10.0, 20.0, -4.0, -6.0);
+    assert_eq!(r.standardize(), rect(6.0, 14.0, 4.0, 6.0));
+    assert_eq!((r.min_x(), r.mid_x(), r.max_x()), (6.0, 8.0, 10.0));
+    assert_eq!((r.min_y(), r.mid_y(), r.max_y()), (14.0, 17.0, 20.0));
+    assert_eq!((r.width(), r.height()), (4.0, 6.0));
+    assert!(null().standardize().is_null());
+    assert!(r.equal_to_rect(&rect(6.0, 14.0, 4.0, 6.0)));
+    assert!(null().equal_to_rect(&null()));
+}
+
+#[test]
+fn union() {
+    // (a, b, a ∪ b)
+    let cases = [
+        (
+            rect(0.0, 0.0, 1.0, 1.0),
+            rect(2.0, 3.0, 1.0, 1.0),
+            rect(0.0, 0.0, 3.0, 4.0),
+        ),
+        (
+            rect(0.0, 0.0, -1.0, 1.0),
+            rect(0.0, 0.0, 1.0, 1.0),
+            rect(-1.0, 0.0, 2.0, 1.0),
+        ),
+        (null(), rect(1.0, 1.0, -1.0, 1.0), rect(0.0, 1.0, 1.0, 1.0)),
+        (rect(1.0, 1.0, 1.0, 1.0), null(), rect(1.0, 1.0, 1.0, 1.0)),
+        (
+            rect(5.0, 5.0, 0.0, 0.0),
+            rect(0.0, 0.0, 1.0, 1.0),
+            rect(0.0, 0.0, 5.0, 5.0),
+        ),
+        (null(), null(), null()),
+    ];
+    for &(a, b, expected) in cases.iter() {
+        assert_eq!(a.union(&b), expected, "{:?} ∪ {:?}", a, b);
+        assert_eq!(b.union(&a), expected, "{:?} ∪ {:?}", b, a);
+    }
+}
+
+#[test]
+fn intersection() {
+    // (a, b, a ∩ b, intersects)
+    let cases = [
+        (
+            rect(0.0, 0.0, 4.0, 4.0),
+            rect(2.0, 1.0, 4.0, 1.0),
+            rect(2.0, 1.0, 2.0, 1.0),
+            true,
+        ),
+        (
+            rect(0.0, 0.0, 4.0, 4.0),
+            rect(4.0, -1.0, -2.0, 2.0),
+            rect(2.0, 0.0, 2.0, 1.0),
+            true,
+        ),
+        (
+            rect(0.0, 0.0, 4.0, 4.0),
+            rect(1.0, 1.0, 1.0, 1.0),
+            rect(1.0, 1.0, 1.0, 1.0),
+            true,
+        ),
+        // Shared edges intersect in a degenerate rectangle that does not count as overlap.
+        (
+            rect(0.0, 0.0, 4.0, 4.0),
+            rect(4.0, 0.0, 4.0, 4.0),
+            rect(4.0, 0.0, 0.0, 4.0),
+            false,
+        ),
+        (
+            rect(0.0, 0.0, 4.0, 4.0),
+            rect(5.0, 0.0, 4.0, 4.0),
+            null(),
+            false,
+        ),
+        (rect(0.0, 0.0, 4.0, 4.0), null(), null(), false),
+        (
+            CG_RECT_INFINITE,
+            rect(1.0, 2.0, 3.0, 4.0),
+            rect(1.0, 2.0, 3.0, 4.0),
+            true,
+        ),
+    ];
+    for &(a, b, expected, intersects) in cases.iter() {
+        assert_eq!(a.intersection(&b), expected, "{:?} ∩ {:?}", a, b);
+        assert_eq!(b.intersection(&a), expected, "{:?} ∩ {:?}", b, a);
+        assert_eq!(a.is_intersects(&b), intersects, "{:?} ∩ {:?}", a, b);
+    }
+}
+
+#[test]
+fn inset_offset_integral() {
+    let r = rect(0.0, 0.0, 10.0, 4.0);
+    assert_eq!(r.inset(&CGSize::new(1.0, 1.0)), rect(1.0, 1.0, 8.0, 2.0));
+    assert_eq!(r.inset(&CGSize::new(-1.0, 0.0)), rect(-1.0, 0.0, 12.0, 4.0));
+    assert_eq!(r.inset(&CGSize::new(0.0, 2.0)), rect(0.0, 2.0, 10.0, 0.0));
+    assert!(r.inset(&CGSize::new(0.0, 3.0)).is_null());
+    assert_eq!(
+        rect(10.0, 4.0, -10.0, -4.0).inset(&CGSize::new(1.0, 1.0)),
+        rect(1.0, 1.0, 8.0, 2.0)
+    );
+    assert!(null().inset(&CGSize::new(-1.0, -1.0)).is_null());
+
+    assert_eq!(r.offset(2.0, -3.0), rect(2.0, -3.0, 10.0, 4.0));
+    assert_eq!(
+        rect(1.0, 1.0, -1.0, 1.0).offset(1.0, 0.0),
+        rect(1.0, 1.0, 1.0, 1.0)
+    );
+    assert!(null().offset(1.0, 1.0).is_null());
+
+    assert_eq!(
+        rect(0.5, -0.5, 1.0, 1.2).integral(),
+        rect(0.0, -1.0, 2.0, 2.0)
+    );
+    assert_eq!(
+        rect(1.5, 1.5, -1.0, -1.0).integral(),
+        rect(0.0, 0.0, 2.0, 2.0)
+    );
+    assert_eq!(
+        rect(1.0, 2.0, 3.0, 4.0).integral(),
+        rect(1.0, 2.0, 3.0, 4.0)
+    );
+    assert!(null().integral().is_null());
+    assert!(CG_RECT_INFINITE.integral().is_infinite());
+}
+
+#[test]
+fn divide() {
+    let r = rect(0.0, 0.0, 10.0, 4.0);
+    // (amount, edge, slice, remainder)
+    let cases = [
+        (
+            3.0,
+            CGRectEdge::MinX,
+            rect(0.0, 0.0, 3.0, 4.0),
+            rect(3.0, 0.0, 7.0, 4.0),
+        ),
+        (
+            3.0,
+            CGRectEdge::MaxX,
+            rect(7.0, 0.0, 3.0, 4.0),
+            rect(0.0, 0.0, 7.0, 4.0),
+        ),
+        (
+            1.0,
+            CGRectEdge::MinY,
+            rect(0.0, 0.0, 10.0, 1.0),
+            rect(0.0, 1.0, 10.0, 3.0),
+        ),
+        (
+            1.0,
+            CGRectEdge::MaxY,
+            rect(0.0, 3.0, 10.0, 1.0),
+            rect(0.0, 0.0, 10.0, 3.0),
+        ),
+        (20.0, CGRectEdge::MinX, r, rect(10.0, 0.0, 0.0, 4.0)),
+        (-1.0, CGRectEdge::MaxY, rect(0.0, 4.0, 10.0, 0.0), r),
+    ];
+    for &(amount, edge, slice, remainder) in cases.iter() {
+        assert_eq!(
+            r.divide(amount, edge),
+            (slice, remainder),
+            "{} {:?}",
+            amount,
+            edge
+        );
+    }
+    assert_eq!(
+        rect(10.0, 4.0, -10.0, -4.0).divide(3.0, CGRectEdge::MinX),
+        r.divide(3.0, CGRectEdge::MinX)
+    );
+    assert_eq!(null().divide(1.0, CGRectEdge::MinX), (null(), null()));
+}
+
+#[test]
+fn contains() {
+    let r = rect(0.0, 0.0, 2.0, 2.0);
+    // (point, contained)
+    let cases = [
+        (CGPoint::new(1.0, 1.0), true),
+        (CGPoint::new(0.0, 0.0), true),
+        (CGPoint::new(2.0, 1.0), false),
+        (CGPoint::new(1.0, 2.0), false),
+        (CGPoint::new(-0.1, 1.0), false),
+    ];
+    for &(point, contained) in cases.iter() {
+        assert_eq!(r.contains(&point), contained, "{:?}", point);
+    }
+    assert!(rect(2.0, 2.0, -2.0, -2.0).contains(&CGPoint::new(0.0, 0.0)));
+    assert!(!null().contains(&CGPoint::new(0.0, 0.0)));
+
+    assert!(r.contains_rect(&rect(0.0, 0.0, 2.0, 2.0)));
+    assert!(r.contains_rect(&rect(1.0, 1.0, 1.0, -1.0)));
+    assert!(!r.contains_rect(&rect(1.0, 1.0, 2.0, 1.0)));
+    assert!(r.contains_rect(&null()));
+    assert!(!null().contains_rect(&r));
+}
+
+#[test]
+fn apply_transform_to_null() {
+    assert!(null()
+        .apply_transform(&CGAffineTransform::scale(2.0, 2.0))
+        .is_null());
+    assert_eq!(
+        rect(0.0, 0.0, -2.0, 1.0).apply_transform(&CGAffineTransform::translation(1.0, 1.0)),
+        rect(-1.0, 1.0, 2.0, 1.0)
+    );
+}
@@ -0,0 +1,166 @@
+use core_graphics_types::base::CGFloat;
+use core_graphics_types::geometry::CGAffineTransform;
+use core_graphics_types::transform3d::{CATransform3D, Quaternion};
+use std::f64::consts::{FRAC_PI_2, PI};
+
+const EPSILON: CGFloat = 1e-9;
+
+fn values(t: &CATransform3D) -> [CGFloat; 16] {
+    [
+        t.m11, t.m12, t.m13, t.m14, t.m21, t.m22, t.m23, t.m24, t.m31, t.m32, t.m33, t.m34, t.m41,
+        t.m42, t.m43, t.m44,
+    ]
+}
+
+fn assert_close(actual: &CATransform3D, expected: &CATransform3D) {
+    let (a, e) = (values(actual), values(expected));
+    for i in 0..16 {
+        assert!(
+            (a[i] - e[i]).abs() < EPSILON,
+            "{:?} != {:?}",
+            actual,
+            expected
+        );
+    }
+}
+
+fn assert_point(actual: (CGFloat, CGFloat, CGFloat), expected: (CGFloat, CGFloat, CGFloat)) {
+    assert!(
+        (actual.0 - expected.0).abs() < EPSILON
+            && (actual.1 - expected.1).abs() < EPSILON
+            && (actual.2 - expected.2).abs() < EPSILON,
+        "{:?} != {:?}",
+        actual,
+        expected
+    );
+}
+
+#[test]
+fn constructors() {
+    assert!(CATransform3D::IDENTITY.is_identity());
+
+    let t = CATransform3D::from_translation(1.0, 2.0, 3.0);
+    assert_point(t.transform_point(1.0, 1.0, 1.0), (2.0, 3.0, 4.0));
+
+    let s = CATransform3D::from_scale(2.0, 3.0, 4.0);
+    assert_point(s.transform_point(1.0, 1.0, 1.0), (2.0, 3.0, 4.0));
+
+    // Rotations follow the right-hand rule, matching `CGAffineTransform::rotation` around z.
+    let z = CATransform3D::from_rotation(FRAC_PI_2, 0.0, 0.0, 1.0);
+    assert_point(z.transform_point(1.0, 0.0, 0.0), (0.0, 1.0, 0.0));
+    assert_close(
+        &z,
+        &CATransform3D::affine(CGAffineTransform::rotation(FRAC_PI_2)),
+    );
+    let x = CATransform3D::from_rotation(FRAC_PI_2, 2.0, 0.0, 0.0);
+    assert_point(x.transform_point(0.0, 1.0, 0.0), (0.0, 0.0, 1.0));
+    let y = CATransform3D::from_rotation(FRAC_PI_2, 0.0, 1.0, 0.0);
+    assert_point(y.transform_point(0.0, 0.0, 1.0), (1.0, 0.0, 0.0));
+
+    assert!(CATransform3D::from_rotation(1.0, 0.0, 0.0, 0.0).is_identity());
+}
+
+#[test]
+fn concat_and_chaining() {
+    let scale = CATransform3D::from_scale(2.0, 2.0, 2.0);
+    let translate = CATransform3D::from_translation(1.0, 0.0, 0.0);
+    assert_point(
+        (scale * translate).transform_point(1.0, 1.0, 1.0),
+        (3.0, 2.0, 2.0),
+    );
+    assert_point(
+        (translate * scale).transform_point(1.0, 1.0, 1.0),
+        (4.0, 2.0, 2.0),
+    );
+
+    // `translate`, `scale` and `rotate` apply the new operation before the existing transform.
+    assert_eq!(scale.translate(1.0, 0.0, 0.0), translate * scale);
+    assert_eq!(translate.scale(2.0, 2.0, 2.0), scale * translate);
+    assert_close(
+        &translate.rotate(PI, 0.0, 0.0, 1.0),
+        &(CATransform3D::from_rotation(PI, 0.0, 0.0, 1.0) * translate),
+    );
+
+    let mut t = scale;
+    t *= translate;
+    assert_eq!(t, scale.concat(&translate));
+}
+
+#[test]
+fn invert() {
+    let t = CATransform3D::from_scale(2.0, 3.0, 4.0)
+        * CATransform3D::from_rotation(0.7, 1.0, 2.0, 3.0)
+        * CATransform3D::from_translation(5.0, -6.0, 7.0)
+        * CATransform3D::from_perspective(500.0);
+    let inverse = t.invert().unwrap();
+    assert_close(&(t * inverse), &CATransform3D::IDENTITY);
+    assert_close(&(inverse * t), &CATransform3D::IDENTITY);
+    assert!((t.determinant() * inverse.determinant() - 1.0).abs() < EPSILON);
+
+    assert_eq!(
+        CATransform3D::from_translation(1.0, 2.0, 3.0).invert(),
+        Some(CATransform3D::from_translation(-1.0, -2.0, -3.0))
+    );
+    assert_eq!(CATransform3D::from_scale(1.0, 0.0, 1.0).invert(), None);
+    assert_eq!(CATransform3D::from_scale(1.0, 1.0, 0.0).determinant(), 0.0);
+}
+
+#[test]
+fn perspective() {
+    let t = CATransform3D::from_perspective(100.0);
+    assert_eq!(t.m34, -0.01);
+    // Points closer to the viewer appear further from the centre.
+    let (x, y, _) = t.transform_point(10.0, 10.0, 50.0);
+    assert!((x - 20.0).abs() < EPSILON && (y - 20.0).abs() < EPSILON);
+    let (x, _, _) = t.transform_point(10.0, 10.0, 0.0);
+    assert!((x - 10.0).abs() < EPSILON);
+    assert!(!t.is_affine());
+    assert!(t.decompose().is_none());
+}
+
+#[test]
+fn affine() {
+    let affine = CGAffineTransform::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+    let t = CATransform3D::affine(affine);
+    assert!(t.is_affine());
+    assert_eq!(t.to_affine(), affine);
+
+    let lifted = CATransform3D::from_scale(1.0, 1.0, 2.0);
+    assert!(!lifted.is_affine());
+    assert!(lifted.to_affine().is_identity());
+    assert!(!CATransform3D::from_rotation(0.1, 1.0, 0.0, 0.0).is_affine());
+}
+
+#[test]
+fn decompose_round_trips() {
+    let transforms = [
+        CATransform3D::IDENTITY,
+        CATransform3D::from_translation(1.0, 2.0, 3.0),
+        CATransform3D::from_rotation(PI, 1.0, 0.0, 0.0),
+        CATransform3D::from_rotation(2.0, 1.0, -1.0, 0.5),
+        CATransform3D::from_scale(-1.0, 2.0, 3.0),
+        CATransform3D::from_scale(2.0, 3.0, 4.0)
+            * CATransform3D::from_rotation(0.7, 1.0, 2.0, 3.0)
+            * CATransform3D::from_translation(5.0, -6.0, 7.0),
+    ];
+    for t in transforms.iter() {
+        let components = t.decompose().unwrap();
+        assert_close(&CATransform3D::from_components(&components), t);
+    }
+
+    let components = (CATransform3D::from_scale(2.0, 3.0, 4.0)
+        * CATransform3D::from_rotation(0.7, 1.0, 2.0, 3.0)
+        * CATransform3D::from_translation(5.0, -6.0, 7.0))
+    .decompose()
+    .unwrap();
+    let expected = Quaternion::from_axis_angle(0.7, 1.0, 2.0, 3.0);
+    assert!((components.scale[0] - 2.0).abs() < EPSILON);
+    assert!((components.scale[2] - 4.0).abs() < EPSILON);
+    assert!((components.rotation.x - expected.x).abs() < EPSILON);
+    assert!((components.rotation.w - expected.w).abs() < EPSILON);
+    assert_eq!(components.translation, [5.0, -6.0, 7.0]);
+
+    assert!(CATransform3D::from_scale(0.0, 1.0, 1.0)
+        .decompose()
+        .is_none());
+}
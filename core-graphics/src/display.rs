@@ -14,7 +14,7 @@ use core::ffi::{c_double, c_void};
 use std::ops::Deref;
 use std::ptr;
 
-pub use crate::base::{boolean_t, CGError};
+pub use crate::base::{boolean_t, CGError, CGErrorCode};
 pub use crate::geometry::{CGPoint, CGRect, CGSize};
 
 use crate::image::CGImage;
@@ -23,7 +23,6 @@ use crate::window::{
 };
 use core_foundation::base::{CFRetain, TCFType};
 use core_foundation::string::{CFString, CFStringRef};
-use core_graphics_types::base::kCGErrorSuccess;
 use foreign_types::{foreign_type, ForeignType};
 
 pub type CGDirectDisplayID = u32;
@@ -168,11 +167,8 @@ impl CGDisplay {
         let result = unsafe {
             CGGetDisplaysWithPoint(point, 0, ptr::null_mut(), &mut matching_display_count)
         };
-        if result == kCGErrorSuccess {
-            Ok(matching_display_count)
-        } else {
-            Err(result)
-        }
+        CGError::check(result)?;
+        Ok(matching_display_count)
     }
 
     /// Return a list of online displays with bounds that include the specified
@@ -196,11 +192,8 @@ impl CGDisplay {
             )
         };
 
-        if result == kCGErrorSuccess {
-            Ok((displays, matching_display_count))
-        } else {
-            Err(result)
-        }
+        CGError::check(result)?;
+        Ok((displays, matching_display_count))
     }
 
     /// Return the number of online displays with bounds that intersect the
@@ -210,11 +203,8 @@ impl CGDisplay {
         let mut matching_display_count: u32 = 0;
         let result =
             unsafe { CGGetDisplaysWithRect(rect, 0, ptr::null_mut(), &mut matching_display_count) };
-        if result == kCGErrorSuccess {
-            Ok(matching_display_count)
-        } else {
-            Err(result)
-        }
+        CGError::check(result)?;
+        Ok(matching_display_count)
     }
 
     /// Return a list of online displays with bounds that intersect the specified rectangle.
@@ -237,11 +227,8 @@ impl CGDisplay {
             )
         };
 
-        if result == kCGErrorSuccess {
-            Ok((displays, matching_display_count))
-        } else {
-            Err(result)
-        }
+        CGError::check(result)?;
+        Ok((displays, matching_display_count))
     }
 
     /// Returns the bounds of a display in the global display coordinate space.
@@ -268,22 +255,14 @@ impl CGDisplay {
         unsafe {
             let mut config_ref: CGDisplayConfigRef = ptr::null_mut();
             let result = CGBeginDisplayConfiguration(&mut config_ref);
-            if result == 0 {
-                Ok(config_ref)
-            } else {
-                Err(result)
-            }
+            CGError::check(result)?;
+            Ok(config_ref)
         }
     }
 
     /// Cancels a set of display configuration changes.
     pub fn cancel_configuration(&self, config_ref: &CGDisplayConfigRef) -> Result<(), CGError> {
-        let result = unsafe { CGCancelDisplayConfiguration(*config_ref) };
-        if result == 0 {
-            Ok(())
-        } else {
-            Err(result)
-        }
+        CGError::check(unsafe { CGCancelDisplayConfiguration(*config_ref) })
     }
 
     /// Completes a set of display configuration changes.
@@ -292,12 +271,7 @@ impl CGDisplay {
         config_ref: &CGDisplayConfigRef,
         option: CGConfigureOption,
     ) -> Result<(), CGError> {
-        let result = unsafe { CGCompleteDisplayConfiguration(*config_ref, option) };
-        if result == 0 {
-            Ok(())
-        } else {
-            Err(result)
-        }
+        CGError::check(unsafe { CGCompleteDisplayConfiguration(*config_ref, option) })
     }
 
     /// Configures the display mode of a display.
@@ -306,19 +280,14 @@ impl CGDisplay {
         config_ref: &CGDisplayConfigRef,
         display_mode: &CGDisplayMode,
     ) -> Result<(), CGError> {
-        let result = unsafe {
+        CGError::check(unsafe {
             CGConfigureDisplayWithDisplayMode(
                 *config_ref,
                 self.id,
                 display_mode.as_ptr(),
                 ptr::null(),
             )
-        };
-        if result == 0 {
-            Ok(())
-        } else {
-            Err(result)
-        }
+        })
     }
 
     /// Configures the origin of a display in the global display coordinate space.
@@ -328,13 +297,7 @@ impl CGDisplay {
         x: i32,
         y: i32,
     ) -> Result<(), CGError> {
-        let result = unsafe { CGConfigureDisplayOrigin(*config_ref, self.id, x, y) };
-
-        if result == 0 {
-            Ok(())
-        } else {
-            Err(result)
-        }
+        CGError::check(unsafe { CGConfigureDisplayOrigin(*config_ref, self.id, x, y) })
     }
 
     /// Changes the configuration of a mirroring set.
@@ -343,13 +306,9 @@ impl CGDisplay {
         config_ref: &CGDisplayConfigRef,
         master: &CGDisplay,
     ) -> Result<(), CGError> {
-        let result = unsafe { CGConfigureDisplayMirrorOfDisplay(*config_ref, self.id, master.id) };
-
-        if result == 0 {
-            Ok(())
-        } else {
-            Err(result)
-        }
+        CGError::check(unsafe {
+            CGConfigureDisplayMirrorOfDisplay(*config_ref, self.id, master.id)
+        })
     }
 
     /// Returns an image containing the contents of the specified display.
@@ -570,12 +529,9 @@ impl CGDisplay {
 
         let result =
             unsafe { CGGetActiveDisplayList(expected_count, buf.as_mut_ptr(), &mut actual_count) };
-        if result == 0 {
-            buf.truncate(actual_count as usize);
-            Ok(buf)
-        } else {
-            Err(result)
-        }
+        CGError::check(result)?;
+        buf.truncate(actual_count as usize);
+        Ok(buf)
     }
 
     /// Provides count of displays that are active (or drawable).
@@ -583,69 +539,41 @@ impl CGDisplay {
     pub fn active_display_count() -> Result<u32, CGError> {
         let mut count: u32 = 0;
         let result = unsafe { CGGetActiveDisplayList(0, ptr::null_mut(), &mut count) };
-        if result == 0 {
-            Ok(count)
-        } else {
-            Err(result)
-        }
+        CGError::check(result)?;
+        Ok(count)
     }
 
     /// Hides the mouse cursor, and increments the hide cursor count.
     #[inline]
     pub fn hide_cursor(&self) -> Result<(), CGError> {
-        let result = unsafe { CGDisplayHideCursor(self.id) };
-        if result == 0 {
-            Ok(())
-        } else {
-            Err(result)
-        }
+        CGError::check(unsafe { CGDisplayHideCursor(self.id) })
     }
 
     /// Decrements the hide cursor count, and shows the mouse cursor if the
     /// count is 0.
     #[inline]
     pub fn show_cursor(&self) -> Result<(), CGError> {
-        let result = unsafe { CGDisplayShowCursor(self.id) };
-        if result == 0 {
-            Ok(())
-        } else {
-            Err(result)
-        }
+        CGError::check(unsafe { CGDisplayShowCursor(self.id) })
     }
 
     /// Moves the mouse cursor to a specified point relative to the display
     /// origin (the upper-left corner of the display).
     #[inline]
     pub fn move_cursor_to_point(&self, point: CGPoint) -> Result<(), CGError> {
-        let result = unsafe { CGDisplayMoveCursorToPoint(self.id, point) };
-        if result == 0 {
-            Ok(())
-        } else {
-            Err(result)
-        }
+        CGError::check(unsafe { CGDisplayMoveCursorToPoint(self.id, point) })
     }
 
     /// Moves the mouse cursor without generating events.
     #[inline]
     pub fn warp_mouse_cursor_position(point: CGPoint) -> Result<(), CGError> {
-        let result = unsafe { CGWarpMouseCursorPosition(point) };
-        if result == 0 {
-            Ok(())
-        } else {
-            Err(result)
-        }
+        CGError::check(unsafe { CGWarpMouseCursorPosition(point) })
     }
 
     /// Connects or disconnects the mouse and cursor while an application is
     /// in the foreground.
     #[inline]
     pub fn associate_mouse_and_mouse_cursor_position(connected: bool) -> Result<(), CGError> {
-        let result = unsafe { CGAssociateMouseAndMouseCursorPosition(connected as boolean_t) };
-        if result == 0 {
-            Ok(())
-        } else {
-            Err(result)
-        }
+        CGError::check(unsafe { CGAssociateMouseAndMouseCursorPosition(connected as boolean_t) })
     }
 }
 
@@ -780,19 +708,19 @@ extern "C" {
         max_displays: u32,
         active_displays: *mut CGDirectDisplayID,
         display_count: *mut u32,
-    ) -> CGError;
+    ) -> CGErrorCode;
     pub fn CGGetDisplaysWithPoint(
         point: CGPoint,
         max_displays: u32,
         displays: *mut CGDirectDisplayID,
         matching_display_count: *mut u32,
-    ) -> CGError;
+    ) -> CGErrorCode;
     pub fn CGGetDisplaysWithRect(
         rect: CGRect,
         max_displays: u32,
         displays: *mut CGDirectDisplayID,
         matching_display_count: *mut u32,
-    ) -> CGError;
+    ) -> CGErrorCode;
     pub fn CGDisplayModelNumber(display: CGDirectDisplayID) -> u32;
     pub fn CGDisplayPixelsHigh(display: CGDirectDisplayID) -> usize;
     pub fn CGDisplayPixelsWide(display: CGDirectDisplayID) -> usize;
@@ -804,43 +732,43 @@ extern "C" {
     ) -> crate::sys::CGImageRef;
 
     // Capturing and Releasing Displays
-    pub fn CGDisplayCapture(display: CGDirectDisplayID) -> CGError;
-    pub fn CGDisplayRelease(display: CGDirectDisplayID) -> CGError;
+    pub fn CGDisplayCapture(display: CGDirectDisplayID) -> CGErrorCode;
+    pub fn CGDisplayRelease(display: CGDirectDisplayID) -> CGErrorCode;
     pub fn CGShieldingWindowLevel() -> CGWindowLevel;
 
     // Configuring Displays
-    pub fn CGBeginDisplayConfiguration(config: *mut CGDisplayConfigRef) -> CGError;
-    pub fn CGCancelDisplayConfiguration(config: CGDisplayConfigRef) -> CGError;
+    pub fn CGBeginDisplayConfiguration(config: *mut CGDisplayConfigRef) -> CGErrorCode;
+    pub fn CGCancelDisplayConfiguration(config: CGDisplayConfigRef) -> CGErrorCode;
     pub fn CGCompleteDisplayConfiguration(
         config: CGDisplayConfigRef,
         option: CGConfigureOption,
-    ) -> CGError;
+    ) -> CGErrorCode;
     pub fn CGConfigureDisplayWithDisplayMode(
         config: CGDisplayConfigRef,
         display: CGDirectDisplayID,
         mode: crate::sys::CGDisplayModeRef,
         options: CFDictionaryRef,
-    ) -> CGError;
+    ) -> CGErrorCode;
     pub fn CGConfigureDisplayMirrorOfDisplay(
         config: CGDisplayConfigRef,
         display: CGDirectDisplayID,
         master: CGDirectDisplayID,
-    ) -> CGError;
+    ) -> CGErrorCode;
     pub fn CGConfigureDisplayOrigin(
         config: CGDisplayConfigRef,
         display: CGDirectDisplayID,
         x: i32,
         y: i32,
-    ) -> CGError;
+    ) -> CGErrorCode;
     pub fn CGRestorePermanentDisplayConfiguration();
     pub fn CGDisplayRegisterReconfigurationCallback(
         callback: CGDisplayReconfigurationCallBack,
         user_info: *const c_void,
-    ) -> CGError;
+    ) -> CGErrorCode;
     pub fn CGDisplayRemoveReconfigurationCallback(
         callback: CGDisplayReconfigurationCallBack,
         user_info: *const c_void,
-    ) -> CGError;
+    ) -> CGErrorCode;
 
     pub fn CGDisplayCopyDisplayMode(display: CGDirectDisplayID) -> crate::sys::CGDisplayModeRef;
     pub fn CGDisplayModeGetHeight(mode: crate::sys::CGDisplayModeRef) -> usize;
@@ -860,14 +788,14 @@ extern "C" {
         display: CGDirectDisplayID,
         mode: crate::sys::CGDisplayModeRef,
         options: CFDictionaryRef,
-    ) -> CGError;
+    ) -> CGErrorCode;
 
     // mouse stuff
-    pub fn CGDisplayHideCursor(display: CGDirectDisplayID) -> CGError;
-    pub fn CGDisplayShowCursor(display: CGDirectDisplayID) -> CGError;
-    pub fn CGDisplayMoveCursorToPoint(display: CGDirectDisplayID, point: CGPoint) -> CGError;
-    pub fn CGWarpMouseCursorPosition(point: CGPoint) -> CGError;
-    pub fn CGAssociateMouseAndMouseCursorPosition(connected: boolean_t) -> CGError;
+    pub fn CGDisplayHideCursor(display: CGDirectDisplayID) -> CGErrorCode;
+    pub fn CGDisplayShowCursor(display: CGDirectDisplayID) -> CGErrorCode;
+    pub fn CGDisplayMoveCursorToPoint(display: CGDirectDisplayID, point: CGPoint) -> CGErrorCode;
+    pub fn CGWarpMouseCursorPosition(point: CGPoint) -> CGErrorCode;
+    pub fn CGAssociateMouseAndMouseCursorPosition(connected: boolean_t) -> CGErrorCode;
 
     // Display Fade Effects
     pub fn CGConfigureDisplayFadeEffect(
@@ -877,11 +805,11 @@ extern "C" {
         fadeRed: f32,
         fadeGreen: f32,
         fadeBlue: f32,
-    ) -> CGError;
+    ) -> CGErrorCode;
     pub fn CGAcquireDisplayFadeReservation(
         seconds: CGDisplayReservationInterval,
         token: *mut CGDisplayFadeReservationToken,
-    ) -> CGError;
+    ) -> CGErrorCode;
     pub fn CGDisplayFade(
         token: CGDisplayFadeReservationToken,
         duration: CGDisplayFadeInterval,
@@ -891,9 +819,9 @@ extern "C" {
         greenBlend: f32,
         blueBlend: f32,
         synchronous: boolean_t,
-    ) -> CGError;
+    ) -> CGErrorCode;
     // CGDisplayFadeOperationInProgress
-    pub fn CGReleaseDisplayFadeReservation(token: CGDisplayFadeReservationToken) -> CGError;
+    pub fn CGReleaseDisplayFadeReservation(token: CGDisplayFadeReservationToken) -> CGErrorCode;
 
     // Window Services Reference
     pub fn CGWindowListCopyWindowInfo(
@@ -1,20 +1,48 @@
 #![allow(non_upper_case_globals)]
+//! Input events.
+//!
+//! The event types, flags, key codes and the helpers built on them are plain Rust and available
+//! on every platform. Creating, posting and tapping events needs macOS.
+
+#[cfg(target_os = "macos")]
 use crate::event_source::CGEventSource;
+#[cfg(target_os = "macos")]
 use crate::geometry::CGPoint;
 
 use bitflags::bitflags;
-use core::ffi::{c_ulong, c_void};
+#[cfg(target_os = "macos")]
+use core::ffi::c_ulong;
+use core::ffi::c_void;
+#[cfg(target_os = "macos")]
 use core_foundation::{
     base::{CFRelease, CFRetain, CFTypeID, TCFType},
     mach_port::{CFMachPort, CFMachPortInvalidate, CFMachPortRef},
     runloop::{kCFRunLoopCommonModes, CFRunLoop},
 };
+#[cfg(target_os = "macos")]
 use foreign_types::{foreign_type, ForeignType};
+use std::fmt;
+#[cfg(target_os = "macos")]
 use std::{mem::ManuallyDrop, ptr};
 
+pub mod keymap;
+pub mod mask;
+pub mod mouse;
+pub mod shortcut;
+pub mod snapshot;
+pub mod typing;
+
+pub use self::mask::CGEventMask;
+pub use self::mouse::{GestureError, MouseEventDescription, MouseGesture};
+pub use self::shortcut::{Shortcut, ShortcutError};
+pub use self::snapshot::{CGEventRecording, CGEventSnapshot, RecordingError};
+pub use self::typing::{TypingPlan, TypingStep};
+
 pub type CGEventField = u32;
 pub type CGKeyCode = u16;
 pub type CGScrollEventUnit = u32;
+/// Nanoseconds since system startup.
+pub type CGEventTimestamp = u64;
 
 bitflags! {
     /// Flags for events
@@ -22,6 +50,8 @@ bitflags! {
     /// [Ref](http://opensource.apple.com/source/IOHIDFamily/IOHIDFamily-700/IOHIDSystem/IOKit/hidsystem/IOLLEvent.h)
     #[repr(C)]
     #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(transparent))]
     pub struct CGEventFlags: u64 {
         const CGEventFlagNull = 0;
 
@@ -184,6 +214,7 @@ impl KeyCode {
     pub const JIS_KEYPAD_COMMA: CGKeyCode = 0x5F;
     pub const JIS_EISU: CGKeyCode = 0x66;
     pub const JIS_KANA: CGKeyCode = 0x68;
+    pub const CONTEXTUAL_MENU: CGKeyCode = 0x6E;
 }
 
 #[repr(C)]
@@ -193,40 +224,207 @@ impl ScrollEventUnit {
     pub const LINE: CGScrollEventUnit = 1;
 }
 
-/// Constants that specify the different types of input events.
+/// The type of an input event.
+///
+/// This wraps the raw value CoreGraphics uses, so event types that are not listed here, such as
+/// new ones added by later versions of macOS, are kept as they are rather than being undefined
+/// behaviour. Use [`kind`] to classify them and [`TryFrom`] to reject unknown values.
 ///
 /// [Ref](http://opensource.apple.com/source/IOHIDFamily/IOHIDFamily-700/IOHIDSystem/IOKit/hidsystem/IOLLEvent.h)
-#[repr(u32)]
-#[derive(Clone, Copy, Debug)]
-pub enum CGEventType {
-    Null = 0,
+///
+/// [`kind`]: #method.kind
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CGEventType(u32);
+
+macro_rules! event_types {
+    ($($(#[$attr:meta])* $name:ident = $value:expr => $kind:ident,)*) => {
+        impl CGEventType {
+            $(
+                $(#[$attr])*
+                pub const $name: CGEventType = CGEventType($value);
+            )*
+
+            /// Every event type with a name, in order of their raw values.
+            pub const ALL: &'static [CGEventType] = &[$(CGEventType::$name),*];
+
+            /// Returns the name of the constant for this event type, if it has one.
+            pub fn name(self) -> Option<&'static str> {
+                match self {
+                    $(CGEventType::$name => Some(stringify!($name)),)*
+                    _ => None,
+                }
+            }
+
+            /// Returns which kind of event this is, or `CGEventKind::Unknown` for event types
+            /// without a name.
+            pub fn kind(self) -> CGEventKind {
+                match self {
+                    $(CGEventType::$name => CGEventKind::$kind,)*
+                    _ => CGEventKind::Unknown,
+                }
+            }
+        }
+    };
+}
+
+event_types! {
+    Null = 0 => Null,
 
     // Mouse events.
-    LeftMouseDown = 1,
-    LeftMouseUp = 2,
-    RightMouseDown = 3,
-    RightMouseUp = 4,
-    MouseMoved = 5,
-    LeftMouseDragged = 6,
-    RightMouseDragged = 7,
+    LeftMouseDown = 1 => Mouse,
+    LeftMouseUp = 2 => Mouse,
+    RightMouseDown = 3 => Mouse,
+    RightMouseUp = 4 => Mouse,
+    MouseMoved = 5 => Mouse,
+    LeftMouseDragged = 6 => Mouse,
+    RightMouseDragged = 7 => Mouse,
+    MouseEntered = 8 => Mouse,
+    MouseExited = 9 => Mouse,
 
     // Keyboard events.
-    KeyDown = 10,
-    KeyUp = 11,
-    FlagsChanged = 12,
+    KeyDown = 10 => Key,
+    KeyUp = 11 => Key,
+    FlagsChanged = 12 => Key,
+
+    // Events defined by the system and applications.
+    AppKitDefined = 13 => System,
+    SystemDefined = 14 => System,
+    ApplicationDefined = 15 => System,
+    Periodic = 16 => System,
+    CursorUpdate = 17 => System,
+
+    // Trackpad gestures. These are not in CGEventTypes.h but are seen by event taps, with the
+    // same values as `NSEventType`.
+    Rotate = 18 => Gesture,
+    BeginGesture = 19 => Gesture,
+    EndGesture = 20 => Gesture,
 
     // Specialized control devices.
-    ScrollWheel = 22,
-    TabletPointer = 23,
-    TabletProximity = 24,
-    OtherMouseDown = 25,
-    OtherMouseUp = 26,
-    OtherMouseDragged = 27,
+    ScrollWheel = 22 => Scroll,
+    TabletPointer = 23 => Tablet,
+    TabletProximity = 24 => Tablet,
+    OtherMouseDown = 25 => Mouse,
+    OtherMouseUp = 26 => Mouse,
+    OtherMouseDragged = 27 => Mouse,
+
+    Gesture = 29 => Gesture,
+    Magnify = 30 => Gesture,
+    Swipe = 31 => Gesture,
+    SmartMagnify = 32 => Gesture,
+    QuickLook = 33 => Gesture,
+    Pressure = 34 => Gesture,
+    DirectTouch = 37 => Gesture,
+    ChangeMode = 38 => System,
 
     // Out of band event types. These are delivered to the event tap callback
     // to notify it of unusual conditions that disable the event tap.
-    TapDisabledByTimeout = 0xFFFFFFFE,
-    TapDisabledByUserInput = 0xFFFFFFFF,
+    TapDisabledByTimeout = 0xFFFFFFFE => TapDisabled,
+    TapDisabledByUserInput = 0xFFFFFFFF => TapDisabled,
+}
+
+impl CGEventType {
+    /// Wraps a raw event type, which does not have to be one of the named constants.
+    #[inline]
+    pub const fn from_raw(raw: u32) -> CGEventType {
+        CGEventType(raw)
+    }
+
+    #[inline]
+    pub const fn raw(self) -> u32 {
+        self.0
+    }
+
+    #[inline]
+    pub fn is_known(self) -> bool {
+        self.name().is_some()
+    }
+}
+
+impl fmt::Debug for CGEventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.name() {
+            Some(name) => f.write_str(name),
+            None => write!(f, "CGEventType({:#x})", self.0),
+        }
+    }
+}
+
+impl From<CGEventType> for u32 {
+    #[inline]
+    fn from(event_type: CGEventType) -> u32 {
+        event_type.0
+    }
+}
+
+/// Converts a raw event type, failing if it is not one of the named constants.
+///
+/// Use [`CGEventType::from_raw`] to keep unknown values.
+///
+/// [`CGEventType::from_raw`]: struct.CGEventType.html#method.from_raw
+impl TryFrom<u32> for CGEventType {
+    type Error = UnknownEventType;
+
+    fn try_from(raw: u32) -> Result<CGEventType, UnknownEventType> {
+        let event_type = CGEventType(raw);
+        if event_type.is_known() {
+            Ok(event_type)
+        } else {
+            Err(UnknownEventType(raw))
+        }
+    }
+}
+
+/// The error returned when converting a raw value that is not a known [`CGEventType`].
+///
+/// [`CGEventType`]: struct.CGEventType.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnknownEventType(pub u32);
+
+impl fmt::Display for UnknownEventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown event type {:#x}", self.0)
+    }
+}
+
+impl std::error::Error for UnknownEventType {}
+
+/// Event types are stored as their raw value, so that unknown types survive a round trip.
+#[cfg(feature = "serde")]
+impl serde::Serialize for CGEventType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CGEventType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<CGEventType, D::Error> {
+        u32::deserialize(deserializer).map(CGEventType)
+    }
+}
+
+/// A broad classification of [`CGEventType`]s.
+///
+/// [`CGEventType`]: struct.CGEventType.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CGEventKind {
+    Null,
+    /// Mouse buttons, movement, dragging, and entering or leaving tracking areas.
+    Mouse,
+    /// Key presses and modifier changes.
+    Key,
+    Scroll,
+    /// Tablet pointer and proximity events.
+    Tablet,
+    /// Trackpad gestures, including force touch pressure.
+    Gesture,
+    /// Events defined by AppKit, the system or applications.
+    System,
+    /// The event tap was disabled and will receive no more events until it is enabled again.
+    TapDisabled,
+    /// An event type without a name.
+    Unknown,
 }
 
 /// Constants used as keys to access specialized fields in low-level events.
@@ -452,14 +650,119 @@ impl EventField {
     /// is line-based.
     pub const SCROLL_WHEEL_EVENT_IS_CONTINUOUS: CGEventField = 88;
 
+    /// Key to access an integer field that contains the [`CGScrollPhase`] of
+    /// a trackpad scroll, or zero for scroll wheels.
+    pub const SCROLL_WHEEL_EVENT_SCROLL_PHASE: CGEventField = 99;
+
+    /// Key to access an integer field that contains the number of scroll
+    /// gestures in quick succession, used for scroll acceleration.
+    pub const SCROLL_WHEEL_EVENT_SCROLL_COUNT: CGEventField = 100;
+
+    /// Key to access an integer field that contains the
+    /// [`CGMomentumScrollPhase`] of a scroll that continues after the fingers
+    /// leave the trackpad.
+    pub const SCROLL_WHEEL_EVENT_MOMENTUM_PHASE: CGEventField = 123;
+
     /// Added in 10.5; made public in 10.7.
     pub const MOUSE_EVENT_WINDOW_UNDER_MOUSE_POINTER: CGEventField = 91;
     pub const MOUSE_EVENT_WINDOW_UNDER_MOUSE_POINTER_THAT_CAN_HANDLE_THIS_EVENT: CGEventField = 92;
+
+    /// Every field above, in numeric order.
+    pub const ALL: &'static [CGEventField] = &[
+        EventField::MOUSE_EVENT_NUMBER,
+        EventField::MOUSE_EVENT_CLICK_STATE,
+        EventField::MOUSE_EVENT_PRESSURE,
+        EventField::MOUSE_EVENT_BUTTON_NUMBER,
+        EventField::MOUSE_EVENT_DELTA_X,
+        EventField::MOUSE_EVENT_DELTA_Y,
+        EventField::MOUSE_EVENT_INSTANT_MOUSER,
+        EventField::MOUSE_EVENT_SUB_TYPE,
+        EventField::KEYBOARD_EVENT_AUTOREPEAT,
+        EventField::KEYBOARD_EVENT_KEYCODE,
+        EventField::KEYBOARD_EVENT_KEYBOARD_TYPE,
+        EventField::SCROLL_WHEEL_EVENT_DELTA_AXIS_1,
+        EventField::SCROLL_WHEEL_EVENT_DELTA_AXIS_2,
+        EventField::SCROLL_WHEEL_EVENT_INSTANT_MOUSER,
+        EventField::TABLET_EVENT_POINT_X,
+        EventField::TABLET_EVENT_POINT_Y,
+        EventField::TABLET_EVENT_POINT_Z,
+        EventField::TABLET_EVENT_POINT_BUTTONS,
+        EventField::TABLET_EVENT_POINT_PRESSURE,
+        EventField::TABLET_EVENT_TILT_X,
+        EventField::TABLET_EVENT_TILT_Y,
+        EventField::TABLET_EVENT_ROTATION,
+        EventField::TABLET_EVENT_TANGENTIAL_PRESSURE,
+        EventField::TABLET_EVENT_DEVICE_ID,
+        EventField::TABLET_EVENT_VENDOR_1,
+        EventField::TABLET_EVENT_VENDOR_2,
+        EventField::TABLET_EVENT_VENDOR_3,
+        EventField::TABLET_PROXIMITY_EVENT_VENDOR_ID,
+        EventField::TABLET_PROXIMITY_EVENT_TABLET_ID,
+        EventField::TABLET_PROXIMITY_EVENT_POINTER_ID,
+        EventField::TABLET_PROXIMITY_EVENT_DEVICE_ID,
+        EventField::TABLET_PROXIMITY_EVENT_SYSTEM_TABLET_ID,
+        EventField::TABLET_PROXIMITY_EVENT_VENDOR_POINTER_TYPE,
+        EventField::TABLET_PROXIMITY_EVENT_VENDOR_POINTER_SERIAL_NUMBER,
+        EventField::TABLET_PROXIMITY_EVENT_VENDOR_UNIQUE_ID,
+        EventField::TABLET_PROXIMITY_EVENT_CAPABILITY_MASK,
+        EventField::TABLET_PROXIMITY_EVENT_POINTER_TYPE,
+        EventField::TABLET_PROXIMITY_EVENT_ENTER_PROXIMITY,
+        EventField::EVENT_TARGET_PROCESS_SERIAL_NUMBER,
+        EventField::EVENT_TARGET_UNIX_PROCESS_ID,
+        EventField::EVENT_SOURCE_UNIX_PROCESS_ID,
+        EventField::EVENT_SOURCE_USER_DATA,
+        EventField::EVENT_SOURCE_USER_ID,
+        EventField::EVENT_SOURCE_GROUP_ID,
+        EventField::EVENT_SOURCE_STATE_ID,
+        EventField::SCROLL_WHEEL_EVENT_IS_CONTINUOUS,
+        EventField::MOUSE_EVENT_WINDOW_UNDER_MOUSE_POINTER,
+        EventField::MOUSE_EVENT_WINDOW_UNDER_MOUSE_POINTER_THAT_CAN_HANDLE_THIS_EVENT,
+        EventField::SCROLL_WHEEL_EVENT_FIXED_POINT_DELTA_AXIS_1,
+        EventField::SCROLL_WHEEL_EVENT_FIXED_POINT_DELTA_AXIS_2,
+        EventField::SCROLL_WHEEL_EVENT_POINT_DELTA_AXIS_1,
+        EventField::SCROLL_WHEEL_EVENT_POINT_DELTA_AXIS_2,
+        EventField::SCROLL_WHEEL_EVENT_SCROLL_PHASE,
+        EventField::SCROLL_WHEEL_EVENT_SCROLL_COUNT,
+        EventField::SCROLL_WHEEL_EVENT_MOMENTUM_PHASE,
+    ];
+
+    /// The fields that hold a double rather than an integer.
+    pub const DOUBLE_FIELDS: &'static [CGEventField] = &[
+        EventField::MOUSE_EVENT_PRESSURE,
+        EventField::TABLET_EVENT_POINT_PRESSURE,
+        EventField::TABLET_EVENT_TILT_X,
+        EventField::TABLET_EVENT_TILT_Y,
+        EventField::TABLET_EVENT_ROTATION,
+        EventField::TABLET_EVENT_TANGENTIAL_PRESSURE,
+        EventField::SCROLL_WHEEL_EVENT_FIXED_POINT_DELTA_AXIS_1,
+        EventField::SCROLL_WHEEL_EVENT_FIXED_POINT_DELTA_AXIS_2,
+    ];
+}
+
+/// The phase of a trackpad scroll gesture.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CGScrollPhase {
+    Began = 1,
+    Changed = 2,
+    Ended = 4,
+    Cancelled = 8,
+    MayBegin = 128,
+}
+
+/// The phase of the momentum scrolling that follows a trackpad scroll gesture.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CGMomentumScrollPhase {
+    None = 0,
+    Begin = 1,
+    Continue = 2,
+    End = 3,
 }
 
 // Constants that specify buttons on a one, two, or three-button mouse.
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum CGMouseButton {
     Left,
     Right,
@@ -495,16 +798,9 @@ pub enum CGEventTapOptions {
     ListenOnly = 0x00000001,
 }
 
-pub type CGEventMask = u64;
-/* Generate an event mask for a single type of event. */
-macro_rules! CGEventMaskBit {
-    ($eventType:expr) => {
-        (1 << $eventType as CGEventMask)
-    };
-}
-
 pub type CGEventTapProxy = *const c_void;
 
+#[cfg(target_os = "macos")]
 /// What the system should do with the event passed to the callback.
 ///
 /// This value is ignored if [`CGEventTapOptions::ListenOnly`] is specified.
@@ -517,8 +813,10 @@ pub enum CallbackResult {
     Replace(CGEvent),
 }
 
+#[cfg(target_os = "macos")]
 type CGEventTapCallbackFn<'tap_life> =
     Box<dyn Fn(CGEventTapProxy, CGEventType, &CGEvent) -> CallbackResult + 'tap_life>;
+#[cfg(target_os = "macos")]
 type CGEventTapCallBackInternal = unsafe extern "C" fn(
     proxy: CGEventTapProxy,
     etype: CGEventType,
@@ -526,6 +824,7 @@ type CGEventTapCallBackInternal = unsafe extern "C" fn(
     user_info: *const c_void,
 ) -> crate::sys::CGEventRef;
 
+#[cfg(target_os = "macos")]
 unsafe extern "C" fn cg_event_tap_callback_internal(
     proxy: CGEventTapProxy,
     etype: CGEventType,
@@ -560,18 +859,20 @@ unsafe extern "C" fn cg_event_tap_callback_internal(
 ///     ||  CFRunLoop::run_current(),
 /// ).expect("Failed to install event tap");
 /// ```
+#[cfg(target_os = "macos")]
 #[must_use = "CGEventTap is disabled when dropped"]
 pub struct CGEventTap<'tap_life> {
     mach_port: CFMachPort,
     _callback: Box<CGEventTapCallbackFn<'tap_life>>,
 }
 
+#[cfg(target_os = "macos")]
 impl CGEventTap<'static> {
     pub fn new<F: Fn(CGEventTapProxy, CGEventType, &CGEvent) -> CallbackResult + Send + 'static>(
         tap: CGEventTapLocation,
         place: CGEventTapPlacement,
         options: CGEventTapOptions,
-        events_of_interest: std::vec::Vec<CGEventType>,
+        events_of_interest: impl Into<CGEventMask>,
         callback: F,
     ) -> Result<Self, ()> {
         // SAFETY: callback is 'static so even if this object is forgotten it
@@ -580,6 +881,7 @@ impl CGEventTap<'static> {
     }
 }
 
+#[cfg(target_os = "macos")]
 impl<'tap_life> CGEventTap<'tap_life> {
     /// Configures an event tap with the supplied options and callback, then
     /// calls `with_fn`.
@@ -590,7 +892,7 @@ impl<'tap_life> CGEventTap<'tap_life> {
         tap: CGEventTapLocation,
         place: CGEventTapPlacement,
         options: CGEventTapOptions,
-        events_of_interest: std::vec::Vec<CGEventType>,
+        events_of_interest: impl Into<CGEventMask>,
         callback: impl Fn(CGEventTapProxy, CGEventType, &CGEvent) -> CallbackResult + 'tap_life,
         with_fn: impl FnOnce() -> R,
     ) -> Result<R, ()> {
@@ -618,14 +920,10 @@ impl<'tap_life> CGEventTap<'tap_life> {
         tap: CGEventTapLocation,
         place: CGEventTapPlacement,
         options: CGEventTapOptions,
-        events_of_interest: std::vec::Vec<CGEventType>,
+        events_of_interest: impl Into<CGEventMask>,
         callback: impl Fn(CGEventTapProxy, CGEventType, &CGEvent) -> CallbackResult + 'tap_life,
     ) -> Result<Self, ()> {
-        let event_mask: CGEventMask = events_of_interest
-            .iter()
-            .fold(CGEventType::Null as CGEventMask, |mask, &etype| {
-                mask | CGEventMaskBit!(etype)
-            });
+        let event_mask: CGEventMask = events_of_interest.into();
         let cb: Box<CGEventTapCallbackFn> = Box::new(Box::new(callback));
         let cbr = Box::into_raw(cb);
         unsafe {
@@ -659,12 +957,14 @@ impl<'tap_life> CGEventTap<'tap_life> {
     }
 }
 
+#[cfg(target_os = "macos")]
 impl Drop for CGEventTap<'_> {
     fn drop(&mut self) {
         unsafe { CFMachPortInvalidate(self.mach_port.as_CFTypeRef() as *mut _) };
     }
 }
 
+#[cfg(target_os = "macos")]
 foreign_type! {
     #[doc(hidden)]
     pub unsafe type CGEvent {
@@ -674,6 +974,7 @@ foreign_type! {
     }
 }
 
+#[cfg(target_os = "macos")]
 impl CGEvent {
     pub fn type_id() -> CFTypeID {
         unsafe { CGEventGetTypeID() }
@@ -801,6 +1102,16 @@ impl CGEvent {
         unsafe { CGEventGetType(self.as_ptr()) }
     }
 
+    pub fn get_timestamp(&self) -> CGEventTimestamp {
+        unsafe { CGEventGetTimestamp(self.as_ptr()) }
+    }
+
+    pub fn set_timestamp(&self, timestamp: CGEventTimestamp) {
+        unsafe {
+            CGEventSetTimestamp(self.as_ptr(), timestamp);
+        }
+    }
+
     pub fn set_string_from_utf16_unchecked(&self, buf: &[u16]) {
         let buflen = buf.len() as c_ulong;
         unsafe {
@@ -813,6 +1124,22 @@ impl CGEvent {
         self.set_string_from_utf16_unchecked(&buf);
     }
 
+    /// Returns the Unicode string of a keyboard event, or an empty string if
+    /// it has none. Unpaired surrogates are replaced.
+    pub fn get_string(&self) -> String {
+        let mut buf = [0u16; 256];
+        let mut len: c_ulong = 0;
+        unsafe {
+            CGEventKeyboardGetUnicodeString(
+                self.as_ptr(),
+                buf.len() as c_ulong,
+                &mut len,
+                buf.as_mut_ptr(),
+            );
+        }
+        String::from_utf16_lossy(&buf[..(len as usize).min(buf.len())])
+    }
+
     pub fn get_integer_value_field(&self, field: CGEventField) -> i64 {
         unsafe { CGEventGetIntegerValueField(self.as_ptr(), field) }
     }
@@ -830,6 +1157,7 @@ impl CGEvent {
     }
 }
 
+#[cfg(target_os = "macos")]
 #[cfg_attr(feature = "link", link(name = "CoreGraphics", kind = "framework"))]
 extern "C" {
     /// Return the type identifier for the opaque type [`CGEventRef`].
@@ -938,6 +1266,24 @@ extern "C" {
         string: *const u16,
     );
 
+    /// Return the Unicode string associated with a keyboard event.
+    ///
+    /// Copies at most `maxStringLength` UTF-16 code units into
+    /// `unicodeString` and stores the full length of the string in
+    /// `actualStringLength`.
+    fn CGEventKeyboardGetUnicodeString(
+        event: crate::sys::CGEventRef,
+        maxStringLength: c_ulong,
+        actualStringLength: *mut c_ulong,
+        unicodeString: *mut u16,
+    );
+
+    /// Return the timestamp of an event, in nanoseconds since system startup.
+    fn CGEventGetTimestamp(event: crate::sys::CGEventRef) -> CGEventTimestamp;
+
+    /// Set the timestamp of an event.
+    fn CGEventSetTimestamp(event: crate::sys::CGEventRef, timestamp: CGEventTimestamp);
+
     /// Return the integer value of a field in an event.
     fn CGEventGetIntegerValueField(event: crate::sys::CGEventRef, field: CGEventField) -> i64;
 
@@ -0,0 +1,397 @@
+// Copyright 2026 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Mapping between macOS virtual key codes, the W3C UI Events `code` and `key`
+//! values, and USB HID keyboard usages.
+//!
+//! [Ref](https://www.w3.org/TR/uievents-code/)
+
+use super::{CGKeyCode, KeyCode};
+
+/// What a physical key is called on other platforms, and what it types with
+/// the US keyboard layout.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KeyMapping {
+    pub key_code: CGKeyCode,
+    /// The `KeyboardEvent.code` value, like `"KeyA"` or `"ArrowLeft"`.
+    pub code: &'static str,
+    /// The usage ID on the USB HID keyboard page (0x07), if the key has one.
+    pub usb_usage: Option<u16>,
+    /// The `KeyboardEvent.key` value with the US layout and no modifiers,
+    /// like `"a"` or `"Enter"`. Keys the US layout does not use are
+    /// `"Unidentified"`.
+    pub key: &'static str,
+    /// The `KeyboardEvent.key` value with the US layout and shift held.
+    pub shifted_key: &'static str,
+}
+
+impl KeyMapping {
+    const fn new(
+        key_code: CGKeyCode,
+        code: &'static str,
+        usb_usage: Option<u16>,
+        key: &'static str,
+        shifted_key: &'static str,
+    ) -> KeyMapping {
+        KeyMapping {
+            key_code,
+            code,
+            usb_usage,
+            key,
+            shifted_key,
+        }
+    }
+
+    /// Returns the character this key types with the US layout, or `None` if
+    /// it does not type one.
+    pub fn char(&self, shift: bool) -> Option<char> {
+        let key = if shift { self.shifted_key } else { self.key };
+        let mut chars = key.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Some(c),
+            _ => None,
+        }
+    }
+}
+
+/// Every key with a known mapping, in order of key code.
+pub const KEY_MAPPINGS: &[KeyMapping] = &[
+    KeyMapping::new(KeyCode::ANSI_A, "KeyA", Some(0x04), "a", "A"),
+    KeyMapping::new(KeyCode::ANSI_S, "KeyS", Some(0x16), "s", "S"),
+    KeyMapping::new(KeyCode::ANSI_D, "KeyD", Some(0x07), "d", "D"),
+    KeyMapping::new(KeyCode::ANSI_F, "KeyF", Some(0x09), "f", "F"),
+    KeyMapping::new(KeyCode::ANSI_H, "KeyH", Some(0x0B), "h", "H"),
+    KeyMapping::new(KeyCode::ANSI_G, "KeyG", Some(0x0A), "g", "G"),
+    KeyMapping::new(KeyCode::ANSI_Z, "KeyZ", Some(0x1D), "z", "Z"),
+    KeyMapping::new(KeyCode::ANSI_X, "KeyX", Some(0x1B), "x", "X"),
+    KeyMapping::new(KeyCode::ANSI_C, "KeyC", Some(0x06), "c", "C"),
+    KeyMapping::new(KeyCode::ANSI_V, "KeyV", Some(0x19), "v", "V"),
+    KeyMapping::new(KeyCode::ISO_SECTION, "IntlBackslash", Some(0x64), "§", "±"),
+    KeyMapping::new(KeyCode::ANSI_B, "KeyB", Some(0x05), "b", "B"),
+    KeyMapping::new(KeyCode::ANSI_Q, "KeyQ", Some(0x14), "q", "Q"),
+    KeyMapping::new(KeyCode::ANSI_W, "KeyW", Some(0x1A), "w", "W"),
+    KeyMapping::new(KeyCode::ANSI_E, "KeyE", Some(0x08), "e", "E"),
+    KeyMapping::new(KeyCode::ANSI_R, "KeyR", Some(0x15), "r", "R"),
+    KeyMapping::new(KeyCode::ANSI_Y, "KeyY", Some(0x1C), "y", "Y"),
+    KeyMapping::new(KeyCode::ANSI_T, "KeyT", Some(0x17), "t", "T"),
+    KeyMapping::new(KeyCode::ANSI_1, "Digit1", Some(0x1E), "1", "!"),
+    KeyMapping::new(KeyCode::ANSI_2, "Digit2", Some(0x1F), "2", "@"),
+    KeyMapping::new(KeyCode::ANSI_3, "Digit3", Some(0x20), "3", "#"),
+    KeyMapping::new(KeyCode::ANSI_4, "Digit4", Some(0x21), "4", "$"),
+    KeyMapping::new(KeyCode::ANSI_6, "Digit6", Some(0x23), "6", "^"),
+    KeyMapping::new(KeyCode::ANSI_5, "Digit5", Some(0x22), "5", "%"),
+    KeyMapping::new(KeyCode::ANSI_EQUAL, "Equal", Some(0x2E), "=", "+"),
+    KeyMapping::new(KeyCode::ANSI_9, "Digit9", Some(0x26), "9", "("),
+    KeyMapping::new(KeyCode::ANSI_7, "Digit7", Some(0x24), "7", "&"),
+    KeyMapping::new(KeyCode::ANSI_MINUS, "Minus", Some(0x2D), "-", "_"),
+    KeyMapping::new(KeyCode::ANSI_8, "Digit8", Some(0x25), "8", "*"),
+    KeyMapping::new(KeyCode::ANSI_0, "Digit0", Some(0x27), "0", ")"),
+    KeyMapping::new(
+        KeyCode::ANSI_RIGHT_BRACKET,
+        "BracketRight",
+        Some(0x30),
+        "]",
+        "}",
+    ),
+    KeyMapping::new(KeyCode::ANSI_O, "KeyO", Some(0x12), "o", "O"),
+    KeyMapping::new(KeyCode::ANSI_U, "KeyU", Some(0x18), "u", "U"),
+    KeyMapping::new(
+        KeyCode::ANSI_LEFT_BRACKET,
+        "BracketLeft",
+        Some(0x2F),
+        "[",
+        "{",
+    ),
+    KeyMapping::new(KeyCode::ANSI_I, "KeyI", Some(0x0C), "i", "I"),
+    KeyMapping::new(KeyCode::ANSI_P, "KeyP", Some(0x13), "p", "P"),
+    KeyMapping::new(KeyCode::RETURN, "Enter", Some(0x28), "Enter", "Enter"),
+    KeyMapping::new(KeyCode::ANSI_L, "KeyL", Some(0x0F), "l", "L"),
+    KeyMapping::new(KeyCode::ANSI_J, "KeyJ", Some(0x0D), "j", "J"),
+    KeyMapping::new(KeyCode::ANSI_QUOTE, "Quote", Some(0x34), "'", "\""),
+    KeyMapping::new(KeyCode::ANSI_K, "KeyK", Some(0x0E), "k", "K"),
+    KeyMapping::new(KeyCode::ANSI_SEMICOLON, "Semicolon", Some(0x33), ";", ":"),
+    KeyMapping::new(KeyCode::ANSI_BACKSLASH, "Backslash", Some(0x31), "\\", "|"),
+    KeyMapping::new(KeyCode::ANSI_COMMA, "Comma", Some(0x36), ",", "<"),
+    KeyMapping::new(KeyCode::ANSI_SLASH, "Slash", Some(0x38), "/", "?"),
+    KeyMapping::new(KeyCode::ANSI_N, "KeyN", Some(0x11), "n", "N"),
+    KeyMapping::new(KeyCode::ANSI_M, "KeyM", Some(0x10), "m", "M"),
+    KeyMapping::new(KeyCode::ANSI_PERIOD, "Period", Some(0x37), ".", ">"),
+    KeyMapping::new(KeyCode::TAB, "Tab", Some(0x2B), "Tab", "Tab"),
+    KeyMapping::new(KeyCode::SPACE, "Space", Some(0x2C), " ", " "),
+    KeyMapping::new(KeyCode::ANSI_GRAVE, "Backquote", Some(0x35), "`", "~"),
+    KeyMapping::new(
+        KeyCode::DELETE,
+        "Backspace",
+        Some(0x2A),
+        "Backspace",
+        "Backspace",
+    ),
+    KeyMapping::new(KeyCode::ESCAPE, "Escape", Some(0x29), "Escape", "Escape"),
+    KeyMapping::new(
+        KeyCode::RIGHT_COMMAND,
+        "MetaRight",
+        Some(0xE7),
+        "Meta",
+        "Meta",
+    ),
+    KeyMapping::new(KeyCode::COMMAND, "MetaLeft", Some(0xE3), "Meta", "Meta"),
+    KeyMapping::new(KeyCode::SHIFT, "ShiftLeft", Some(0xE1), "Shift", "Shift"),
+    KeyMapping::new(
+        KeyCode::CAPS_LOCK,
+        "CapsLock",
+        Some(0x39),
+        "CapsLock",
+        "CapsLock",
+    ),
+    KeyMapping::new(KeyCode::OPTION, "AltLeft", Some(0xE2), "Alt", "Alt"),
+    KeyMapping::new(
+        KeyCode::CONTROL,
+        "ControlLeft",
+        Some(0xE0),
+        "Control",
+        "Control",
+    ),
+    KeyMapping::new(
+        KeyCode::RIGHT_SHIFT,
+        "ShiftRight",
+        Some(0xE5),
+        "Shift",
+        "Shift",
+    ),
+    KeyMapping::new(KeyCode::RIGHT_OPTION, "AltRight", Some(0xE6), "Alt", "Alt"),
+    KeyMapping::new(
+        KeyCode::RIGHT_CONTROL,
+        "ControlRight",
+        Some(0xE4),
+        "Control",
+        "Control",
+    ),
+    KeyMapping::new(KeyCode::FUNCTION, "Fn", None, "Fn", "Fn"),
+    KeyMapping::new(KeyCode::F17, "F17", Some(0x6C), "F17", "F17"),
+    KeyMapping::new(
+        KeyCode::ANSI_KEYPAD_DECIMAL,
+        "NumpadDecimal",
+        Some(0x63),
+        ".",
+        ".",
+    ),
+    KeyMapping::new(
+        KeyCode::ANSI_KEYPAD_MULTIPLY,
+        "NumpadMultiply",
+        Some(0x55),
+        "*",
+        "*",
+    ),
+    KeyMapping::new(KeyCode::ANSI_KEYPAD_PLUS, "NumpadAdd", Some(0x57), "+", "+"),
+    KeyMapping::new(
+        KeyCode::ANSI_KEYPAD_CLEAR,
+        "NumLock",
+        Some(0x53),
+        "Clear",
+        "Clear",
+    ),
+    KeyMapping::new(
+        KeyCode::VOLUME_UP,
+        "AudioVolumeUp",
+        Some(0x80),
+        "AudioVolumeUp",
+        "AudioVolumeUp",
+    ),
+    KeyMapping::new(
+        KeyCode::VOLUME_DOWN,
+        "AudioVolumeDown",
+        Some(0x81),
+        "AudioVolumeDown",
+        "AudioVolumeDown",
+    ),
+    KeyMapping::new(
+        KeyCode::MUTE,
+        "AudioVolumeMute",
+        Some(0x7F),
+        "AudioVolumeMute",
+        "AudioVolumeMute",
+    ),
+    KeyMapping::new(
+        KeyCode::ANSI_KEYPAD_DIVIDE,
+        "NumpadDivide",
+        Some(0x54),
+        "/",
+        "/",
+    ),
+    KeyMapping::new(
+        KeyCode::ANSI_KEYPAD_ENTER,
+        "NumpadEnter",
+        Some(0x58),
+        "Enter",
+        "Enter",
+    ),
+    KeyMapping::new(
+        KeyCode::ANSI_KEYPAD_MINUS,
+        "NumpadSubtract",
+        Some(0x56),
+        "-",
+        "-",
+    ),
+    KeyMapping::new(KeyCode::F18, "F18", Some(0x6D), "F18", "F18"),
+    KeyMapping::new(KeyCode::F19, "F19", Some(0x6E), "F19", "F19"),
+    KeyMapping::new(
+        KeyCode::ANSI_KEYPAD_EQUAL,
+        "NumpadEqual",
+        Some(0x67),
+        "=",
+        "=",
+    ),
+    KeyMapping::new(KeyCode::ANSI_KEYPAD_0, "Numpad0", Some(0x62), "0", "0"),
+    KeyMapping::new(KeyCode::ANSI_KEYPAD_1, "Numpad1", Some(0x59), "1", "1"),
+    KeyMapping::new(KeyCode::ANSI_KEYPAD_2, "Numpad2", Some(0x5A), "2", "2"),
+    KeyMapping::new(KeyCode::ANSI_KEYPAD_3, "Numpad3", Some(0x5B), "3", "3"),
+    KeyMapping::new(KeyCode::ANSI_KEYPAD_4, "Numpad4", Some(0x5C), "4", "4"),
+    KeyMapping::new(KeyCode::ANSI_KEYPAD_5, "Numpad5", Some(0x5D), "5", "5"),
+    KeyMapping::new(KeyCode::ANSI_KEYPAD_6, "Numpad6", Some(0x5E), "6", "6"),
+    KeyMapping::new(KeyCode::ANSI_KEYPAD_7, "Numpad7", Some(0x5F), "7", "7"),
+    KeyMapping::new(KeyCode::F20, "F20", Some(0x6F), "F20", "F20"),
+    KeyMapping::new(KeyCode::ANSI_KEYPAD_8, "Numpad8", Some(0x60), "8", "8"),
+    KeyMapping::new(KeyCode::ANSI_KEYPAD_9, "Numpad9", Some(0x61), "9", "9"),
+    KeyMapping::new(
+        KeyCode::JIS_YEN,
+        "IntlYen",
+        Some(0x89),
+        "Unidentified",
+        "Unidentified",
+    ),
+    KeyMapping::new(
+        KeyCode::JIS_UNDERSCORE,
+        "IntlRo",
+        Some(0x87),
+        "Unidentified",
+        "Unidentified",
+    ),
+    KeyMapping::new(
+        KeyCode::JIS_KEYPAD_COMMA,
+        "NumpadComma",
+        Some(0x85),
+        ",",
+        ",",
+    ),
+    KeyMapping::new(KeyCode::F5, "F5", Some(0x3E), "F5", "F5"),
+    KeyMapping::new(KeyCode::F6, "F6", Some(0x3F), "F6", "F6"),
+    KeyMapping::new(KeyCode::F7, "F7", Some(0x40), "F7", "F7"),
+    KeyMapping::new(KeyCode::F3, "F3", Some(0x3C), "F3", "F3"),
+    KeyMapping::new(KeyCode::F8, "F8", Some(0x41), "F8", "F8"),
+    KeyMapping::new(KeyCode::F9, "F9", Some(0x42), "F9", "F9"),
+    KeyMapping::new(KeyCode::JIS_EISU, "Lang2", Some(0x91), "Eisu", "Eisu"),
+    KeyMapping::new(KeyCode::F11, "F11", Some(0x44), "F11", "F11"),
+    KeyMapping::new(
+        KeyCode::JIS_KANA,
+        "Lang1",
+        Some(0x90),
+        "KanaMode",
+        "KanaMode",
+    ),
+    KeyMapping::new(KeyCode::F13, "F13", Some(0x68), "F13", "F13"),
+    KeyMapping::new(KeyCode::F16, "F16", Some(0x6B), "F16", "F16"),
+    KeyMapping::new(KeyCode::F14, "F14", Some(0x69), "F14", "F14"),
+    KeyMapping::new(KeyCode::F10, "F10", Some(0x43), "F10", "F10"),
+    KeyMapping::new(
+        KeyCode::CONTEXTUAL_MENU,
+        "ContextMenu",
+        Some(0x65),
+        "ContextMenu",
+        "ContextMenu",
+    ),
+    KeyMapping::new(KeyCode::F12, "F12", Some(0x45), "F12", "F12"),
+    KeyMapping::new(KeyCode::F15, "F15", Some(0x6A), "F15", "F15"),
+    KeyMapping::new(KeyCode::HELP, "Insert", Some(0x49), "Help", "Help"),
+    KeyMapping::new(KeyCode::HOME, "Home", Some(0x4A), "Home", "Home"),
+    KeyMapping::new(KeyCode::PAGE_UP, "PageUp", Some(0x4B), "PageUp", "PageUp"),
+    KeyMapping::new(
+        KeyCode::FORWARD_DELETE,
+        "Delete",
+        Some(0x4C),
+        "Delete",
+        "Delete",
+    ),
+    KeyMapping::new(KeyCode::F4, "F4", Some(0x3D), "F4", "F4"),
+    KeyMapping::new(KeyCode::END, "End", Some(0x4D), "End", "End"),
+    KeyMapping::new(KeyCode::F2, "F2", Some(0x3B), "F2", "F2"),
+    KeyMapping::new(
+        KeyCode::PAGE_DOWN,
+        "PageDown",
+        Some(0x4E),
+        "PageDown",
+        "PageDown",
+    ),
+    KeyMapping::new(KeyCode::F1, "F1", Some(0x3A), "F1", "F1"),
+    KeyMapping::new(
+        KeyCode::LEFT_ARROW,
+        "ArrowLeft",
+        Some(0x50),
+        "ArrowLeft",
+        "ArrowLeft",
+    ),
+    KeyMapping::new(
+        KeyCode::RIGHT_ARROW,
+        "ArrowRight",
+        Some(0x4F),
+        "ArrowRight",
+        "ArrowRight",
+    ),
+    KeyMapping::new(
+        KeyCode::DOWN_ARROW,
+        "ArrowDown",
+        Some(0x51),
+        "ArrowDown",
+        "ArrowDown",
+    ),
+    KeyMapping::new(
+        KeyCode::UP_ARROW,
+        "ArrowUp",
+        Some(0x52),
+        "ArrowUp",
+        "ArrowUp",
+    ),
+];
+
+/// Returns the mapping for a key code, if it is known.
+pub fn key_mapping(key_code: CGKeyCode) -> Option<&'static KeyMapping> {
+    KEY_MAPPINGS.iter().find(|m| m.key_code == key_code)
+}
+
+/// Returns the `KeyboardEvent.code` value for a key code.
+pub fn code_from_key_code(key_code: CGKeyCode) -> Option<&'static str> {
+    key_mapping(key_code).map(|m| m.code)
+}
+
+/// Returns the key code for a `KeyboardEvent.code` value. The comparison is
+/// case sensitive, as in the specification.
+pub fn key_code_from_code(code: &str) -> Option<CGKeyCode> {
+    KEY_MAPPINGS
+        .iter()
+        .find(|m| m.code == code)
+        .map(|m| m.key_code)
+}
+
+/// Returns the USB HID keyboard page usage ID for a key code.
+pub fn usb_usage_from_key_code(key_code: CGKeyCode) -> Option<u16> {
+    key_mapping(key_code).and_then(|m| m.usb_usage)
+}
+
+/// Returns the key code for a USB HID keyboard page usage ID.
+pub fn key_code_from_usb_usage(usage: u16) -> Option<CGKeyCode> {
+    KEY_MAPPINGS
+        .iter()
+        .find(|m| m.usb_usage == Some(usage))
+        .map(|m| m.key_code)
+}
+
+/// Returns the character a key types with the US layout, with or without
+/// shift.
+pub fn us_ansi_char(key_code: CGKeyCode, shift: bool) -> Option<char> {
+    key_mapping(key_code).and_then(|m| m.char(shift))
+}
@@ -0,0 +1,324 @@
+// Copyright 2026 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Sets of event types, as passed to event taps.
+
+use super::{CGEventKind, CGEventType};
+use std::fmt;
+use std::ops::{
+    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Sub, SubAssign,
+};
+
+/// A set of event types, with bit `n` standing for the event type with raw value `n`.
+///
+/// Only event types below 64 fit in a mask. The tap disabled types do not, and are delivered to
+/// every tap anyway; adding them does nothing and [`contains`] always returns `false` for them.
+///
+/// ```
+/// use core_graphics::event::{CGEventMask, CGEventType};
+///
+/// let mask = CGEventMask::KEYBOARD | CGEventType::LeftMouseDown;
+/// assert!(mask.contains(CGEventType::FlagsChanged));
+/// assert!(!mask.contains(CGEventType::LeftMouseUp));
+/// assert_eq!(mask - CGEventMask::KEYBOARD, CGEventMask::from(CGEventType::LeftMouseDown));
+/// ```
+///
+/// [`contains`]: #method.contains
+#[repr(transparent)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CGEventMask(u64);
+
+impl CGEventMask {
+    /// No event types.
+    pub const NONE: CGEventMask = CGEventMask(0);
+
+    /// Every event type, including ones without a name. This is `kCGEventMaskForAllEvents`.
+    pub const ALL: CGEventMask = CGEventMask(!0);
+
+    /// Key down, key up and flags changed events.
+    pub const KEYBOARD: CGEventMask = CGEventMask::from_types(&[
+        CGEventType::KeyDown,
+        CGEventType::KeyUp,
+        CGEventType::FlagsChanged,
+    ]);
+
+    /// Presses and releases of any mouse button.
+    pub const MOUSE_BUTTONS: CGEventMask = CGEventMask::from_types(&[
+        CGEventType::LeftMouseDown,
+        CGEventType::LeftMouseUp,
+        CGEventType::RightMouseDown,
+        CGEventType::RightMouseUp,
+        CGEventType::OtherMouseDown,
+        CGEventType::OtherMouseUp,
+    ]);
+
+    /// Mouse moves, and drags with any button.
+    pub const MOUSE_MOTION: CGEventMask = CGEventMask::from_types(&[
+        CGEventType::MouseMoved,
+        CGEventType::LeftMouseDragged,
+        CGEventType::RightMouseDragged,
+        CGEventType::OtherMouseDragged,
+    ]);
+
+    /// Every event type of [`CGEventKind::Mouse`].
+    ///
+    /// [`CGEventKind::Mouse`]: enum.CGEventKind.html#variant.Mouse
+    pub const MOUSE: CGEventMask = CGEventMask::MOUSE_BUTTONS
+        .union(CGEventMask::MOUSE_MOTION)
+        .union(CGEventMask::from_types(&[
+            CGEventType::MouseEntered,
+            CGEventType::MouseExited,
+        ]));
+
+    pub const SCROLL: CGEventMask = CGEventMask::from_types(&[CGEventType::ScrollWheel]);
+
+    pub const TABLET: CGEventMask =
+        CGEventMask::from_types(&[CGEventType::TabletPointer, CGEventType::TabletProximity]);
+
+    /// Every event type of [`CGEventKind::Gesture`].
+    ///
+    /// [`CGEventKind::Gesture`]: enum.CGEventKind.html#variant.Gesture
+    pub const GESTURE: CGEventMask = CGEventMask::from_types(&[
+        CGEventType::Rotate,
+        CGEventType::BeginGesture,
+        CGEventType::EndGesture,
+        CGEventType::Gesture,
+        CGEventType::Magnify,
+        CGEventType::Swipe,
+        CGEventType::SmartMagnify,
+        CGEventType::QuickLook,
+        CGEventType::Pressure,
+        CGEventType::DirectTouch,
+    ]);
+
+    /// Returns the bit for `event_type`, or zero if it does not fit in a mask.
+    const fn bit(event_type: CGEventType) -> u64 {
+        if event_type.raw() < u64::BITS {
+            1 << event_type.raw()
+        } else {
+            0
+        }
+    }
+
+    /// Returns a mask of `types`, leaving out types that do not fit in a mask.
+    pub const fn from_types(types: &[CGEventType]) -> CGEventMask {
+        let mut bits = 0;
+        let mut i = 0;
+        while i < types.len() {
+            bits |= CGEventMask::bit(types[i]);
+            i += 1;
+        }
+        CGEventMask(bits)
+    }
+
+    /// Returns a mask of the named event types of `kind`.
+    pub fn from_kind(kind: CGEventKind) -> CGEventMask {
+        CGEventType::ALL
+            .iter()
+            .filter(|event_type| event_type.kind() == kind)
+            .collect()
+    }
+
+    /// Returns the mask with a single event type, or `None` if it does not fit in a mask.
+    pub const fn from_type(event_type: CGEventType) -> Option<CGEventMask> {
+        match CGEventMask::bit(event_type) {
+            0 => None,
+            bit => Some(CGEventMask(bit)),
+        }
+    }
+
+    #[inline]
+    pub const fn from_bits(bits: u64) -> CGEventMask {
+        CGEventMask(bits)
+    }
+
+    #[inline]
+    pub const fn bits(self) -> u64 {
+        self.0
+    }
+
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    #[inline]
+    pub const fn contains(self, event_type: CGEventType) -> bool {
+        self.0 & CGEventMask::bit(event_type) != 0
+    }
+
+    /// Returns whether every event type in `other` is also in `self`.
+    #[inline]
+    pub const fn contains_all(self, other: CGEventMask) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    #[inline]
+    pub const fn intersects(self, other: CGEventMask) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    /// Adds `event_type`, returning `false` if it does not fit in a mask.
+    pub fn insert(&mut self, event_type: CGEventType) -> bool {
+        let bit = CGEventMask::bit(event_type);
+        self.0 |= bit;
+        bit != 0
+    }
+
+    pub fn remove(&mut self, event_type: CGEventType) {
+        self.0 &= !CGEventMask::bit(event_type);
+    }
+
+    #[inline]
+    pub const fn union(self, other: CGEventMask) -> CGEventMask {
+        CGEventMask(self.0 | other.0)
+    }
+
+    #[inline]
+    pub const fn intersection(self, other: CGEventMask) -> CGEventMask {
+        CGEventMask(self.0 & other.0)
+    }
+
+    #[inline]
+    pub const fn difference(self, other: CGEventMask) -> CGEventMask {
+        CGEventMask(self.0 & !other.0)
+    }
+
+    #[inline]
+    pub const fn symmetric_difference(self, other: CGEventMask) -> CGEventMask {
+        CGEventMask(self.0 ^ other.0)
+    }
+
+    #[inline]
+    pub const fn complement(self) -> CGEventMask {
+        CGEventMask(!self.0)
+    }
+
+    /// Returns the event types in the mask, in order of their raw values.
+    pub fn iter(self) -> impl Iterator<Item = CGEventType> {
+        (0..u64::BITS)
+            .filter(move |raw| self.0 & (1 << raw) != 0)
+            .map(CGEventType::from_raw)
+    }
+}
+
+impl fmt::Debug for CGEventMask {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("CGEventMask(")?;
+        if *self == CGEventMask::ALL {
+            f.write_str("ALL")?;
+        } else if self.is_empty() {
+            f.write_str("NONE")?;
+        } else {
+            for (i, event_type) in self.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(" | ")?;
+                }
+                write!(f, "{:?}", event_type)?;
+            }
+        }
+        f.write_str(")")
+    }
+}
+
+impl From<CGEventMask> for u64 {
+    #[inline]
+    fn from(mask: CGEventMask) -> u64 {
+        mask.0
+    }
+}
+
+/// Types that do not fit in a mask give an empty mask.
+impl From<CGEventType> for CGEventMask {
+    #[inline]
+    fn from(event_type: CGEventType) -> CGEventMask {
+        CGEventMask(CGEventMask::bit(event_type))
+    }
+}
+
+impl From<CGEventKind> for CGEventMask {
+    #[inline]
+    fn from(kind: CGEventKind) -> CGEventMask {
+        CGEventMask::from_kind(kind)
+    }
+}
+
+impl From<&[CGEventType]> for CGEventMask {
+    #[inline]
+    fn from(types: &[CGEventType]) -> CGEventMask {
+        CGEventMask::from_types(types)
+    }
+}
+
+impl From<Vec<CGEventType>> for CGEventMask {
+    #[inline]
+    fn from(types: Vec<CGEventType>) -> CGEventMask {
+        CGEventMask::from_types(&types)
+    }
+}
+
+impl FromIterator<CGEventType> for CGEventMask {
+    fn from_iter<I: IntoIterator<Item = CGEventType>>(iter: I) -> CGEventMask {
+        let mut mask = CGEventMask::NONE;
+        mask.extend(iter);
+        mask
+    }
+}
+
+impl<'a> FromIterator<&'a CGEventType> for CGEventMask {
+    fn from_iter<I: IntoIterator<Item = &'a CGEventType>>(iter: I) -> CGEventMask {
+        iter.into_iter().copied().collect()
+    }
+}
+
+impl Extend<CGEventType> for CGEventMask {
+    fn extend<I: IntoIterator<Item = CGEventType>>(&mut self, iter: I) {
+        for event_type in iter {
+            self.insert(event_type);
+        }
+    }
+}
+
+macro_rules! mask_ops {
+    ($($op:ident::$method:ident, $assign:ident::$assign_method:ident => $set_method:ident;)*) => {
+        $(
+            impl<T: Into<CGEventMask>> $op<T> for CGEventMask {
+                type Output = CGEventMask;
+
+                #[inline]
+                fn $method(self, other: T) -> CGEventMask {
+                    self.$set_method(other.into())
+                }
+            }
+
+            impl<T: Into<CGEventMask>> $assign<T> for CGEventMask {
+                #[inline]
+                fn $assign_method(&mut self, other: T) {
+                    *self = self.$set_method(other.into());
+                }
+            }
+        )*
+    };
+}
+
+mask_ops! {
+    BitOr::bitor, BitOrAssign::bitor_assign => union;
+    BitAnd::bitand, BitAndAssign::bitand_assign => intersection;
+    BitXor::bitxor, BitXorAssign::bitxor_assign => symmetric_difference;
+    Sub::sub, SubAssign::sub_assign => difference;
+}
+
+impl Not for CGEventMask {
+    type Output = CGEventMask;
+
+    #[inline]
+    fn not(self) -> CGEventMask {
+        self.complement()
+    }
+}
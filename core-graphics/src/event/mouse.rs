@@ -0,0 +1,529 @@
+// Copyright 2026 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Describing mouse gestures, such as double clicks, drags and trackpad
+//! scrolls, as sequences of events.
+
+#[cfg(all(target_os = "macos", feature = "highsierra"))]
+use super::{CGEvent, CGEventTapLocation, EventField};
+use super::{
+    CGEventType, CGMomentumScrollPhase, CGMouseButton, CGScrollEventUnit, CGScrollPhase,
+    ScrollEventUnit,
+};
+use crate::base::CGFloat;
+#[cfg(all(target_os = "macos", feature = "highsierra"))]
+use crate::event_source::CGEventSource;
+use crate::geometry::CGPoint;
+use std::error;
+use std::fmt;
+use std::time::Duration;
+
+/// The time between events if [`MouseGesture::interval`] is not called.
+///
+/// [`MouseGesture::interval`]: struct.MouseGesture.html#method.interval
+pub const DEFAULT_INTERVAL: Duration = Duration::from_millis(10);
+
+/// The scroll fields of a scroll wheel event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScrollDescription {
+    /// `ScrollEventUnit::PIXEL` or `ScrollEventUnit::LINE`.
+    pub unit: CGScrollEventUnit,
+    pub delta_y: i32,
+    pub delta_x: i32,
+    /// The trackpad gesture phase, or `None` for a scroll wheel.
+    pub phase: Option<CGScrollPhase>,
+    pub momentum_phase: CGMomentumScrollPhase,
+}
+
+/// Everything needed to create one mouse event.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MouseEventDescription {
+    pub event_type: CGEventType,
+    pub location: CGPoint,
+    /// The button pressed, released or dragged with, or `None` for moves and
+    /// scrolls.
+    pub button: Option<CGMouseButton>,
+    /// `EventField::MOUSE_EVENT_CLICK_STATE`: 1 for a single click, 2 for the
+    /// second click of a double click, and so on. Zero for other events.
+    pub click_state: i64,
+    /// `EventField::MOUSE_EVENT_NUMBER`, shared by a button press and its
+    /// release. Zero for other events.
+    pub event_number: i64,
+    /// `EventField::MOUSE_EVENT_DELTA_X` and `MOUSE_EVENT_DELTA_Y`: how far
+    /// the mouse moved since the previous event.
+    pub delta: (i64, i64),
+    /// Set for scroll wheel events only.
+    pub scroll: Option<ScrollDescription>,
+    /// How long to wait after the previous event before posting this one.
+    pub delay: Duration,
+}
+
+/// The reasons a gesture or event sequence is invalid. Errors about a
+/// particular event carry its index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GestureError {
+    /// A click with a count of zero was requested.
+    ZeroClicks,
+    /// A drag with zero steps was requested.
+    ZeroDragSteps,
+    /// A scroll with no deltas was requested.
+    EmptyScroll,
+    /// The event's location is not finite.
+    InvalidLocation(usize),
+    /// The event is of a type other than mouse or scroll events, or has a
+    /// button or scroll fields that do not fit its type.
+    InvalidEvent(usize),
+    /// A button was pressed while already down.
+    ButtonAlreadyDown(usize),
+    /// A button was released or dragged with while up.
+    ButtonNotDown(usize),
+    /// A button release's event number or click state differs from its press.
+    MismatchedRelease(usize),
+    /// A press's click state does not follow the previous click of its
+    /// button.
+    InvalidClickState(usize),
+    /// The sequence ends with a button still down.
+    ButtonStillDown,
+}
+
+impl fmt::Display for GestureError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            GestureError::ZeroClicks => f.write_str("click count is zero"),
+            GestureError::ZeroDragSteps => f.write_str("drag has no steps"),
+            GestureError::EmptyScroll => f.write_str("scroll has no deltas"),
+            GestureError::InvalidLocation(i) => write!(f, "event {} has an invalid location", i),
+            GestureError::InvalidEvent(i) => write!(f, "event {} is not a valid mouse event", i),
+            GestureError::ButtonAlreadyDown(i) => {
+                write!(f, "event {} presses a button that is already down", i)
+            }
+            GestureError::ButtonNotDown(i) => write!(f, "event {} uses a button that is up", i),
+            GestureError::MismatchedRelease(i) => {
+                write!(f, "event {} does not match its button press", i)
+            }
+            GestureError::InvalidClickState(i) => {
+                write!(f, "event {} has an invalid click state", i)
+            }
+            GestureError::ButtonStillDown => f.write_str("a button is still down at the end"),
+        }
+    }
+}
+
+impl error::Error for GestureError {}
+
+/// The event types for pressing, releasing and dragging with a button.
+fn button_event_types(button: CGMouseButton) -> [CGEventType; 3] {
+    match button {
+        CGMouseButton::Left => [
+            CGEventType::LeftMouseDown,
+            CGEventType::LeftMouseUp,
+            CGEventType::LeftMouseDragged,
+        ],
+        CGMouseButton::Right => [
+            CGEventType::RightMouseDown,
+            CGEventType::RightMouseUp,
+            CGEventType::RightMouseDragged,
+        ],
+        CGMouseButton::Center => [
+            CGEventType::OtherMouseDown,
+            CGEventType::OtherMouseUp,
+            CGEventType::OtherMouseDragged,
+        ],
+    }
+}
+
+fn button_index(button: CGMouseButton) -> usize {
+    match button {
+        CGMouseButton::Left => 0,
+        CGMouseButton::Right => 1,
+        CGMouseButton::Center => 2,
+    }
+}
+
+/// Builds the events for a sequence of mouse gestures.
+///
+/// ```
+/// use core_graphics::event::mouse::MouseGesture;
+/// use core_graphics::event::CGMouseButton;
+/// use core_graphics::geometry::CGPoint;
+///
+/// let events = MouseGesture::new(CGPoint::new(10., 10.))
+///     .double_click(CGMouseButton::Left)
+///     .drag_to(CGMouseButton::Left, CGPoint::new(100., 10.), 5)
+///     .build()
+///     .unwrap();
+/// assert_eq!(events.len(), 4 + 7);
+/// ```
+#[derive(Clone, Debug)]
+pub struct MouseGesture {
+    events: Vec<MouseEventDescription>,
+    location: CGPoint,
+    next_event_number: i64,
+    interval: Duration,
+    pending_delay: Duration,
+    error: Option<GestureError>,
+}
+
+impl MouseGesture {
+    /// Starts a gesture with the mouse at `location`.
+    pub fn new(location: CGPoint) -> MouseGesture {
+        MouseGesture {
+            events: Vec::new(),
+            location,
+            next_event_number: 1,
+            interval: DEFAULT_INTERVAL,
+            pending_delay: Duration::ZERO,
+            error: None,
+        }
+    }
+
+    /// Sets the time between the events added after this call.
+    pub fn interval(mut self, interval: Duration) -> MouseGesture {
+        self.interval = interval;
+        self
+    }
+
+    /// Sets the event number of the next button press. Later presses count up
+    /// from it.
+    pub fn event_number(mut self, event_number: i64) -> MouseGesture {
+        self.next_event_number = event_number;
+        self
+    }
+
+    /// Waits for `duration` on top of the interval before the next event.
+    pub fn wait(mut self, duration: Duration) -> MouseGesture {
+        self.pending_delay += duration;
+        self
+    }
+
+    /// Moves the mouse to `location` without any button down.
+    pub fn move_to(mut self, location: CGPoint) -> MouseGesture {
+        self.push_move(CGEventType::MouseMoved, None, location);
+        self
+    }
+
+    pub fn click(self, button: CGMouseButton) -> MouseGesture {
+        self.clicks(button, 1)
+    }
+
+    pub fn double_click(self, button: CGMouseButton) -> MouseGesture {
+        self.clicks(button, 2)
+    }
+
+    /// Clicks `count` times in a row, with the click state counting up so
+    /// that the system sees a double or triple click.
+    pub fn clicks(mut self, button: CGMouseButton, count: u32) -> MouseGesture {
+        if count == 0 {
+            self.fail(GestureError::ZeroClicks);
+        }
+        let [down, up, _] = button_event_types(button);
+        for click_state in 1..=i64::from(count) {
+            let event_number = self.take_event_number();
+            self.push_button(down, button, click_state, event_number);
+            self.push_button(up, button, click_state, event_number);
+        }
+        self
+    }
+
+    /// Presses `button`, drags to `to` in `steps` equal moves and releases
+    /// the button there.
+    pub fn drag_to(mut self, button: CGMouseButton, to: CGPoint, steps: u32) -> MouseGesture {
+        if steps == 0 {
+            self.fail(GestureError::ZeroDragSteps);
+            return self;
+        }
+        let [down, up, dragged] = button_event_types(button);
+        let from = self.location;
+        let event_number = self.take_event_number();
+        self.push_button(down, button, 1, event_number);
+        for step in 1..=steps {
+            let t = step as CGFloat / steps as CGFloat;
+            let location = CGPoint::new(from.x + (to.x - from.x) * t, from.y + (to.y - from.y) * t);
+            self.push_move(dragged, Some(button), location);
+        }
+        self.push_button(up, button, 1, event_number);
+        self
+    }
+
+    /// Turns a scroll wheel by `delta_y` lines vertically and `delta_x` lines
+    /// horizontally.
+    pub fn scroll_wheel(mut self, delta_y: i32, delta_x: i32) -> MouseGesture {
+        self.push_scroll(ScrollDescription {
+            unit: ScrollEventUnit::LINE,
+            delta_y,
+            delta_x,
+            phase: None,
+            momentum_phase: CGMomentumScrollPhase::None,
+        });
+        self
+    }
+
+    /// Scrolls by the pixel deltas `(y, x)` with a trackpad gesture: the
+    /// first delta begins the gesture, the rest change it, and a final event
+    /// without movement ends it.
+    pub fn scroll(self, deltas: &[(i32, i32)]) -> MouseGesture {
+        self.scroll_with_momentum(deltas, &[])
+    }
+
+    /// Scrolls like [`scroll`], then continues with `momentum` deltas as if
+    /// the fingers had flicked the trackpad.
+    ///
+    /// [`scroll`]: #method.scroll
+    pub fn scroll_with_momentum(
+        mut self,
+        deltas: &[(i32, i32)],
+        momentum: &[(i32, i32)],
+    ) -> MouseGesture {
+        if deltas.is_empty() {
+            self.fail(GestureError::EmptyScroll);
+            return self;
+        }
+        let pixels = |(delta_y, delta_x), phase, momentum_phase| ScrollDescription {
+            unit: ScrollEventUnit::PIXEL,
+            delta_y,
+            delta_x,
+            phase,
+            momentum_phase,
+        };
+        for (i, &delta) in deltas.iter().enumerate() {
+            let phase = if i == 0 {
+                CGScrollPhase::Began
+            } else {
+                CGScrollPhase::Changed
+            };
+            self.push_scroll(pixels(delta, Some(phase), CGMomentumScrollPhase::None));
+        }
+        self.push_scroll(pixels(
+            (0, 0),
+            Some(CGScrollPhase::Ended),
+            CGMomentumScrollPhase::None,
+        ));
+        if !momentum.is_empty() {
+            for (i, &delta) in momentum.iter().enumerate() {
+                let phase = if i == 0 {
+                    CGMomentumScrollPhase::Begin
+                } else {
+                    CGMomentumScrollPhase::Continue
+                };
+                self.push_scroll(pixels(delta, None, phase));
+            }
+            self.push_scroll(pixels((0, 0), None, CGMomentumScrollPhase::End));
+        }
+        self
+    }
+
+    /// Returns the events of the gesture, checked with [`validate`].
+    ///
+    /// [`validate`]: fn.validate.html
+    pub fn build(self) -> Result<Vec<MouseEventDescription>, GestureError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        validate(&self.events)?;
+        Ok(self.events)
+    }
+
+    fn fail(&mut self, error: GestureError) {
+        self.error.get_or_insert(error);
+    }
+
+    fn take_event_number(&mut self) -> i64 {
+        let event_number = self.next_event_number;
+        self.next_event_number += 1;
+        event_number
+    }
+
+    fn next_delay(&mut self) -> Duration {
+        let delay = if self.events.is_empty() {
+            Duration::ZERO
+        } else {
+            self.interval
+        };
+        delay + std::mem::replace(&mut self.pending_delay, Duration::ZERO)
+    }
+
+    fn push(&mut self, mut event: MouseEventDescription) {
+        event.delay = self.next_delay();
+        self.events.push(event);
+    }
+
+    fn push_move(&mut self, event_type: CGEventType, button: Option<CGMouseButton>, to: CGPoint) {
+        let delta = (
+            (to.x - self.location.x).round() as i64,
+            (to.y - self.location.y).round() as i64,
+        );
+        self.location = to;
+        self.push(MouseEventDescription {
+            event_type,
+            location: to,
+            button,
+            click_state: 0,
+            event_number: 0,
+            delta,
+            scroll: None,
+            delay: Duration::ZERO,
+        });
+    }
+
+    fn push_button(
+        &mut self,
+        event_type: CGEventType,
+        button: CGMouseButton,
+        click_state: i64,
+        event_number: i64,
+    ) {
+        self.push(MouseEventDescription {
+            event_type,
+            location: self.location,
+            button: Some(button),
+            click_state,
+            event_number,
+            delta: (0, 0),
+            scroll: None,
+            delay: Duration::ZERO,
+        });
+    }
+
+    fn push_scroll(&mut self, scroll: ScrollDescription) {
+        self.push(MouseEventDescription {
+            event_type: CGEventType::ScrollWheel,
+            location: self.location,
+            button: None,
+            click_state: 0,
+            event_number: 0,
+            delta: (0, 0),
+            scroll: Some(scroll),
+            delay: Duration::ZERO,
+        });
+    }
+}
+
+/// Checks that a sequence of events is one the system would produce: each
+/// button press is released with the same event number and click state,
+/// drags only happen while their button is down, click states count up from
+/// one, and every button is up at the end.
+pub fn validate(events: &[MouseEventDescription]) -> Result<(), GestureError> {
+    // The press event number and click state of each button that is down.
+    let mut down: [Option<(i64, i64)>; 3] = [None; 3];
+    let mut last_click_state = [0i64; 3];
+    for (i, event) in events.iter().enumerate() {
+        if !(event.location.x.is_finite() && event.location.y.is_finite()) {
+            return Err(GestureError::InvalidLocation(i));
+        }
+        if event.event_type == CGEventType::ScrollWheel
+            || event.event_type == CGEventType::MouseMoved
+        {
+            let is_scroll = event.event_type == CGEventType::ScrollWheel;
+            if event.button.is_some() || event.scroll.is_some() != is_scroll {
+                return Err(GestureError::InvalidEvent(i));
+            }
+            continue;
+        }
+        let button = match event.button {
+            Some(button) if event.scroll.is_none() => button,
+            _ => return Err(GestureError::InvalidEvent(i)),
+        };
+        let [press, release, dragged] = button_event_types(button);
+        let state = &mut down[button_index(button)];
+        if event.event_type == press {
+            if state.is_some() {
+                return Err(GestureError::ButtonAlreadyDown(i));
+            }
+            let previous = last_click_state[button_index(button)];
+            if event.click_state != 1 && event.click_state != previous + 1 {
+                return Err(GestureError::InvalidClickState(i));
+            }
+            last_click_state[button_index(button)] = event.click_state;
+            *state = Some((event.event_number, event.click_state));
+        } else if event.event_type == release {
+            match state.take() {
+                None => return Err(GestureError::ButtonNotDown(i)),
+                Some(press) if press != (event.event_number, event.click_state) => {
+                    return Err(GestureError::MismatchedRelease(i))
+                }
+                Some(_) => {}
+            }
+        } else if event.event_type == dragged {
+            if state.is_none() {
+                return Err(GestureError::ButtonNotDown(i));
+            }
+        } else {
+            return Err(GestureError::InvalidEvent(i));
+        }
+    }
+    if down.iter().any(Option::is_some) {
+        return Err(GestureError::ButtonStillDown);
+    }
+    Ok(())
+}
+
+#[cfg(all(target_os = "macos", feature = "highsierra"))]
+impl MouseEventDescription {
+    /// Creates the event this describes. `delay` is not used.
+    ///
+    /// Scroll events are created with `CGEventCreateScrollWheelEvent2`, which needs the
+    /// `highsierra` feature.
+    pub fn to_event(&self, source: &CGEventSource) -> Result<CGEvent, ()> {
+        let event = match self.scroll {
+            Some(scroll) => {
+                // Two wheels, so that both axes are set along with the fields derived from them.
+                let event = CGEvent::new_scroll_event(
+                    source.clone(),
+                    scroll.unit,
+                    2,
+                    scroll.delta_y,
+                    scroll.delta_x,
+                    0,
+                )?;
+                event.set_location(self.location);
+                event.set_integer_value_field(
+                    EventField::SCROLL_WHEEL_EVENT_SCROLL_PHASE,
+                    scroll.phase.map_or(0, |phase| phase as i64),
+                );
+                event.set_integer_value_field(
+                    EventField::SCROLL_WHEEL_EVENT_MOMENTUM_PHASE,
+                    scroll.momentum_phase as i64,
+                );
+                event
+            }
+            None => {
+                let button = self.button.unwrap_or(CGMouseButton::Left);
+                let event = CGEvent::new_mouse_event(
+                    source.clone(),
+                    self.event_type,
+                    self.location,
+                    button,
+                )?;
+                event
+                    .set_integer_value_field(EventField::MOUSE_EVENT_CLICK_STATE, self.click_state);
+                event.set_integer_value_field(EventField::MOUSE_EVENT_NUMBER, self.event_number);
+                event.set_integer_value_field(EventField::MOUSE_EVENT_DELTA_X, self.delta.0);
+                event.set_integer_value_field(EventField::MOUSE_EVENT_DELTA_Y, self.delta.1);
+                event
+            }
+        };
+        Ok(event)
+    }
+}
+
+/// Posts `events` to `tap_location`, sleeping for each event's delay first.
+#[cfg(all(target_os = "macos", feature = "highsierra"))]
+pub fn post_events(
+    events: &[MouseEventDescription],
+    source: &CGEventSource,
+    tap_location: CGEventTapLocation,
+) -> Result<(), ()> {
+    for event in events {
+        if !event.delay.is_zero() {
+            std::thread::sleep(event.delay);
+        }
+        event.to_event(source)?.post(tap_location);
+    }
+    Ok(())
+}
@@ -0,0 +1,504 @@
+// Copyright 2026 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Keyboard shortcuts such as `cmd+shift+K` or `⌃⌥F5`.
+
+#[cfg(target_os = "macos")]
+use super::{CGEvent, EventField};
+use super::{CGEventFlags, CGEventType, CGKeyCode, KeyCode};
+use std::error;
+use std::fmt;
+use std::str::FromStr;
+
+/// The flags that can be part of a shortcut. Other flags, like caps lock or
+/// `CGEventFlagNonCoalesced`, are ignored when matching events.
+pub const SHORTCUT_MODIFIERS: CGEventFlags = CGEventFlags::CGEventFlagSecondaryFn
+    .union(CGEventFlags::CGEventFlagControl)
+    .union(CGEventFlags::CGEventFlagAlternate)
+    .union(CGEventFlags::CGEventFlagShift)
+    .union(CGEventFlags::CGEventFlagCommand);
+
+/// The modifiers in the order macOS shows them, with their glyph and names.
+/// The first name is the one used when formatting.
+const MODIFIERS: &[(CGEventFlags, char, &[&str])] = &[
+    (
+        CGEventFlags::CGEventFlagSecondaryFn,
+        '🌐',
+        &["fn", "function", "globe"],
+    ),
+    (
+        CGEventFlags::CGEventFlagControl,
+        '⌃',
+        &["ctrl", "control", "ctl"],
+    ),
+    (
+        CGEventFlags::CGEventFlagAlternate,
+        '⌥',
+        &["opt", "option", "alt"],
+    ),
+    (CGEventFlags::CGEventFlagShift, '⇧', &["shift"]),
+    (
+        CGEventFlags::CGEventFlagCommand,
+        '⌘',
+        &["cmd", "command", "super"],
+    ),
+];
+
+/// Keys that can be used in shortcuts, with the glyph macOS shows in menus and
+/// their names. The first name is the one used when formatting; names are
+/// matched ignoring case.
+const KEYS: &[(CGKeyCode, Option<char>, &[&str])] = &[
+    (KeyCode::ANSI_A, None, &["A"]),
+    (KeyCode::ANSI_B, None, &["B"]),
+    (KeyCode::ANSI_C, None, &["C"]),
+    (KeyCode::ANSI_D, None, &["D"]),
+    (KeyCode::ANSI_E, None, &["E"]),
+    (KeyCode::ANSI_F, None, &["F"]),
+    (KeyCode::ANSI_G, None, &["G"]),
+    (KeyCode::ANSI_H, None, &["H"]),
+    (KeyCode::ANSI_I, None, &["I"]),
+    (KeyCode::ANSI_J, None, &["J"]),
+    (KeyCode::ANSI_K, None, &["K"]),
+    (KeyCode::ANSI_L, None, &["L"]),
+    (KeyCode::ANSI_M, None, &["M"]),
+    (KeyCode::ANSI_N, None, &["N"]),
+    (KeyCode::ANSI_O, None, &["O"]),
+    (KeyCode::ANSI_P, None, &["P"]),
+    (KeyCode::ANSI_Q, None, &["Q"]),
+    (KeyCode::ANSI_R, None, &["R"]),
+    (KeyCode::ANSI_S, None, &["S"]),
+    (KeyCode::ANSI_T, None, &["T"]),
+    (KeyCode::ANSI_U, None, &["U"]),
+    (KeyCode::ANSI_V, None, &["V"]),
+    (KeyCode::ANSI_W, None, &["W"]),
+    (KeyCode::ANSI_X, None, &["X"]),
+    (KeyCode::ANSI_Y, None, &["Y"]),
+    (KeyCode::ANSI_Z, None, &["Z"]),
+    (KeyCode::ANSI_0, None, &["0"]),
+    (KeyCode::ANSI_1, None, &["1"]),
+    (KeyCode::ANSI_2, None, &["2"]),
+    (KeyCode::ANSI_3, None, &["3"]),
+    (KeyCode::ANSI_4, None, &["4"]),
+    (KeyCode::ANSI_5, None, &["5"]),
+    (KeyCode::ANSI_6, None, &["6"]),
+    (KeyCode::ANSI_7, None, &["7"]),
+    (KeyCode::ANSI_8, None, &["8"]),
+    (KeyCode::ANSI_9, None, &["9"]),
+    (KeyCode::ANSI_MINUS, None, &["-", "Minus"]),
+    (KeyCode::ANSI_EQUAL, None, &["=", "Equal", "Equals"]),
+    (KeyCode::ANSI_LEFT_BRACKET, None, &["[", "LeftBracket"]),
+    (KeyCode::ANSI_RIGHT_BRACKET, None, &["]", "RightBracket"]),
+    (KeyCode::ANSI_BACKSLASH, None, &["\\", "Backslash"]),
+    (KeyCode::ANSI_SEMICOLON, None, &[";", "Semicolon"]),
+    (KeyCode::ANSI_QUOTE, None, &["'", "Quote"]),
+    (KeyCode::ANSI_COMMA, None, &[",", "Comma"]),
+    (KeyCode::ANSI_PERIOD, None, &[".", "Period"]),
+    (KeyCode::ANSI_SLASH, None, &["/", "Slash"]),
+    (KeyCode::ANSI_GRAVE, None, &["`", "Grave", "Backquote"]),
+    (KeyCode::SPACE, None, &["Space"]),
+    (KeyCode::RETURN, Some('↩'), &["Return", "Enter"]),
+    (KeyCode::TAB, Some('⇥'), &["Tab"]),
+    (KeyCode::DELETE, Some('⌫'), &["Delete", "Backspace"]),
+    (KeyCode::FORWARD_DELETE, Some('⌦'), &["ForwardDelete"]),
+    (KeyCode::ESCAPE, Some('⎋'), &["Escape", "Esc"]),
+    (KeyCode::HOME, Some('↖'), &["Home"]),
+    (KeyCode::END, Some('↘'), &["End"]),
+    (KeyCode::PAGE_UP, Some('⇞'), &["PageUp", "PgUp"]),
+    (KeyCode::PAGE_DOWN, Some('⇟'), &["PageDown", "PgDn"]),
+    (
+        KeyCode::LEFT_ARROW,
+        Some('←'),
+        &["Left", "LeftArrow", "ArrowLeft"],
+    ),
+    (
+        KeyCode::RIGHT_ARROW,
+        Some('→'),
+        &["Right", "RightArrow", "ArrowRight"],
+    ),
+    (KeyCode::UP_ARROW, Some('↑'), &["Up", "UpArrow", "ArrowUp"]),
+    (
+        KeyCode::DOWN_ARROW,
+        Some('↓'),
+        &["Down", "DownArrow", "ArrowDown"],
+    ),
+    (KeyCode::HELP, None, &["Help"]),
+    (KeyCode::F1, None, &["F1"]),
+    (KeyCode::F2, None, &["F2"]),
+    (KeyCode::F3, None, &["F3"]),
+    (KeyCode::F4, None, &["F4"]),
+    (KeyCode::F5, None, &["F5"]),
+    (KeyCode::F6, None, &["F6"]),
+    (KeyCode::F7, None, &["F7"]),
+    (KeyCode::F8, None, &["F8"]),
+    (KeyCode::F9, None, &["F9"]),
+    (KeyCode::F10, None, &["F10"]),
+    (KeyCode::F11, None, &["F11"]),
+    (KeyCode::F12, None, &["F12"]),
+    (KeyCode::F13, None, &["F13"]),
+    (KeyCode::F14, None, &["F14"]),
+    (KeyCode::F15, None, &["F15"]),
+    (KeyCode::F16, None, &["F16"]),
+    (KeyCode::F17, None, &["F17"]),
+    (KeyCode::F18, None, &["F18"]),
+    (KeyCode::F19, None, &["F19"]),
+    (KeyCode::F20, None, &["F20"]),
+    (KeyCode::VOLUME_UP, None, &["VolumeUp"]),
+    (KeyCode::VOLUME_DOWN, None, &["VolumeDown"]),
+    (KeyCode::MUTE, None, &["Mute"]),
+    (KeyCode::ANSI_KEYPAD_0, None, &["Keypad0"]),
+    (KeyCode::ANSI_KEYPAD_1, None, &["Keypad1"]),
+    (KeyCode::ANSI_KEYPAD_2, None, &["Keypad2"]),
+    (KeyCode::ANSI_KEYPAD_3, None, &["Keypad3"]),
+    (KeyCode::ANSI_KEYPAD_4, None, &["Keypad4"]),
+    (KeyCode::ANSI_KEYPAD_5, None, &["Keypad5"]),
+    (KeyCode::ANSI_KEYPAD_6, None, &["Keypad6"]),
+    (KeyCode::ANSI_KEYPAD_7, None, &["Keypad7"]),
+    (KeyCode::ANSI_KEYPAD_8, None, &["Keypad8"]),
+    (KeyCode::ANSI_KEYPAD_9, None, &["Keypad9"]),
+    (KeyCode::ANSI_KEYPAD_DECIMAL, None, &["KeypadDecimal"]),
+    (KeyCode::ANSI_KEYPAD_MULTIPLY, None, &["KeypadMultiply"]),
+    (KeyCode::ANSI_KEYPAD_PLUS, None, &["KeypadPlus"]),
+    (KeyCode::ANSI_KEYPAD_MINUS, None, &["KeypadMinus"]),
+    (KeyCode::ANSI_KEYPAD_DIVIDE, None, &["KeypadDivide"]),
+    (
+        KeyCode::ANSI_KEYPAD_EQUAL,
+        None,
+        &["KeypadEquals", "KeypadEqual"],
+    ),
+    (KeyCode::ANSI_KEYPAD_ENTER, Some('⌤'), &["KeypadEnter"]),
+    (
+        KeyCode::ANSI_KEYPAD_CLEAR,
+        Some('⌧'),
+        &["KeypadClear", "Clear"],
+    ),
+    (KeyCode::ISO_SECTION, Some('§'), &["Section"]),
+    (KeyCode::JIS_YEN, Some('¥'), &["Yen"]),
+    (KeyCode::JIS_UNDERSCORE, None, &["Underscore"]),
+    (KeyCode::JIS_KEYPAD_COMMA, None, &["KeypadComma"]),
+    (KeyCode::JIS_EISU, None, &["Eisu"]),
+    (KeyCode::JIS_KANA, None, &["Kana"]),
+];
+
+/// The modifier keys themselves, which cannot be the key of a shortcut.
+const MODIFIER_KEYS: &[CGKeyCode] = &[
+    KeyCode::COMMAND,
+    KeyCode::SHIFT,
+    KeyCode::CAPS_LOCK,
+    KeyCode::OPTION,
+    KeyCode::CONTROL,
+    KeyCode::RIGHT_COMMAND,
+    KeyCode::RIGHT_SHIFT,
+    KeyCode::RIGHT_OPTION,
+    KeyCode::RIGHT_CONTROL,
+    KeyCode::FUNCTION,
+];
+
+/// Keys whose events always carry `CGEventFlagSecondaryFn`, because on
+/// compact keyboards they are reached with the fn key.
+const FN_KEYS: &[CGKeyCode] = &[
+    KeyCode::FORWARD_DELETE,
+    KeyCode::HOME,
+    KeyCode::END,
+    KeyCode::PAGE_UP,
+    KeyCode::PAGE_DOWN,
+    KeyCode::LEFT_ARROW,
+    KeyCode::RIGHT_ARROW,
+    KeyCode::UP_ARROW,
+    KeyCode::DOWN_ARROW,
+    KeyCode::HELP,
+    KeyCode::F1,
+    KeyCode::F2,
+    KeyCode::F3,
+    KeyCode::F4,
+    KeyCode::F5,
+    KeyCode::F6,
+    KeyCode::F7,
+    KeyCode::F8,
+    KeyCode::F9,
+    KeyCode::F10,
+    KeyCode::F11,
+    KeyCode::F12,
+    KeyCode::F13,
+    KeyCode::F14,
+    KeyCode::F15,
+    KeyCode::F16,
+    KeyCode::F17,
+    KeyCode::F18,
+    KeyCode::F19,
+    KeyCode::F20,
+];
+
+fn key_entry(
+    key_code: CGKeyCode,
+) -> Option<&'static (CGKeyCode, Option<char>, &'static [&'static str])> {
+    KEYS.iter().find(|entry| entry.0 == key_code)
+}
+
+/// Returns the name of a key as used in shortcuts, like `"K"`, `"F5"` or
+/// `"PageUp"`.
+pub fn key_name(key_code: CGKeyCode) -> Option<&'static str> {
+    key_entry(key_code).map(|entry| entry.2[0])
+}
+
+/// Returns the key with the given shortcut name or glyph, ignoring case.
+pub fn key_code_from_name(name: &str) -> Option<CGKeyCode> {
+    let mut chars = name.chars();
+    let glyph = match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    };
+    KEYS.iter()
+        .find(|(_, key_glyph, names)| {
+            (glyph.is_some() && *key_glyph == glyph)
+                || names.iter().any(|n| n.eq_ignore_ascii_case(name))
+        })
+        .map(|entry| entry.0)
+}
+
+/// Whether a key types a character, so using it with no modifier other than
+/// shift would get in the way of typing.
+fn is_typing_key(key_code: CGKeyCode) -> bool {
+    match key_entry(key_code) {
+        Some((_, None, names)) => names[0].chars().count() == 1 || key_code == KeyCode::SPACE,
+        _ => false,
+    }
+}
+
+/// The reasons a shortcut can be invalid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ShortcutError {
+    /// The string had no key.
+    MissingKey,
+    /// A part of the string was not a known modifier name or glyph.
+    UnknownModifier(String),
+    /// A modifier was given twice.
+    DuplicateModifier(String),
+    /// The key name or glyph was not recognised.
+    UnknownKey(String),
+    /// The key was a modifier key, like shift or caps lock.
+    ModifierKey(CGKeyCode),
+    /// The flags include bits that are not shortcut modifiers.
+    InvalidModifiers(CGEventFlags),
+    /// The key types a character, so it needs a modifier other than shift.
+    NeedsModifier(CGKeyCode),
+}
+
+impl fmt::Display for ShortcutError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ShortcutError::MissingKey => f.write_str("shortcut has no key"),
+            ShortcutError::UnknownModifier(name) => write!(f, "unknown modifier {:?}", name),
+            ShortcutError::DuplicateModifier(name) => write!(f, "duplicate modifier {:?}", name),
+            ShortcutError::UnknownKey(name) => write!(f, "unknown key {:?}", name),
+            ShortcutError::ModifierKey(key_code) => {
+                write!(f, "modifier key {:#x} cannot be a shortcut key", key_code)
+            }
+            ShortcutError::InvalidModifiers(flags) => {
+                write!(f, "{:?} are not shortcut modifiers", flags)
+            }
+            ShortcutError::NeedsModifier(key_code) => write!(
+                f,
+                "shortcut key {} needs a modifier other than shift",
+                key_name(*key_code).unwrap_or("?")
+            ),
+        }
+    }
+}
+
+impl error::Error for ShortcutError {}
+
+/// A keyboard shortcut: a key and the modifiers held with it.
+///
+/// Shortcuts parse from and format to a textual notation like `cmd+shift+K`,
+/// and can also be parsed from and formatted to the macOS glyph notation like
+/// `⇧⌘K` with [`to_glyph_string`]. Both notations can be mixed when parsing,
+/// and modifier and key names are matched ignoring case.
+///
+/// Keys are named as on a US keyboard, so `cmd+[` is the key to the right of
+/// `P` whatever the keyboard layout. ISO and JIS keys use names like
+/// `Section` and `Yen`; see [`key_name`].
+///
+/// [`to_glyph_string`]: #method.to_glyph_string
+/// [`key_name`]: fn.key_name.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Shortcut {
+    key_code: CGKeyCode,
+    modifiers: CGEventFlags,
+}
+
+impl Shortcut {
+    /// Creates a shortcut, checking that `modifiers` only has
+    /// [`SHORTCUT_MODIFIERS`], that the key is not itself a modifier key, and
+    /// that keys which type characters have a modifier other than shift.
+    ///
+    /// [`SHORTCUT_MODIFIERS`]: constant.SHORTCUT_MODIFIERS.html
+    pub fn new(key_code: CGKeyCode, modifiers: CGEventFlags) -> Result<Shortcut, ShortcutError> {
+        let invalid = modifiers.difference(SHORTCUT_MODIFIERS);
+        if !invalid.is_empty() {
+            return Err(ShortcutError::InvalidModifiers(invalid));
+        }
+        if MODIFIER_KEYS.contains(&key_code) {
+            return Err(ShortcutError::ModifierKey(key_code));
+        }
+        if is_typing_key(key_code)
+            && modifiers
+                .difference(CGEventFlags::CGEventFlagShift)
+                .is_empty()
+        {
+            return Err(ShortcutError::NeedsModifier(key_code));
+        }
+        Ok(Shortcut {
+            key_code,
+            modifiers,
+        })
+    }
+
+    #[inline]
+    pub fn key_code(&self) -> CGKeyCode {
+        self.key_code
+    }
+
+    #[inline]
+    pub fn modifiers(&self) -> CGEventFlags {
+        self.modifiers
+    }
+
+    /// Returns whether a key down event with `key_code` and `flags` triggers
+    /// this shortcut.
+    ///
+    /// Flags that are not [`SHORTCUT_MODIFIERS`] are ignored, as is the fn
+    /// flag that macOS adds to function and navigation keys, unless the
+    /// shortcut itself needs fn.
+    ///
+    /// [`SHORTCUT_MODIFIERS`]: constant.SHORTCUT_MODIFIERS.html
+    pub fn matches(
+        &self,
+        event_type: CGEventType,
+        key_code: CGKeyCode,
+        flags: CGEventFlags,
+    ) -> bool {
+        if event_type != CGEventType::KeyDown || key_code != self.key_code {
+            return false;
+        }
+        let mut flags = flags.intersection(SHORTCUT_MODIFIERS);
+        if FN_KEYS.contains(&key_code)
+            && !self
+                .modifiers
+                .contains(CGEventFlags::CGEventFlagSecondaryFn)
+        {
+            flags.remove(CGEventFlags::CGEventFlagSecondaryFn);
+        }
+        flags == self.modifiers
+    }
+
+    /// Returns whether `event` triggers this shortcut. See [`matches`].
+    ///
+    /// [`matches`]: #method.matches
+    #[cfg(target_os = "macos")]
+    pub fn matches_event(&self, event: &CGEvent) -> bool {
+        let key_code = event.get_integer_value_field(EventField::KEYBOARD_EVENT_KEYCODE);
+        self.matches(event.get_type(), key_code as CGKeyCode, event.get_flags())
+    }
+
+    /// Formats the shortcut with macOS glyphs, like `⌃⌥F5` or `⇧⌘↩`.
+    pub fn to_glyph_string(&self) -> String {
+        let mut s: String = MODIFIERS
+            .iter()
+            .filter(|(flag, _, _)| self.modifiers.contains(*flag))
+            .map(|(_, glyph, _)| *glyph)
+            .collect();
+        match key_entry(self.key_code) {
+            Some((_, Some(glyph), _)) => s.push(*glyph),
+            Some((_, None, names)) => s.push_str(names[0]),
+            None => s.push_str(&format!("{:#x}", self.key_code)),
+        }
+        s
+    }
+}
+
+/// Formats the shortcut in textual notation, like `ctrl+opt+F5`. Keys without
+/// a name are written as their hexadecimal key code.
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (flag, _, names) in MODIFIERS {
+            if self.modifiers.contains(*flag) {
+                write!(f, "{}+", names[0])?;
+            }
+        }
+        match key_name(self.key_code) {
+            Some(name) => f.write_str(name),
+            None => write!(f, "{:#x}", self.key_code),
+        }
+    }
+}
+
+impl FromStr for Shortcut {
+    type Err = ShortcutError;
+
+    fn from_str(s: &str) -> Result<Shortcut, ShortcutError> {
+        let s = s.trim();
+        let mut modifiers = CGEventFlags::empty();
+        let mut add = |flag: CGEventFlags, name: &str| {
+            if modifiers.contains(flag) {
+                return Err(ShortcutError::DuplicateModifier(name.to_owned()));
+            }
+            modifiers.insert(flag);
+            Ok(())
+        };
+
+        // Everything before the last separator is modifiers.
+        let (prefix, key) = match s.rfind('+') {
+            Some(i) if i + 1 == s.len() => return Err(ShortcutError::MissingKey),
+            Some(i) => (&s[..i], &s[i + 1..]),
+            None => ("", s),
+        };
+        if !prefix.is_empty() {
+            for part in prefix.split('+') {
+                let part = part.trim();
+                if let Some((flag, _, _)) = MODIFIERS
+                    .iter()
+                    .find(|(_, _, names)| names.iter().any(|n| n.eq_ignore_ascii_case(part)))
+                {
+                    add(*flag, part)?;
+                } else if !part.is_empty() && part.chars().all(|c| modifier_glyph(c).is_some()) {
+                    for c in part.chars() {
+                        add(modifier_glyph(c).unwrap(), &c.to_string())?;
+                    }
+                } else {
+                    return Err(ShortcutError::UnknownModifier(part.to_owned()));
+                }
+            }
+        }
+
+        // Glyph notation puts the modifiers right before the key.
+        let mut key = key.trim();
+        while let Some(c) = key.chars().next() {
+            match modifier_glyph(c) {
+                Some(flag) if key.len() > c.len_utf8() => {
+                    add(flag, &c.to_string())?;
+                    key = &key[c.len_utf8()..];
+                }
+                _ => break,
+            }
+        }
+        if key.is_empty() {
+            return Err(ShortcutError::MissingKey);
+        }
+        let key_code =
+            key_code_from_name(key).ok_or_else(|| ShortcutError::UnknownKey(key.to_owned()))?;
+        Shortcut::new(key_code, modifiers)
+    }
+}
+
+fn modifier_glyph(c: char) -> Option<CGEventFlags> {
+    MODIFIERS
+        .iter()
+        .find(|(_, glyph, _)| *glyph == c)
+        .map(|(flag, _, _)| *flag)
+}
@@ -0,0 +1,525 @@
+// Copyright 2026 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Plain data copies of events, and recordings of them that can be saved and replayed.
+//!
+//! A recording is stored as an XML property list, indented with tabs:
+//!
+//! ```xml
+//! <?xml version="1.0" encoding="UTF-8"?>
+//! <!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+//! <plist version="1.0">
+//! <dict>
+//!     <key>Format</key>
+//!     <string>core-graphics.event-recording</string>
+//!     <key>Version</key>
+//!     <integer>1</integer>
+//!     <key>Events</key>
+//!     <array>
+//!         <dict>
+//!             <key>Type</key>
+//!             <integer>10</integer>
+//!             <key>X</key>
+//!             <real>0.0</real>
+//!             <key>Y</key>
+//!             <real>0.0</real>
+//!             <key>Flags</key>
+//!             <integer>131072</integer>
+//!             <key>Timestamp</key>
+//!             <integer>1000</integer>
+//!             <key>KeyCode</key>
+//!             <integer>0</integer>
+//!             <key>String</key>
+//!             <string>A</string>
+//!             <key>IntegerFields</key>
+//!             <dict>
+//!                 <key>8</key>
+//!                 <integer>1</integer>
+//!             </dict>
+//!             <key>DoubleFields</key>
+//!             <dict/>
+//!         </dict>
+//!     </array>
+//! </dict>
+//! </plist>
+//! ```
+//!
+//! `KeyCode` and `String` are left out when the event has none. Field dictionaries are keyed by
+//! the decimal [`EventField`] number and only hold fields that are not zero. Readers ignore keys
+//! they do not know, so later versions may add keys without bumping the version.
+//!
+//! [`EventField`]: ../struct.EventField.html
+
+#[cfg(target_os = "macos")]
+use super::{CGEvent, CGEventKind, CGEventTapLocation, CGMouseButton, EventField};
+use super::{CGEventField, CGEventFlags, CGEventMask, CGEventTimestamp, CGEventType, CGKeyCode};
+use crate::base::CGFloat;
+#[cfg(target_os = "macos")]
+use crate::event_source::CGEventSource;
+use crate::geometry::CGPoint;
+use core_foundation::propertylist::{xml, Dictionary, Value};
+use std::collections::BTreeMap;
+use std::error;
+use std::fmt;
+use std::time::Duration;
+
+/// The `Format` string that identifies a recording.
+pub const RECORDING_FORMAT: &str = "core-graphics.event-recording";
+
+/// The recording format version written by [`CGEventRecording::to_plist`]. Recordings with a
+/// higher version are rejected.
+///
+/// [`CGEventRecording::to_plist`]: struct.CGEventRecording.html#method.to_plist
+pub const RECORDING_VERSION: i128 = 1;
+
+/// The reasons a recording cannot be read.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RecordingError {
+    /// The data is not a valid XML property list.
+    Xml(xml::Error),
+    /// The property list is not a dictionary with the recording `Format` string.
+    NotARecording,
+    /// The recording was written by a newer version of the format.
+    UnsupportedVersion(i128),
+    /// An event is missing a required key, or a key has a value of the wrong type or range.
+    InvalidEvent { index: usize, key: &'static str },
+}
+
+impl fmt::Display for RecordingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RecordingError::Xml(err) => err.fmt(f),
+            RecordingError::NotARecording => f.write_str("not an event recording"),
+            RecordingError::UnsupportedVersion(version) => {
+                write!(f, "unsupported event recording version {}", version)
+            }
+            RecordingError::InvalidEvent { index, key } => {
+                write!(f, "event {} has a missing or invalid {}", index, key)
+            }
+        }
+    }
+}
+
+impl error::Error for RecordingError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            RecordingError::Xml(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<xml::Error> for RecordingError {
+    fn from(err: xml::Error) -> RecordingError {
+        RecordingError::Xml(err)
+    }
+}
+
+/// A copy of everything in an event that matters for replaying it.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CGEventSnapshot {
+    pub event_type: CGEventType,
+    #[cfg_attr(feature = "serde", serde(with = "serde_point"))]
+    pub location: CGPoint,
+    pub flags: CGEventFlags,
+    pub timestamp: CGEventTimestamp,
+    /// The virtual key code of key down, key up and flags changed events.
+    pub key_code: Option<CGKeyCode>,
+    /// The Unicode string of a keyboard event, or an empty string.
+    pub string: String,
+    /// The integer [`EventField`]s that are not zero.
+    ///
+    /// [`EventField`]: ../struct.EventField.html
+    #[cfg_attr(feature = "serde", serde(with = "serde_fields"))]
+    pub integer_fields: BTreeMap<CGEventField, i64>,
+    /// The double [`EventField`]s that are not zero.
+    ///
+    /// [`EventField`]: ../struct.EventField.html
+    #[cfg_attr(feature = "serde", serde(with = "serde_fields"))]
+    pub double_fields: BTreeMap<CGEventField, f64>,
+}
+
+impl CGEventSnapshot {
+    /// Returns a snapshot of an event with no flags, fields or string.
+    pub fn new(event_type: CGEventType, location: CGPoint) -> CGEventSnapshot {
+        CGEventSnapshot {
+            event_type,
+            location,
+            flags: CGEventFlags::empty(),
+            timestamp: 0,
+            key_code: None,
+            string: String::new(),
+            integer_fields: BTreeMap::new(),
+            double_fields: BTreeMap::new(),
+        }
+    }
+
+    /// Encodes the snapshot as one event dictionary of the recording format.
+    pub fn to_plist(&self) -> Value {
+        let mut dict = Dictionary::new();
+        dict.insert("Type", Value::Integer(self.event_type.raw().into()));
+        dict.insert("X", real(self.location.x));
+        dict.insert("Y", real(self.location.y));
+        dict.insert("Flags", Value::Integer(self.flags.bits().into()));
+        dict.insert("Timestamp", Value::Integer(self.timestamp.into()));
+        if let Some(key_code) = self.key_code {
+            dict.insert("KeyCode", Value::Integer(key_code.into()));
+        }
+        if !self.string.is_empty() {
+            dict.insert("String", Value::String(self.string.clone()));
+        }
+        let integers = self
+            .integer_fields
+            .iter()
+            .map(|(field, value)| (field.to_string(), Value::Integer((*value).into())));
+        dict.insert("IntegerFields", Value::Dictionary(integers.collect()));
+        let doubles = self
+            .double_fields
+            .iter()
+            .map(|(field, value)| (field.to_string(), Value::Real(*value)));
+        dict.insert("DoubleFields", Value::Dictionary(doubles.collect()));
+        Value::Dictionary(dict)
+    }
+
+    /// Decodes an event dictionary of the recording format. `index` is only used in errors.
+    pub fn from_plist(value: &Value, index: usize) -> Result<CGEventSnapshot, RecordingError> {
+        let invalid = |key| RecordingError::InvalidEvent { index, key };
+        let dict = value.as_dictionary().ok_or_else(|| invalid("event"))?;
+        let integer = |key| -> Result<Option<i128>, RecordingError> {
+            match dict.get(key) {
+                None => Ok(None),
+                Some(value) => value.as_integer().map(Some).ok_or_else(|| invalid(key)),
+            }
+        };
+        let required = |key| integer(key)?.ok_or_else(|| invalid(key));
+        let real = |key| {
+            dict.get(key)
+                .and_then(Value::as_real)
+                .ok_or_else(|| invalid(key))
+        };
+
+        let event_type = u32::try_from(required("Type")?).map_err(|_| invalid("Type"))?;
+        let flags = u64::try_from(required("Flags")?).map_err(|_| invalid("Flags"))?;
+        let timestamp = u64::try_from(required("Timestamp")?).map_err(|_| invalid("Timestamp"))?;
+        let key_code = match integer("KeyCode")? {
+            Some(key_code) => Some(CGKeyCode::try_from(key_code).map_err(|_| invalid("KeyCode"))?),
+            None => None,
+        };
+        let string = match dict.get("String") {
+            Some(value) => value
+                .as_string()
+                .ok_or_else(|| invalid("String"))?
+                .to_owned(),
+            None => String::new(),
+        };
+        let mut integer_fields = BTreeMap::new();
+        for (field, value) in fields(dict, "IntegerFields", index)? {
+            let value = value
+                .as_integer()
+                .and_then(|value| i64::try_from(value).ok())
+                .ok_or_else(|| invalid("IntegerFields"))?;
+            integer_fields.insert(field, value);
+        }
+        let mut double_fields = BTreeMap::new();
+        for (field, value) in fields(dict, "DoubleFields", index)? {
+            let value = value.as_real().ok_or_else(|| invalid("DoubleFields"))?;
+            double_fields.insert(field, value);
+        }
+        Ok(CGEventSnapshot {
+            event_type: CGEventType::from_raw(event_type),
+            location: CGPoint::new(real("X")? as _, real("Y")? as _),
+            flags: CGEventFlags::from_bits_retain(flags),
+            timestamp,
+            key_code,
+            string,
+            integer_fields,
+            double_fields,
+        })
+    }
+}
+
+// `CGFloat` is only `f32` on 32-bit targets.
+#[allow(clippy::unnecessary_cast)]
+fn real(value: CGFloat) -> Value {
+    Value::Real(value as f64)
+}
+
+/// Returns the entries of a field dictionary with their keys parsed, treating a missing
+/// dictionary as empty.
+fn fields<'a>(
+    dict: &'a Dictionary,
+    key: &'static str,
+    index: usize,
+) -> Result<Vec<(CGEventField, &'a Value)>, RecordingError> {
+    let invalid = RecordingError::InvalidEvent { index, key };
+    let fields = match dict.get(key) {
+        Some(value) => value.as_dictionary().ok_or_else(|| invalid.clone())?,
+        None => return Ok(Vec::new()),
+    };
+    fields
+        .iter()
+        .map(|(field, value)| match field.parse() {
+            Ok(field) => Ok((field, value)),
+            Err(_) => Err(invalid.clone()),
+        })
+        .collect()
+}
+
+#[cfg(target_os = "macos")]
+impl CGEventSnapshot {
+    /// Copies the type, location, flags, timestamp, key code, string and every
+    /// [`EventField`] that is not zero out of `event`.
+    ///
+    /// [`EventField`]: ../struct.EventField.html
+    pub fn from_event(event: &CGEvent) -> CGEventSnapshot {
+        let event_type = event.get_type();
+        let mut snapshot = CGEventSnapshot::new(event_type, event.location());
+        snapshot.flags = event.get_flags();
+        snapshot.timestamp = event.get_timestamp();
+        let is_key = event_type == CGEventType::KeyDown || event_type == CGEventType::KeyUp;
+        if is_key || event_type == CGEventType::FlagsChanged {
+            let key_code = event.get_integer_value_field(EventField::KEYBOARD_EVENT_KEYCODE);
+            snapshot.key_code = Some(key_code as CGKeyCode);
+        }
+        if is_key {
+            snapshot.string = event.get_string();
+        }
+        for &field in EventField::ALL {
+            if EventField::DOUBLE_FIELDS.contains(&field) {
+                let value = event.get_double_value_field(field);
+                if value != 0.0 {
+                    snapshot.double_fields.insert(field, value);
+                }
+            } else {
+                let value = event.get_integer_value_field(field);
+                if value != 0 {
+                    snapshot.integer_fields.insert(field, value);
+                }
+            }
+        }
+        snapshot
+    }
+
+    /// Creates an event from the snapshot.
+    ///
+    /// Fields that describe the process that created or received the original event are not
+    /// copied, so that the new event looks like it came from `source`.
+    pub fn to_event(&self, source: &CGEventSource) -> Result<CGEvent, ()> {
+        let event = match self.key_code {
+            Some(key_code) if self.event_type != CGEventType::FlagsChanged => {
+                let key_down = self.event_type == CGEventType::KeyDown;
+                CGEvent::new_keyboard_event(source.clone(), key_code, key_down)?
+            }
+            _ if self.event_type.kind() == CGEventKind::Mouse => CGEvent::new_mouse_event(
+                source.clone(),
+                self.event_type,
+                self.location,
+                CGMouseButton::Left,
+            )?,
+            _ => {
+                let event = CGEvent::new(source.clone())?;
+                event.set_type(self.event_type);
+                event
+            }
+        };
+        event.set_location(self.location);
+        event.set_flags(self.flags);
+        event.set_timestamp(self.timestamp);
+        if !self.string.is_empty() {
+            event.set_string(&self.string);
+        }
+        for (&field, &value) in &self.integer_fields {
+            if !(EventField::EVENT_TARGET_PROCESS_SERIAL_NUMBER..=EventField::EVENT_SOURCE_STATE_ID)
+                .contains(&field)
+            {
+                event.set_integer_value_field(field, value);
+            }
+        }
+        for (&field, &value) in &self.double_fields {
+            event.set_double_value_field(field, value);
+        }
+        Ok(event)
+    }
+}
+
+/// A sequence of event snapshots, such as the events seen by an event tap.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CGEventRecording {
+    pub events: Vec<CGEventSnapshot>,
+}
+
+impl CGEventRecording {
+    pub fn new() -> CGEventRecording {
+        CGEventRecording { events: Vec::new() }
+    }
+
+    #[inline]
+    pub fn push(&mut self, snapshot: CGEventSnapshot) {
+        self.events.push(snapshot);
+    }
+
+    /// Removes mouse moved events. Presses, releases and drags keep their own locations, so
+    /// replaying the rest still clicks in the same places.
+    pub fn strip_mouse_moves(&mut self) {
+        self.events
+            .retain(|event| event.event_type != CGEventType::MouseMoved);
+    }
+
+    /// Keeps only the events whose type is in `mask`.
+    pub fn retain_types(&mut self, mask: CGEventMask) {
+        self.events.retain(|event| mask.contains(event.event_type));
+    }
+
+    /// Scales the time between events by `factor`, keeping the first event's timestamp. A
+    /// factor of 0.5 replays twice as fast.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is negative or not finite.
+    pub fn rescale_timing(&mut self, factor: f64) {
+        assert!(
+            factor.is_finite() && factor >= 0.0,
+            "invalid timing factor {}",
+            factor
+        );
+        let start = match self.events.first() {
+            Some(first) => first.timestamp,
+            None => return,
+        };
+        for event in &mut self.events {
+            let elapsed = event.timestamp.saturating_sub(start) as f64 * factor;
+            event.timestamp = start.saturating_add(elapsed.round() as u64);
+        }
+    }
+
+    /// Returns how long to wait before each event, measured from the previous event. Events
+    /// whose timestamp goes backwards are not delayed.
+    pub fn delays(&self) -> Vec<Duration> {
+        let mut previous = self.events.first().map_or(0, |first| first.timestamp);
+        self.events
+            .iter()
+            .map(|event| {
+                let delay = event.timestamp.saturating_sub(previous);
+                previous = previous.max(event.timestamp);
+                Duration::from_nanos(delay)
+            })
+            .collect()
+    }
+
+    /// Returns the time between the first and the last event.
+    pub fn duration(&self) -> Duration {
+        self.delays().iter().sum()
+    }
+
+    /// Encodes the recording as a property list value.
+    pub fn to_plist(&self) -> Value {
+        let mut dict = Dictionary::new();
+        dict.insert("Format", Value::String(RECORDING_FORMAT.to_owned()));
+        dict.insert("Version", Value::Integer(RECORDING_VERSION));
+        let events = self.events.iter().map(CGEventSnapshot::to_plist).collect();
+        dict.insert("Events", Value::Array(events));
+        Value::Dictionary(dict)
+    }
+
+    /// Decodes a recording from a property list value.
+    pub fn from_plist(value: &Value) -> Result<CGEventRecording, RecordingError> {
+        let dict = value
+            .as_dictionary()
+            .filter(|dict| dict.get("Format").and_then(Value::as_string) == Some(RECORDING_FORMAT))
+            .ok_or(RecordingError::NotARecording)?;
+        match dict.get("Version").and_then(Value::as_integer) {
+            Some(version) if (1..=RECORDING_VERSION).contains(&version) => {}
+            Some(version) => return Err(RecordingError::UnsupportedVersion(version)),
+            None => return Err(RecordingError::NotARecording),
+        }
+        let events = dict
+            .get("Events")
+            .and_then(Value::as_array)
+            .ok_or(RecordingError::NotARecording)?;
+        let events = events
+            .iter()
+            .enumerate()
+            .map(|(index, event)| CGEventSnapshot::from_plist(event, index))
+            .collect::<Result<_, _>>()?;
+        Ok(CGEventRecording { events })
+    }
+
+    /// Encodes the recording as an XML property list.
+    pub fn to_xml(&self) -> String {
+        // Every value in a recording fits in a property list.
+        xml::to_string(&self.to_plist()).unwrap()
+    }
+
+    /// Decodes a recording from an XML property list.
+    pub fn from_xml(string: &str) -> Result<CGEventRecording, RecordingError> {
+        CGEventRecording::from_plist(&xml::from_str(string)?)
+    }
+
+    /// Posts the recorded events to `tap_location`, waiting between them as long as the
+    /// timestamps say.
+    #[cfg(target_os = "macos")]
+    pub fn post(&self, source: &CGEventSource, tap_location: CGEventTapLocation) -> Result<(), ()> {
+        for (event, delay) in self.events.iter().zip(self.delays()) {
+            if !delay.is_zero() {
+                std::thread::sleep(delay);
+            }
+            event.to_event(source)?.post(tap_location);
+        }
+        Ok(())
+    }
+}
+
+impl FromIterator<CGEventSnapshot> for CGEventRecording {
+    fn from_iter<I: IntoIterator<Item = CGEventSnapshot>>(iter: I) -> CGEventRecording {
+        CGEventRecording {
+            events: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// Stores a point as an `[x, y]` pair.
+#[cfg(feature = "serde")]
+mod serde_point {
+    use crate::base::CGFloat;
+    use crate::geometry::CGPoint;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(point: &CGPoint, serializer: S) -> Result<S::Ok, S::Error> {
+        [point.x, point.y].serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<CGPoint, D::Error> {
+        let [x, y] = <[CGFloat; 2]>::deserialize(deserializer)?;
+        Ok(CGPoint::new(x, y))
+    }
+}
+
+/// Stores a field map as a list of `[field, value]` pairs, since not every format allows
+/// number keys.
+#[cfg(feature = "serde")]
+mod serde_fields {
+    use super::CGEventField;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::collections::BTreeMap;
+
+    pub fn serialize<T: Serialize, S: Serializer>(
+        fields: &BTreeMap<CGEventField, T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(fields)
+    }
+
+    pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<BTreeMap<CGEventField, T>, D::Error> {
+        Vec::<(CGEventField, T)>::deserialize(deserializer).map(|pairs| pairs.into_iter().collect())
+    }
+}
@@ -0,0 +1,223 @@
+// Copyright 2026 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Planning the keyboard events that type a string.
+
+use super::keymap::KEY_MAPPINGS;
+#[cfg(target_os = "macos")]
+use super::{CGEvent, CGEventTapLocation};
+use super::{CGEventFlags, CGKeyCode, KeyCode};
+#[cfg(target_os = "macos")]
+use crate::event_source::CGEventSource;
+
+/// The most UTF-16 code units a keyboard event carries. Longer strings passed
+/// to `CGEvent::set_string` are truncated.
+pub const MAX_EVENT_STRING_LEN: usize = 20;
+
+const SHIFT: CGEventFlags = CGEventFlags::CGEventFlagShift;
+const OPTION: CGEventFlags = CGEventFlags::CGEventFlagAlternate;
+const SHIFT_OPTION: CGEventFlags = SHIFT.union(OPTION);
+
+/// Characters typed with option, or shift and option, in the US layout. Dead
+/// keys, which only type a character together with the next key, are left
+/// out.
+const OPTION_CHARS: &[(char, CGKeyCode, CGEventFlags)] = &[
+    ('å', KeyCode::ANSI_A, OPTION),
+    ('∫', KeyCode::ANSI_B, OPTION),
+    ('ç', KeyCode::ANSI_C, OPTION),
+    ('∂', KeyCode::ANSI_D, OPTION),
+    ('ƒ', KeyCode::ANSI_F, OPTION),
+    ('©', KeyCode::ANSI_G, OPTION),
+    ('˙', KeyCode::ANSI_H, OPTION),
+    ('∆', KeyCode::ANSI_J, OPTION),
+    ('˚', KeyCode::ANSI_K, OPTION),
+    ('¬', KeyCode::ANSI_L, OPTION),
+    ('µ', KeyCode::ANSI_M, OPTION),
+    ('ø', KeyCode::ANSI_O, OPTION),
+    ('π', KeyCode::ANSI_P, OPTION),
+    ('œ', KeyCode::ANSI_Q, OPTION),
+    ('®', KeyCode::ANSI_R, OPTION),
+    ('ß', KeyCode::ANSI_S, OPTION),
+    ('†', KeyCode::ANSI_T, OPTION),
+    ('√', KeyCode::ANSI_V, OPTION),
+    ('∑', KeyCode::ANSI_W, OPTION),
+    ('≈', KeyCode::ANSI_X, OPTION),
+    ('¥', KeyCode::ANSI_Y, OPTION),
+    ('Ω', KeyCode::ANSI_Z, OPTION),
+    ('¡', KeyCode::ANSI_1, OPTION),
+    ('™', KeyCode::ANSI_2, OPTION),
+    ('£', KeyCode::ANSI_3, OPTION),
+    ('¢', KeyCode::ANSI_4, OPTION),
+    ('∞', KeyCode::ANSI_5, OPTION),
+    ('§', KeyCode::ANSI_6, OPTION),
+    ('¶', KeyCode::ANSI_7, OPTION),
+    ('•', KeyCode::ANSI_8, OPTION),
+    ('ª', KeyCode::ANSI_9, OPTION),
+    ('º', KeyCode::ANSI_0, OPTION),
+    ('–', KeyCode::ANSI_MINUS, OPTION),
+    ('≠', KeyCode::ANSI_EQUAL, OPTION),
+    ('“', KeyCode::ANSI_LEFT_BRACKET, OPTION),
+    ('‘', KeyCode::ANSI_RIGHT_BRACKET, OPTION),
+    ('«', KeyCode::ANSI_BACKSLASH, OPTION),
+    ('…', KeyCode::ANSI_SEMICOLON, OPTION),
+    ('æ', KeyCode::ANSI_QUOTE, OPTION),
+    ('≤', KeyCode::ANSI_COMMA, OPTION),
+    ('≥', KeyCode::ANSI_PERIOD, OPTION),
+    ('÷', KeyCode::ANSI_SLASH, OPTION),
+    ('Å', KeyCode::ANSI_A, SHIFT_OPTION),
+    ('Ç', KeyCode::ANSI_C, SHIFT_OPTION),
+    ('Ø', KeyCode::ANSI_O, SHIFT_OPTION),
+    ('∏', KeyCode::ANSI_P, SHIFT_OPTION),
+    ('Œ', KeyCode::ANSI_Q, SHIFT_OPTION),
+    ('€', KeyCode::ANSI_2, SHIFT_OPTION),
+    ('‹', KeyCode::ANSI_3, SHIFT_OPTION),
+    ('›', KeyCode::ANSI_4, SHIFT_OPTION),
+    ('‡', KeyCode::ANSI_7, SHIFT_OPTION),
+    ('°', KeyCode::ANSI_8, SHIFT_OPTION),
+    ('·', KeyCode::ANSI_9, SHIFT_OPTION),
+    ('‚', KeyCode::ANSI_0, SHIFT_OPTION),
+    ('—', KeyCode::ANSI_MINUS, SHIFT_OPTION),
+    ('±', KeyCode::ANSI_EQUAL, SHIFT_OPTION),
+    ('”', KeyCode::ANSI_LEFT_BRACKET, SHIFT_OPTION),
+    ('’', KeyCode::ANSI_RIGHT_BRACKET, SHIFT_OPTION),
+    ('»', KeyCode::ANSI_BACKSLASH, SHIFT_OPTION),
+    ('Æ', KeyCode::ANSI_QUOTE, SHIFT_OPTION),
+    ('¯', KeyCode::ANSI_COMMA, SHIFT_OPTION),
+    ('˘', KeyCode::ANSI_PERIOD, SHIFT_OPTION),
+    ('¿', KeyCode::ANSI_SLASH, SHIFT_OPTION),
+];
+
+/// Returns the key and modifiers that type `c` in the US layout, using the
+/// main keyboard rather than the keypad.
+pub fn us_key_for_char(c: char) -> Option<(CGKeyCode, CGEventFlags)> {
+    match c {
+        '\n' | '\r' => return Some((KeyCode::RETURN, CGEventFlags::empty())),
+        '\t' => return Some((KeyCode::TAB, CGEventFlags::empty())),
+        _ => {}
+    }
+    let main_keys = || {
+        KEY_MAPPINGS
+            .iter()
+            .filter(|m| !m.code.starts_with("Numpad") && m.key_code != KeyCode::ISO_SECTION)
+    };
+    if let Some(m) = main_keys().find(|m| m.char(false) == Some(c)) {
+        return Some((m.key_code, CGEventFlags::empty()));
+    }
+    if let Some(m) = main_keys().find(|m| m.char(true) == Some(c)) {
+        return Some((m.key_code, SHIFT));
+    }
+    OPTION_CHARS
+        .iter()
+        .find(|(option_char, _, _)| *option_char == c)
+        .map(|(_, key_code, flags)| (*key_code, *flags))
+}
+
+/// One step of a [`TypingPlan`].
+///
+/// [`TypingPlan`]: struct.TypingPlan.html
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TypingStep {
+    /// Press or release a key with the given modifier flags.
+    Key {
+        key_code: CGKeyCode,
+        key_down: bool,
+        flags: CGEventFlags,
+    },
+    /// Type text that has no key in the US layout, by pressing and releasing
+    /// a key with the text attached. Holds at most [`MAX_EVENT_STRING_LEN`]
+    /// UTF-16 code units, and never splits a surrogate pair.
+    ///
+    /// [`MAX_EVENT_STRING_LEN`]: constant.MAX_EVENT_STRING_LEN.html
+    Text(Vec<u16>),
+}
+
+/// The keyboard events that type a string, as plain data.
+///
+/// Characters with a key in the US layout become a key down and key up with
+/// shift or option flags as needed. `'\n'`, `'\r'` and `"\r\n"` type return.
+/// Runs of other characters become [`TypingStep::Text`] steps.
+///
+/// [`TypingStep::Text`]: enum.TypingStep.html#variant.Text
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TypingPlan {
+    steps: Vec<TypingStep>,
+}
+
+impl TypingPlan {
+    pub fn new(text: &str) -> TypingPlan {
+        let mut steps = Vec::new();
+        let mut pending: Vec<u16> = Vec::new();
+        let mut chars = text.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c == '\r' && chars.peek() == Some(&'\n') {
+                continue;
+            }
+            match us_key_for_char(c) {
+                Some((key_code, flags)) => {
+                    push_text(&mut steps, &mut pending);
+                    for key_down in [true, false] {
+                        steps.push(TypingStep::Key {
+                            key_code,
+                            key_down,
+                            flags,
+                        });
+                    }
+                }
+                None => {
+                    let mut buf = [0; 2];
+                    let units = c.encode_utf16(&mut buf);
+                    if pending.len() + units.len() > MAX_EVENT_STRING_LEN {
+                        push_text(&mut steps, &mut pending);
+                    }
+                    pending.extend_from_slice(units);
+                }
+            }
+        }
+        push_text(&mut steps, &mut pending);
+        TypingPlan { steps }
+    }
+
+    #[inline]
+    pub fn steps(&self) -> &[TypingStep] {
+        &self.steps
+    }
+
+    /// Posts the planned events to `tap_location`, creating them with
+    /// `source`.
+    #[cfg(target_os = "macos")]
+    pub fn post(&self, source: &CGEventSource, tap_location: CGEventTapLocation) -> Result<(), ()> {
+        for step in &self.steps {
+            match step {
+                TypingStep::Key {
+                    key_code,
+                    key_down,
+                    flags,
+                } => {
+                    let event = CGEvent::new_keyboard_event(source.clone(), *key_code, *key_down)?;
+                    event.set_flags(*flags);
+                    event.post(tap_location);
+                }
+                TypingStep::Text(text) => {
+                    for key_down in [true, false] {
+                        let event = CGEvent::new_keyboard_event(source.clone(), 0, key_down)?;
+                        event.set_string_from_utf16_unchecked(text);
+                        event.post(tap_location);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn push_text(steps: &mut Vec<TypingStep>, pending: &mut Vec<u16>) {
+    if !pending.is_empty() {
+        steps.push(TypingStep::Text(std::mem::take(pending)));
+    }
+}
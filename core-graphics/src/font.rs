@@ -13,6 +13,7 @@ use core_foundation::array::{CFArray, CFArrayRef};
 use core_foundation::base::{CFRelease, CFRetain, CFType, CFTypeID, TCFType};
 use core_foundation::data::{CFData, CFDataRef};
 use core_foundation::dictionary::{CFDictionary, CFDictionaryRef};
+use core_foundation::four_char_code::FourCharCode;
 use core_foundation::number::CFNumber;
 use core_foundation::string::{CFString, CFStringRef};
 use std::ptr::NonNull;
@@ -130,8 +131,16 @@ impl CGFont {
         unsafe { TCFType::wrap_under_create_rule(CGFontCopyTableTags(self.as_ptr())) }
     }
 
-    pub fn copy_table_for_tag(&self, tag: u32) -> Option<CFData> {
-        let data_ref = unsafe { CGFontCopyTableForTag(self.as_ptr(), tag) };
+    /// Returns the tags of the tables in the font.
+    pub fn table_tags(&self) -> Vec<FourCharCode> {
+        self.copy_table_tags()
+            .iter()
+            .map(|tag| FourCharCode::from_u32(*tag))
+            .collect()
+    }
+
+    pub fn copy_table_for_tag(&self, tag: impl Into<FourCharCode>) -> Option<CFData> {
+        let data_ref = unsafe { CGFontCopyTableForTag(self.as_ptr(), tag.into().to_u32()) };
         if !data_ref.is_null() {
             Some(unsafe { TCFType::wrap_under_create_rule(data_ref) })
         } else {
@@ -202,4 +211,10 @@ extern "C" {
     fn CGFontCopyTableForTag(font: crate::sys::CGFontRef, tag: u32) -> CFDataRef;
     fn CGFontCopyVariations(font: crate::sys::CGFontRef) -> CFDictionaryRef;
     fn CGFontCopyVariationAxes(font: crate::sys::CGFontRef) -> CFArrayRef;
+
+    // Keys of the axis dictionaries returned by `copy_variation_axes`.
+    pub static kCGFontVariationAxisName: CFStringRef;
+    pub static kCGFontVariationAxisMinValue: CFStringRef;
+    pub static kCGFontVariationAxisMaxValue: CFStringRef;
+    pub static kCGFontVariationAxisDefaultValue: CFStringRef;
 }
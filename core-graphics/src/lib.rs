@@ -16,7 +16,6 @@ pub mod context;
 pub mod data_provider;
 #[cfg(target_os = "macos")]
 pub mod display;
-#[cfg(target_os = "macos")]
 pub mod event;
 #[cfg(target_os = "macos")]
 pub mod event_source;
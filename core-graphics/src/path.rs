@@ -9,10 +9,12 @@
 
 pub use crate::sys::CGPathRef as SysCGPathRef;
 
-use crate::geometry::{CGAffineTransform, CGPoint, CGRect};
+use crate::base::CGFloat;
+use crate::geometry::{CGAffineTransform, CGPoint, CGRect, CGSize};
 use core::ffi::c_void;
 use core_foundation::base::{CFRelease, CFRetain, CFTypeID};
-use foreign_types::{foreign_type, ForeignType};
+use foreign_types::{foreign_type, ForeignType, ForeignTypeRef};
+use std::f64::consts::{FRAC_PI_2, PI};
 use std::fmt::{self, Debug, Formatter};
 use std::marker::PhantomData;
 use std::ops::Deref;
@@ -30,19 +32,40 @@ foreign_type! {
 
 impl CGPath {
     pub fn from_rect(rect: CGRect, transform: Option<&CGAffineTransform>) -> CGPath {
-        unsafe {
-            let transform = match transform {
-                None => ptr::null(),
-                Some(transform) => transform as *const CGAffineTransform,
-            };
-            CGPath::from_ptr(CGPathCreateWithRect(rect, transform))
-        }
+        unsafe { CGPath::from_ptr(CGPathCreateWithRect(rect, transform_ptr(transform))) }
+    }
+
+    /// Creates an immutable path with the elements of `data`.
+    pub fn from_path_data(data: &PathData) -> CGPath {
+        let mut path = CGMutablePath::new();
+        path.add_path_data(None, data);
+        path.copy()
     }
 
     pub fn type_id() -> CFTypeID {
         unsafe { CGPathGetTypeID() }
     }
 
+    /// Returns the elements of this path as a [`PathData`].
+    ///
+    /// [`PathData`]: struct.PathData.html
+    pub fn to_path_data(&self) -> PathData {
+        let mut data = PathData::new();
+        unsafe {
+            CGPathApply(
+                self.as_ptr(),
+                &mut data as *mut PathData as *mut c_void,
+                push_element,
+            );
+        }
+        return data;
+
+        unsafe extern "C" fn push_element(info: *mut c_void, element: *const CGPathElement) {
+            let data = &mut *(info as *mut PathData);
+            data.push(PathElement::from(&*element));
+        }
+    }
+
     pub fn apply<'a, F>(&'a self, mut closure: &'a F)
     where
         F: FnMut(CGPathElementRef<'a>),
@@ -65,6 +88,171 @@ impl CGPath {
     }
 }
 
+foreign_type! {
+    #[doc(hidden)]
+    pub unsafe type CGMutablePath {
+        type CType = crate::sys::CGPath;
+        fn drop = |p| CFRelease(p as *mut _);
+        fn clone = |p| CFRetain(p as *const _) as *mut _;
+    }
+}
+
+impl CGMutablePath {
+    pub fn new() -> CGMutablePath {
+        unsafe { CGMutablePath::from_ptr(CGPathCreateMutable()) }
+    }
+}
+
+impl Default for CGMutablePath {
+    fn default() -> CGMutablePath {
+        CGMutablePath::new()
+    }
+}
+
+impl CGMutablePathRef {
+    /// Returns an immutable copy of this path.
+    pub fn copy(&self) -> CGPath {
+        unsafe { CGPath::from_ptr(CGPathCreateCopy(self.as_ptr())) }
+    }
+
+    pub fn add_path(&mut self, transform: Option<&CGAffineTransform>, path: &CGPathRef) {
+        unsafe { CGPathAddPath(self.as_ptr(), transform_ptr(transform), path.as_ptr()) }
+    }
+
+    pub fn add_path_data(&mut self, transform: Option<&CGAffineTransform>, data: &PathData) {
+        for element in data {
+            match *element {
+                PathElement::MoveToPoint(point) => self.move_to_point(transform, point),
+                PathElement::AddLineToPoint(point) => self.add_line_to_point(transform, point),
+                PathElement::AddQuadCurveToPoint(control, point) => {
+                    self.add_quad_curve_to_point(transform, control, point)
+                }
+                PathElement::AddCurveToPoint(control1, control2, point) => {
+                    self.add_curve_to_point(transform, control1, control2, point)
+                }
+                PathElement::CloseSubpath => self.close_subpath(),
+            }
+        }
+    }
+}
+
+impl Deref for CGMutablePathRef {
+    type Target = CGPathRef;
+
+    fn deref(&self) -> &CGPathRef {
+        unsafe { CGPathRef::from_ptr(self.as_ptr()) }
+    }
+}
+
+fn transform_ptr(transform: Option<&CGAffineTransform>) -> *const CGAffineTransform {
+    match transform {
+        None => ptr::null(),
+        Some(transform) => transform as *const CGAffineTransform,
+    }
+}
+
+impl PathBuilder for CGMutablePathRef {
+    fn move_to_point(&mut self, transform: Option<&CGAffineTransform>, point: CGPoint) {
+        unsafe { CGPathMoveToPoint(self.as_ptr(), transform_ptr(transform), point.x, point.y) }
+    }
+
+    fn add_line_to_point(&mut self, transform: Option<&CGAffineTransform>, point: CGPoint) {
+        unsafe { CGPathAddLineToPoint(self.as_ptr(), transform_ptr(transform), point.x, point.y) }
+    }
+
+    fn add_quad_curve_to_point(
+        &mut self,
+        transform: Option<&CGAffineTransform>,
+        control: CGPoint,
+        point: CGPoint,
+    ) {
+        unsafe {
+            CGPathAddQuadCurveToPoint(
+                self.as_ptr(),
+                transform_ptr(transform),
+                control.x,
+                control.y,
+                point.x,
+                point.y,
+            )
+        }
+    }
+
+    fn add_curve_to_point(
+        &mut self,
+        transform: Option<&CGAffineTransform>,
+        control1: CGPoint,
+        control2: CGPoint,
+        point: CGPoint,
+    ) {
+        unsafe {
+            CGPathAddCurveToPoint(
+                self.as_ptr(),
+                transform_ptr(transform),
+                control1.x,
+                control1.y,
+                control2.x,
+                control2.y,
+                point.x,
+                point.y,
+            )
+        }
+    }
+
+    fn close_subpath(&mut self) {
+        unsafe { CGPathCloseSubpath(self.as_ptr()) }
+    }
+
+    fn add_arc(
+        &mut self,
+        transform: Option<&CGAffineTransform>,
+        center: CGPoint,
+        radius: CGFloat,
+        start_angle: CGFloat,
+        end_angle: CGFloat,
+        clockwise: bool,
+    ) {
+        unsafe {
+            CGPathAddArc(
+                self.as_ptr(),
+                transform_ptr(transform),
+                center.x,
+                center.y,
+                radius,
+                start_angle,
+                end_angle,
+                clockwise,
+            )
+        }
+    }
+
+    fn add_rect(&mut self, transform: Option<&CGAffineTransform>, rect: CGRect) {
+        unsafe { CGPathAddRect(self.as_ptr(), transform_ptr(transform), rect) }
+    }
+
+    fn add_ellipse_in_rect(&mut self, transform: Option<&CGAffineTransform>, rect: CGRect) {
+        unsafe { CGPathAddEllipseInRect(self.as_ptr(), transform_ptr(transform), rect) }
+    }
+
+    fn add_rounded_rect(
+        &mut self,
+        transform: Option<&CGAffineTransform>,
+        rect: CGRect,
+        corner_width: CGFloat,
+        corner_height: CGFloat,
+    ) {
+        unsafe {
+            CGPathAddRoundedRect(
+                self.as_ptr(),
+                transform_ptr(transform),
+                rect,
+                corner_width,
+                corner_height,
+            )
+        }
+    }
+}
+
 #[repr(i32)]
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum CGPathElementType {
@@ -123,6 +311,845 @@ impl CGPathElement {
     }
 }
 
+/// Operations for building a path, shared by [`CGMutablePath`] and [`PathData`].
+///
+/// Each operation takes an optional transform that is applied to the points it adds. Lines and
+/// curves are ignored if the path has no current point, as in CoreGraphics.
+///
+/// [`CGMutablePath`]: struct.CGMutablePath.html
+/// [`PathData`]: struct.PathData.html
+pub trait PathBuilder {
+    /// Starts a new subpath at `point`.
+    fn move_to_point(&mut self, transform: Option<&CGAffineTransform>, point: CGPoint);
+
+    fn add_line_to_point(&mut self, transform: Option<&CGAffineTransform>, point: CGPoint);
+
+    fn add_quad_curve_to_point(
+        &mut self,
+        transform: Option<&CGAffineTransform>,
+        control: CGPoint,
+        point: CGPoint,
+    );
+
+    fn add_curve_to_point(
+        &mut self,
+        transform: Option<&CGAffineTransform>,
+        control1: CGPoint,
+        control2: CGPoint,
+        point: CGPoint,
+    );
+
+    /// Closes the current subpath, leaving the current point at its start.
+    fn close_subpath(&mut self);
+
+    /// Adds a circular arc around `center`, from `start_angle` to `end_angle` in radians.
+    ///
+    /// If the path has a current point, a line is first added to the start of the arc; otherwise
+    /// a new subpath is started there. `clockwise` refers to a y-up coordinate system.
+    fn add_arc(
+        &mut self,
+        transform: Option<&CGAffineTransform>,
+        center: CGPoint,
+        radius: CGFloat,
+        start_angle: CGFloat,
+        end_angle: CGFloat,
+        clockwise: bool,
+    );
+
+    /// Adds a closed subpath around `rect`, starting at its minimum corner and going
+    /// counterclockwise.
+    fn add_rect(&mut self, transform: Option<&CGAffineTransform>, rect: CGRect);
+
+    /// Adds a closed ellipse that fits in `rect`, made of four cubic curves starting at the
+    /// middle of the right edge and going counterclockwise.
+    fn add_ellipse_in_rect(&mut self, transform: Option<&CGAffineTransform>, rect: CGRect);
+
+    /// Adds a closed rectangle whose corners are quarter ellipses of the given size.
+    fn add_rounded_rect(
+        &mut self,
+        transform: Option<&CGAffineTransform>,
+        rect: CGRect,
+        corner_width: CGFloat,
+        corner_height: CGFloat,
+    );
+}
+
+/// One element of a path, like a [`CGPathElement`] but owning its points.
+///
+/// [`CGPathElement`]: struct.CGPathElement.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PathElement {
+    MoveToPoint(CGPoint),
+    AddLineToPoint(CGPoint),
+    /// A control point and an end point.
+    AddQuadCurveToPoint(CGPoint, CGPoint),
+    /// Two control points and an end point.
+    AddCurveToPoint(CGPoint, CGPoint, CGPoint),
+    CloseSubpath,
+}
+
+impl PathElement {
+    pub fn element_type(&self) -> CGPathElementType {
+        match *self {
+            PathElement::MoveToPoint(..) => CGPathElementType::MoveToPoint,
+            PathElement::AddLineToPoint(..) => CGPathElementType::AddLineToPoint,
+            PathElement::AddQuadCurveToPoint(..) => CGPathElementType::AddQuadCurveToPoint,
+            PathElement::AddCurveToPoint(..) => CGPathElementType::AddCurveToPoint,
+            PathElement::CloseSubpath => CGPathElementType::CloseSubpath,
+        }
+    }
+
+    /// Returns the point this element ends at, or `None` for `CloseSubpath`.
+    pub fn end_point(&self) -> Option<CGPoint> {
+        match *self {
+            PathElement::MoveToPoint(point)
+            | PathElement::AddLineToPoint(point)
+            | PathElement::AddQuadCurveToPoint(_, point)
+            | PathElement::AddCurveToPoint(_, _, point) => Some(point),
+            PathElement::CloseSubpath => None,
+        }
+    }
+
+    pub fn apply_transform(&self, t: &CGAffineTransform) -> PathElement {
+        match *self {
+            PathElement::MoveToPoint(p) => PathElement::MoveToPoint(p.apply_transform(t)),
+            PathElement::AddLineToPoint(p) => PathElement::AddLineToPoint(p.apply_transform(t)),
+            PathElement::AddQuadCurveToPoint(c, p) => {
+                PathElement::AddQuadCurveToPoint(c.apply_transform(t), p.apply_transform(t))
+            }
+            PathElement::AddCurveToPoint(c1, c2, p) => PathElement::AddCurveToPoint(
+                c1.apply_transform(t),
+                c2.apply_transform(t),
+                p.apply_transform(t),
+            ),
+            PathElement::CloseSubpath => PathElement::CloseSubpath,
+        }
+    }
+}
+
+impl From<&CGPathElement> for PathElement {
+    fn from(element: &CGPathElement) -> PathElement {
+        let points = element.points();
+        match element.element_type {
+            CGPathElementType::MoveToPoint => PathElement::MoveToPoint(points[0]),
+            CGPathElementType::AddLineToPoint => PathElement::AddLineToPoint(points[0]),
+            CGPathElementType::AddQuadCurveToPoint => {
+                PathElement::AddQuadCurveToPoint(points[0], points[1])
+            }
+            CGPathElementType::AddCurveToPoint => {
+                PathElement::AddCurveToPoint(points[0], points[1], points[2])
+            }
+            CGPathElementType::CloseSubpath => PathElement::CloseSubpath,
+        }
+    }
+}
+
+/// A path stored as a list of [`PathElement`]s, which can be built and inspected without
+/// CoreGraphics.
+///
+/// Use [`CGPath::from_path_data`] and [`CGPathRef::to_path_data`] to convert to and from a
+/// `CGPath`.
+///
+/// [`PathElement`]: enum.PathElement.html
+/// [`CGPath::from_path_data`]: struct.CGPath.html#method.from_path_data
+/// [`CGPathRef::to_path_data`]: struct.CGPathRef.html#method.to_path_data
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PathData {
+    elements: Vec<PathElement>,
+    current_point: Option<CGPoint>,
+    subpath_start: Option<CGPoint>,
+}
+
+impl PathData {
+    pub fn new() -> PathData {
+        PathData::default()
+    }
+
+    #[inline]
+    pub fn elements(&self) -> &[PathElement] {
+        &self.elements
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Returns the point that the next line or curve starts from.
+    #[inline]
+    pub fn current_point(&self) -> Option<CGPoint> {
+        self.current_point
+    }
+
+    /// Appends an element as is. Lines, curves and closes without a current point are ignored.
+    pub fn push(&mut self, element: PathElement) {
+        match element {
+            PathElement::MoveToPoint(point) => {
+                self.subpath_start = Some(point);
+                self.current_point = Some(point);
+            }
+            PathElement::CloseSubpath => {
+                if self.current_point.is_none() {
+                    return;
+                }
+                self.current_point = self.subpath_start;
+            }
+            _ => {
+                if self.current_point.is_none() {
+                    return;
+                }
+                self.current_point = element.end_point();
+            }
+        }
+        self.elements.push(element);
+    }
+
+    /// Appends the elements of `other`, transformed by `transform` if given.
+    pub fn add_path(&mut self, transform: Option<&CGAffineTransform>, other: &PathData) {
+        for element in &other.elements {
+            self.push(transformed(transform, *element));
+        }
+    }
+
+    pub fn apply_transform(&self, t: &CGAffineTransform) -> PathData {
+        self.elements
+            .iter()
+            .map(|element| element.apply_transform(t))
+            .collect()
+    }
+}
+
+impl FromIterator<PathElement> for PathData {
+    fn from_iter<I: IntoIterator<Item = PathElement>>(iter: I) -> PathData {
+        let mut data = PathData::new();
+        data.extend(iter);
+        data
+    }
+}
+
+impl Extend<PathElement> for PathData {
+    fn extend<I: IntoIterator<Item = PathElement>>(&mut self, iter: I) {
+        for element in iter {
+            self.push(element);
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a PathData {
+    type Item = &'a PathElement;
+    type IntoIter = slice::Iter<'a, PathElement>;
+
+    fn into_iter(self) -> slice::Iter<'a, PathElement> {
+        self.elements.iter()
+    }
+}
+
+fn transformed(transform: Option<&CGAffineTransform>, element: PathElement) -> PathElement {
+    match transform {
+        Some(transform) => element.apply_transform(transform),
+        None => element,
+    }
+}
+
+impl PathData {
+    /// Adds a cubic approximation of the elliptical arc around `center` with radii `radii` from
+    /// `start` to `start + sweep`, in segments of at most a quarter turn. Assumes the current
+    /// point is already at the start of the arc.
+    fn add_elliptical_arc(
+        &mut self,
+        transform: Option<&CGAffineTransform>,
+        center: CGPoint,
+        radii: CGSize,
+        start: CGFloat,
+        sweep: CGFloat,
+    ) {
+        let segments = (sweep.abs() / FRAC_PI_2).ceil().max(1.0) as usize;
+        let step = sweep / segments as CGFloat;
+        let k = 4.0 / 3.0 * (step / 4.0).tan();
+        let point_at = |angle: CGFloat| {
+            CGPoint::new(
+                center.x + radii.width * angle.cos(),
+                center.y + radii.height * angle.sin(),
+            )
+        };
+        let tangent_at = |angle: CGFloat| {
+            CGPoint::new(
+                -radii.width * angle.sin() * k,
+                radii.height * angle.cos() * k,
+            )
+        };
+        for i in 0..segments {
+            let a0 = start + step * i as CGFloat;
+            let a1 = if i + 1 == segments {
+                start + sweep
+            } else {
+                a0 + step
+            };
+            // Full turns end exactly where they started, so they close without a gap.
+            let p1 = if i + 1 == segments && sweep.abs() >= 2.0 * PI as CGFloat {
+                point_at(start)
+            } else {
+                point_at(a1)
+            };
+            let p0 = point_at(a0);
+            let (t0, t1) = (tangent_at(a0), tangent_at(a1));
+            self.add_curve_to_point(
+                transform,
+                CGPoint::new(p0.x + t0.x, p0.y + t0.y),
+                CGPoint::new(p1.x - t1.x, p1.y - t1.y),
+                p1,
+            );
+        }
+    }
+}
+
+impl PathBuilder for PathData {
+    fn move_to_point(&mut self, transform: Option<&CGAffineTransform>, point: CGPoint) {
+        self.push(transformed(transform, PathElement::MoveToPoint(point)));
+    }
+
+    fn add_line_to_point(&mut self, transform: Option<&CGAffineTransform>, point: CGPoint) {
+        self.push(transformed(transform, PathElement::AddLineToPoint(point)));
+    }
+
+    fn add_quad_curve_to_point(
+        &mut self,
+        transform: Option<&CGAffineTransform>,
+        control: CGPoint,
+        point: CGPoint,
+    ) {
+        self.push(transformed(
+            transform,
+            PathElement::AddQuadCurveToPoint(control, point),
+        ));
+    }
+
+    fn add_curve_to_point(
+        &mut self,
+        transform: Option<&CGAffineTransform>,
+        control1: CGPoint,
+        control2: CGPoint,
+        point: CGPoint,
+    ) {
+        self.push(transformed(
+            transform,
+            PathElement::AddCurveToPoint(control1, control2, point),
+        ));
+    }
+
+    fn close_subpath(&mut self) {
+        self.push(PathElement::CloseSubpath);
+    }
+
+    fn add_arc(
+        &mut self,
+        transform: Option<&CGAffineTransform>,
+        center: CGPoint,
+        radius: CGFloat,
+        start_angle: CGFloat,
+        end_angle: CGFloat,
+        clockwise: bool,
+    ) {
+        let turn = 2.0 * PI as CGFloat;
+        let delta = end_angle - start_angle;
+        let sweep = if clockwise {
+            if delta <= -turn {
+                -turn
+            } else {
+                -(-delta).rem_euclid(turn)
+            }
+        } else if delta >= turn {
+            turn
+        } else {
+            delta.rem_euclid(turn)
+        };
+
+        let start = CGPoint::new(
+            center.x + radius * start_angle.cos(),
+            center.y + radius * start_angle.sin(),
+        );
+        if self.current_point.is_some() {
+            self.add_line_to_point(transform, start);
+        } else {
+            self.move_to_point(transform, start);
+        }
+        if sweep != 0.0 {
+            self.add_elliptical_arc(
+                transform,
+                center,
+                CGSize::new(radius, radius),
+                start_angle,
+                sweep,
+            );
+        }
+    }
+
+    fn add_rect(&mut self, transform: Option<&CGAffineTransform>, rect: CGRect) {
+        let (min_x, min_y, max_x, max_y) = (rect.min_x(), rect.min_y(), rect.max_x(), rect.max_y());
+        self.move_to_point(transform, CGPoint::new(min_x, min_y));
+        self.add_line_to_point(transform, CGPoint::new(max_x, min_y));
+        self.add_line_to_point(transform, CGPoint::new(max_x, max_y));
+        self.add_line_to_point(transform, CGPoint::new(min_x, max_y));
+        self.close_subpath();
+    }
+
+    fn add_ellipse_in_rect(&mut self, transform: Option<&CGAffineTransform>, rect: CGRect) {
+        let center = CGPoint::new(rect.mid_x(), rect.mid_y());
+        let radii = CGSize::new(rect.width() / 2.0, rect.height() / 2.0);
+        self.move_to_point(transform, CGPoint::new(rect.max_x(), center.y));
+        self.add_elliptical_arc(transform, center, radii, 0.0, 2.0 * PI as CGFloat);
+        self.close_subpath();
+    }
+
+    fn add_rounded_rect(
+        &mut self,
+        transform: Option<&CGAffineTransform>,
+        rect: CGRect,
+        corner_width: CGFloat,
+        corner_height: CGFloat,
+    ) {
+        let rx = corner_width.max(0.0).min(rect.width() / 2.0);
+        let ry = corner_height.max(0.0).min(rect.height() / 2.0);
+        if rx == 0.0 || ry == 0.0 {
+            return self.add_rect(transform, rect);
+        }
+        let (min_x, min_y, max_x, max_y) = (rect.min_x(), rect.min_y(), rect.max_x(), rect.max_y());
+        let radii = CGSize::new(rx, ry);
+        let quarter = FRAC_PI_2 as CGFloat;
+
+        self.move_to_point(transform, CGPoint::new(min_x + rx, min_y));
+        self.add_line_to_point(transform, CGPoint::new(max_x - rx, min_y));
+        self.add_elliptical_arc(
+            transform,
+            CGPoint::new(max_x - rx, min_y + ry),
+            radii,
+            -quarter,
+            quarter,
+        );
+        self.add_line_to_point(transform, CGPoint::new(max_x, max_y - ry));
+        self.add_elliptical_arc(
+            transform,
+            CGPoint::new(max_x - rx, max_y - ry),
+            radii,
+            0.0,
+            quarter,
+        );
+        self.add_line_to_point(transform, CGPoint::new(min_x + rx, max_y));
+        self.add_elliptical_arc(
+            transform,
+            CGPoint::new(min_x + rx, max_y - ry),
+            radii,
+            quarter,
+            quarter,
+        );
+        self.add_line_to_point(transform, CGPoint::new(min_x, min_y + ry));
+        self.add_elliptical_arc(
+            transform,
+            CGPoint::new(min_x + rx, min_y + ry),
+            radii,
+            2.0 * quarter,
+            quarter,
+        );
+        self.close_subpath();
+    }
+}
+
+/// Rules for deciding which points are inside a path.
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CGPathFillRule {
+    /// A point is inside if the path winds around it a non-zero number of times.
+    Winding = 0,
+    /// A point is inside if a ray from it crosses the path an odd number of times.
+    EvenOdd = 1,
+}
+
+/// The flattening tolerance used by [`PathData::contains_point`], as a fraction of the larger
+/// side of the path's bounding box.
+///
+/// [`PathData::contains_point`]: struct.PathData.html#method.contains_point
+const HIT_TEST_TOLERANCE: CGFloat = 1e-4;
+
+/// The most lines a single curve is flattened into, which bounds the work for curves that are
+/// huge compared to the tolerance.
+const MAX_FLATTENING_SEGMENTS: usize = 1 << 12;
+
+/// A subpath split out of a [`PathData`]: its start point, the segments after it, and whether it
+/// was closed.
+#[derive(Debug)]
+struct Subpath {
+    start: CGPoint,
+    segments: Vec<PathElement>,
+    closed: bool,
+}
+
+impl Subpath {
+    fn end(&self) -> CGPoint {
+        self.segments
+            .last()
+            .and_then(PathElement::end_point)
+            .unwrap_or(self.start)
+    }
+
+    /// Returns the line that closes the subpath, if its ends are apart.
+    fn closing_line(&self) -> Option<(CGPoint, CGPoint)> {
+        let end = self.end();
+        if end != self.start {
+            Some((end, self.start))
+        } else {
+            None
+        }
+    }
+}
+
+fn lerp(a: CGPoint, b: CGPoint, t: CGFloat) -> CGPoint {
+    CGPoint::new(a.x + (b.x - a.x) * t, a.y + (b.y - a.y) * t)
+}
+
+fn distance(a: CGPoint, b: CGPoint) -> CGFloat {
+    (b.x - a.x).hypot(b.y - a.y)
+}
+
+fn quad_point(p0: CGPoint, p1: CGPoint, p2: CGPoint, t: CGFloat) -> CGPoint {
+    lerp(lerp(p0, p1, t), lerp(p1, p2, t), t)
+}
+
+fn cubic_point(p0: CGPoint, p1: CGPoint, p2: CGPoint, p3: CGPoint, t: CGFloat) -> CGPoint {
+    quad_point(lerp(p0, p1, t), lerp(p1, p2, t), lerp(p2, p3, t), t)
+}
+
+/// Returns the parameters in (0, 1) where `a t² + b t + c` is zero.
+fn unit_roots(a: CGFloat, b: CGFloat, c: CGFloat) -> impl Iterator<Item = CGFloat> {
+    let mut roots = [None, None];
+    if a.abs() < 1e-12 {
+        if b != 0.0 {
+            roots[0] = Some(-c / b);
+        }
+    } else {
+        let discriminant = b * b - 4.0 * a * c;
+        if discriminant >= 0.0 {
+            let sqrt = discriminant.sqrt();
+            roots[0] = Some((-b + sqrt) / (2.0 * a));
+            roots[1] = Some((-b - sqrt) / (2.0 * a));
+        }
+    }
+    roots.into_iter().flatten().filter(|t| *t > 0.0 && *t < 1.0)
+}
+
+/// Returns the parameters in (0, 1) where the quadratic curve has an extremum along an axis.
+fn quad_extrema(p0: CGFloat, p1: CGFloat, p2: CGFloat) -> impl Iterator<Item = CGFloat> {
+    unit_roots(0.0, p0 - 2.0 * p1 + p2, p1 - p0)
+}
+
+/// Returns the parameters in (0, 1) where the cubic curve has an extremum along an axis.
+fn cubic_extrema(
+    p0: CGFloat,
+    p1: CGFloat,
+    p2: CGFloat,
+    p3: CGFloat,
+) -> impl Iterator<Item = CGFloat> {
+    let (a, b, c) = (p1 - p0, p2 - p1, p3 - p2);
+    unit_roots(a - 2.0 * b + c, 2.0 * (b - a), a)
+}
+
+/// Returns how many lines a curve needs so that none is further than `tolerance` from it, given
+/// the largest second difference of its control points and the bound factor for its degree.
+///
+/// The count is capped at [`MAX_FLATTENING_SEGMENTS`], and curves with non-finite control points
+/// are drawn as a single line.
+fn flattening_segments(second_difference: CGFloat, factor: CGFloat, tolerance: CGFloat) -> usize {
+    let segments = (factor * second_difference / tolerance).sqrt().ceil();
+    if !segments.is_finite() {
+        return 1;
+    }
+    (segments as usize).clamp(1, MAX_FLATTENING_SEGMENTS)
+}
+
+fn second_difference(a: CGPoint, b: CGPoint, c: CGPoint) -> CGFloat {
+    (a.x - 2.0 * b.x + c.x).hypot(a.y - 2.0 * b.y + c.y)
+}
+
+/// Calls `line_to` with the points of lines approximating the segment from `from`.
+fn flatten_segment<F>(from: CGPoint, segment: &PathElement, tolerance: CGFloat, mut line_to: F)
+where
+    F: FnMut(CGPoint),
+{
+    match *segment {
+        PathElement::AddLineToPoint(point) => line_to(point),
+        PathElement::AddQuadCurveToPoint(control, point) => {
+            let n = flattening_segments(second_difference(from, control, point), 0.25, tolerance);
+            for i in 1..n {
+                line_to(quad_point(
+                    from,
+                    control,
+                    point,
+                    i as CGFloat / n as CGFloat,
+                ));
+            }
+            line_to(point);
+        }
+        PathElement::AddCurveToPoint(control1, control2, point) => {
+            let difference = second_difference(from, control1, control2)
+                .max(second_difference(control1, control2, point));
+            let n = flattening_segments(difference, 0.75, tolerance);
+            for i in 1..n {
+                let t = i as CGFloat / n as CGFloat;
+                line_to(cubic_point(from, control1, control2, point, t));
+            }
+            line_to(point);
+        }
+        PathElement::MoveToPoint(_) | PathElement::CloseSubpath => {}
+    }
+}
+
+fn reverse_segment(from: CGPoint, segment: &PathElement) -> PathElement {
+    match *segment {
+        PathElement::AddQuadCurveToPoint(control, _) => {
+            PathElement::AddQuadCurveToPoint(control, from)
+        }
+        PathElement::AddCurveToPoint(control1, control2, _) => {
+            PathElement::AddCurveToPoint(control2, control1, from)
+        }
+        _ => PathElement::AddLineToPoint(from),
+    }
+}
+
+fn bounds_of<I: IntoIterator<Item = CGPoint>>(points: I) -> CGRect {
+    let mut points = points.into_iter();
+    let first = match points.next() {
+        Some(point) => point,
+        None => return CGRect::null(),
+    };
+    let (mut min, mut max) = (first, first);
+    for point in points {
+        min = CGPoint::new(min.x.min(point.x), min.y.min(point.y));
+        max = CGPoint::new(max.x.max(point.x), max.y.max(point.y));
+    }
+    CGRect::new(&min, &CGSize::new(max.x - min.x, max.y - min.y))
+}
+
+// Geometry queries. These all work on the recorded elements, so they behave the same on every
+// platform.
+impl PathData {
+    fn subpaths(&self) -> Vec<Subpath> {
+        let mut subpaths = Vec::new();
+        let mut current: Option<Subpath> = None;
+        // Whether `current` was started by a close rather than a move, in which case it is
+        // dropped if nothing is added to it.
+        let mut implicit = false;
+        for element in &self.elements {
+            let finished = match *element {
+                PathElement::MoveToPoint(point) => {
+                    let finished = current.replace(Subpath {
+                        start: point,
+                        segments: Vec::new(),
+                        closed: false,
+                    });
+                    let was_implicit = implicit;
+                    implicit = false;
+                    finished.filter(|subpath| !was_implicit || !subpath.segments.is_empty())
+                }
+                PathElement::CloseSubpath => current.take().map(|mut subpath| {
+                    // A segment after a close starts a new subpath at the old start point.
+                    current = Some(Subpath {
+                        start: subpath.start,
+                        segments: Vec::new(),
+                        closed: false,
+                    });
+                    implicit = true;
+                    subpath.closed = true;
+                    subpath
+                }),
+                segment => {
+                    if let Some(subpath) = current.as_mut() {
+                        subpath.segments.push(segment);
+                    }
+                    None
+                }
+            };
+            subpaths.extend(finished);
+        }
+        subpaths.extend(current.filter(|subpath| !implicit || !subpath.segments.is_empty()));
+        subpaths
+    }
+
+    /// Returns the smallest rectangle containing every point of the path, including control
+    /// points, or the null rectangle if the path is empty.
+    ///
+    /// This is the same as `CGPathGetBoundingBox`.
+    pub fn bounding_box(&self) -> CGRect {
+        bounds_of(self.elements.iter().flat_map(|element| {
+            let points = match *element {
+                PathElement::MoveToPoint(p) | PathElement::AddLineToPoint(p) => {
+                    [Some(p), None, None]
+                }
+                PathElement::AddQuadCurveToPoint(c, p) => [Some(c), Some(p), None],
+                PathElement::AddCurveToPoint(c1, c2, p) => [Some(c1), Some(c2), Some(p)],
+                PathElement::CloseSubpath => [None, None, None],
+            };
+            points.into_iter().flatten()
+        }))
+    }
+
+    /// Returns the smallest rectangle containing the path as drawn, without control points that
+    /// lie off its curves, or the null rectangle if the path is empty.
+    ///
+    /// This is the same as `CGPathGetPathBoundingBox`.
+    pub fn path_bounding_box(&self) -> CGRect {
+        let mut points = Vec::new();
+        for subpath in self.subpaths() {
+            let mut from = subpath.start;
+            points.push(from);
+            for segment in &subpath.segments {
+                match *segment {
+                    PathElement::AddQuadCurveToPoint(c, p) => {
+                        let ts =
+                            quad_extrema(from.x, c.x, p.x).chain(quad_extrema(from.y, c.y, p.y));
+                        points.extend(ts.map(|t| quad_point(from, c, p, t)));
+                    }
+                    PathElement::AddCurveToPoint(c1, c2, p) => {
+                        let ts = cubic_extrema(from.x, c1.x, c2.x, p.x)
+                            .chain(cubic_extrema(from.y, c1.y, c2.y, p.y));
+                        points.extend(ts.map(|t| cubic_point(from, c1, c2, p, t)));
+                    }
+                    _ => {}
+                }
+                from = segment.end_point().unwrap_or(from);
+                points.push(from);
+            }
+        }
+        bounds_of(points)
+    }
+
+    /// Returns a copy of the path with every curve replaced by lines that stay within
+    /// `tolerance` of it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tolerance` is not positive and finite.
+    pub fn flatten(&self, tolerance: CGFloat) -> PathData {
+        assert!(
+            tolerance > 0.0 && tolerance.is_finite(),
+            "flattening tolerance must be positive and finite"
+        );
+        let mut flattened = PathData::new();
+        for subpath in self.subpaths() {
+            flattened.push(PathElement::MoveToPoint(subpath.start));
+            let mut from = subpath.start;
+            for segment in &subpath.segments {
+                flatten_segment(from, segment, tolerance, |point| {
+                    flattened.push(PathElement::AddLineToPoint(point))
+                });
+                from = segment.end_point().unwrap_or(from);
+            }
+            if subpath.closed {
+                flattened.push(PathElement::CloseSubpath);
+            }
+        }
+        flattened
+    }
+
+    /// Returns whether `point` is inside the area the path would fill with `rule`.
+    ///
+    /// Open subpaths are treated as closed, as when filling. Curves are flattened first, to
+    /// within a ten-thousandth of the larger side of the path's bounding box.
+    pub fn contains_point(&self, point: CGPoint, rule: CGPathFillRule) -> bool {
+        let bounds = self.bounding_box();
+        let tolerance = bounds.size.width.max(bounds.size.height) * HIT_TEST_TOLERANCE;
+        let mut winding = 0i32;
+        let mut edge = |a: CGPoint, b: CGPoint| {
+            let side = (b.x - a.x) * (point.y - a.y) - (point.x - a.x) * (b.y - a.y);
+            if a.y <= point.y && point.y < b.y && side > 0.0 {
+                winding += 1;
+            } else if b.y <= point.y && point.y < a.y && side < 0.0 {
+                winding -= 1;
+            }
+        };
+        for subpath in self.subpaths() {
+            let mut from = subpath.start;
+            for segment in &subpath.segments {
+                flatten_segment(from, segment, tolerance, |to| {
+                    edge(from, to);
+                    from = to;
+                });
+            }
+            edge(from, subpath.start);
+        }
+        match rule {
+            CGPathFillRule::Winding => winding != 0,
+            CGPathFillRule::EvenOdd => winding % 2 != 0,
+        }
+    }
+
+    /// Returns the length of the path, including the lines that close subpaths, measuring curves
+    /// along lines within `tolerance` of them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tolerance` is not positive and finite.
+    pub fn length(&self, tolerance: CGFloat) -> CGFloat {
+        assert!(
+            tolerance > 0.0 && tolerance.is_finite(),
+            "flattening tolerance must be positive and finite"
+        );
+        let mut length = 0.0;
+        for subpath in self.subpaths() {
+            let mut from = subpath.start;
+            for segment in &subpath.segments {
+                flatten_segment(from, segment, tolerance, |to| {
+                    length += distance(from, to);
+                    from = to;
+                });
+            }
+            if subpath.closed {
+                length += distance(from, subpath.start);
+            }
+        }
+        length
+    }
+
+    /// Returns the path with the direction of every subpath reversed.
+    ///
+    /// Open subpaths start again where they ended. Closed subpaths keep their start point and stay
+    /// closed.
+    pub fn reversed(&self) -> PathData {
+        let mut reversed = PathData::new();
+        for subpath in self.subpaths() {
+            let mut start = subpath.end();
+            let mut segments = Vec::with_capacity(subpath.segments.len() + 1);
+            if subpath.closed {
+                if let Some((_, to)) = subpath.closing_line() {
+                    // Keep the original start, so the old closing line becomes the first line.
+                    segments.push(PathElement::AddLineToPoint(start));
+                    start = to;
+                }
+            }
+            let mut from = subpath.start;
+            let mut backwards = Vec::with_capacity(subpath.segments.len());
+            for segment in &subpath.segments {
+                backwards.push(reverse_segment(from, segment));
+                from = segment.end_point().unwrap_or(from);
+            }
+            segments.extend(backwards.into_iter().rev());
+            if start != subpath.end() {
+                // The last line leads back to the start; closing the subpath draws it.
+                if let Some(PathElement::AddLineToPoint(_)) = segments.last() {
+                    segments.pop();
+                }
+            }
+
+            reversed.push(PathElement::MoveToPoint(start));
+            for segment in segments {
+                reversed.push(segment);
+            }
+            if subpath.closed {
+                reversed.push(PathElement::CloseSubpath);
+            }
+        }
+        reversed
+    }
+}
+
 type CGPathApplierFunction = unsafe extern "C" fn(info: *mut c_void, element: *const CGPathElement);
 
 #[cfg_attr(feature = "link", link(name = "CoreGraphics", kind = "framework"))]
@@ -133,4 +1160,91 @@ extern "C" {
     ) -> crate::sys::CGPathRef;
     fn CGPathApply(path: crate::sys::CGPathRef, info: *mut c_void, function: CGPathApplierFunction);
     fn CGPathGetTypeID() -> CFTypeID;
+    fn CGPathCreateCopy(path: crate::sys::CGPathRef) -> crate::sys::CGPathRef;
+    fn CGPathCreateMutable() -> crate::sys::CGPathRef;
+    fn CGPathMoveToPoint(
+        path: crate::sys::CGPathRef,
+        m: *const CGAffineTransform,
+        x: CGFloat,
+        y: CGFloat,
+    );
+    fn CGPathAddLineToPoint(
+        path: crate::sys::CGPathRef,
+        m: *const CGAffineTransform,
+        x: CGFloat,
+        y: CGFloat,
+    );
+    fn CGPathAddQuadCurveToPoint(
+        path: crate::sys::CGPathRef,
+        m: *const CGAffineTransform,
+        cpx: CGFloat,
+        cpy: CGFloat,
+        x: CGFloat,
+        y: CGFloat,
+    );
+    fn CGPathAddCurveToPoint(
+        path: crate::sys::CGPathRef,
+        m: *const CGAffineTransform,
+        cp1x: CGFloat,
+        cp1y: CGFloat,
+        cp2x: CGFloat,
+        cp2y: CGFloat,
+        x: CGFloat,
+        y: CGFloat,
+    );
+    fn CGPathCloseSubpath(path: crate::sys::CGPathRef);
+    fn CGPathAddArc(
+        path: crate::sys::CGPathRef,
+        m: *const CGAffineTransform,
+        x: CGFloat,
+        y: CGFloat,
+        radius: CGFloat,
+        start_angle: CGFloat,
+        end_angle: CGFloat,
+        clockwise: bool,
+    );
+    fn CGPathAddRect(path: crate::sys::CGPathRef, m: *const CGAffineTransform, rect: CGRect);
+    fn CGPathAddEllipseInRect(
+        path: crate::sys::CGPathRef,
+        m: *const CGAffineTransform,
+        rect: CGRect,
+    );
+    fn CGPathAddRoundedRect(
+        path: crate::sys::CGPathRef,
+        m: *const CGAffineTransform,
+        rect: CGRect,
+        corner_width: CGFloat,
+        corner_height: CGFloat,
+    );
+    fn CGPathAddPath(
+        path1: crate::sys::CGPathRef,
+        m: *const CGAffineTransform,
+        path2: crate::sys::CGPathRef,
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_path_data_round_trip() {
+        let mut data = PathData::new();
+        data.add_rect(
+            None,
+            CGRect::new(&CGPoint::new(1., 2.), &CGSize::new(3., 4.)),
+        );
+        data.move_to_point(None, CGPoint::new(0., 0.));
+        data.add_quad_curve_to_point(None, CGPoint::new(1., 1.), CGPoint::new(2., 0.));
+        let path = CGPath::from_path_data(&data);
+        assert_eq!(path.to_path_data(), data);
+
+        let mut mutable = CGMutablePath::new();
+        mutable.add_rect(
+            None,
+            CGRect::new(&CGPoint::new(1., 2.), &CGSize::new(3., 4.)),
+        );
+        mutable.add_path(None, &path);
+        assert_eq!(mutable.copy().to_path_data().elements().len(), 12);
+    }
 }
@@ -0,0 +1,123 @@
+use core_graphics::event::{CGEventKind, CGEventMask, CGEventType};
+
+#[test]
+fn groups_match_kinds() {
+    assert_eq!(
+        CGEventMask::from_kind(CGEventKind::Key),
+        CGEventMask::KEYBOARD
+    );
+    assert_eq!(
+        CGEventMask::from_kind(CGEventKind::Mouse),
+        CGEventMask::MOUSE
+    );
+    assert_eq!(
+        CGEventMask::from_kind(CGEventKind::Gesture),
+        CGEventMask::GESTURE
+    );
+    assert_eq!(
+        CGEventMask::from_kind(CGEventKind::Scroll),
+        CGEventMask::SCROLL
+    );
+    assert_eq!(
+        CGEventMask::from_kind(CGEventKind::Tablet),
+        CGEventMask::TABLET
+    );
+    assert_eq!(
+        CGEventMask::from(CGEventKind::TapDisabled),
+        CGEventMask::NONE
+    );
+    assert!(CGEventMask::MOUSE.contains_all(CGEventMask::MOUSE_BUTTONS | CGEventMask::MOUSE_MOTION));
+    assert!(!CGEventMask::MOUSE_BUTTONS.intersects(CGEventMask::MOUSE_MOTION));
+    for &event_type in CGEventType::ALL {
+        if event_type.kind() == CGEventKind::TapDisabled {
+            continue;
+        }
+        assert!(CGEventMask::ALL.contains(event_type));
+        assert_eq!(
+            CGEventMask::MOUSE.contains(event_type),
+            event_type.kind() == CGEventKind::Mouse
+        );
+    }
+}
+
+#[test]
+fn bits() {
+    assert_eq!(CGEventMask::KEYBOARD.bits(), 0x1c00);
+    assert_eq!(
+        CGEventMask::from(vec![CGEventType::LeftMouseDown, CGEventType::ScrollWheel]).bits(),
+        (1 << 1) | (1 << 22)
+    );
+    assert_eq!(CGEventMask::ALL.bits(), u64::MAX);
+    assert_eq!(u64::from(CGEventMask::from_bits(0x42)), 0x42);
+    assert_eq!(CGEventMask::from(CGEventType::from_raw(63)).bits(), 1 << 63);
+}
+
+#[test]
+fn out_of_range_types() {
+    let mut mask = CGEventMask::NONE;
+    assert!(!mask.insert(CGEventType::TapDisabledByTimeout));
+    assert!(!mask.insert(CGEventType::from_raw(64)));
+    assert!(mask.is_empty());
+    assert!(!CGEventMask::ALL.contains(CGEventType::TapDisabledByUserInput));
+    assert_eq!(
+        CGEventMask::from_type(CGEventType::TapDisabledByTimeout),
+        None
+    );
+    assert_eq!(
+        CGEventMask::from_type(CGEventType::KeyUp),
+        Some(CGEventMask::from_bits(1 << 11))
+    );
+    let mask: CGEventMask = [CGEventType::KeyDown, CGEventType::TapDisabledByUserInput]
+        .iter()
+        .collect();
+    assert_eq!(mask, CGEventMask::from(CGEventType::KeyDown));
+}
+
+#[test]
+fn set_operations() {
+    let mut mask = CGEventMask::KEYBOARD | CGEventType::ScrollWheel;
+    assert!(mask.contains(CGEventType::KeyUp));
+    mask.remove(CGEventType::KeyUp);
+    assert!(!mask.contains(CGEventType::KeyUp));
+    mask -= CGEventMask::KEYBOARD;
+    assert_eq!(mask, CGEventMask::SCROLL);
+    mask |= CGEventType::MouseMoved;
+    mask &= CGEventMask::MOUSE_MOTION;
+    assert_eq!(mask, CGEventMask::from(CGEventType::MouseMoved));
+    mask ^= CGEventMask::MOUSE_MOTION;
+    assert_eq!(
+        mask.iter().collect::<Vec<_>>(),
+        [
+            CGEventType::LeftMouseDragged,
+            CGEventType::RightMouseDragged,
+            CGEventType::OtherMouseDragged
+        ]
+    );
+    assert_eq!(!CGEventMask::NONE, CGEventMask::ALL);
+    assert_eq!(
+        CGEventMask::ALL.difference(CGEventMask::ALL),
+        CGEventMask::NONE
+    );
+
+    let mut extended = CGEventMask::default();
+    extended.extend([
+        CGEventType::KeyDown,
+        CGEventType::KeyUp,
+        CGEventType::FlagsChanged,
+    ]);
+    assert_eq!(extended, CGEventMask::KEYBOARD);
+}
+
+#[test]
+fn debug() {
+    assert_eq!(
+        format!("{:?}", CGEventMask::KEYBOARD),
+        "CGEventMask(KeyDown | KeyUp | FlagsChanged)"
+    );
+    assert_eq!(
+        format!("{:?}", CGEventMask::from_bits(1 << 40 | 1)),
+        "CGEventMask(Null | CGEventType(0x28))"
+    );
+    assert_eq!(format!("{:?}", CGEventMask::ALL), "CGEventMask(ALL)");
+    assert_eq!(format!("{:?}", CGEventMask::NONE), "CGEventMask(NONE)");
+}
@@ -0,0 +1,251 @@
+use core_foundation::propertylist::Value;
+use core_graphics::event::snapshot::{RECORDING_FORMAT, RECORDING_VERSION};
+use core_graphics::event::{
+    CGEventFlags, CGEventMask, CGEventRecording, CGEventSnapshot, CGEventType, EventField,
+    RecordingError,
+};
+use core_graphics::geometry::CGPoint;
+use std::time::Duration;
+
+fn key_down(timestamp: u64) -> CGEventSnapshot {
+    let mut snapshot = CGEventSnapshot::new(CGEventType::KeyDown, CGPoint::new(0., 0.));
+    snapshot.flags = CGEventFlags::CGEventFlagShift | CGEventFlags::from_bits_retain(0x102);
+    snapshot.timestamp = timestamp;
+    snapshot.key_code = Some(0);
+    snapshot.string = "A".to_owned();
+    snapshot
+        .integer_fields
+        .insert(EventField::KEYBOARD_EVENT_AUTOREPEAT, 1);
+    snapshot
+}
+
+fn mouse(event_type: CGEventType, x: f64, timestamp: u64) -> CGEventSnapshot {
+    let mut snapshot = CGEventSnapshot::new(event_type, CGPoint::new(x, 20.5));
+    snapshot.timestamp = timestamp;
+    snapshot
+        .integer_fields
+        .insert(EventField::MOUSE_EVENT_CLICK_STATE, 1);
+    snapshot
+        .double_fields
+        .insert(EventField::MOUSE_EVENT_PRESSURE, 0.75);
+    snapshot
+}
+
+fn recording() -> CGEventRecording {
+    [
+        mouse(CGEventType::MouseMoved, 1., 1_000),
+        mouse(CGEventType::MouseMoved, 2., 3_000),
+        mouse(CGEventType::LeftMouseDown, 2., 4_000),
+        mouse(CGEventType::LeftMouseUp, 2., 9_000),
+        key_down(10_000),
+    ]
+    .into_iter()
+    .collect()
+}
+
+#[test]
+fn xml_round_trip() {
+    let mut recording = recording();
+    recording.push(CGEventSnapshot::new(
+        CGEventType::from_raw(0x7f),
+        CGPoint::new(-1.5, 1e9),
+    ));
+    let xml = recording.to_xml();
+    assert!(xml.contains("<string>core-graphics.event-recording</string>"));
+    assert_eq!(CGEventRecording::from_xml(&xml), Ok(recording));
+}
+
+#[test]
+fn stable_format() {
+    let value = CGEventRecording::from_iter([key_down(1_000)]).to_plist();
+    let dict = value.as_dictionary().unwrap();
+    assert_eq!(
+        dict.keys().collect::<Vec<_>>(),
+        ["Format", "Version", "Events"]
+    );
+    assert_eq!(dict.get("Version"), Some(&Value::Integer(1)));
+    let event = dict.get("Events").unwrap().as_array().unwrap()[0]
+        .as_dictionary()
+        .unwrap()
+        .clone();
+    assert_eq!(
+        event.keys().collect::<Vec<_>>(),
+        [
+            "Type",
+            "X",
+            "Y",
+            "Flags",
+            "Timestamp",
+            "KeyCode",
+            "String",
+            "IntegerFields",
+            "DoubleFields"
+        ]
+    );
+    assert_eq!(event.get("Type"), Some(&Value::Integer(10)));
+    assert_eq!(event.get("Flags"), Some(&Value::Integer(0x20102)));
+    let fields = event.get("IntegerFields").unwrap().as_dictionary().unwrap();
+    assert_eq!(fields.get("8"), Some(&Value::Integer(1)));
+
+    // The example in the module documentation.
+    let mut snapshot = CGEventSnapshot::new(CGEventType::KeyDown, CGPoint::new(0., 0.));
+    snapshot.flags = CGEventFlags::CGEventFlagShift;
+    snapshot.timestamp = 1_000;
+    snapshot.key_code = Some(0);
+    snapshot.string = "A".to_owned();
+    snapshot
+        .integer_fields
+        .insert(EventField::KEYBOARD_EVENT_AUTOREPEAT, 1);
+    let documented = include_str!("../src/event/snapshot.rs")
+        .lines()
+        .skip_while(|line| *line != "//! ```xml")
+        .skip(1)
+        .take_while(|line| *line != "//! ```")
+        .map(|line| line.trim_start_matches("//! ").replace("    ", "\t") + "\n")
+        .collect::<String>();
+    assert_eq!(CGEventRecording::from_iter([snapshot]).to_xml(), documented);
+
+    // Written by hand, with an extra key from a later minor revision.
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<plist version="1.0">
+<dict>
+    <key>Format</key><string>core-graphics.event-recording</string>
+    <key>Version</key><integer>1</integer>
+    <key>Events</key>
+    <array>
+        <dict>
+            <key>Type</key><integer>1</integer>
+            <key>X</key><real>3</real>
+            <key>Y</key><integer>4</integer>
+            <key>Flags</key><integer>0</integer>
+            <key>Timestamp</key><integer>5</integer>
+            <key>Comment</key><string>ignored</string>
+        </dict>
+    </array>
+</dict>
+</plist>"#;
+    let mut expected = CGEventSnapshot::new(CGEventType::LeftMouseDown, CGPoint::new(3., 4.));
+    expected.timestamp = 5;
+    assert_eq!(CGEventRecording::from_xml(xml).unwrap().events, [expected]);
+}
+
+#[test]
+fn versioning() {
+    let with = |key: &str, value: Value| {
+        let mut dict = recording().to_plist().as_dictionary().unwrap().clone();
+        dict.insert(key, value);
+        CGEventRecording::from_plist(&Value::Dictionary(dict))
+    };
+    assert_eq!(
+        with("Version", Value::Integer(RECORDING_VERSION + 1)),
+        Err(RecordingError::UnsupportedVersion(2))
+    );
+    assert_eq!(
+        with("Version", Value::Integer(0)),
+        Err(RecordingError::UnsupportedVersion(0))
+    );
+    assert_eq!(
+        with("Format", Value::String("something-else".into())),
+        Err(RecordingError::NotARecording)
+    );
+    assert_eq!(
+        CGEventRecording::from_plist(&Value::Array(vec![])),
+        Err(RecordingError::NotARecording)
+    );
+    assert!(matches!(
+        CGEventRecording::from_xml("<dict>"),
+        Err(RecordingError::Xml(_))
+    ));
+}
+
+#[test]
+fn invalid_events() {
+    let base = "<key>Type</key><integer>1</integer><key>X</key><real>0</real>\
+                <key>Y</key><real>0</real><key>Timestamp</key><integer>0</integer>";
+    let check = |event: &str, key| {
+        let xml = format!(
+            "<dict><key>Format</key><string>{}</string><key>Version</key><integer>1</integer>\
+             <key>Events</key><array><dict>{}<key>Flags</key><integer>0</integer></dict>{}</array>\
+             </dict>",
+            RECORDING_FORMAT, base, event
+        );
+        assert_eq!(
+            CGEventRecording::from_xml(&xml),
+            Err(RecordingError::InvalidEvent { index: 1, key })
+        );
+    };
+    check(&format!("<dict>{}</dict>", base), "Flags");
+    check(
+        &format!("<dict>{}<key>Flags</key><integer>-1</integer></dict>", base),
+        "Flags",
+    );
+    check(
+        &format!(
+            "<dict>{}<key>Flags</key><integer>0</integer>\
+             <key>KeyCode</key><integer>65536</integer></dict>",
+            base
+        ),
+        "KeyCode",
+    );
+    check(
+        &format!(
+            "<dict>{}<key>Flags</key><integer>0</integer>\
+             <key>IntegerFields</key><dict><key>x</key><integer>1</integer></dict></dict>",
+            base
+        ),
+        "IntegerFields",
+    );
+    check("<string>event</string>", "event");
+}
+
+#[test]
+fn filtering() {
+    let mut recording = recording();
+    recording.strip_mouse_moves();
+    let types: Vec<_> = recording.events.iter().map(|e| e.event_type).collect();
+    assert_eq!(
+        types,
+        [
+            CGEventType::LeftMouseDown,
+            CGEventType::LeftMouseUp,
+            CGEventType::KeyDown
+        ]
+    );
+}
+
+#[test]
+fn timing() {
+    let mut recording = recording();
+    assert_eq!(
+        recording.delays(),
+        [0, 2_000, 1_000, 5_000, 1_000].map(Duration::from_nanos)
+    );
+    assert_eq!(recording.duration(), Duration::from_nanos(9_000));
+
+    recording.rescale_timing(0.5);
+    let timestamps: Vec<_> = recording.events.iter().map(|e| e.timestamp).collect();
+    assert_eq!(timestamps, [1_000, 2_000, 2_500, 5_000, 5_500]);
+
+    recording.events.swap(1, 2);
+    recording.events[1].timestamp = 500;
+    assert_eq!(
+        recording.delays(),
+        [0, 0, 1_000, 3_000, 500].map(Duration::from_nanos)
+    );
+
+    CGEventRecording::new().rescale_timing(2.);
+}
+
+#[test]
+#[should_panic]
+fn negative_timing() {
+    recording().rescale_timing(-1.);
+}
+
+#[test]
+fn filtering_by_mask() {
+    let mut recording = recording();
+    recording.retain_types(CGEventMask::KEYBOARD | CGEventType::LeftMouseUp);
+    let types: Vec<_> = recording.events.iter().map(|e| e.event_type).collect();
+    assert_eq!(types, [CGEventType::LeftMouseUp, CGEventType::KeyDown]);
+}
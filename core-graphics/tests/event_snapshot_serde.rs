@@ -0,0 +1,34 @@
+use core_foundation::propertylist::serde::{from_value, to_value};
+use core_foundation::propertylist::Value;
+use core_graphics::event::{
+    CGEventFlags, CGEventRecording, CGEventSnapshot, CGEventType, EventField,
+};
+use core_graphics::geometry::CGPoint;
+
+#[test]
+fn snapshot_round_trip() {
+    let mut snapshot = CGEventSnapshot::new(CGEventType::from_raw(0x7f), CGPoint::new(1.5, -2.));
+    snapshot.flags = CGEventFlags::CGEventFlagCommand | CGEventFlags::from_bits_retain(0x8);
+    snapshot.timestamp = u64::MAX;
+    snapshot.key_code = Some(0x35);
+    snapshot.string = "\u{1b}".to_owned();
+    snapshot
+        .integer_fields
+        .insert(EventField::MOUSE_EVENT_NUMBER, -3);
+    snapshot
+        .double_fields
+        .insert(EventField::TABLET_EVENT_TILT_X, 0.25);
+
+    let value = to_value(&snapshot).unwrap();
+    let dict = value.as_dictionary().unwrap();
+    assert_eq!(dict.get("event_type"), Some(&Value::Integer(0x7f)));
+    assert_eq!(
+        dict.get("location"),
+        Some(&Value::Array(vec![Value::Real(1.5), Value::Real(-2.)]))
+    );
+    assert_eq!(from_value::<CGEventSnapshot>(value).unwrap(), snapshot);
+
+    let recording: CGEventRecording = vec![snapshot.clone(), snapshot].into_iter().collect();
+    let value = to_value(&recording).unwrap();
+    assert_eq!(from_value::<CGEventRecording>(value).unwrap(), recording);
+}
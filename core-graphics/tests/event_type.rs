@@ -0,0 +1,68 @@
+use core_graphics::event::{CGEventKind, CGEventType, UnknownEventType};
+use std::collections::HashSet;
+
+#[test]
+fn known_types_round_trip() {
+    let mut seen = HashSet::new();
+    for &event_type in CGEventType::ALL {
+        let raw = u32::from(event_type);
+        assert!(seen.insert(raw), "duplicate value {:#x}", raw);
+        assert_eq!(CGEventType::from_raw(raw), event_type);
+        assert_eq!(CGEventType::try_from(raw), Ok(event_type));
+        assert!(event_type.is_known());
+        assert_ne!(event_type.kind(), CGEventKind::Unknown);
+        assert_eq!(format!("{:?}", event_type), event_type.name().unwrap());
+    }
+    assert!(CGEventType::ALL.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn unknown_types_are_preserved() {
+    let unknown = (0..=64)
+        .chain([0x7FFF_FFFF, 0xFFFF_FFFD])
+        .filter(|raw| !CGEventType::ALL.contains(&CGEventType::from_raw(*raw)));
+    let mut count = 0;
+    for raw in unknown {
+        let event_type = CGEventType::from_raw(raw);
+        assert_eq!(event_type.raw(), raw);
+        assert_eq!(event_type.name(), None);
+        assert_eq!(event_type.kind(), CGEventKind::Unknown);
+        assert_eq!(CGEventType::try_from(raw), Err(UnknownEventType(raw)));
+        assert_eq!(
+            format!("{:?}", event_type),
+            format!("CGEventType({:#x})", raw)
+        );
+        count += 1;
+    }
+    assert!(count > 20);
+    assert_eq!(UnknownEventType(21).to_string(), "unknown event type 0x15");
+}
+
+#[test]
+fn kinds() {
+    let cases = [
+        (CGEventType::Null, CGEventKind::Null),
+        (CGEventType::LeftMouseDown, CGEventKind::Mouse),
+        (CGEventType::OtherMouseDragged, CGEventKind::Mouse),
+        (CGEventType::MouseEntered, CGEventKind::Mouse),
+        (CGEventType::KeyDown, CGEventKind::Key),
+        (CGEventType::FlagsChanged, CGEventKind::Key),
+        (CGEventType::ScrollWheel, CGEventKind::Scroll),
+        (CGEventType::TabletPointer, CGEventKind::Tablet),
+        (CGEventType::TabletProximity, CGEventKind::Tablet),
+        (CGEventType::Magnify, CGEventKind::Gesture),
+        (CGEventType::Swipe, CGEventKind::Gesture),
+        (CGEventType::Pressure, CGEventKind::Gesture),
+        (CGEventType::SystemDefined, CGEventKind::System),
+        (CGEventType::TapDisabledByTimeout, CGEventKind::TapDisabled),
+        (
+            CGEventType::TapDisabledByUserInput,
+            CGEventKind::TapDisabled,
+        ),
+    ];
+    for (event_type, kind) in cases {
+        assert_eq!(event_type.kind(), kind, "{:?}", event_type);
+    }
+    assert_eq!(CGEventType::TapDisabledByUserInput.raw(), 0xFFFF_FFFF);
+    assert_eq!(CGEventType::ScrollWheel.raw(), 22);
+}
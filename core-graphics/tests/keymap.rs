@@ -0,0 +1,146 @@
+use core_graphics::event::keymap::{
+    code_from_key_code, key_code_from_code, key_code_from_usb_usage, key_mapping, us_ansi_char,
+    usb_usage_from_key_code, KEY_MAPPINGS,
+};
+use core_graphics::event::KeyCode;
+use std::collections::HashSet;
+
+#[test]
+fn table_is_consistent() {
+    assert!(KEY_MAPPINGS
+        .windows(2)
+        .all(|w| w[0].key_code < w[1].key_code));
+
+    let mut codes = HashSet::new();
+    let mut usages = HashSet::new();
+    for mapping in KEY_MAPPINGS {
+        assert!(codes.insert(mapping.code), "duplicate {}", mapping.code);
+        if let Some(usage) = mapping.usb_usage {
+            assert!(usages.insert(usage), "duplicate usage {:#x}", usage);
+            assert_eq!(key_code_from_usb_usage(usage), Some(mapping.key_code));
+        }
+        assert_eq!(key_mapping(mapping.key_code), Some(mapping));
+        assert_eq!(key_code_from_code(mapping.code), Some(mapping.key_code));
+        assert_eq!(code_from_key_code(mapping.key_code), Some(mapping.code));
+        assert_eq!(usb_usage_from_key_code(mapping.key_code), mapping.usb_usage);
+        assert!(!mapping.key.is_empty() && !mapping.shifted_key.is_empty());
+    }
+}
+
+#[test]
+fn every_key_code_constant_is_mapped() {
+    let constants = [
+        KeyCode::ANSI_A,
+        KeyCode::ANSI_Z,
+        KeyCode::ANSI_0,
+        KeyCode::ANSI_GRAVE,
+        KeyCode::ANSI_KEYPAD_9,
+        KeyCode::ANSI_KEYPAD_CLEAR,
+        KeyCode::RETURN,
+        KeyCode::TAB,
+        KeyCode::SPACE,
+        KeyCode::DELETE,
+        KeyCode::ESCAPE,
+        KeyCode::COMMAND,
+        KeyCode::SHIFT,
+        KeyCode::CAPS_LOCK,
+        KeyCode::OPTION,
+        KeyCode::CONTROL,
+        KeyCode::RIGHT_COMMAND,
+        KeyCode::RIGHT_SHIFT,
+        KeyCode::RIGHT_OPTION,
+        KeyCode::RIGHT_CONTROL,
+        KeyCode::FUNCTION,
+        KeyCode::VOLUME_UP,
+        KeyCode::VOLUME_DOWN,
+        KeyCode::MUTE,
+        KeyCode::F1,
+        KeyCode::F20,
+        KeyCode::HELP,
+        KeyCode::HOME,
+        KeyCode::PAGE_UP,
+        KeyCode::FORWARD_DELETE,
+        KeyCode::END,
+        KeyCode::PAGE_DOWN,
+        KeyCode::LEFT_ARROW,
+        KeyCode::UP_ARROW,
+        KeyCode::ISO_SECTION,
+        KeyCode::JIS_YEN,
+        KeyCode::JIS_UNDERSCORE,
+        KeyCode::JIS_KEYPAD_COMMA,
+        KeyCode::JIS_EISU,
+        KeyCode::JIS_KANA,
+        KeyCode::CONTEXTUAL_MENU,
+    ];
+    for key_code in constants {
+        assert!(key_mapping(key_code).is_some(), "{:#x}", key_code);
+    }
+    // Every code from 0x00 to 0x7E except the unused ones.
+    let unused = [0x34, 0x42, 0x44, 0x46, 0x4D, 0x6C, 0x70];
+    for key_code in 0..=0x7E {
+        assert_eq!(
+            key_mapping(key_code).is_some(),
+            !unused.contains(&key_code),
+            "{:#x}",
+            key_code
+        );
+    }
+    assert_eq!(key_mapping(0x7F), None);
+}
+
+#[test]
+fn codes_and_usages() {
+    assert_eq!(code_from_key_code(KeyCode::ANSI_A), Some("KeyA"));
+    assert_eq!(code_from_key_code(KeyCode::RETURN), Some("Enter"));
+    assert_eq!(
+        code_from_key_code(KeyCode::ANSI_KEYPAD_ENTER),
+        Some("NumpadEnter")
+    );
+    assert_eq!(code_from_key_code(KeyCode::DELETE), Some("Backspace"));
+    assert_eq!(code_from_key_code(KeyCode::FORWARD_DELETE), Some("Delete"));
+    assert_eq!(code_from_key_code(KeyCode::COMMAND), Some("MetaLeft"));
+    assert_eq!(
+        code_from_key_code(KeyCode::ISO_SECTION),
+        Some("IntlBackslash")
+    );
+    assert_eq!(key_code_from_code("ArrowUp"), Some(KeyCode::UP_ARROW));
+    assert_eq!(key_code_from_code("arrowup"), None);
+
+    assert_eq!(usb_usage_from_key_code(KeyCode::ANSI_A), Some(0x04));
+    assert_eq!(usb_usage_from_key_code(KeyCode::ESCAPE), Some(0x29));
+    assert_eq!(usb_usage_from_key_code(KeyCode::FUNCTION), None);
+    assert_eq!(key_code_from_usb_usage(0x1E), Some(KeyCode::ANSI_1));
+    assert_eq!(key_code_from_usb_usage(0xE3), Some(KeyCode::COMMAND));
+    assert_eq!(key_code_from_usb_usage(0x00), None);
+}
+
+#[test]
+fn us_ansi_characters() {
+    assert_eq!(us_ansi_char(KeyCode::ANSI_A, false), Some('a'));
+    assert_eq!(us_ansi_char(KeyCode::ANSI_A, true), Some('A'));
+    assert_eq!(us_ansi_char(KeyCode::ANSI_2, true), Some('@'));
+    assert_eq!(us_ansi_char(KeyCode::ANSI_QUOTE, true), Some('"'));
+    assert_eq!(us_ansi_char(KeyCode::ANSI_BACKSLASH, false), Some('\\'));
+    assert_eq!(us_ansi_char(KeyCode::ANSI_GRAVE, true), Some('~'));
+    assert_eq!(us_ansi_char(KeyCode::SPACE, true), Some(' '));
+    assert_eq!(us_ansi_char(KeyCode::ANSI_KEYPAD_PLUS, false), Some('+'));
+    assert_eq!(us_ansi_char(KeyCode::RETURN, false), None);
+    assert_eq!(us_ansi_char(KeyCode::JIS_YEN, false), None);
+
+    let mapping = key_mapping(KeyCode::LEFT_ARROW).unwrap();
+    assert_eq!(
+        (mapping.key, mapping.shifted_key),
+        ("ArrowLeft", "ArrowLeft")
+    );
+
+    // Every printable ASCII character can be typed on a US keyboard.
+    for c in (0x20u8..0x7F).map(char::from) {
+        assert!(
+            KEY_MAPPINGS
+                .iter()
+                .any(|m| m.char(false) == Some(c) || m.char(true) == Some(c)),
+            "{:?}",
+            c
+        );
+    }
+}
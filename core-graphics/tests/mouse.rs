@@ -0,0 +1,192 @@
+use core_graphics::event::mouse::{validate, DEFAULT_INTERVAL};
+use core_graphics::event::{
+    CGEventType, CGMomentumScrollPhase, CGMouseButton, CGScrollPhase, GestureError, MouseGesture,
+    ScrollEventUnit,
+};
+use core_graphics::geometry::CGPoint;
+use std::time::Duration;
+
+fn origin() -> CGPoint {
+    CGPoint::new(10., 20.)
+}
+
+#[test]
+fn double_click() {
+    let events = MouseGesture::new(origin())
+        .event_number(7)
+        .double_click(CGMouseButton::Left)
+        .build()
+        .unwrap();
+    let summary: Vec<_> = events
+        .iter()
+        .map(|e| (e.event_type, e.click_state, e.event_number))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            (CGEventType::LeftMouseDown, 1, 7),
+            (CGEventType::LeftMouseUp, 1, 7),
+            (CGEventType::LeftMouseDown, 2, 8),
+            (CGEventType::LeftMouseUp, 2, 8),
+        ]
+    );
+    assert!(events.iter().all(|e| e.location == origin()));
+    assert!(events.iter().all(|e| e.button == Some(CGMouseButton::Left)));
+    assert_eq!(events[0].delay, Duration::ZERO);
+    assert!(events[1..].iter().all(|e| e.delay == DEFAULT_INTERVAL));
+}
+
+#[test]
+fn other_buttons() {
+    let events = MouseGesture::new(origin())
+        .click(CGMouseButton::Right)
+        .click(CGMouseButton::Center)
+        .build()
+        .unwrap();
+    let types: Vec<_> = events.iter().map(|e| e.event_type).collect();
+    assert_eq!(
+        types,
+        [
+            CGEventType::RightMouseDown,
+            CGEventType::RightMouseUp,
+            CGEventType::OtherMouseDown,
+            CGEventType::OtherMouseUp,
+        ]
+    );
+}
+
+#[test]
+fn drag() {
+    let events = MouseGesture::new(origin())
+        .interval(Duration::from_millis(5))
+        .move_to(CGPoint::new(0., 0.))
+        .wait(Duration::from_millis(100))
+        .drag_to(CGMouseButton::Left, CGPoint::new(30., -15.), 3)
+        .build()
+        .unwrap();
+    assert_eq!(events.len(), 6);
+    assert_eq!(events[0].event_type, CGEventType::MouseMoved);
+    assert_eq!(events[0].button, None);
+    assert_eq!(events[0].delta, (-10, -20));
+    assert_eq!(events[1].event_type, CGEventType::LeftMouseDown);
+    assert_eq!(events[1].delay, Duration::from_millis(105));
+    for (event, x) in events[2..5].iter().zip([10., 20., 30.]) {
+        assert_eq!(event.event_type, CGEventType::LeftMouseDragged);
+        assert_eq!(event.location, CGPoint::new(x, x / -2.));
+        assert_eq!(event.delta, (10, -5));
+    }
+    assert_eq!(events[5].event_type, CGEventType::LeftMouseUp);
+    assert_eq!(events[5].location, CGPoint::new(30., -15.));
+    assert_eq!(events[5].event_number, events[1].event_number);
+}
+
+#[test]
+fn trackpad_scroll() {
+    let events = MouseGesture::new(origin())
+        .scroll_with_momentum(&[(-3, 0), (-5, 1)], &[(-4, 0)])
+        .scroll_wheel(2, 0)
+        .build()
+        .unwrap();
+    assert!(events
+        .iter()
+        .all(|e| e.event_type == CGEventType::ScrollWheel && e.location == origin()));
+    let scrolls: Vec<_> = events.iter().map(|e| e.scroll.unwrap()).collect();
+    let summary: Vec<_> = scrolls
+        .iter()
+        .map(|s| (s.delta_y, s.delta_x, s.phase, s.momentum_phase))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            (
+                -3,
+                0,
+                Some(CGScrollPhase::Began),
+                CGMomentumScrollPhase::None
+            ),
+            (
+                -5,
+                1,
+                Some(CGScrollPhase::Changed),
+                CGMomentumScrollPhase::None
+            ),
+            (
+                0,
+                0,
+                Some(CGScrollPhase::Ended),
+                CGMomentumScrollPhase::None
+            ),
+            (-4, 0, None, CGMomentumScrollPhase::Begin),
+            (0, 0, None, CGMomentumScrollPhase::End),
+            (2, 0, None, CGMomentumScrollPhase::None),
+        ]
+    );
+    assert!(scrolls[..5]
+        .iter()
+        .all(|s| s.unit == ScrollEventUnit::PIXEL));
+    assert_eq!(scrolls[5].unit, ScrollEventUnit::LINE);
+}
+
+#[test]
+fn builder_errors() {
+    let at = MouseGesture::new(origin());
+    assert_eq!(
+        at.clone().clicks(CGMouseButton::Left, 0).build(),
+        Err(GestureError::ZeroClicks)
+    );
+    assert_eq!(
+        at.clone()
+            .drag_to(CGMouseButton::Left, CGPoint::new(0., 0.), 0)
+            .build(),
+        Err(GestureError::ZeroDragSteps)
+    );
+    assert_eq!(
+        at.clone().scroll(&[]).build(),
+        Err(GestureError::EmptyScroll)
+    );
+    assert_eq!(
+        at.move_to(CGPoint::new(f64::NAN, 0.)).build(),
+        Err(GestureError::InvalidLocation(0))
+    );
+}
+
+#[test]
+fn validation() {
+    let events = MouseGesture::new(origin())
+        .drag_to(CGMouseButton::Left, CGPoint::new(0., 0.), 1)
+        .double_click(CGMouseButton::Right)
+        .build()
+        .unwrap();
+    assert_eq!(validate(&events), Ok(()));
+
+    // Releasing before the drag.
+    let mut swapped = events.clone();
+    swapped.swap(1, 2);
+    assert_eq!(validate(&swapped), Err(GestureError::ButtonNotDown(2)));
+
+    assert_eq!(validate(&events[..2]), Err(GestureError::ButtonStillDown));
+    assert_eq!(validate(&events[1..]), Err(GestureError::ButtonNotDown(0)));
+
+    let mut renumbered = events.clone();
+    renumbered[2].event_number += 1;
+    assert_eq!(
+        validate(&renumbered),
+        Err(GestureError::MismatchedRelease(2))
+    );
+
+    let mut skipped = events.clone();
+    skipped[5].click_state = 3;
+    skipped[6].click_state = 3;
+    assert_eq!(validate(&skipped), Err(GestureError::InvalidClickState(5)));
+
+    let mut pressed_twice = events.clone();
+    pressed_twice.insert(1, events[0].clone());
+    assert_eq!(
+        validate(&pressed_twice),
+        Err(GestureError::ButtonAlreadyDown(1))
+    );
+
+    let mut keyboard = events;
+    keyboard[3].event_type = CGEventType::KeyDown;
+    assert_eq!(validate(&keyboard), Err(GestureError::InvalidEvent(3)));
+}
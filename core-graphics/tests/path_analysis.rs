@@ -0,0 +1,204 @@
+use core_graphics::base::CGFloat;
+use core_graphics::geometry::{CGPoint, CGRect, CGSize};
+use core_graphics::path::{CGPathFillRule, PathBuilder, PathData, PathElement};
+use std::f64::consts::PI;
+
+const EPSILON: CGFloat = 1e-9;
+
+fn point(x: CGFloat, y: CGFloat) -> CGPoint {
+    CGPoint::new(x, y)
+}
+
+fn rect(x: CGFloat, y: CGFloat, width: CGFloat, height: CGFloat) -> CGRect {
+    CGRect::new(&point(x, y), &CGSize::new(width, height))
+}
+
+fn assert_rect(actual: CGRect, expected: CGRect) {
+    let (a, e): ([CGFloat; 4], [CGFloat; 4]) = (actual.into(), expected.into());
+    assert!(
+        a.iter().zip(&e).all(|(a, e)| (a - e).abs() < 1e-6),
+        "{:?} != {:?}",
+        actual,
+        expected
+    );
+}
+
+#[test]
+fn bounding_boxes() {
+    assert!(PathData::new().bounding_box().is_null());
+    assert!(PathData::new().path_bounding_box().is_null());
+
+    let mut path = PathData::new();
+    path.move_to_point(None, point(0.0, 0.0));
+    path.add_quad_curve_to_point(None, point(1.0, 2.0), point(2.0, 0.0));
+    // The control point is included in the loose box, but the curve only reaches y = 1.
+    assert_rect(path.bounding_box(), rect(0.0, 0.0, 2.0, 2.0));
+    assert_rect(path.path_bounding_box(), rect(0.0, 0.0, 2.0, 1.0));
+
+    let mut path = PathData::new();
+    path.move_to_point(None, point(0.0, 0.0));
+    path.add_curve_to_point(None, point(0.0, 4.0), point(4.0, -4.0), point(4.0, 0.0));
+    assert_rect(path.bounding_box(), rect(0.0, -4.0, 4.0, 8.0));
+    // The curve peaks at y = ±2/√3.
+    let tight = path.path_bounding_box();
+    assert_rect(
+        tight,
+        rect(0.0, -2.0 / 3.0_f64.sqrt(), 4.0, 4.0 / 3.0_f64.sqrt()),
+    );
+
+    let mut circle = PathData::new();
+    circle.add_ellipse_in_rect(None, rect(-1.0, -1.0, 2.0, 2.0));
+    assert_rect(circle.path_bounding_box(), rect(-1.0, -1.0, 2.0, 2.0));
+}
+
+#[test]
+fn flatten() {
+    let mut circle = PathData::new();
+    circle.add_arc(None, point(0.0, 0.0), 10.0, 0.0, 2.0 * PI, false);
+    circle.close_subpath();
+
+    let coarse = circle.flatten(1.0);
+    let fine = circle.flatten(0.01);
+    assert!(coarse.elements().len() < fine.elements().len());
+    for flattened in [&coarse, &fine] {
+        assert_eq!(flattened.elements()[0], circle.elements()[0]);
+        assert_eq!(
+            flattened.elements().last(),
+            Some(&PathElement::CloseSubpath)
+        );
+        assert!(flattened.elements()[1..flattened.elements().len() - 1]
+            .iter()
+            .all(|e| matches!(e, PathElement::AddLineToPoint(_))));
+    }
+    // Every vertex lies on the circle, within the approximation error of the curves.
+    for element in fine.elements() {
+        if let Some(p) = element.end_point() {
+            assert!((p.x.hypot(p.y) - 10.0).abs() < 0.01);
+        }
+    }
+
+    let mut lines = PathData::new();
+    lines.add_rect(None, rect(0.0, 0.0, 1.0, 1.0));
+    assert_eq!(lines.flatten(0.1), lines);
+}
+
+#[test]
+fn contains_point() {
+    // Two nested squares drawn in the same direction.
+    let mut path = PathData::new();
+    path.add_rect(None, rect(0.0, 0.0, 10.0, 10.0));
+    path.add_rect(None, rect(2.0, 2.0, 6.0, 6.0));
+
+    let inner = point(5.0, 5.0);
+    let ring = point(1.0, 5.0);
+    let outside = point(11.0, 5.0);
+    assert!(path.contains_point(inner, CGPathFillRule::Winding));
+    assert!(!path.contains_point(inner, CGPathFillRule::EvenOdd));
+    assert!(path.contains_point(ring, CGPathFillRule::Winding));
+    assert!(path.contains_point(ring, CGPathFillRule::EvenOdd));
+    assert!(!path.contains_point(outside, CGPathFillRule::Winding));
+
+    // Reversing the inner square punches a hole for both rules.
+    let mut inner_square = PathData::new();
+    inner_square.add_rect(None, rect(2.0, 2.0, 6.0, 6.0));
+    let mut donut = PathData::new();
+    donut.add_rect(None, rect(0.0, 0.0, 10.0, 10.0));
+    donut.add_path(None, &inner_square.reversed());
+    assert!(!donut.contains_point(inner, CGPathFillRule::Winding));
+    assert!(donut.contains_point(ring, CGPathFillRule::Winding));
+
+    // Open subpaths are filled as if closed, and curves are followed.
+    let mut half_disc = PathData::new();
+    half_disc.add_arc(None, point(0.0, 0.0), 1.0, 0.0, PI, false);
+    assert!(half_disc.contains_point(point(0.0, 0.9), CGPathFillRule::Winding));
+    assert!(!half_disc.contains_point(point(0.0, -0.1), CGPathFillRule::Winding));
+    assert!(!half_disc.contains_point(point(0.7, 0.75), CGPathFillRule::EvenOdd));
+}
+
+#[test]
+fn length() {
+    let mut square = PathData::new();
+    square.add_rect(None, rect(0.0, 0.0, 3.0, 4.0));
+    assert!((square.length(0.1) - 14.0).abs() < EPSILON);
+
+    let mut open = PathData::new();
+    open.move_to_point(None, point(0.0, 0.0));
+    open.add_line_to_point(None, point(3.0, 4.0));
+    assert!((open.length(0.1) - 5.0).abs() < EPSILON);
+
+    let mut circle = PathData::new();
+    circle.add_ellipse_in_rect(None, rect(-1.0, -1.0, 2.0, 2.0));
+    assert!((circle.length(1e-4) - 2.0 * PI).abs() < 1e-3);
+}
+
+#[test]
+fn extreme_curves() {
+    // A curve that is huge next to the tolerance is flattened into a bounded number of lines.
+    let mut huge = PathData::new();
+    huge.move_to_point(None, point(0.0, 0.0));
+    huge.add_quad_curve_to_point(None, point(1.0, 1e15), point(2.0, 0.0));
+    assert!(huge.flatten(0.01).elements().len() <= 5000);
+    assert!(huge.contains_point(point(1.0, 1e14), CGPathFillRule::Winding));
+
+    // Curves with non-finite control points are drawn as a single line.
+    let mut infinite = PathData::new();
+    infinite.move_to_point(None, point(0.0, 0.0));
+    infinite.add_curve_to_point(
+        None,
+        point(CGFloat::INFINITY, 0.0),
+        point(1.0, CGFloat::NAN),
+        point(3.0, 4.0),
+    );
+    assert_eq!(
+        infinite.flatten(0.01).elements(),
+        [
+            PathElement::MoveToPoint(point(0.0, 0.0)),
+            PathElement::AddLineToPoint(point(3.0, 4.0)),
+        ]
+    );
+    assert!((infinite.length(0.01) - 5.0).abs() < EPSILON);
+    assert!(!infinite.contains_point(point(1.0, 1.0), CGPathFillRule::Winding));
+}
+
+#[test]
+#[should_panic(expected = "flattening tolerance must be positive and finite")]
+fn non_finite_tolerance() {
+    let mut square = PathData::new();
+    square.add_rect(None, rect(0.0, 0.0, 1.0, 1.0));
+    square.length(CGFloat::NAN);
+}
+
+#[test]
+fn reversed() {
+    let mut square = PathData::new();
+    square.add_rect(None, rect(0.0, 0.0, 1.0, 1.0));
+    assert_eq!(
+        square.reversed().elements(),
+        [
+            PathElement::MoveToPoint(point(0.0, 0.0)),
+            PathElement::AddLineToPoint(point(0.0, 1.0)),
+            PathElement::AddLineToPoint(point(1.0, 1.0)),
+            PathElement::AddLineToPoint(point(1.0, 0.0)),
+            PathElement::CloseSubpath,
+        ]
+    );
+
+    let mut open = PathData::new();
+    open.move_to_point(None, point(0.0, 0.0));
+    open.add_quad_curve_to_point(None, point(1.0, 1.0), point(2.0, 0.0));
+    open.add_curve_to_point(None, point(3.0, 1.0), point(4.0, 2.0), point(5.0, 0.0));
+    assert_eq!(
+        open.reversed().elements(),
+        [
+            PathElement::MoveToPoint(point(5.0, 0.0)),
+            PathElement::AddCurveToPoint(point(4.0, 2.0), point(3.0, 1.0), point(2.0, 0.0)),
+            PathElement::AddQuadCurveToPoint(point(1.0, 1.0), point(0.0, 0.0)),
+        ]
+    );
+    assert_eq!(open.reversed().reversed(), open);
+
+    let mut circle = PathData::new();
+    circle.add_ellipse_in_rect(None, rect(-1.0, -1.0, 2.0, 2.0));
+    assert_eq!(circle.reversed().reversed(), circle);
+    assert!((circle.reversed().length(1e-3) - circle.length(1e-3)).abs() < EPSILON);
+}
@@ -0,0 +1,195 @@
+use core_graphics::base::CGFloat;
+use core_graphics::geometry::{CGAffineTransform, CGPoint, CGRect, CGSize};
+use core_graphics::path::{CGPathElementType, PathBuilder, PathData, PathElement};
+use std::f64::consts::{FRAC_PI_2, PI};
+
+const EPSILON: CGFloat = 1e-9;
+
+fn point(x: CGFloat, y: CGFloat) -> CGPoint {
+    CGPoint::new(x, y)
+}
+
+fn assert_point(actual: CGPoint, expected: CGPoint) {
+    assert!(
+        (actual.x - expected.x).abs() < EPSILON && (actual.y - expected.y).abs() < EPSILON,
+        "{:?} != {:?}",
+        actual,
+        expected
+    );
+}
+
+fn rect() -> CGRect {
+    CGRect::new(&point(1.0, 2.0), &CGSize::new(4.0, 6.0))
+}
+
+#[test]
+fn records_elements() {
+    let mut path = PathData::new();
+    assert!(path.is_empty());
+    path.move_to_point(None, point(0.0, 0.0));
+    path.add_line_to_point(None, point(1.0, 0.0));
+    path.add_quad_curve_to_point(None, point(2.0, 0.0), point(2.0, 1.0));
+    path.add_curve_to_point(None, point(2.0, 2.0), point(1.0, 2.0), point(0.0, 2.0));
+    assert_eq!(path.current_point(), Some(point(0.0, 2.0)));
+    path.close_subpath();
+    assert_eq!(path.current_point(), Some(point(0.0, 0.0)));
+
+    let types: Vec<_> = path.elements().iter().map(|e| e.element_type()).collect();
+    assert_eq!(
+        types,
+        [
+            CGPathElementType::MoveToPoint,
+            CGPathElementType::AddLineToPoint,
+            CGPathElementType::AddQuadCurveToPoint,
+            CGPathElementType::AddCurveToPoint,
+            CGPathElementType::CloseSubpath,
+        ]
+    );
+    assert_eq!(
+        path.elements()[2],
+        PathElement::AddQuadCurveToPoint(point(2.0, 0.0), point(2.0, 1.0))
+    );
+}
+
+#[test]
+fn ignores_segments_without_current_point() {
+    let mut path = PathData::new();
+    path.add_line_to_point(None, point(1.0, 1.0));
+    path.add_curve_to_point(None, point(1.0, 1.0), point(2.0, 2.0), point(3.0, 3.0));
+    path.close_subpath();
+    assert!(path.is_empty());
+    assert_eq!(path.current_point(), None);
+}
+
+#[test]
+fn rect_and_transform() {
+    let mut path = PathData::new();
+    path.add_rect(None, rect());
+    assert_eq!(
+        path.elements(),
+        [
+            PathElement::MoveToPoint(point(1.0, 2.0)),
+            PathElement::AddLineToPoint(point(5.0, 2.0)),
+            PathElement::AddLineToPoint(point(5.0, 8.0)),
+            PathElement::AddLineToPoint(point(1.0, 8.0)),
+            PathElement::CloseSubpath,
+        ]
+    );
+
+    let translation = CGAffineTransform::translation(10.0, 20.0);
+    let mut moved = PathData::new();
+    moved.add_rect(Some(&translation), rect());
+    assert_eq!(moved, path.apply_transform(&translation));
+    assert_eq!(
+        moved.elements()[0],
+        PathElement::MoveToPoint(point(11.0, 22.0))
+    );
+}
+
+#[test]
+fn ellipse() {
+    let mut path = PathData::new();
+    path.add_ellipse_in_rect(None, rect());
+    let elements = path.elements();
+    assert_eq!(elements.len(), 6);
+    assert_eq!(elements[0], PathElement::MoveToPoint(point(5.0, 5.0)));
+    let ends: Vec<_> = elements[1..5]
+        .iter()
+        .map(|e| e.end_point().unwrap())
+        .collect();
+    for (actual, expected) in ends.iter().zip(&[
+        point(3.0, 8.0),
+        point(1.0, 5.0),
+        point(3.0, 2.0),
+        point(5.0, 5.0),
+    ]) {
+        assert_point(*actual, *expected);
+    }
+    // The first control point is tangent to the right edge.
+    match elements[1] {
+        PathElement::AddCurveToPoint(c1, _, _) => {
+            assert_point(c1, point(5.0, 5.0 + 3.0 * 0.552_284_749_830_793_4))
+        }
+        ref other => panic!("unexpected {:?}", other),
+    }
+    assert_eq!(elements[5], PathElement::CloseSubpath);
+}
+
+#[test]
+fn arcs() {
+    let center = point(0.0, 0.0);
+
+    // A counterclockwise half circle is split into two quarter curves.
+    let mut path = PathData::new();
+    path.add_arc(None, center, 2.0, 0.0, PI, false);
+    assert_eq!(path.elements().len(), 3);
+    assert_point(path.elements()[0].end_point().unwrap(), point(2.0, 0.0));
+    assert_point(path.elements()[1].end_point().unwrap(), point(0.0, 2.0));
+    assert_point(path.current_point().unwrap(), point(-2.0, 0.0));
+
+    // Clockwise goes the other way round.
+    let mut path = PathData::new();
+    path.add_arc(None, center, 1.0, 0.0, FRAC_PI_2, true);
+    assert_eq!(path.elements().len(), 4);
+    assert_point(path.elements()[1].end_point().unwrap(), point(0.0, -1.0));
+    assert_point(path.current_point().unwrap(), point(0.0, 1.0));
+
+    // A full circle ends exactly at its start point.
+    let mut path = PathData::new();
+    path.add_arc(None, center, 3.0, 0.3, 0.3 + 2.0 * PI, false);
+    path.close_subpath();
+    assert_eq!(path.elements().len(), 6);
+    assert_eq!(
+        path.elements()[4].end_point(),
+        path.elements()[0].end_point()
+    );
+
+    // With a current point, the arc is joined to it with a line.
+    let mut path = PathData::new();
+    path.move_to_point(None, point(5.0, 5.0));
+    path.add_arc(None, center, 1.0, 0.0, 0.0, false);
+    assert_eq!(
+        path.elements(),
+        [
+            PathElement::MoveToPoint(point(5.0, 5.0)),
+            PathElement::AddLineToPoint(point(1.0, 0.0)),
+        ]
+    );
+}
+
+#[test]
+fn rounded_rect() {
+    let mut path = PathData::new();
+    path.add_rounded_rect(None, rect(), 1.0, 10.0);
+    let elements = path.elements();
+    // A move, four lines, four corner curves and a close.
+    assert_eq!(elements.len(), 10);
+    assert_eq!(elements[0], PathElement::MoveToPoint(point(2.0, 2.0)));
+    assert_point(elements[2].end_point().unwrap(), point(5.0, 5.0));
+    assert_point(elements[8].end_point().unwrap(), point(2.0, 2.0));
+    assert_eq!(elements[9], PathElement::CloseSubpath);
+
+    let mut square = PathData::new();
+    square.add_rounded_rect(None, rect(), 0.0, 1.0);
+    let mut expected = PathData::new();
+    expected.add_rect(None, rect());
+    assert_eq!(square, expected);
+}
+
+#[test]
+fn add_path_and_collect() {
+    let mut square = PathData::new();
+    square.add_rect(None, rect());
+
+    let mut path = PathData::new();
+    path.add_path(Some(&CGAffineTransform::scale(2.0, 2.0)), &square);
+    path.add_path(None, &square);
+    assert_eq!(path.elements().len(), 10);
+    assert_eq!(
+        path.elements()[0],
+        PathElement::MoveToPoint(point(2.0, 4.0))
+    );
+
+    let collected: PathData = square.elements().iter().copied().collect();
+    assert_eq!(collected, square);
+}
@@ -0,0 +1,151 @@
+use core_graphics::event::shortcut::{key_code_from_name, key_name, SHORTCUT_MODIFIERS};
+use core_graphics::event::{CGEventFlags, CGEventType, KeyCode, Shortcut, ShortcutError};
+
+const CMD: CGEventFlags = CGEventFlags::CGEventFlagCommand;
+const SHIFT: CGEventFlags = CGEventFlags::CGEventFlagShift;
+const CTRL: CGEventFlags = CGEventFlags::CGEventFlagControl;
+const OPT: CGEventFlags = CGEventFlags::CGEventFlagAlternate;
+const FN: CGEventFlags = CGEventFlags::CGEventFlagSecondaryFn;
+
+fn parse(s: &str) -> Shortcut {
+    s.parse().unwrap_or_else(|e| panic!("{:?}: {}", s, e))
+}
+
+#[test]
+fn parse_text() {
+    let shortcut = parse("cmd+shift+K");
+    assert_eq!(shortcut.key_code(), KeyCode::ANSI_K);
+    assert_eq!(shortcut.modifiers(), CMD | SHIFT);
+
+    assert_eq!(parse("Command + Shift + k"), shortcut);
+    assert_eq!(parse("SHIFT+CMD+K"), shortcut);
+    assert_eq!(parse("ctrl+alt+Delete"), parse("control+option+backspace"));
+    assert_eq!(parse("cmd+-").key_code(), KeyCode::ANSI_MINUS);
+    assert_eq!(parse("cmd+,").key_code(), KeyCode::ANSI_COMMA);
+    assert_eq!(parse("fn+F1").modifiers(), FN);
+    assert_eq!(parse("F5").modifiers(), CGEventFlags::empty());
+    assert_eq!(parse("opt+Section").key_code(), KeyCode::ISO_SECTION);
+    assert_eq!(parse("ctrl+Yen").key_code(), KeyCode::JIS_YEN);
+    assert_eq!(parse("cmd+Kana").key_code(), KeyCode::JIS_KANA);
+}
+
+#[test]
+fn parse_glyphs() {
+    let shortcut = parse("⌃⌥F5");
+    assert_eq!(shortcut.key_code(), KeyCode::F5);
+    assert_eq!(shortcut.modifiers(), CTRL | OPT);
+
+    assert_eq!(parse("⇧⌘K"), parse("cmd+shift+K"));
+    assert_eq!(parse("⌘↩"), parse("cmd+Return"));
+    assert_eq!(parse("⌘+←"), parse("cmd+Left"));
+    assert_eq!(parse("ctrl+⌥⇧↑"), parse("⌃⌥⇧Up"));
+    assert_eq!(parse("🌐⌃F"), parse("fn+ctrl+F"));
+    assert_eq!(parse("⌥§").key_code(), KeyCode::ISO_SECTION);
+}
+
+#[test]
+fn format() {
+    let shortcut = parse("shift+cmd+opt+ctrl+K");
+    assert_eq!(shortcut.to_string(), "ctrl+opt+shift+cmd+K");
+    assert_eq!(shortcut.to_glyph_string(), "⌃⌥⇧⌘K");
+    assert_eq!(parse("cmd+PgDn").to_string(), "cmd+PageDown");
+    assert_eq!(parse("cmd+PgDn").to_glyph_string(), "⌘⇟");
+    assert_eq!(parse("fn+F12").to_glyph_string(), "🌐F12");
+    assert_eq!(parse("cmd+Space").to_glyph_string(), "⌘Space");
+
+    for s in [
+        "cmd+K",
+        "ctrl+opt+F5",
+        "shift+Tab",
+        "cmd+\\",
+        "opt+Keypad7",
+        "fn+ctrl+Delete",
+    ] {
+        let shortcut = parse(s);
+        assert_eq!(shortcut.to_string(), s);
+        assert_eq!(parse(&shortcut.to_string()), shortcut);
+        assert_eq!(parse(&shortcut.to_glyph_string()), shortcut);
+    }
+}
+
+#[test]
+fn errors() {
+    let err = |s: &str| s.parse::<Shortcut>().unwrap_err();
+    assert_eq!(err(""), ShortcutError::MissingKey);
+    assert_eq!(err("cmd+"), ShortcutError::MissingKey);
+    assert_eq!(err("⌘"), ShortcutError::UnknownKey("⌘".to_owned()));
+    assert_eq!(
+        err("hyper+K"),
+        ShortcutError::UnknownModifier("hyper".to_owned())
+    );
+    assert_eq!(
+        err("cmd+command+K"),
+        ShortcutError::DuplicateModifier("command".to_owned())
+    );
+    assert_eq!(err("⌘⌘K"), ShortcutError::DuplicateModifier("⌘".to_owned()));
+    assert_eq!(
+        err("cmd+Banana"),
+        ShortcutError::UnknownKey("Banana".to_owned())
+    );
+    assert_eq!(err("K"), ShortcutError::NeedsModifier(KeyCode::ANSI_K));
+    assert_eq!(
+        err("shift+1"),
+        ShortcutError::NeedsModifier(KeyCode::ANSI_1)
+    );
+    assert_eq!(err("Space"), ShortcutError::NeedsModifier(KeyCode::SPACE));
+
+    assert_eq!(
+        Shortcut::new(KeyCode::SHIFT, CMD),
+        Err(ShortcutError::ModifierKey(KeyCode::SHIFT))
+    );
+    assert_eq!(
+        Shortcut::new(KeyCode::ANSI_K, CMD | CGEventFlags::CGEventFlagAlphaShift),
+        Err(ShortcutError::InvalidModifiers(
+            CGEventFlags::CGEventFlagAlphaShift
+        ))
+    );
+    assert_eq!(err("cmd+Banana").to_string(), "unknown key \"Banana\"");
+}
+
+#[test]
+fn matches() {
+    let shortcut = parse("cmd+shift+K");
+    let key_down = CGEventType::KeyDown;
+    assert!(shortcut.matches(key_down, KeyCode::ANSI_K, CMD | SHIFT));
+    // Caps lock and device-dependent bits are ignored.
+    assert!(shortcut.matches(
+        key_down,
+        KeyCode::ANSI_K,
+        CMD | SHIFT
+            | CGEventFlags::CGEventFlagAlphaShift
+            | CGEventFlags::CGEventFlagNonCoalesced
+            | CGEventFlags::from_bits_retain(0x10a)
+    ));
+    assert!(!shortcut.matches(key_down, KeyCode::ANSI_K, CMD));
+    assert!(!shortcut.matches(key_down, KeyCode::ANSI_K, CMD | SHIFT | OPT));
+    assert!(!shortcut.matches(key_down, KeyCode::ANSI_J, CMD | SHIFT));
+    assert!(!shortcut.matches(CGEventType::KeyUp, KeyCode::ANSI_K, CMD | SHIFT));
+
+    // Arrow and function key events carry fn and numeric pad flags.
+    let left = parse("cmd+Left");
+    let arrow_flags = CMD | FN | CGEventFlags::CGEventFlagNumericPad;
+    assert!(left.matches(key_down, KeyCode::LEFT_ARROW, arrow_flags));
+    assert!(left.matches(key_down, KeyCode::LEFT_ARROW, CMD));
+    let fn_f1 = parse("fn+F1");
+    assert!(fn_f1.matches(key_down, KeyCode::F1, FN));
+    assert!(!fn_f1.matches(key_down, KeyCode::F1, CGEventFlags::empty()));
+    // Elsewhere, fn counts.
+    assert!(!shortcut.matches(key_down, KeyCode::ANSI_K, CMD | SHIFT | FN));
+}
+
+#[test]
+fn key_names() {
+    assert_eq!(key_name(KeyCode::ANSI_A), Some("A"));
+    assert_eq!(key_name(KeyCode::ESCAPE), Some("Escape"));
+    assert_eq!(key_name(KeyCode::COMMAND), None);
+    assert_eq!(key_code_from_name("esc"), Some(KeyCode::ESCAPE));
+    assert_eq!(key_code_from_name("⎋"), Some(KeyCode::ESCAPE));
+    assert_eq!(key_code_from_name("f20"), Some(KeyCode::F20));
+    assert_eq!(key_code_from_name("F21"), None);
+    assert_eq!(SHORTCUT_MODIFIERS, FN | CTRL | OPT | SHIFT | CMD);
+}
@@ -0,0 +1,111 @@
+use core_graphics::event::typing::{us_key_for_char, MAX_EVENT_STRING_LEN};
+use core_graphics::event::{CGEventFlags, CGKeyCode, KeyCode, TypingPlan, TypingStep};
+
+const SHIFT: CGEventFlags = CGEventFlags::CGEventFlagShift;
+const OPTION: CGEventFlags = CGEventFlags::CGEventFlagAlternate;
+
+fn press(key_code: CGKeyCode, flags: CGEventFlags) -> [TypingStep; 2] {
+    [true, false].map(|key_down| TypingStep::Key {
+        key_code,
+        key_down,
+        flags,
+    })
+}
+
+fn text(s: &str) -> TypingStep {
+    TypingStep::Text(s.encode_utf16().collect())
+}
+
+#[test]
+fn keys() {
+    let plan = TypingPlan::new("aB!\n");
+    let expected: Vec<_> = [
+        press(KeyCode::ANSI_A, CGEventFlags::empty()),
+        press(KeyCode::ANSI_B, SHIFT),
+        press(KeyCode::ANSI_1, SHIFT),
+        press(KeyCode::RETURN, CGEventFlags::empty()),
+    ]
+    .concat();
+    assert_eq!(plan.steps(), expected);
+
+    assert_eq!(TypingPlan::new("\r\n"), TypingPlan::new("\n"));
+    assert_eq!(
+        TypingPlan::new("\t").steps(),
+        press(KeyCode::TAB, CGEventFlags::empty())
+    );
+    assert!(TypingPlan::new("").steps().is_empty());
+}
+
+#[test]
+fn us_layout() {
+    assert_eq!(us_key_for_char('*'), Some((KeyCode::ANSI_8, SHIFT)));
+    assert_eq!(
+        us_key_for_char('.'),
+        Some((KeyCode::ANSI_PERIOD, CGEventFlags::empty()))
+    );
+    assert_eq!(
+        us_key_for_char(' '),
+        Some((KeyCode::SPACE, CGEventFlags::empty()))
+    );
+    assert_eq!(us_key_for_char('"'), Some((KeyCode::ANSI_QUOTE, SHIFT)));
+    assert_eq!(us_key_for_char('ß'), Some((KeyCode::ANSI_S, OPTION)));
+    assert_eq!(
+        us_key_for_char('—'),
+        Some((KeyCode::ANSI_MINUS, SHIFT | OPTION))
+    );
+    assert_eq!(us_key_for_char('é'), None);
+    assert_eq!(us_key_for_char('±').map(|k| k.0), Some(KeyCode::ANSI_EQUAL));
+
+    for c in (0x20u8..0x7F).map(char::from) {
+        assert!(us_key_for_char(c).is_some(), "{:?}", c);
+    }
+}
+
+#[test]
+fn text_fallback() {
+    let plan = TypingPlan::new("héllo 日本");
+    let mut expected = Vec::new();
+    expected.extend(press(KeyCode::ANSI_H, CGEventFlags::empty()));
+    expected.push(text("é"));
+    for key_code in [
+        KeyCode::ANSI_L,
+        KeyCode::ANSI_L,
+        KeyCode::ANSI_O,
+        KeyCode::SPACE,
+    ] {
+        expected.extend(press(key_code, CGEventFlags::empty()));
+    }
+    expected.push(text("日本"));
+    assert_eq!(plan.steps(), expected);
+}
+
+#[test]
+fn text_is_chunked() {
+    let long: String = "日".repeat(45);
+    let plan = TypingPlan::new(&long);
+    let lengths: Vec<_> = plan
+        .steps()
+        .iter()
+        .map(|step| match step {
+            TypingStep::Text(units) => units.len(),
+            other => panic!("unexpected {:?}", other),
+        })
+        .collect();
+    assert_eq!(lengths, [20, 20, 5]);
+
+    // Surrogate pairs are never split across steps.
+    let emoji: String = "😀".repeat(11);
+    let plan = TypingPlan::new(&emoji);
+    let mut round_trip = String::new();
+    for step in plan.steps() {
+        match step {
+            TypingStep::Text(units) => {
+                assert!(units.len() <= MAX_EVENT_STRING_LEN);
+                round_trip.push_str(&String::from_utf16(units).unwrap());
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+    assert_eq!(round_trip, emoji);
+    assert_eq!(plan.steps().len(), 2);
+}
@@ -14,12 +14,14 @@ use crate::font_descriptor::{CTFontDescriptor, CTFontDescriptorRef, CTFontOrient
 use crate::font_descriptor::{
     CTFontSymbolicTraits, CTFontTraits, SymbolicTraitAccessors, TraitAccessors,
 };
-use crate::font_manager::create_font_descriptor;
+use crate::font_manager::{create_font_descriptor, create_font_descriptor_with_index};
+use crate::sfnt::{CharacterMap, FvarTable, NameTable, VariationAxis};
 
 use core_foundation::array::{CFArray, CFArrayRef};
 use core_foundation::base::{CFIndex, CFOptionFlags, CFType, CFTypeID, CFTypeRef, TCFType};
 use core_foundation::data::{CFData, CFDataRef};
 use core_foundation::dictionary::{CFDictionary, CFDictionaryRef};
+use core_foundation::four_char_code::FourCharCode;
 use core_foundation::number::CFNumber;
 use core_foundation::string::{CFString, CFStringRef, UniChar};
 use core_foundation::url::{CFURLRef, CFURL};
@@ -68,7 +70,80 @@ pub const kCTFontToolTipFontType: CTFontUIFontType = 25;
 pub const kCTFontControlContentFontType: CTFontUIFontType = 26;
 
 pub type CTFontTableTag = u32;
-// TODO: create bindings for enum with 'chars' values
+pub const kCTFontTableBASE: CTFontTableTag = FourCharCode::new(b"BASE").to_u32();
+pub const kCTFontTableCBDT: CTFontTableTag = FourCharCode::new(b"CBDT").to_u32();
+pub const kCTFontTableCBLC: CTFontTableTag = FourCharCode::new(b"CBLC").to_u32();
+pub const kCTFontTableCFF: CTFontTableTag = FourCharCode::new(b"CFF ").to_u32();
+pub const kCTFontTableCFF2: CTFontTableTag = FourCharCode::new(b"CFF2").to_u32();
+pub const kCTFontTableCOLR: CTFontTableTag = FourCharCode::new(b"COLR").to_u32();
+pub const kCTFontTableCPAL: CTFontTableTag = FourCharCode::new(b"CPAL").to_u32();
+pub const kCTFontTableDSIG: CTFontTableTag = FourCharCode::new(b"DSIG").to_u32();
+pub const kCTFontTableEBDT: CTFontTableTag = FourCharCode::new(b"EBDT").to_u32();
+pub const kCTFontTableEBLC: CTFontTableTag = FourCharCode::new(b"EBLC").to_u32();
+pub const kCTFontTableEBSC: CTFontTableTag = FourCharCode::new(b"EBSC").to_u32();
+pub const kCTFontTableGDEF: CTFontTableTag = FourCharCode::new(b"GDEF").to_u32();
+pub const kCTFontTableGPOS: CTFontTableTag = FourCharCode::new(b"GPOS").to_u32();
+pub const kCTFontTableGSUB: CTFontTableTag = FourCharCode::new(b"GSUB").to_u32();
+pub const kCTFontTableHVAR: CTFontTableTag = FourCharCode::new(b"HVAR").to_u32();
+pub const kCTFontTableJSTF: CTFontTableTag = FourCharCode::new(b"JSTF").to_u32();
+pub const kCTFontTableLTSH: CTFontTableTag = FourCharCode::new(b"LTSH").to_u32();
+pub const kCTFontTableMATH: CTFontTableTag = FourCharCode::new(b"MATH").to_u32();
+pub const kCTFontTableMERG: CTFontTableTag = FourCharCode::new(b"MERG").to_u32();
+pub const kCTFontTableMVAR: CTFontTableTag = FourCharCode::new(b"MVAR").to_u32();
+pub const kCTFontTableOS2: CTFontTableTag = FourCharCode::new(b"OS/2").to_u32();
+pub const kCTFontTablePCLT: CTFontTableTag = FourCharCode::new(b"PCLT").to_u32();
+pub const kCTFontTableSTAT: CTFontTableTag = FourCharCode::new(b"STAT").to_u32();
+pub const kCTFontTableSVG: CTFontTableTag = FourCharCode::new(b"SVG ").to_u32();
+pub const kCTFontTableVDMX: CTFontTableTag = FourCharCode::new(b"VDMX").to_u32();
+pub const kCTFontTableVORG: CTFontTableTag = FourCharCode::new(b"VORG").to_u32();
+pub const kCTFontTableVVAR: CTFontTableTag = FourCharCode::new(b"VVAR").to_u32();
+pub const kCTFontTableZapf: CTFontTableTag = FourCharCode::new(b"Zapf").to_u32();
+pub const kCTFontTableAcnt: CTFontTableTag = FourCharCode::new(b"acnt").to_u32();
+pub const kCTFontTableAnkr: CTFontTableTag = FourCharCode::new(b"ankr").to_u32();
+pub const kCTFontTableAvar: CTFontTableTag = FourCharCode::new(b"avar").to_u32();
+pub const kCTFontTableBdat: CTFontTableTag = FourCharCode::new(b"bdat").to_u32();
+pub const kCTFontTableBhed: CTFontTableTag = FourCharCode::new(b"bhed").to_u32();
+pub const kCTFontTableBloc: CTFontTableTag = FourCharCode::new(b"bloc").to_u32();
+pub const kCTFontTableBsln: CTFontTableTag = FourCharCode::new(b"bsln").to_u32();
+pub const kCTFontTableCidg: CTFontTableTag = FourCharCode::new(b"cidg").to_u32();
+pub const kCTFontTableCmap: CTFontTableTag = FourCharCode::new(b"cmap").to_u32();
+pub const kCTFontTableCvar: CTFontTableTag = FourCharCode::new(b"cvar").to_u32();
+pub const kCTFontTableCvt: CTFontTableTag = FourCharCode::new(b"cvt ").to_u32();
+pub const kCTFontTableFdsc: CTFontTableTag = FourCharCode::new(b"fdsc").to_u32();
+pub const kCTFontTableFeat: CTFontTableTag = FourCharCode::new(b"feat").to_u32();
+pub const kCTFontTableFmtx: CTFontTableTag = FourCharCode::new(b"fmtx").to_u32();
+pub const kCTFontTableFond: CTFontTableTag = FourCharCode::new(b"fond").to_u32();
+pub const kCTFontTableFpgm: CTFontTableTag = FourCharCode::new(b"fpgm").to_u32();
+pub const kCTFontTableFvar: CTFontTableTag = FourCharCode::new(b"fvar").to_u32();
+pub const kCTFontTableGasp: CTFontTableTag = FourCharCode::new(b"gasp").to_u32();
+pub const kCTFontTableGlyf: CTFontTableTag = FourCharCode::new(b"glyf").to_u32();
+pub const kCTFontTableGvar: CTFontTableTag = FourCharCode::new(b"gvar").to_u32();
+pub const kCTFontTableHdmx: CTFontTableTag = FourCharCode::new(b"hdmx").to_u32();
+pub const kCTFontTableHead: CTFontTableTag = FourCharCode::new(b"head").to_u32();
+pub const kCTFontTableHhea: CTFontTableTag = FourCharCode::new(b"hhea").to_u32();
+pub const kCTFontTableHmtx: CTFontTableTag = FourCharCode::new(b"hmtx").to_u32();
+pub const kCTFontTableHsty: CTFontTableTag = FourCharCode::new(b"hsty").to_u32();
+pub const kCTFontTableJust: CTFontTableTag = FourCharCode::new(b"just").to_u32();
+pub const kCTFontTableKern: CTFontTableTag = FourCharCode::new(b"kern").to_u32();
+pub const kCTFontTableKerx: CTFontTableTag = FourCharCode::new(b"kerx").to_u32();
+pub const kCTFontTableLcar: CTFontTableTag = FourCharCode::new(b"lcar").to_u32();
+pub const kCTFontTableLoca: CTFontTableTag = FourCharCode::new(b"loca").to_u32();
+pub const kCTFontTableLtag: CTFontTableTag = FourCharCode::new(b"ltag").to_u32();
+pub const kCTFontTableMaxp: CTFontTableTag = FourCharCode::new(b"maxp").to_u32();
+pub const kCTFontTableMeta: CTFontTableTag = FourCharCode::new(b"meta").to_u32();
+pub const kCTFontTableMort: CTFontTableTag = FourCharCode::new(b"mort").to_u32();
+pub const kCTFontTableMorx: CTFontTableTag = FourCharCode::new(b"morx").to_u32();
+pub const kCTFontTableName: CTFontTableTag = FourCharCode::new(b"name").to_u32();
+pub const kCTFontTableOpbd: CTFontTableTag = FourCharCode::new(b"opbd").to_u32();
+pub const kCTFontTablePost: CTFontTableTag = FourCharCode::new(b"post").to_u32();
+pub const kCTFontTablePrep: CTFontTableTag = FourCharCode::new(b"prep").to_u32();
+pub const kCTFontTableProp: CTFontTableTag = FourCharCode::new(b"prop").to_u32();
+pub const kCTFontTableSbit: CTFontTableTag = FourCharCode::new(b"sbit").to_u32();
+pub const kCTFontTableSbix: CTFontTableTag = FourCharCode::new(b"sbix").to_u32();
+pub const kCTFontTableTrak: CTFontTableTag = FourCharCode::new(b"trak").to_u32();
+pub const kCTFontTableVhea: CTFontTableTag = FourCharCode::new(b"vhea").to_u32();
+pub const kCTFontTableVmtx: CTFontTableTag = FourCharCode::new(b"vmtx").to_u32();
+pub const kCTFontTableXref: CTFontTableTag = FourCharCode::new(b"xref").to_u32();
 
 pub type CTFontTableOptions = u32;
 pub const kCTFontTableOptionsNoOptions: CTFontTableOptions = 0;
@@ -202,6 +277,13 @@ pub fn new_from_buffer(buffer: &[u8]) -> Result<CTFont, ()> {
     Ok(new_from_descriptor(&ct_font_descriptor, 16.0))
 }
 
+/// Creates a font from the face at `index` of a font collection, or from a single font when
+/// `index` is zero.
+pub fn new_from_buffer_with_index(buffer: &[u8], index: usize) -> Result<CTFont, ()> {
+    let ct_font_descriptor = create_font_descriptor_with_index(buffer, index)?;
+    Ok(new_from_descriptor(&ct_font_descriptor, 16.0))
+}
+
 pub fn new_from_name(name: &str, pt_size: f64) -> Result<CTFont, ()> {
     unsafe {
         let name: CFString = name.parse().unwrap();
@@ -325,6 +407,15 @@ impl CTFont {
         }
     }
 
+    /// Parses the `name` table, which has every record in every language rather than only the
+    /// names of [`CTFontNameSpecifier`]. Returns `None` if the font has no valid `name` table.
+    ///
+    /// [`CTFontNameSpecifier`]: enum.CTFontNameSpecifier.html
+    pub fn name_table(&self) -> Option<NameTable> {
+        let data = self.get_font_table(kCTFontTableName)?;
+        NameTable::parse(data.bytes()).ok()
+    }
+
     pub fn family_name(&self) -> String {
         let value = self.get_string_by_name_key(CTFontNameSpecifier::Family);
         value.expect("Fonts should always have a family name.")
@@ -438,15 +529,15 @@ impl CTFont {
         CTFontGetVerticalTranslationsForGlyphs(self.0, orientation, glyphs, translations, count)
     }
 
-    pub fn has_table(&self, tag: u32) -> bool {
-        unsafe { CTFontHasTable(self.0, tag as CTFontTableTag) }
+    pub fn has_table(&self, tag: impl Into<FourCharCode>) -> bool {
+        unsafe { CTFontHasTable(self.0, tag.into().to_u32()) }
     }
 
-    pub fn get_font_table(&self, tag: u32) -> Option<CFData> {
+    pub fn get_font_table(&self, tag: impl Into<FourCharCode>) -> Option<CFData> {
         unsafe {
             let result = CTFontCopyTable(
                 self.0,
-                tag as CTFontTableTag,
+                tag.into().to_u32(),
                 kCTFontTableOptionsExcludeSynthetic,
             );
             if result.is_null() {
@@ -468,6 +559,25 @@ impl CTFont {
         }
     }
 
+    /// Returns the tags of the tables in the font, leaving out synthetic tables.
+    pub fn available_table_tags(&self) -> Vec<FourCharCode> {
+        self.get_available_font_tables()
+            .map(|tags| {
+                tags.iter()
+                    .map(|tag| FourCharCode::from_u32(*tag))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Parses the Unicode character map of the `cmap` table, which can be cached to answer
+    /// glyph coverage questions without the font. Returns `None` if the font has no valid
+    /// `cmap` table.
+    pub fn character_map(&self) -> Option<CharacterMap> {
+        let data = self.get_font_table(kCTFontTableCmap)?;
+        CharacterMap::parse(data.bytes()).ok()
+    }
+
     pub fn get_bounding_rects_for_glyphs(
         &self,
         orientation: CTFontOrientation,
@@ -522,6 +632,26 @@ impl CTFont {
         }
     }
 
+    /// Returns the variation axes of the font, or an empty vector if it is not a variable font.
+    pub fn variation_axes(&self) -> Vec<VariationAxis> {
+        self.get_variation_axes().map_or_else(Vec::new, |axes| {
+            axes.iter()
+                .filter_map(|axis| VariationAxis::from_dictionary(&axis))
+                .collect()
+        })
+    }
+
+    /// Reads the `fvar` table, with names from the `name` table in the best match for
+    /// `language`, such as `en-US`.
+    pub fn fvar_table(&self, language: &str) -> Option<FvarTable> {
+        let data = self.get_font_table(kCTFontTableFvar)?;
+        let mut fvar = FvarTable::parse(data.bytes()).ok()?;
+        if let Some(names) = self.name_table() {
+            fvar.resolve_names(&names, language);
+        }
+        Some(fvar)
+    }
+
     pub fn create_path_for_glyph(
         &self,
         glyph: CGGlyph,
@@ -629,13 +759,12 @@ extern "C" {
     static kCTFontSampleTextNameKey: CFStringRef;
     static kCTFontPostScriptCIDNameKey: CFStringRef;
 
-    #[cfg(test)]
-    static kCTFontVariationAxisIdentifierKey: CFStringRef;
-    //static kCTFontVariationAxisMinimumValueKey: CFStringRef;
-    #[cfg(test)]
-    static kCTFontVariationAxisMaximumValueKey: CFStringRef;
-    //static kCTFontVariationAxisDefaultValueKey: CFStringRef;
-    //static kCTFontVariationAxisNameKey: CFStringRef;
+    pub static kCTFontVariationAxisIdentifierKey: CFStringRef;
+    pub static kCTFontVariationAxisMinimumValueKey: CFStringRef;
+    pub static kCTFontVariationAxisMaximumValueKey: CFStringRef;
+    pub static kCTFontVariationAxisDefaultValueKey: CFStringRef;
+    pub static kCTFontVariationAxisNameKey: CFStringRef;
+    pub static kCTFontVariationAxisHiddenKey: CFStringRef;
 
     //static kCTFontFeatureTypeIdentifierKey: CFStringRef;
     //static kCTFontFeatureTypeNameKey: CFStringRef;
@@ -828,6 +957,24 @@ fn copy_font() {
     assert_eq!(font.family_name(), "Zapf Dingbats");
 }
 
+#[test]
+fn collection_faces() {
+    let collection = include_bytes!("../tests/fixtures/collection.ttc");
+    let family =
+        |index| new_from_buffer_with_index(collection, index).map(|font| font.family_name());
+    assert_eq!(family(0), Ok("Fixture Sans".to_owned()));
+    assert_eq!(family(1), Ok("Fixture Cmap".to_owned()));
+    assert_eq!(family(2), Err(()));
+
+    // Data that only CoreText can read, such as WOFF, is loaded as a single face.
+    let woff = include_bytes!("../tests/fixtures/outlines.woff");
+    assert_eq!(
+        new_from_buffer_with_index(woff, 0).map(|font| font.family_name()),
+        new_from_buffer(woff).map(|font| font.family_name())
+    );
+    assert!(new_from_buffer_with_index(woff, 1).is_err());
+}
+
 #[cfg(test)]
 fn macos_version() -> (i32, i32, i32) {
     use std::io::Read;
@@ -1177,6 +1324,84 @@ fn system_font_variation() {
     dbg!(ct_var_font_desc);
 }
 
+#[test]
+fn variation_axis_dictionaries() {
+    use core_foundation::boolean::CFBoolean;
+    use core_graphics::font::{
+        kCGFontVariationAxisDefaultValue, kCGFontVariationAxisMaxValue,
+        kCGFontVariationAxisMinValue, kCGFontVariationAxisName,
+    };
+
+    let key = |key: CFStringRef| unsafe { CFString::wrap_under_get_rule(key) };
+    let number = |value: f64| CFNumber::from(value).as_CFType();
+    let name = CFString::new("Weight").as_CFType();
+    let wght = FourCharCode::new(b"wght");
+    let expected = VariationAxis {
+        tag: wght,
+        name: Some("Weight".to_owned()),
+        name_id: None,
+        min_value: 100.,
+        default_value: 400.,
+        max_value: 900.,
+        hidden: false,
+    };
+
+    let ct_axis = unsafe {
+        CFDictionary::from_CFType_pairs(&[
+            (
+                key(kCTFontVariationAxisIdentifierKey),
+                CFNumber::from(wght.to_u32() as i64).as_CFType(),
+            ),
+            (key(kCTFontVariationAxisNameKey), name.clone()),
+            (key(kCTFontVariationAxisMinimumValueKey), number(100.)),
+            (key(kCTFontVariationAxisDefaultValueKey), number(400.)),
+            (key(kCTFontVariationAxisMaximumValueKey), number(900.)),
+            (
+                key(kCTFontVariationAxisHiddenKey),
+                CFBoolean::true_value().as_CFType(),
+            ),
+        ])
+    };
+    assert_eq!(
+        VariationAxis::from_dictionary(&ct_axis),
+        Some(VariationAxis {
+            hidden: true,
+            ..expected.clone()
+        })
+    );
+    assert_eq!(VariationAxis::from_cg_dictionary(&ct_axis, wght), None);
+
+    let cg_axis = unsafe {
+        CFDictionary::from_CFType_pairs(&[
+            (key(kCGFontVariationAxisName), name),
+            (key(kCGFontVariationAxisMinValue), number(100.)),
+            (key(kCGFontVariationAxisDefaultValue), number(400.)),
+            (key(kCGFontVariationAxisMaxValue), number(900.)),
+        ])
+    };
+    assert_eq!(
+        VariationAxis::from_cg_dictionary(&cg_axis, wght),
+        Some(expected)
+    );
+    assert_eq!(VariationAxis::from_dictionary(&cg_axis), None);
+}
+
+#[test]
+fn cg_font_variation_axes() {
+    use crate::sfnt::cg_font_variation_axes;
+
+    let font = new_ui_font_for_language(kCTFontSystemFontType, 12., None);
+    let summary = |axes: Vec<VariationAxis>| -> Vec<_> {
+        axes.into_iter()
+            .map(|axis| (axis.tag, axis.min_value, axis.default_value, axis.max_value))
+            .collect()
+    };
+    assert_eq!(
+        summary(cg_font_variation_axes(&font.copy_to_CGFont())),
+        summary(font.variation_axes())
+    );
+}
+
 #[test]
 fn ui_font() {
     // pass some garbagey inputs
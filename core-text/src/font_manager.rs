@@ -8,6 +8,7 @@
 // except according to those terms.
 
 use crate::font_descriptor::{CTFontDescriptor, CTFontDescriptorRef};
+use crate::sfnt::FontCollection;
 use core_foundation::array::{CFArray, CFArrayRef};
 use core_foundation::base::TCFType;
 use core_foundation::data::{CFData, CFDataRef};
@@ -32,6 +33,30 @@ pub fn create_font_descriptor(buffer: &[u8]) -> Result<CTFontDescriptor, ()> {
     }
 }
 
+/// Creates a descriptor for the face at `index` of a font collection (`.ttc` or `.otc`), which
+/// CoreText would otherwise only read the first face of.
+///
+/// A buffer holding a single font is treated as a collection of one face. Index 0 also accepts
+/// anything [`create_font_descriptor`] does, such as WOFF data.
+pub fn create_font_descriptor_with_index(
+    buffer: &[u8],
+    index: usize,
+) -> Result<CTFontDescriptor, ()> {
+    let collection = match FontCollection::parse(buffer) {
+        Ok(collection) => collection,
+        Err(_) if index == 0 => return create_font_descriptor(buffer),
+        Err(_) => return Err(()),
+    };
+    if !collection.is_collection() {
+        return match index {
+            0 => create_font_descriptor(buffer),
+            _ => Err(()),
+        };
+    }
+    let face = collection.face(index).ok_or(())?.map_err(|_| ())?;
+    create_font_descriptor(&face.to_sfnt())
+}
+
 pub fn create_font_descriptor_with_data(data: CFData) -> Result<CTFontDescriptor, ()> {
     unsafe {
         let ct_font_descriptor_ref =
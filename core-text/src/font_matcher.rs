@@ -0,0 +1,386 @@
+// Copyright 2026 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Font matching following the [CSS Fonts Level 4] algorithm, over snapshots of font
+//! descriptors.
+//!
+//! The matching itself does not call into CoreText, so it can be used with any set of faces.
+//!
+//! [CSS Fonts Level 4]: https://www.w3.org/TR/css-fonts-4/#font-matching-algorithm
+
+use crate::font_collection;
+use crate::font_descriptor::{
+    kCTFontItalicTrait, kCTFontMonoSpaceTrait, CTFontDescriptor, TraitAccessors,
+};
+use std::cmp::Ordering;
+use std::path::PathBuf;
+
+/// The slant of a face, or the requested slant of a query.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FontSlant {
+    Normal,
+    Italic,
+    /// Slanted by an angle in degrees, where positive angles lean towards the end of the line.
+    Oblique(f64),
+}
+
+impl FontSlant {
+    /// The angle that `font-style: oblique` uses when no angle is given.
+    pub const DEFAULT_OBLIQUE_ANGLE: f64 = 14.;
+}
+
+/// A snapshot of the properties of a face that matching uses.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FaceDescriptor {
+    pub family_name: String,
+    pub style_name: String,
+    /// The CSS weight, from 1 to 1000, where 400 is normal and 700 is bold.
+    pub weight: f64,
+    /// The CSS width as a percentage of the normal width, from 50 to 200.
+    pub width: f64,
+    pub slant: FontSlant,
+    pub monospace: bool,
+    pub path: Option<PathBuf>,
+}
+
+impl FaceDescriptor {
+    /// Takes a snapshot of a descriptor, converting its normalized traits to CSS values with
+    /// [`css_weight`], [`css_width`] and [`css_oblique_angle`].
+    pub fn from_descriptor(descriptor: &CTFontDescriptor) -> FaceDescriptor {
+        let traits = descriptor.traits();
+        let symbolic_traits = traits.symbolic_traits();
+        let slant = if symbolic_traits & kCTFontItalicTrait != 0 {
+            FontSlant::Italic
+        } else if traits.normalized_slant() == 0. {
+            FontSlant::Normal
+        } else {
+            FontSlant::Oblique(css_oblique_angle(traits.normalized_slant()))
+        };
+        FaceDescriptor {
+            family_name: descriptor.family_name(),
+            style_name: descriptor.style_name(),
+            weight: css_weight(traits.normalized_weight()),
+            width: css_width(traits.normalized_width()),
+            slant,
+            monospace: symbolic_traits & kCTFontMonoSpaceTrait != 0,
+            path: descriptor.font_path(),
+        }
+    }
+}
+
+/// The normalized CoreText weights of the `NSFontWeight` constants, and their CSS weights.
+const WEIGHTS: [(f64, f64); 11] = [
+    (-1., 1.),
+    (-0.8, 100.),
+    (-0.6, 200.),
+    (-0.4, 300.),
+    (0., 400.),
+    (0.23, 500.),
+    (0.3, 600.),
+    (0.4, 700.),
+    (0.56, 800.),
+    (0.62, 900.),
+    (1., 1000.),
+];
+
+/// Converts a normalized CoreText weight, from -1 to 1, to a CSS weight by interpolating
+/// between the weights of the `NSFontWeight` constants.
+///
+/// ```
+/// use core_text::font_matcher::css_weight;
+///
+/// assert_eq!(css_weight(0.), 400.);
+/// assert_eq!(css_weight(0.4), 700.);
+/// ```
+pub fn css_weight(normalized: f64) -> f64 {
+    let normalized = normalized.clamp(-1., 1.);
+    let upper = WEIGHTS
+        .iter()
+        .position(|&(from, _)| from >= normalized)
+        .unwrap_or(WEIGHTS.len() - 1)
+        .max(1);
+    let ((from_low, to_low), (from_high, to_high)) = (WEIGHTS[upper - 1], WEIGHTS[upper]);
+    to_low + (to_high - to_low) * (normalized - from_low) / (from_high - from_low)
+}
+
+/// Converts a normalized CoreText width, from -1 to 1, to a CSS width percentage, mapping -1 to
+/// 50% and 1 to 200%.
+pub fn css_width(normalized: f64) -> f64 {
+    let normalized = normalized.clamp(-1., 1.);
+    match normalized < 0. {
+        true => 100. + 50. * normalized,
+        false => 100. + 100. * normalized,
+    }
+}
+
+/// Converts a normalized CoreText slant, from -1 to 1, to an oblique angle, where 1 is 30
+/// degrees.
+pub fn css_oblique_angle(normalized: f64) -> f64 {
+    normalized.clamp(-1., 1.) * 30.
+}
+
+/// A generic font family, which stands for families chosen by the user or the platform.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GenericFamily {
+    Serif,
+    SansSerif,
+    Monospace,
+    Cursive,
+    Fantasy,
+    SystemUi,
+}
+
+/// An entry in the list of families of a query.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FamilyName {
+    Named(String),
+    Generic(GenericFamily),
+}
+
+/// The properties of the face to find, like the `font-family`, `font-weight`, `font-stretch`
+/// and `font-style` properties in CSS.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FontQuery {
+    /// The families to try, in order of preference.
+    pub families: Vec<FamilyName>,
+    pub weight: f64,
+    pub width: f64,
+    pub slant: FontSlant,
+}
+
+impl Default for FontQuery {
+    fn default() -> FontQuery {
+        FontQuery {
+            families: Vec::new(),
+            weight: 400.,
+            width: 100.,
+            slant: FontSlant::Normal,
+        }
+    }
+}
+
+/// Finds the face that best matches a query among a set of faces.
+///
+/// ```
+/// use core_text::font_matcher::{FaceDescriptor, FamilyName, FontMatcher, FontQuery, FontSlant};
+///
+/// let face = |style_name: &str, weight, slant| FaceDescriptor {
+///     family_name: "Fixture Sans".to_owned(),
+///     style_name: style_name.to_owned(),
+///     weight,
+///     width: 100.,
+///     slant,
+///     monospace: false,
+///     path: None,
+/// };
+/// let matcher = FontMatcher::new(vec![
+///     face("Regular", 400., FontSlant::Normal),
+///     face("Bold", 700., FontSlant::Normal),
+///     face("Italic", 400., FontSlant::Italic),
+/// ]);
+/// let query = FontQuery {
+///     families: vec![FamilyName::Named("fixture sans".to_owned())],
+///     weight: 600.,
+///     slant: FontSlant::Italic,
+///     ..FontQuery::default()
+/// };
+/// // The slant is matched before the weight.
+/// assert_eq!(matcher.find_best_match(&query).unwrap().style_name, "Italic");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct FontMatcher {
+    faces: Vec<FaceDescriptor>,
+    generic_families: Vec<(GenericFamily, Vec<String>)>,
+}
+
+impl FontMatcher {
+    pub fn new(faces: Vec<FaceDescriptor>) -> FontMatcher {
+        FontMatcher {
+            faces,
+            generic_families: Vec::new(),
+        }
+    }
+
+    /// Creates a matcher over a snapshot of every font installed on the system.
+    pub fn from_available_fonts() -> FontMatcher {
+        let faces = font_collection::create_for_all_families()
+            .get_descriptors()
+            .map_or_else(Vec::new, |descriptors| {
+                descriptors
+                    .iter()
+                    .map(|descriptor| FaceDescriptor::from_descriptor(&descriptor))
+                    .collect()
+            });
+        FontMatcher::new(faces)
+    }
+
+    pub fn faces(&self) -> &[FaceDescriptor] {
+        &self.faces
+    }
+
+    /// Sets the families that a generic family stands for, in order of preference.
+    ///
+    /// Without this, [`GenericFamily::Monospace`] matches the faces that are marked as
+    /// monospaced, and the other generic families match nothing.
+    pub fn set_generic_family(&mut self, generic: GenericFamily, families: Vec<String>) {
+        self.generic_families.retain(|&(g, _)| g != generic);
+        self.generic_families.push((generic, families));
+    }
+
+    /// Returns the faces of a family, matching names case-insensitively.
+    pub fn family_faces(&self, family: &FamilyName) -> Vec<&FaceDescriptor> {
+        let named = |name: &str| -> Vec<&FaceDescriptor> {
+            self.faces
+                .iter()
+                .filter(|face| face.family_name.eq_ignore_ascii_case(name))
+                .collect()
+        };
+        match family {
+            FamilyName::Named(name) => named(name),
+            FamilyName::Generic(generic) => {
+                match self.generic_families.iter().find(|(g, _)| g == generic) {
+                    Some((_, names)) => names
+                        .iter()
+                        .map(|name| named(name))
+                        .find(|faces| !faces.is_empty())
+                        .unwrap_or_default(),
+                    None if *generic == GenericFamily::Monospace => {
+                        self.faces.iter().filter(|face| face.monospace).collect()
+                    }
+                    None => Vec::new(),
+                }
+            }
+        }
+    }
+
+    /// Returns the best face of the first family in the query that has any faces, or `None`
+    /// if none of them do.
+    pub fn find_best_match(&self, query: &FontQuery) -> Option<&FaceDescriptor> {
+        query
+            .families
+            .iter()
+            .map(|family| self.family_faces(family))
+            .find(|faces| !faces.is_empty())
+            .and_then(|faces| best_face(faces, query))
+    }
+}
+
+/// Narrows the faces of a family down by width, then slant, then weight, as in step 4 of the
+/// CSS algorithm, and returns the first face left.
+pub fn best_face<'a>(
+    faces: Vec<&'a FaceDescriptor>,
+    query: &FontQuery,
+) -> Option<&'a FaceDescriptor> {
+    let faces = narrow(faces, |face| width_key(query.width, face.width));
+    let faces = narrow(faces, |face| slant_key(query.slant, face.slant));
+    let faces = narrow(faces, |face| weight_key(query.weight, face.weight));
+    faces.first().copied()
+}
+
+/// A rank for the group of values that a value falls in, and its distance to the desired value.
+/// Lower keys are better.
+type Key = (u8, f64);
+
+fn compare_keys(a: &Key, b: &Key) -> Ordering {
+    a.0.cmp(&b.0).then(a.1.total_cmp(&b.1))
+}
+
+/// Keeps the faces with the best key.
+fn narrow(
+    faces: Vec<&FaceDescriptor>,
+    key: impl Fn(&FaceDescriptor) -> Key,
+) -> Vec<&FaceDescriptor> {
+    let Some(best) = faces.iter().map(|face| key(face)).min_by(compare_keys) else {
+        return faces;
+    };
+    faces
+        .into_iter()
+        .filter(|face| compare_keys(&key(face), &best) == Ordering::Equal)
+        .collect()
+}
+
+/// Narrower widths are preferred up to 100%, and wider ones above it.
+fn width_key(desired: f64, width: f64) -> Key {
+    match desired <= 100. {
+        true if width <= desired => (0, desired - width),
+        true => (1, width - desired),
+        false if width >= desired => (0, width - desired),
+        false => (1, desired - width),
+    }
+}
+
+/// Between 400 and 500, heavier weights up to 500 are preferred, then lighter ones, then ones
+/// above 500. Below 400 lighter weights are preferred, and above 500 heavier ones.
+fn weight_key(desired: f64, weight: f64) -> Key {
+    if (400. ..=500.).contains(&desired) {
+        if weight >= desired && weight <= 500. {
+            (0, weight - desired)
+        } else if weight < desired {
+            (1, desired - weight)
+        } else {
+            (2, weight - desired)
+        }
+    } else if desired < 400. {
+        match weight <= desired {
+            true => (0, desired - weight),
+            false => (1, weight - desired),
+        }
+    } else {
+        match weight >= desired {
+            true => (0, weight - desired),
+            false => (1, desired - weight),
+        }
+    }
+}
+
+/// Normal faces and queries are treated as oblique at 0 degrees. Italic queries prefer italic
+/// faces, then go through the oblique faces as for the default oblique angle.
+fn slant_key(desired: FontSlant, slant: FontSlant) -> Key {
+    let angle = match slant {
+        FontSlant::Normal => Some(0.),
+        FontSlant::Italic => None,
+        FontSlant::Oblique(angle) => Some(angle),
+    };
+    match desired {
+        FontSlant::Italic => match angle {
+            None => (0, 0.),
+            Some(angle) => {
+                let (rank, distance) = oblique_key(FontSlant::DEFAULT_OBLIQUE_ANGLE, Some(angle));
+                (rank + 1, distance)
+            }
+        },
+        FontSlant::Normal => oblique_key(0., angle),
+        FontSlant::Oblique(desired) => oblique_key(desired, angle),
+    }
+}
+
+/// The order for an oblique query, with `None` for italic faces. Angles on the same side as the
+/// desired one come first, then italic faces, then angles on the other side. Small desired
+/// angles, under 11 degrees, also accept upright faces before italic ones.
+fn oblique_key(desired: f64, angle: Option<f64>) -> Key {
+    let Some(angle) = angle else {
+        return (2, 0.);
+    };
+    let small = desired.abs() < 11.;
+    if desired >= 0. {
+        if angle >= desired {
+            (0, angle - desired)
+        } else if angle > 0. || (small && angle == 0.) {
+            (1, desired - angle)
+        } else {
+            (3, -angle)
+        }
+    } else if angle <= desired {
+        (0, desired - angle)
+    } else if angle < 0. || (small && angle == 0.) {
+        (1, angle - desired)
+    } else {
+        (3, angle)
+    }
+}
@@ -20,8 +20,10 @@ pub mod font;
 pub mod font_collection;
 pub mod font_descriptor;
 pub mod font_manager;
+pub mod font_matcher;
 pub mod frame;
 pub mod framesetter;
 pub mod line;
 pub mod run;
+pub mod sfnt;
 pub mod string_attributes;
@@ -0,0 +1,343 @@
+// Copyright 2026 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Pure-Rust readers for sfnt (TrueType and OpenType) font data.
+//!
+//! These work on whole font files, or on single tables as returned by
+//! [`CTFont::get_font_table`] and `CGFont::copy_table_for_tag`. They do not call into CoreText
+//! and work on any platform.
+//!
+//! With the `woff` feature, the `woff` and `woff2` modules decode web fonts into font files
+//! that can be passed to `new_from_buffer` or `CGFont::from_data_provider`.
+//!
+//! [`CTFont::get_font_table`]: ../font/struct.CTFont.html#method.get_font_table
+
+use core_foundation::four_char_code::FourCharCode;
+use std::error;
+use std::fmt;
+
+pub mod cmap;
+pub mod collection;
+pub mod name;
+pub mod variations;
+#[cfg(feature = "woff")]
+pub mod woff;
+#[cfg(feature = "woff")]
+pub mod woff2;
+
+pub use self::cmap::{CharacterMap, CmapTable, Coverage};
+pub use self::collection::FontCollection;
+pub use self::name::{NameId, NameRecord, NameTable, PlatformId};
+pub use self::variations::{
+    cg_font_variation_axes, normalize_coordinates, AvarTable, FvarTable, NamedInstance, StatTable,
+    VariationAxis,
+};
+
+/// An error produced while reading font data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The data ends before the end of the structure at `offset`.
+    Truncated { offset: usize },
+    /// The data does not start with a known sfnt version.
+    InvalidHeader,
+    /// The table directory entry for `tag` lies outside the data, or the table is malformed.
+    InvalidTable { tag: FourCharCode },
+    /// The face at `index` of a collection lies outside the data, or at the same offset as an
+    /// earlier face.
+    InvalidFace { index: usize },
+    /// The table has a version this crate cannot read.
+    UnsupportedVersion { table: FourCharCode, version: u32 },
+    /// The subtable has a format this crate cannot read.
+    UnsupportedFormat { table: FourCharCode, format: u16 },
+    /// Compressed data in a web font could not be decompressed.
+    Decompression,
+    /// A web font would decode to more than `woff::MAX_DECODED_SIZE` bytes.
+    TooLarge,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Truncated { offset } => {
+                write!(f, "font data is truncated at offset {}", offset)
+            }
+            Error::InvalidHeader => write!(f, "not an sfnt font"),
+            Error::InvalidTable { tag } => write!(f, "table '{}' lies outside the font", tag),
+            Error::InvalidFace { index } => write!(f, "invalid offset for face {}", index),
+            Error::UnsupportedVersion { table, version } => {
+                write!(f, "unsupported '{}' table version {:#x}", table, version)
+            }
+            Error::UnsupportedFormat { table, format } => {
+                write!(f, "unsupported '{}' subtable format {}", table, format)
+            }
+            Error::Decompression => write!(f, "invalid compressed font data"),
+            Error::TooLarge => write!(f, "web font is too large to decode"),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+/// The `version` of a TrueType font with `glyf` outlines.
+pub const VERSION_TRUETYPE: FourCharCode = FourCharCode::from_u32(0x00010000);
+/// The `version` of an OpenType font with `CFF ` or `CFF2` outlines.
+pub const VERSION_OPENTYPE: FourCharCode = FourCharCode::new(b"OTTO");
+/// The `version` of an old-style Apple TrueType font.
+pub const VERSION_APPLE_TRUETYPE: FourCharCode = FourCharCode::new(b"true");
+/// The `version` of an Apple font wrapping a PostScript Type 1 font.
+pub const VERSION_TYPE1: FourCharCode = FourCharCode::new(b"typ1");
+
+const HEAD: FourCharCode = FourCharCode::new(b"head");
+const NAME: FourCharCode = FourCharCode::new(b"name");
+
+/// An entry in the table directory of a font.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TableRecord {
+    pub tag: FourCharCode,
+    pub checksum: u32,
+    /// The offset of the table from the start of the file.
+    pub offset: u32,
+    pub length: u32,
+}
+
+/// The table directory of a single font, borrowing the font data.
+///
+/// ```
+/// use core_text::sfnt::{Error, FontFile};
+///
+/// assert_eq!(FontFile::parse(b"wOFF").unwrap_err(), Error::InvalidHeader);
+/// ```
+#[derive(Clone, Debug)]
+pub struct FontFile<'a> {
+    data: &'a [u8],
+    version: FourCharCode,
+    tables: Vec<TableRecord>,
+}
+
+impl<'a> FontFile<'a> {
+    /// Reads the table directory, checking that every table lies within `data`.
+    pub fn parse(data: &'a [u8]) -> Result<FontFile<'a>, Error> {
+        FontFile::parse_at(data, 0)
+    }
+
+    /// Reads a table directory that starts at `offset`, as the faces of a font collection do.
+    /// Table offsets are relative to the start of `data`.
+    pub fn parse_at(data: &'a [u8], offset: usize) -> Result<FontFile<'a>, Error> {
+        let mut reader = Reader::at(data, offset);
+        let version = FourCharCode::from_u32(reader.u32().map_err(|_| Error::InvalidHeader)?);
+        if ![
+            VERSION_TRUETYPE,
+            VERSION_OPENTYPE,
+            VERSION_APPLE_TRUETYPE,
+            VERSION_TYPE1,
+        ]
+        .contains(&version)
+        {
+            return Err(Error::InvalidHeader);
+        }
+        let count = reader.u16()?;
+        // searchRange, entrySelector and rangeShift can be computed from the count.
+        reader.skip(6)?;
+        let mut tables = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            tables.push(TableRecord {
+                tag: FourCharCode::from_u32(reader.u32()?),
+                checksum: reader.u32()?,
+                offset: reader.u32()?,
+                length: reader.u32()?,
+            });
+        }
+        if let Some(record) = tables
+            .iter()
+            .find(|record| record.offset as u64 + record.length as u64 > data.len() as u64)
+        {
+            return Err(Error::InvalidTable { tag: record.tag });
+        }
+        Ok(FontFile {
+            data,
+            version,
+            tables,
+        })
+    }
+
+    /// Returns the sfnt version, one of the `VERSION_*` constants.
+    pub fn version(&self) -> FourCharCode {
+        self.version
+    }
+
+    pub fn tables(&self) -> &[TableRecord] {
+        &self.tables
+    }
+
+    /// Returns the bytes of the table with `tag`, if the font has one.
+    pub fn table_data(&self, tag: impl Into<FourCharCode>) -> Option<&'a [u8]> {
+        let tag = tag.into();
+        let record = self.tables.iter().find(|record| record.tag == tag)?;
+        let start = record.offset as usize;
+        Some(&self.data[start..start + record.length as usize])
+    }
+
+    /// Parses the `name` table, if the font has a valid one.
+    pub fn name_table(&self) -> Option<NameTable> {
+        NameTable::parse(self.table_data(NAME)?).ok()
+    }
+
+    /// Writes the font as a standalone sfnt file, such as one face of a collection.
+    ///
+    /// The tables are written in directory order, each padded to four bytes, and the table
+    /// checksums and the checksum adjustment in `head` are recomputed for the new file.
+    pub fn to_sfnt(&self) -> Vec<u8> {
+        let tables: Vec<_> = self
+            .tables
+            .iter()
+            .map(|record| {
+                let start = record.offset as usize;
+                (
+                    record.tag,
+                    &self.data[start..start + record.length as usize],
+                )
+            })
+            .collect();
+        write_sfnt(self.version, &tables)
+    }
+}
+
+/// Writes a font file with `tables` in the given order, computing the checksums and the
+/// checksum adjustment in `head`.
+pub(crate) fn write_sfnt(version: FourCharCode, tables: &[(FourCharCode, &[u8])]) -> Vec<u8> {
+    let mut font = Vec::new();
+    write_offset_table(&mut font, version, tables.len());
+    let mut offset = 12 + 16 * tables.len();
+    let mut head_offset = None;
+    for &(tag, data) in tables {
+        if tag == HEAD && data.len() >= 12 {
+            head_offset = Some(offset);
+        }
+        write_table_record(&mut font, tag, data, offset);
+        offset += (data.len() + 3) & !3;
+    }
+    for &(_, data) in tables {
+        font.extend_from_slice(data);
+        font.resize((font.len() + 3) & !3, 0);
+    }
+
+    if let Some(head) = head_offset {
+        font[head + 8..head + 12].fill(0);
+        let adjustment = 0xB1B0AFBAu32.wrapping_sub(checksum(&font));
+        font[head + 8..head + 12].copy_from_slice(&adjustment.to_be_bytes());
+    }
+    font
+}
+
+/// Writes the header of a table directory with `count` entries.
+pub(crate) fn write_offset_table(out: &mut Vec<u8>, version: FourCharCode, count: usize) {
+    // The largest power of two that is at most the count, and its log2.
+    let (search_range, entry_selector) = match count {
+        0 => (0, 0),
+        _ => {
+            let entry_selector = usize::BITS - 1 - count.leading_zeros();
+            (16 << entry_selector, entry_selector as usize)
+        }
+    };
+    out.extend_from_slice(&version.to_bytes());
+    for value in [
+        count,
+        search_range,
+        entry_selector,
+        count * 16 - search_range,
+    ] {
+        out.extend_from_slice(&(value as u16).to_be_bytes());
+    }
+}
+
+/// Writes a table directory entry for `data` stored at `offset`.
+pub(crate) fn write_table_record(out: &mut Vec<u8>, tag: FourCharCode, data: &[u8], offset: usize) {
+    // The checksum of `head` is taken with its checksumAdjustment, at offset 8, set to zero.
+    let mut sum = checksum(data);
+    if tag == HEAD && data.len() >= 12 {
+        sum = sum.wrapping_sub(checksum(&data[8..12]));
+    }
+    out.extend_from_slice(&tag.to_bytes());
+    out.extend_from_slice(&sum.to_be_bytes());
+    out.extend_from_slice(&(offset as u32).to_be_bytes());
+    out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+}
+
+/// Computes the checksum of a table or font: the sum of its big-endian `u32`s, with the data
+/// padded with zeros to a multiple of four bytes.
+pub fn checksum(data: &[u8]) -> u32 {
+    data.chunks(4).fold(0u32, |sum, chunk| {
+        let mut word = [0; 4];
+        word[..chunk.len()].copy_from_slice(chunk);
+        sum.wrapping_add(u32::from_be_bytes(word))
+    })
+}
+
+/// A cursor over big-endian font data.
+#[derive(Clone)]
+pub(crate) struct Reader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Reader<'a> {
+        Reader { data, offset: 0 }
+    }
+
+    pub(crate) fn at(data: &'a [u8], offset: usize) -> Reader<'a> {
+        Reader { data, offset }
+    }
+
+    pub(crate) fn bytes(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        let bytes = slice(self.data, self.offset, len)?;
+        self.offset += len;
+        Ok(bytes)
+    }
+
+    pub(crate) fn skip(&mut self, len: usize) -> Result<(), Error> {
+        self.bytes(len).map(|_| ())
+    }
+
+    pub(crate) fn u8(&mut self) -> Result<u8, Error> {
+        self.bytes(1).map(|b| b[0])
+    }
+
+    pub(crate) fn u16(&mut self) -> Result<u16, Error> {
+        self.bytes(2).map(|b| u16::from_be_bytes([b[0], b[1]]))
+    }
+
+    pub(crate) fn u24(&mut self) -> Result<u32, Error> {
+        self.bytes(3)
+            .map(|b| u32::from_be_bytes([0, b[0], b[1], b[2]]))
+    }
+
+    pub(crate) fn u32(&mut self) -> Result<u32, Error> {
+        self.bytes(4)
+            .map(|b| u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    /// Reads a signed 16.16 fixed-point number.
+    pub(crate) fn fixed(&mut self) -> Result<f64, Error> {
+        self.u32().map(|v| v as i32 as f64 / 65536.)
+    }
+
+    /// Reads a signed 2.14 fixed-point number.
+    pub(crate) fn f2dot14(&mut self) -> Result<f64, Error> {
+        self.u16().map(|v| v as i16 as f64 / 16384.)
+    }
+}
+
+/// Returns `len` bytes of `data` starting at `offset`.
+pub(crate) fn slice(data: &[u8], offset: usize, len: usize) -> Result<&[u8], Error> {
+    offset
+        .checked_add(len)
+        .and_then(|end| data.get(offset..end))
+        .ok_or(Error::Truncated { offset })
+}
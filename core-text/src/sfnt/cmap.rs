@@ -0,0 +1,612 @@
+// Copyright 2026 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The `cmap` table, which maps characters to glyphs.
+
+use super::{Error, PlatformId, Reader};
+use core_foundation::four_char_code::FourCharCode;
+use core_graphics::font::CGGlyph;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+
+const TAG: FourCharCode = FourCharCode::new(b"cmap");
+
+const MAX_CODE_POINT: u32 = char::MAX as u32;
+const SURROGATES: RangeInclusive<u32> = 0xD800..=0xDFFF;
+
+/// An entry in the `cmap` table header, describing one subtable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EncodingRecord {
+    pub platform_id: PlatformId,
+    pub encoding_id: u16,
+    pub format: u16,
+    /// The offset of the subtable from the start of the `cmap` table.
+    pub offset: u32,
+}
+
+impl EncodingRecord {
+    /// Returns how much to prefer this subtable as the Unicode character map, lower being better,
+    /// or `None` if it does not map Unicode characters.
+    fn unicode_rank(&self) -> Option<u8> {
+        match (self.platform_id, self.encoding_id, self.format) {
+            (PlatformId::WINDOWS, 10, 12) | (PlatformId::UNICODE, 4, 12) => Some(0),
+            (PlatformId::UNICODE, 6, 13) => Some(1),
+            (PlatformId::WINDOWS, 1, _) | (PlatformId::UNICODE, 0..=3, _) => Some(2),
+            (PlatformId::WINDOWS, 0, _) => Some(3),
+            _ => None,
+        }
+    }
+}
+
+/// The header of a `cmap` table, borrowing the table data.
+#[derive(Clone, Debug)]
+pub struct CmapTable<'a> {
+    data: &'a [u8],
+    records: Vec<EncodingRecord>,
+}
+
+impl<'a> CmapTable<'a> {
+    /// Reads the encoding records of a `cmap` table. The subtables are read on demand.
+    pub fn parse(data: &'a [u8]) -> Result<CmapTable<'a>, Error> {
+        let mut reader = Reader::new(data);
+        let version = reader.u16()?;
+        if version != 0 {
+            return Err(Error::UnsupportedVersion {
+                table: TAG,
+                version: version.into(),
+            });
+        }
+        let count = reader.u16()?;
+        let mut records = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let platform_id = PlatformId(reader.u16()?);
+            let encoding_id = reader.u16()?;
+            let offset = reader.u32()?;
+            let format = Reader::at(data, offset as usize).u16()?;
+            records.push(EncodingRecord {
+                platform_id,
+                encoding_id,
+                format,
+                offset,
+            });
+        }
+        Ok(CmapTable { data, records })
+    }
+
+    pub fn records(&self) -> &[EncodingRecord] {
+        &self.records
+    }
+
+    /// Reads a format 4, 6, 12 or 13 subtable as a map from the codes of its encoding to glyphs.
+    ///
+    /// For a subtable that is not a Unicode encoding, the codes are returned as `char`s as they
+    /// are; a Mac Roman subtable maps `'\u{8E}'` rather than `'é'`.
+    pub fn subtable(&self, record: &EncodingRecord) -> Result<CharacterMap, Error> {
+        let mut reader = Reader::at(self.data, record.offset as usize);
+        let mut builder = SegmentBuilder::default();
+        match record.format {
+            4 => read_format4(self.data, record.offset as usize, &mut builder)?,
+            6 => {
+                reader.skip(6)?;
+                let first = reader.u16()? as u32;
+                let count = reader.u16()? as u32;
+                for code in first..first + count {
+                    builder.push(code, code, reader.u16()?, false);
+                }
+            }
+            12 | 13 => {
+                reader.skip(12)?;
+                let count = reader.u32()?;
+                for _ in 0..count {
+                    let start = reader.u32()?;
+                    let end = reader.u32()?;
+                    let glyph = reader.u32()?;
+                    if let Ok(glyph) = CGGlyph::try_from(glyph) {
+                        builder.push(start, end, glyph, record.format == 13);
+                    }
+                }
+            }
+            format => return Err(Error::UnsupportedFormat { table: TAG, format }),
+        }
+        Ok(CharacterMap {
+            segments: builder.finish(),
+            variations: Vec::new(),
+        })
+    }
+
+    /// Reads the best Unicode subtable, preferring ones that cover all of Unicode, along with
+    /// any format 14 variation sequences.
+    ///
+    /// Returns an empty map if the table has no Unicode subtable in a supported format.
+    pub fn character_map(&self) -> Result<CharacterMap, Error> {
+        let mut candidates: Vec<_> = self
+            .records
+            .iter()
+            .filter(|record| matches!(record.format, 4 | 6 | 12 | 13))
+            .filter_map(|record| Some((record.unicode_rank()?, record)))
+            .collect();
+        candidates.sort_by_key(|&(rank, _)| rank);
+        let mut map = match candidates.first() {
+            Some((_, record)) => self.subtable(record)?,
+            None => CharacterMap::default(),
+        };
+        let variations = self.records.iter().find(|record| {
+            record.platform_id == PlatformId::UNICODE
+                && record.encoding_id == 5
+                && record.format == 14
+        });
+        if let Some(record) = variations {
+            map.variations = read_format14(self.data, record.offset as usize)?;
+        }
+        Ok(map)
+    }
+}
+
+/// Consecutive characters mapped to consecutive glyphs, or all to the same glyph.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Segment {
+    start: u32,
+    end: u32,
+    glyph: CGGlyph,
+    constant: bool,
+}
+
+impl Segment {
+    fn glyph(&self, code: u32) -> CGGlyph {
+        if self.constant {
+            self.glyph
+        } else {
+            self.glyph + (code - self.start) as CGGlyph
+        }
+    }
+
+    fn compare(&self, code: u32) -> Ordering {
+        if code < self.start {
+            Ordering::Greater
+        } else if code > self.end {
+            Ordering::Less
+        } else {
+            Ordering::Equal
+        }
+    }
+}
+
+/// Collects segments, merging runs of single characters and dropping unmapped characters,
+/// surrogates and code points beyond Unicode.
+#[derive(Default)]
+struct SegmentBuilder {
+    segments: Vec<Segment>,
+}
+
+impl SegmentBuilder {
+    fn push(&mut self, start: u32, end: u32, glyph: CGGlyph, constant: bool) {
+        let mut end = end.min(MAX_CODE_POINT);
+        if !constant {
+            // Leave out characters whose glyph would overflow.
+            end = end.min(start.saturating_add((CGGlyph::MAX - glyph) as u32));
+        }
+        if glyph == 0 {
+            // Only the first character is unmapped when the glyphs count up from zero.
+            if !constant && start < end {
+                self.push(start + 1, end, 1, false);
+            }
+            return;
+        }
+        if start > end {
+            return;
+        }
+        if start <= *SURROGATES.end() && end >= *SURROGATES.start() {
+            if start < *SURROGATES.start() {
+                self.push(start, SURROGATES.start() - 1, glyph, constant);
+            }
+            if end > *SURROGATES.end() {
+                let after = SURROGATES.end() + 1;
+                let glyph = if constant {
+                    glyph
+                } else {
+                    glyph + (after - start) as CGGlyph
+                };
+                self.push(after, end, glyph, constant);
+            }
+            return;
+        }
+        if let Some(last) = self.segments.last_mut() {
+            let extends = last.end + 1 == start
+                && last.constant == constant
+                && if constant {
+                    last.glyph == glyph
+                } else {
+                    last.glyph(last.end) as u32 + 1 == glyph as u32
+                };
+            if extends {
+                last.end = end;
+                return;
+            }
+        }
+        self.segments.push(Segment {
+            start,
+            end,
+            glyph,
+            constant,
+        });
+    }
+
+    /// Sorts the segments, giving earlier segments precedence where they overlap.
+    fn finish(self) -> Vec<Segment> {
+        let mut segments = self.segments;
+        segments.sort_by_key(|segment| segment.start);
+        let mut result: Vec<Segment> = Vec::with_capacity(segments.len());
+        for mut segment in segments {
+            if let Some(last) = result.last() {
+                if segment.end <= last.end {
+                    continue;
+                }
+                if segment.start <= last.end {
+                    let skipped = last.end + 1 - segment.start;
+                    segment.start += skipped;
+                    if !segment.constant {
+                        segment.glyph += skipped as CGGlyph;
+                    }
+                }
+            }
+            result.push(segment);
+        }
+        result
+    }
+}
+
+fn read_format4(data: &[u8], offset: usize, builder: &mut SegmentBuilder) -> Result<(), Error> {
+    // The length is often wrong in fonts with large glyph arrays, so it is not checked.
+    let count = Reader::at(data, offset + 6).u16()? as usize / 2;
+    let ends = offset + 14;
+    let starts = ends + 2 * count + 2;
+    let deltas = starts + 2 * count;
+    let range_offsets = deltas + 2 * count;
+    let u16_at = |offset: usize| Reader::at(data, offset).u16();
+    // The characters that earlier segments map, so that overlapping segments in a hostile font
+    // cannot make the glyph array be read more than once per character.
+    let mut seen = vec![0u64; 0x10000 / 64];
+    for i in 0..count {
+        let end = u16_at(ends + 2 * i)? as u32;
+        let start = u16_at(starts + 2 * i)? as u32;
+        let delta = u16_at(deltas + 2 * i)?;
+        let range_offset = u16_at(range_offsets + 2 * i)? as usize;
+        // The final 0xFFFF segment only marks the end of the table.
+        let end = end.min(0xFFFE);
+        if start > end {
+            continue;
+        }
+        if range_offset == 0 {
+            let glyph = (start as u16).wrapping_add(delta);
+            builder.push(start, end, glyph, false);
+            // Glyph ids wrap around from 0xFFFF to 0 within the segment.
+            let wrap = start + (0x10000 - glyph as u32);
+            if wrap <= end {
+                builder.push(wrap, end, 0, false);
+            }
+            mark_seen(&mut seen, start, end);
+        } else {
+            let glyphs = range_offsets + 2 * i + range_offset;
+            let mut code = start;
+            while code <= end {
+                let word = &mut seen[code as usize / 64];
+                if *word == u64::MAX {
+                    code = (code | 63) + 1;
+                    continue;
+                }
+                let bit = 1 << (code % 64);
+                if *word & bit == 0 {
+                    *word |= bit;
+                    let glyph = u16_at(glyphs + 2 * (code - start) as usize)?;
+                    if glyph != 0 {
+                        builder.push(code, code, glyph.wrapping_add(delta), false);
+                    }
+                }
+                code += 1;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn mark_seen(seen: &mut [u64], start: u32, end: u32) {
+    for word in start / 64..=end / 64 {
+        let low = if word == start / 64 { start % 64 } else { 0 };
+        let high = if word == end / 64 { end % 64 } else { 63 };
+        seen[word as usize] |= (u64::MAX >> (63 - high)) & (u64::MAX << low);
+    }
+}
+
+/// The glyphs for the variation sequences with one variation selector.
+///
+/// Several selectors can share the same default and non-default tables, so the tables are
+/// shared rather than read again for each selector.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct VariationSelector {
+    selector: u32,
+    /// Ranges of characters that use their default glyph with this selector.
+    default: Arc<[RangeInclusive<u32>]>,
+    /// Characters that use a specific glyph with this selector, sorted by character.
+    glyphs: Arc<[(u32, CGGlyph)]>,
+}
+
+fn read_format14(data: &[u8], offset: usize) -> Result<Vec<VariationSelector>, Error> {
+    let mut reader = Reader::at(data, offset + 2);
+    // Every entry of a default or non-default table takes at least four bytes of the subtable,
+    // which bounds how many entries the distinct tables can hold between them.
+    let mut budget = reader.u32()? as usize / 4;
+    let count = reader.u32()?;
+    let mut selectors = Vec::with_capacity(count.min(256) as usize);
+    let mut defaults = HashMap::new();
+    let mut glyph_tables = HashMap::new();
+    for _ in 0..count {
+        let selector = reader.u24()?;
+        let default_offset = reader.u32()? as usize;
+        let glyphs_offset = reader.u32()? as usize;
+        let default = match defaults.get(&default_offset) {
+            Some(default) => Arc::clone(default),
+            None => {
+                let mut default = Vec::new();
+                if default_offset != 0 {
+                    let mut reader = Reader::at(data, offset + default_offset);
+                    let count = reader.u32()? as usize;
+                    budget = budget.checked_sub(count).ok_or(Error::Truncated {
+                        offset: offset + default_offset,
+                    })?;
+                    for _ in 0..count {
+                        let start = reader.u24()?;
+                        default.push(start..=start + reader.u8()? as u32);
+                    }
+                }
+                default.sort_by_key(|range| *range.start());
+                let default: Arc<[_]> = default.into();
+                defaults.insert(default_offset, Arc::clone(&default));
+                default
+            }
+        };
+        let glyphs = match glyph_tables.get(&glyphs_offset) {
+            Some(glyphs) => Arc::clone(glyphs),
+            None => {
+                let mut glyphs = Vec::new();
+                if glyphs_offset != 0 {
+                    let mut reader = Reader::at(data, offset + glyphs_offset);
+                    let count = reader.u32()? as usize;
+                    budget = budget.checked_sub(count).ok_or(Error::Truncated {
+                        offset: offset + glyphs_offset,
+                    })?;
+                    for _ in 0..count {
+                        glyphs.push((reader.u24()?, reader.u16()?));
+                    }
+                }
+                glyphs.sort_by_key(|&(code, _)| code);
+                let glyphs: Arc<[_]> = glyphs.into();
+                glyph_tables.insert(glyphs_offset, Arc::clone(&glyphs));
+                glyphs
+            }
+        };
+        selectors.push(VariationSelector {
+            selector,
+            default,
+            glyphs,
+        });
+    }
+    selectors.sort_by_key(|selector| selector.selector);
+    Ok(selectors)
+}
+
+/// A map from characters to glyphs, read from a `cmap` table.
+///
+/// ```
+/// use core_text::sfnt::CharacterMap;
+///
+/// // A format 6 subtable mapping 'A' and 'B' to glyphs 3 and 4.
+/// let data = [
+///     0, 0, 0, 1, 0, 3, 0, 1, 0, 0, 0, 12, // Header with a (0, 3) encoding record.
+///     0, 6, 0, 14, 0, 0, 0, 0x41, 0, 2, 0, 3, 0, 4,
+/// ];
+/// let map = CharacterMap::parse(&data).unwrap();
+/// assert_eq!(map.glyph('B'), Some(4));
+/// assert_eq!(map.glyph('C'), None);
+/// assert!(map.coverage().contains('A'));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CharacterMap {
+    segments: Vec<Segment>,
+    variations: Vec<VariationSelector>,
+}
+
+impl CharacterMap {
+    /// Reads the best Unicode character map of a `cmap` table.
+    ///
+    /// This is a shorthand for [`CmapTable::parse`] followed by [`CmapTable::character_map`].
+    ///
+    /// [`CmapTable::parse`]: struct.CmapTable.html#method.parse
+    /// [`CmapTable::character_map`]: struct.CmapTable.html#method.character_map
+    pub fn parse(data: &[u8]) -> Result<CharacterMap, Error> {
+        CmapTable::parse(data)?.character_map()
+    }
+
+    pub fn glyph(&self, c: char) -> Option<CGGlyph> {
+        let code = c as u32;
+        let i = self
+            .segments
+            .binary_search_by(|segment| segment.compare(code))
+            .ok()?;
+        Some(self.segments[i].glyph(code))
+    }
+
+    /// Returns the glyph for `c` followed by the variation selector `selector`, such as
+    /// `'\u{FE0F}'`.
+    ///
+    /// Returns `None` if the font has no glyph for the sequence, in which case the glyph for `c`
+    /// alone should be used.
+    pub fn variation_glyph(&self, c: char, selector: char) -> Option<CGGlyph> {
+        let code = c as u32;
+        let i = self
+            .variations
+            .binary_search_by_key(&(selector as u32), |v| v.selector)
+            .ok()?;
+        let variation = &self.variations[i];
+        if variation.default.iter().any(|range| range.contains(&code)) {
+            return self.glyph(c);
+        }
+        let i = variation
+            .glyphs
+            .binary_search_by_key(&code, |&(code, _)| code)
+            .ok()?;
+        Some(variation.glyphs[i].1)
+    }
+
+    /// Returns the variation selectors that have glyphs in the font.
+    pub fn variation_selectors(&self) -> impl Iterator<Item = char> + '_ {
+        self.variations
+            .iter()
+            .filter_map(|variation| char::from_u32(variation.selector))
+    }
+
+    /// Returns the mapped characters and their glyphs, in order of the characters.
+    pub fn iter(&self) -> impl Iterator<Item = (char, CGGlyph)> + '_ {
+        self.segments.iter().flat_map(|segment| {
+            (segment.start..=segment.end)
+                .filter_map(move |code| Some((char::from_u32(code)?, segment.glyph(code))))
+        })
+    }
+
+    /// Returns the number of mapped characters.
+    pub fn len(&self) -> usize {
+        self.segments
+            .iter()
+            .map(|segment| (segment.end - segment.start) as usize + 1)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Returns the set of mapped characters.
+    pub fn coverage(&self) -> Coverage {
+        let mut ranges: Vec<RangeInclusive<u32>> = Vec::new();
+        for segment in &self.segments {
+            match ranges.last_mut() {
+                Some(last) if last.end() + 1 == segment.start => {
+                    *last = *last.start()..=segment.end;
+                }
+                _ => ranges.push(segment.start..=segment.end),
+            }
+        }
+        Coverage { ranges }
+    }
+}
+
+/// A set of characters, such as the characters a font has glyphs for.
+///
+/// ```
+/// use core_text::sfnt::Coverage;
+///
+/// let latin: Coverage = ('a'..='z').chain('A'..='Z').collect();
+/// assert_eq!(latin.ranges().len(), 2);
+/// assert_eq!(latin.missing("Añob").collect::<String>(), "ñ");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Coverage {
+    /// Sorted, non-adjacent ranges that never include surrogates.
+    ranges: Vec<RangeInclusive<u32>>,
+}
+
+impl Coverage {
+    pub fn contains(&self, c: char) -> bool {
+        let code = c as u32;
+        self.ranges
+            .binary_search_by(|range| {
+                if code < *range.start() {
+                    Ordering::Greater
+                } else if code > *range.end() {
+                    Ordering::Less
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .is_ok()
+    }
+
+    /// Returns whether every character of `text` is in the set.
+    pub fn contains_all(&self, text: &str) -> bool {
+        text.chars().all(|c| self.contains(c))
+    }
+
+    /// Returns the characters of `text` that are not in the set, in order.
+    pub fn missing<'a>(&'a self, text: &'a str) -> impl Iterator<Item = char> + 'a {
+        text.chars().filter(move |&c| !self.contains(c))
+    }
+
+    /// Returns the sorted, non-adjacent ranges of characters in the set.
+    pub fn ranges(&self) -> Vec<RangeInclusive<char>> {
+        self.ranges
+            .iter()
+            .map(|range| char_at(*range.start())..=char_at(*range.end()))
+            .collect()
+    }
+
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.ranges
+            .iter()
+            .flat_map(|range| char_at(*range.start())..=char_at(*range.end()))
+    }
+
+    /// Returns the number of characters in the set.
+    pub fn len(&self) -> usize {
+        self.ranges
+            .iter()
+            .map(|range| (range.end() - range.start()) as usize + 1)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Returns the characters in either set.
+    pub fn union(&self, other: &Coverage) -> Coverage {
+        let mut all: Vec<_> = self.ranges.iter().chain(&other.ranges).cloned().collect();
+        all.sort_by_key(|range| *range.start());
+        Coverage::from_sorted(all)
+    }
+
+    /// Merges overlapping and adjacent ranges, which must be sorted by their start.
+    fn from_sorted(sorted: Vec<RangeInclusive<u32>>) -> Coverage {
+        let mut ranges: Vec<RangeInclusive<u32>> = Vec::with_capacity(sorted.len());
+        for range in sorted {
+            match ranges.last_mut() {
+                Some(last) if *range.start() <= last.end() + 1 => {
+                    if range.end() > last.end() {
+                        *last = *last.start()..=*range.end();
+                    }
+                }
+                _ => ranges.push(range),
+            }
+        }
+        Coverage { ranges }
+    }
+}
+
+impl FromIterator<char> for Coverage {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Coverage {
+        let mut codes: Vec<u32> = iter.into_iter().map(u32::from).collect();
+        codes.sort_unstable();
+        Coverage::from_sorted(codes.into_iter().map(|code| code..=code).collect())
+    }
+}
+
+/// Converts a code point that is known not to be a surrogate.
+fn char_at(code: u32) -> char {
+    char::from_u32(code).unwrap()
+}
@@ -0,0 +1,112 @@
+// Copyright 2026 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! TrueType and OpenType font collections (`.ttc` and `.otc` files), which hold several faces
+//! that can share tables.
+
+use super::{Error, FontFile, Reader};
+use core_foundation::four_char_code::FourCharCode;
+
+/// The tag at the start of a font collection.
+pub const TAG_TTCF: FourCharCode = FourCharCode::new(b"ttcf");
+
+/// The faces of a font collection, or the single face of a plain sfnt file.
+///
+/// ```
+/// use core_text::sfnt::{Error, FontCollection};
+///
+/// assert_eq!(FontCollection::parse(b"ttcf").unwrap_err(), Error::Truncated { offset: 4 });
+/// assert_eq!(FontCollection::parse(b"wOF2").unwrap_err(), Error::InvalidHeader);
+/// ```
+#[derive(Clone, Debug)]
+pub struct FontCollection<'a> {
+    data: &'a [u8],
+    is_collection: bool,
+    offsets: Vec<usize>,
+}
+
+impl<'a> FontCollection<'a> {
+    /// Reads the offsets of the faces, checking that each one lies within `data` and that no
+    /// two faces share an offset.
+    ///
+    /// The table directory of a face is only read when the face is asked for, so one broken
+    /// face does not prevent reading the others. Data that is a single font rather than a
+    /// collection is read as a collection of one face, whose table directory is checked here.
+    pub fn parse(data: &'a [u8]) -> Result<FontCollection<'a>, Error> {
+        let mut reader = Reader::new(data);
+        if reader.u32().ok() != Some(TAG_TTCF.to_u32()) {
+            FontFile::parse(data)?;
+            return Ok(FontCollection {
+                data,
+                is_collection: false,
+                offsets: vec![0],
+            });
+        }
+        let version = reader.u32()?;
+        // Version 2 only adds a `DSIG` table for the collection, which is not needed to read it.
+        if !matches!(version >> 16, 1 | 2) {
+            return Err(Error::UnsupportedVersion {
+                table: TAG_TTCF,
+                version,
+            });
+        }
+        let count = reader.u32()? as usize;
+        // Check the count against the data before allocating for it.
+        if count > (data.len() - 12) / 4 {
+            return Err(Error::Truncated { offset: 12 });
+        }
+        let mut offsets = Vec::with_capacity(count);
+        for index in 0..count {
+            let offset = reader.u32()? as usize;
+            // Every face starts with a 12-byte offset table.
+            if offset.saturating_add(12) > data.len() {
+                return Err(Error::InvalidFace { index });
+            }
+            offsets.push(offset);
+        }
+        let mut sorted: Vec<_> = offsets.iter().enumerate().map(|(i, &o)| (o, i)).collect();
+        sorted.sort_unstable();
+        if let Some(pair) = sorted.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+            return Err(Error::InvalidFace {
+                index: pair[0].1.max(pair[1].1),
+            });
+        }
+        Ok(FontCollection {
+            data,
+            is_collection: true,
+            offsets,
+        })
+    }
+
+    /// Returns whether the data was a collection, rather than a single font.
+    pub fn is_collection(&self) -> bool {
+        self.is_collection
+    }
+
+    /// Reads the table directory of every face, in order.
+    pub fn faces(&self) -> impl Iterator<Item = Result<FontFile<'a>, Error>> + '_ {
+        self.offsets
+            .iter()
+            .map(move |&offset| FontFile::parse_at(self.data, offset))
+    }
+
+    /// Reads the table directory of the face at `index`, counting from zero.
+    pub fn face(&self, index: usize) -> Option<Result<FontFile<'a>, Error>> {
+        let offset = *self.offsets.get(index)?;
+        Some(FontFile::parse_at(self.data, offset))
+    }
+
+    pub fn len(&self) -> usize {
+        self.offsets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.offsets.is_empty()
+    }
+}
@@ -0,0 +1,541 @@
+// Copyright 2026 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The `name` table, which holds the localized names and other strings of a font.
+
+use super::{slice, Error, Reader};
+use core_foundation::four_char_code::FourCharCode;
+use std::collections::BTreeMap;
+
+const TAG: FourCharCode = FourCharCode::new(b"name");
+
+/// The meaning of a name record, such as the family name or the copyright notice.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NameId(pub u16);
+
+impl NameId {
+    pub const COPYRIGHT: NameId = NameId(0);
+    pub const FAMILY: NameId = NameId(1);
+    pub const SUBFAMILY: NameId = NameId(2);
+    pub const UNIQUE_ID: NameId = NameId(3);
+    pub const FULL_NAME: NameId = NameId(4);
+    pub const VERSION: NameId = NameId(5);
+    pub const POSTSCRIPT_NAME: NameId = NameId(6);
+    pub const TRADEMARK: NameId = NameId(7);
+    pub const MANUFACTURER: NameId = NameId(8);
+    pub const DESIGNER: NameId = NameId(9);
+    pub const DESCRIPTION: NameId = NameId(10);
+    pub const VENDOR_URL: NameId = NameId(11);
+    pub const DESIGNER_URL: NameId = NameId(12);
+    pub const LICENSE: NameId = NameId(13);
+    pub const LICENSE_URL: NameId = NameId(14);
+    /// The family name to use when the font has more than the four styles of [`FAMILY`].
+    ///
+    /// [`FAMILY`]: #associatedconstant.FAMILY
+    pub const TYPOGRAPHIC_FAMILY: NameId = NameId(16);
+    pub const TYPOGRAPHIC_SUBFAMILY: NameId = NameId(17);
+    pub const COMPATIBLE_FULL_NAME: NameId = NameId(18);
+    pub const SAMPLE_TEXT: NameId = NameId(19);
+    pub const POSTSCRIPT_CID_NAME: NameId = NameId(20);
+    pub const WWS_FAMILY: NameId = NameId(21);
+    pub const WWS_SUBFAMILY: NameId = NameId(22);
+    pub const LIGHT_BACKGROUND_PALETTE: NameId = NameId(23);
+    pub const DARK_BACKGROUND_PALETTE: NameId = NameId(24);
+    pub const VARIATIONS_POSTSCRIPT_NAME_PREFIX: NameId = NameId(25);
+}
+
+/// The platform of a name record, which determines how its encoding and language IDs are read.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PlatformId(pub u16);
+
+impl PlatformId {
+    pub const UNICODE: PlatformId = PlatformId(0);
+    pub const MACINTOSH: PlatformId = PlatformId(1);
+    /// Deprecated by the OpenType specification, but still found in old fonts.
+    pub const ISO: PlatformId = PlatformId(2);
+    pub const WINDOWS: PlatformId = PlatformId(3);
+    pub const CUSTOM: PlatformId = PlatformId(4);
+}
+
+/// A single string in the `name` table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NameRecord {
+    pub platform_id: PlatformId,
+    pub encoding_id: u16,
+    pub language_id: u16,
+    pub name_id: NameId,
+    /// The string, in the encoding given by the platform and encoding IDs.
+    pub bytes: Vec<u8>,
+}
+
+impl NameRecord {
+    /// Decodes the string, or returns `None` if its encoding is not supported.
+    ///
+    /// UTF-16BE, Mac Roman, ASCII and ISO 8859-1 strings are supported. Malformed UTF-16 is
+    /// decoded with replacement characters.
+    pub fn decode(&self) -> Option<String> {
+        match (self.platform_id, self.encoding_id) {
+            (PlatformId::UNICODE, _) | (PlatformId::WINDOWS, 0 | 1 | 10) | (PlatformId::ISO, 1) => {
+                Some(decode_utf16_be(&self.bytes))
+            }
+            (PlatformId::MACINTOSH, 0) => Some(
+                self.bytes
+                    .iter()
+                    .map(|&b| match b {
+                        0..=0x7F => char::from(b),
+                        _ => MAC_ROMAN[b as usize - 0x80],
+                    })
+                    .collect(),
+            ),
+            // ASCII is a subset of ISO 8859-1, which maps bytes to the same code points.
+            (PlatformId::ISO, 0 | 2) => Some(self.bytes.iter().map(|&b| char::from(b)).collect()),
+            _ => None,
+        }
+    }
+
+    /// Returns how much to prefer this record over others for the same name and language,
+    /// lower being better, or `None` if it cannot be decoded.
+    fn platform_rank(&self) -> Option<u8> {
+        match (self.platform_id, self.encoding_id) {
+            (PlatformId::WINDOWS, 1 | 10) => Some(0),
+            (PlatformId::UNICODE, _) => Some(1),
+            (PlatformId::WINDOWS, 0) => Some(2),
+            (PlatformId::MACINTOSH, 0) => Some(3),
+            (PlatformId::ISO, 0..=2) => Some(4),
+            _ => None,
+        }
+    }
+}
+
+/// A parsed `name` table.
+///
+/// ```
+/// use core_text::sfnt::{NameId, NameTable};
+///
+/// // A format 0 table with one Windows en-US family name.
+/// let mut data = vec![0, 0, 0, 1, 0, 18, 0, 3, 0, 1, 4, 9, 0, 1, 0, 4, 0, 0];
+/// data.extend_from_slice(&[0, b'H', 0, b'i']);
+/// let table = NameTable::parse(&data).unwrap();
+/// assert_eq!(table.best_name(NameId::FAMILY, "fr-FR").as_deref(), Some("Hi"));
+/// assert_eq!(table.language_tag(&table.records()[0]), Some("en-US"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NameTable {
+    records: Vec<NameRecord>,
+    language_tags: Vec<String>,
+}
+
+impl NameTable {
+    /// Parses the bytes of a format 0 or format 1 `name` table.
+    pub fn parse(data: &[u8]) -> Result<NameTable, Error> {
+        let mut reader = Reader::new(data);
+        let version = reader.u16()?;
+        if version > 1 {
+            return Err(Error::UnsupportedVersion {
+                table: TAG,
+                version: version.into(),
+            });
+        }
+        let count = reader.u16()?;
+        let storage = reader.u16()? as usize;
+        let mut records = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let platform_id = PlatformId(reader.u16()?);
+            let encoding_id = reader.u16()?;
+            let language_id = reader.u16()?;
+            let name_id = NameId(reader.u16()?);
+            let length = reader.u16()? as usize;
+            let offset = storage + reader.u16()? as usize;
+            records.push(NameRecord {
+                platform_id,
+                encoding_id,
+                language_id,
+                name_id,
+                bytes: slice(data, offset, length)?.to_vec(),
+            });
+        }
+        let mut language_tags = Vec::new();
+        if version == 1 {
+            let count = reader.u16()?;
+            for _ in 0..count {
+                let length = reader.u16()? as usize;
+                let offset = storage + reader.u16()? as usize;
+                language_tags.push(decode_utf16_be(slice(data, offset, length)?));
+            }
+        }
+        Ok(NameTable {
+            records,
+            language_tags,
+        })
+    }
+
+    /// Returns every record, in the order they appear in the table.
+    pub fn records(&self) -> &[NameRecord] {
+        &self.records
+    }
+
+    /// Returns the BCP 47 language tags stored in a format 1 table.
+    pub fn language_tags(&self) -> &[String] {
+        &self.language_tags
+    }
+
+    /// Returns the BCP 47 language tag of `record`, such as `en-US`, or `None` if the language
+    /// is unknown or not specified.
+    pub fn language_tag<'a>(&'a self, record: &NameRecord) -> Option<&'a str> {
+        let id = record.language_id;
+        match record.platform_id {
+            _ if id >= 0x8000 => self
+                .language_tags
+                .get(id as usize - 0x8000)
+                .map(String::as_str),
+            PlatformId::WINDOWS => WINDOWS_LANGUAGES
+                .binary_search_by_key(&id, |&(id, _)| id)
+                .ok()
+                .map(|i| WINDOWS_LANGUAGES[i].1),
+            PlatformId::MACINTOSH => match id {
+                0..=94 => MAC_LANGUAGES.get(id as usize).copied(),
+                128..=150 => MAC_LANGUAGES_128.get(id as usize - 128).copied(),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns the records for `name_id` that can be decoded.
+    pub fn records_for(&self, name_id: NameId) -> impl Iterator<Item = &NameRecord> {
+        self.records
+            .iter()
+            .filter(move |record| record.name_id == name_id && record.platform_rank().is_some())
+    }
+
+    /// Returns the name for `name_id` that best matches `language`, a BCP 47 tag such as `de-AT`.
+    ///
+    /// An exact match is preferred, then a match of the primary language subtag, then US
+    /// English, any English, a name with no language and finally any name at all. Among names in
+    /// the same language, Windows Unicode records are preferred over Unicode, Macintosh and ISO
+    /// ones.
+    pub fn best_name(&self, name_id: NameId, language: &str) -> Option<String> {
+        let primary = primary_subtag(language);
+        self.records_for(name_id)
+            .min_by_key(|record| {
+                let score = match self.language_tag(record) {
+                    Some(tag) if tag.eq_ignore_ascii_case(language) => 0,
+                    Some(tag) if primary_subtag(tag).eq_ignore_ascii_case(primary) => 1,
+                    Some(tag) if tag.eq_ignore_ascii_case("en-US") => 2,
+                    Some(tag) if primary_subtag(tag).eq_ignore_ascii_case("en") => 3,
+                    None => 4,
+                    Some(_) => 5,
+                };
+                (score, record.platform_rank())
+            })
+            .and_then(NameRecord::decode)
+    }
+
+    /// Returns the names for `name_id` by language tag, choosing the preferred platform when a
+    /// language has several records. Records with an unknown language are left out.
+    pub fn localized_names(&self, name_id: NameId) -> BTreeMap<String, String> {
+        let mut best = BTreeMap::<&str, &NameRecord>::new();
+        for record in self.records_for(name_id) {
+            if let Some(tag) = self.language_tag(record) {
+                let current = best.entry(tag).or_insert(record);
+                if record.platform_rank() < current.platform_rank() {
+                    *current = record;
+                }
+            }
+        }
+        best.into_iter()
+            .filter_map(|(tag, record)| Some((tag.to_owned(), record.decode()?)))
+            .collect()
+    }
+}
+
+fn decode_utf16_be(bytes: &[u8]) -> String {
+    let units = bytes.chunks(2).map(|unit| match *unit {
+        [high, low] => u16::from_be_bytes([high, low]),
+        // An odd trailing byte decodes as an unpaired surrogate.
+        _ => 0xDC00,
+    });
+    char::decode_utf16(units)
+        .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+        .collect()
+}
+
+fn primary_subtag(tag: &str) -> &str {
+    tag.split('-').next().unwrap_or(tag)
+}
+
+/// The characters of bytes 0x80 to 0xFF in Mac Roman.
+const MAC_ROMAN: [char; 128] = [
+    '\u{00C4}', '\u{00C5}', '\u{00C7}', '\u{00C9}', '\u{00D1}', '\u{00D6}', '\u{00DC}', '\u{00E1}',
+    '\u{00E0}', '\u{00E2}', '\u{00E4}', '\u{00E3}', '\u{00E5}', '\u{00E7}', '\u{00E9}', '\u{00E8}',
+    '\u{00EA}', '\u{00EB}', '\u{00ED}', '\u{00EC}', '\u{00EE}', '\u{00EF}', '\u{00F1}', '\u{00F3}',
+    '\u{00F2}', '\u{00F4}', '\u{00F6}', '\u{00F5}', '\u{00FA}', '\u{00F9}', '\u{00FB}', '\u{00FC}',
+    '\u{2020}', '\u{00B0}', '\u{00A2}', '\u{00A3}', '\u{00A7}', '\u{2022}', '\u{00B6}', '\u{00DF}',
+    '\u{00AE}', '\u{00A9}', '\u{2122}', '\u{00B4}', '\u{00A8}', '\u{2260}', '\u{00C6}', '\u{00D8}',
+    '\u{221E}', '\u{00B1}', '\u{2264}', '\u{2265}', '\u{00A5}', '\u{00B5}', '\u{2202}', '\u{2211}',
+    '\u{220F}', '\u{03C0}', '\u{222B}', '\u{00AA}', '\u{00BA}', '\u{03A9}', '\u{00E6}', '\u{00F8}',
+    '\u{00BF}', '\u{00A1}', '\u{00AC}', '\u{221A}', '\u{0192}', '\u{2248}', '\u{2206}', '\u{00AB}',
+    '\u{00BB}', '\u{2026}', '\u{00A0}', '\u{00C0}', '\u{00C3}', '\u{00D5}', '\u{0152}', '\u{0153}',
+    '\u{2013}', '\u{2014}', '\u{201C}', '\u{201D}', '\u{2018}', '\u{2019}', '\u{00F7}', '\u{25CA}',
+    '\u{00FF}', '\u{0178}', '\u{2044}', '\u{20AC}', '\u{2039}', '\u{203A}', '\u{FB01}', '\u{FB02}',
+    '\u{2021}', '\u{00B7}', '\u{201A}', '\u{201E}', '\u{2030}', '\u{00C2}', '\u{00CA}', '\u{00C1}',
+    '\u{00CB}', '\u{00C8}', '\u{00CD}', '\u{00CE}', '\u{00CF}', '\u{00CC}', '\u{00D3}', '\u{00D4}',
+    '\u{F8FF}', '\u{00D2}', '\u{00DA}', '\u{00DB}', '\u{00D9}', '\u{0131}', '\u{02C6}', '\u{02DC}',
+    '\u{00AF}', '\u{02D8}', '\u{02D9}', '\u{02DA}', '\u{00B8}', '\u{02DD}', '\u{02DB}', '\u{02C7}',
+];
+
+/// Macintosh language IDs 0 to 94.
+const MAC_LANGUAGES: [&str; 95] = [
+    "en", "fr", "de", "it", "nl", "sv", "es", "da", "pt", "nb", "he", "ja", "ar", "fi", "el", "is",
+    "mt", "tr", "hr", "zh-Hant", "ur", "hi", "th", "ko", "lt", "pl", "hu", "et", "lv", "se", "fo",
+    "fa", "ru", "zh-Hans", "nl-BE", "ga", "sq", "ro", "cs", "sk", "sl", "yi", "sr", "mk", "bg",
+    "uk", "be", "uz", "kk", "az-Cyrl", "az-Arab", "hy", "ka", "ro-MD", "ky", "tg", "tk", "mn-Mong",
+    "mn-Cyrl", "ps", "ku", "ks", "sd", "bo", "ne", "sa", "mr", "bn", "as", "gu", "pa", "or", "ml",
+    "kn", "ta", "te", "si", "my", "km", "lo", "vi", "id", "tl", "ms", "ms-Arab", "am", "ti", "om",
+    "so", "sw", "rw", "rn", "ny", "mg", "eo",
+];
+
+/// Macintosh language IDs 128 to 150.
+const MAC_LANGUAGES_128: [&str; 23] = [
+    "cy",
+    "eu",
+    "ca",
+    "la",
+    "qu",
+    "gn",
+    "ay",
+    "tt",
+    "ug",
+    "dz",
+    "jv",
+    "su",
+    "gl",
+    "af",
+    "br",
+    "iu",
+    "gd",
+    "gv",
+    "ga",
+    "to",
+    "el-polyton",
+    "kl",
+    "az-Latn",
+];
+
+/// Windows language IDs, sorted by ID.
+const WINDOWS_LANGUAGES: &[(u16, &str)] = &[
+    (0x0401, "ar-SA"),
+    (0x0402, "bg-BG"),
+    (0x0403, "ca-ES"),
+    (0x0404, "zh-TW"),
+    (0x0405, "cs-CZ"),
+    (0x0406, "da-DK"),
+    (0x0407, "de-DE"),
+    (0x0408, "el-GR"),
+    (0x0409, "en-US"),
+    (0x040A, "es-ES"),
+    (0x040B, "fi-FI"),
+    (0x040C, "fr-FR"),
+    (0x040D, "he-IL"),
+    (0x040E, "hu-HU"),
+    (0x040F, "is-IS"),
+    (0x0410, "it-IT"),
+    (0x0411, "ja-JP"),
+    (0x0412, "ko-KR"),
+    (0x0413, "nl-NL"),
+    (0x0414, "nb-NO"),
+    (0x0415, "pl-PL"),
+    (0x0416, "pt-BR"),
+    (0x0417, "rm-CH"),
+    (0x0418, "ro-RO"),
+    (0x0419, "ru-RU"),
+    (0x041A, "hr-HR"),
+    (0x041B, "sk-SK"),
+    (0x041C, "sq-AL"),
+    (0x041D, "sv-SE"),
+    (0x041E, "th-TH"),
+    (0x041F, "tr-TR"),
+    (0x0420, "ur-PK"),
+    (0x0421, "id-ID"),
+    (0x0422, "uk-UA"),
+    (0x0423, "be-BY"),
+    (0x0424, "sl-SI"),
+    (0x0425, "et-EE"),
+    (0x0426, "lv-LV"),
+    (0x0427, "lt-LT"),
+    (0x0428, "tg-Cyrl-TJ"),
+    (0x0429, "fa-IR"),
+    (0x042A, "vi-VN"),
+    (0x042B, "hy-AM"),
+    (0x042C, "az-Latn-AZ"),
+    (0x042D, "eu-ES"),
+    (0x042E, "hsb-DE"),
+    (0x042F, "mk-MK"),
+    (0x0432, "tn-ZA"),
+    (0x0434, "xh-ZA"),
+    (0x0435, "zu-ZA"),
+    (0x0436, "af-ZA"),
+    (0x0437, "ka-GE"),
+    (0x0438, "fo-FO"),
+    (0x0439, "hi-IN"),
+    (0x043A, "mt-MT"),
+    (0x043B, "se-NO"),
+    (0x043E, "ms-MY"),
+    (0x043F, "kk-KZ"),
+    (0x0440, "ky-KG"),
+    (0x0441, "sw-KE"),
+    (0x0442, "tk-TM"),
+    (0x0443, "uz-Latn-UZ"),
+    (0x0444, "tt-RU"),
+    (0x0445, "bn-IN"),
+    (0x0446, "pa-IN"),
+    (0x0447, "gu-IN"),
+    (0x0448, "or-IN"),
+    (0x0449, "ta-IN"),
+    (0x044A, "te-IN"),
+    (0x044B, "kn-IN"),
+    (0x044C, "ml-IN"),
+    (0x044D, "as-IN"),
+    (0x044E, "mr-IN"),
+    (0x044F, "sa-IN"),
+    (0x0450, "mn-MN"),
+    (0x0451, "bo-CN"),
+    (0x0452, "cy-GB"),
+    (0x0453, "km-KH"),
+    (0x0454, "lo-LA"),
+    (0x0456, "gl-ES"),
+    (0x0457, "kok-IN"),
+    (0x045A, "syr-SY"),
+    (0x045B, "si-LK"),
+    (0x045D, "iu-Cans-CA"),
+    (0x045E, "am-ET"),
+    (0x0461, "ne-NP"),
+    (0x0462, "fy-NL"),
+    (0x0463, "ps-AF"),
+    (0x0464, "fil-PH"),
+    (0x0465, "dv-MV"),
+    (0x0468, "ha-Latn-NG"),
+    (0x046A, "yo-NG"),
+    (0x046B, "quz-BO"),
+    (0x046C, "nso-ZA"),
+    (0x046D, "ba-RU"),
+    (0x046E, "lb-LU"),
+    (0x046F, "kl-GL"),
+    (0x0478, "ii-CN"),
+    (0x047A, "arn-CL"),
+    (0x047C, "moh-CA"),
+    (0x047E, "br-FR"),
+    (0x0480, "ug-CN"),
+    (0x0481, "mi-NZ"),
+    (0x0482, "oc-FR"),
+    (0x0483, "co-FR"),
+    (0x0484, "gsw-FR"),
+    (0x0485, "sah-RU"),
+    (0x0486, "qut-GT"),
+    (0x0487, "rw-RW"),
+    (0x0488, "wo-SN"),
+    (0x048C, "prs-AF"),
+    (0x0491, "gd-GB"),
+    (0x0492, "ku-Arab-IQ"),
+    (0x0801, "ar-IQ"),
+    (0x0804, "zh-CN"),
+    (0x0807, "de-CH"),
+    (0x0809, "en-GB"),
+    (0x080A, "es-MX"),
+    (0x080C, "fr-BE"),
+    (0x0810, "it-CH"),
+    (0x0813, "nl-BE"),
+    (0x0814, "nn-NO"),
+    (0x0816, "pt-PT"),
+    (0x081A, "sr-Latn-CS"),
+    (0x081D, "sv-FI"),
+    (0x0820, "ur-IN"),
+    (0x082C, "az-Cyrl-AZ"),
+    (0x082E, "dsb-DE"),
+    (0x083B, "se-SE"),
+    (0x083C, "ga-IE"),
+    (0x083E, "ms-BN"),
+    (0x0843, "uz-Cyrl-UZ"),
+    (0x0850, "mn-Mong-CN"),
+    (0x0851, "bo-BT"),
+    (0x085D, "iu-Latn-CA"),
+    (0x085F, "tzm-Latn-DZ"),
+    (0x086B, "quz-EC"),
+    (0x0C01, "ar-EG"),
+    (0x0C04, "zh-HK"),
+    (0x0C07, "de-AT"),
+    (0x0C09, "en-AU"),
+    (0x0C0A, "es-ES"),
+    (0x0C0C, "fr-CA"),
+    (0x0C1A, "sr-Cyrl-CS"),
+    (0x0C3B, "se-FI"),
+    (0x0C6B, "quz-PE"),
+    (0x1001, "ar-LY"),
+    (0x1004, "zh-SG"),
+    (0x1007, "de-LU"),
+    (0x1009, "en-CA"),
+    (0x100A, "es-GT"),
+    (0x100C, "fr-CH"),
+    (0x101A, "hr-BA"),
+    (0x103B, "smj-NO"),
+    (0x1401, "ar-DZ"),
+    (0x1404, "zh-MO"),
+    (0x1407, "de-LI"),
+    (0x1409, "en-NZ"),
+    (0x140A, "es-CR"),
+    (0x140C, "fr-LU"),
+    (0x141A, "bs-Latn-BA"),
+    (0x143B, "smj-SE"),
+    (0x1801, "ar-MA"),
+    (0x1809, "en-IE"),
+    (0x180A, "es-PA"),
+    (0x180C, "fr-MC"),
+    (0x181A, "sr-Latn-BA"),
+    (0x183B, "sma-NO"),
+    (0x1C01, "ar-TN"),
+    (0x1C09, "en-ZA"),
+    (0x1C0A, "es-DO"),
+    (0x1C1A, "sr-Cyrl-BA"),
+    (0x1C3B, "sma-SE"),
+    (0x2001, "ar-OM"),
+    (0x2009, "en-JM"),
+    (0x200A, "es-VE"),
+    (0x201A, "bs-Cyrl-BA"),
+    (0x203B, "sms-FI"),
+    (0x2401, "ar-YE"),
+    (0x2409, "en-029"),
+    (0x240A, "es-CO"),
+    (0x243B, "smn-FI"),
+    (0x2801, "ar-SY"),
+    (0x2809, "en-BZ"),
+    (0x280A, "es-PE"),
+    (0x2C01, "ar-JO"),
+    (0x2C09, "en-TT"),
+    (0x2C0A, "es-AR"),
+    (0x3001, "ar-LB"),
+    (0x3009, "en-ZW"),
+    (0x300A, "es-EC"),
+    (0x3401, "ar-KW"),
+    (0x3409, "en-PH"),
+    (0x340A, "es-CL"),
+    (0x3801, "ar-AE"),
+    (0x380A, "es-UY"),
+    (0x3C01, "ar-BH"),
+    (0x3C0A, "es-PY"),
+    (0x4001, "ar-QA"),
+    (0x4009, "en-IN"),
+    (0x400A, "es-BO"),
+    (0x4409, "en-MY"),
+    (0x440A, "es-SV"),
+    (0x4809, "en-SG"),
+    (0x480A, "es-HN"),
+    (0x4C0A, "es-NI"),
+    (0x500A, "es-PR"),
+    (0x540A, "es-US"),
+];
@@ -0,0 +1,514 @@
+// Copyright 2026 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Variable font axes and named instances, from the `fvar`, `avar` and `STAT` tables or from
+//! CoreText and CoreGraphics axis dictionaries.
+
+use super::{Error, NameId, NameTable, Reader};
+use crate::font::{
+    kCTFontVariationAxisDefaultValueKey, kCTFontVariationAxisHiddenKey,
+    kCTFontVariationAxisIdentifierKey, kCTFontVariationAxisMaximumValueKey,
+    kCTFontVariationAxisMinimumValueKey, kCTFontVariationAxisNameKey,
+};
+use core_foundation::base::CFType;
+use core_foundation::boolean::CFBoolean;
+use core_foundation::dictionary::CFDictionary;
+use core_foundation::four_char_code::FourCharCode;
+use core_foundation::number::CFNumber;
+use core_foundation::string::{CFString, CFStringRef};
+use core_graphics::font::{
+    kCGFontVariationAxisDefaultValue, kCGFontVariationAxisMaxValue, kCGFontVariationAxisMinValue,
+    kCGFontVariationAxisName, CGFont,
+};
+
+const FVAR: FourCharCode = FourCharCode::new(b"fvar");
+const AVAR: FourCharCode = FourCharCode::new(b"avar");
+const STAT: FourCharCode = FourCharCode::new(b"STAT");
+
+/// The flag of `fvar` axis records for axes that should not be shown to users.
+const HIDDEN_AXIS: u16 = 0x0001;
+
+/// A design axis of a variable font, such as weight or width.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VariationAxis {
+    pub tag: FourCharCode,
+    /// The localized name, if it has been looked up.
+    pub name: Option<String>,
+    /// The `name` table record of the name, when read from an `fvar` table.
+    pub name_id: Option<NameId>,
+    pub min_value: f64,
+    pub default_value: f64,
+    pub max_value: f64,
+    /// Whether the axis is meant for programmatic use only and should not be shown to users.
+    pub hidden: bool,
+}
+
+impl VariationAxis {
+    /// Reads an axis from one of the dictionaries returned by
+    /// [`CTFont::get_variation_axes`], or returns `None` if a required key is missing.
+    ///
+    /// [`CTFont::get_variation_axes`]: ../../font/struct.CTFont.html#method.get_variation_axes
+    pub fn from_dictionary(axis: &CFDictionary<CFString, CFType>) -> Option<VariationAxis> {
+        unsafe {
+            let tag = number(axis, kCTFontVariationAxisIdentifierKey)?.to_i64()?;
+            let mut result = read_axis(
+                axis,
+                FourCharCode::from_u32(u32::try_from(tag).ok()?),
+                [
+                    kCTFontVariationAxisNameKey,
+                    kCTFontVariationAxisMinimumValueKey,
+                    kCTFontVariationAxisDefaultValueKey,
+                    kCTFontVariationAxisMaximumValueKey,
+                ],
+            )?;
+            result.hidden = axis
+                .find(kCTFontVariationAxisHiddenKey)
+                .and_then(|hidden| hidden.downcast::<CFBoolean>())
+                .map_or(false, bool::from);
+            Some(result)
+        }
+    }
+
+    /// Reads an axis from one of the dictionaries returned by [`CGFont::copy_variation_axes`],
+    /// or returns `None` if a required key is missing.
+    ///
+    /// These dictionaries have neither a tag nor a hidden flag, so the tag is passed in and the
+    /// axis is not hidden. [`cg_font_variation_axes`] takes both from the `fvar` table instead.
+    ///
+    /// [`CGFont::copy_variation_axes`]: core_graphics::font::CGFont::copy_variation_axes
+    /// [`cg_font_variation_axes`]: fn.cg_font_variation_axes.html
+    pub fn from_cg_dictionary(
+        axis: &CFDictionary<CFString, CFType>,
+        tag: FourCharCode,
+    ) -> Option<VariationAxis> {
+        unsafe {
+            read_axis(
+                axis,
+                tag,
+                [
+                    kCGFontVariationAxisName,
+                    kCGFontVariationAxisMinValue,
+                    kCGFontVariationAxisDefaultValue,
+                    kCGFontVariationAxisMaxValue,
+                ],
+            )
+        }
+    }
+
+    /// Maps a user-space value, such as a weight of 700, to the normalized range from -1 to 1,
+    /// with the default value at 0.
+    ///
+    /// Values outside the axis range are clamped. This is the default normalization, before any
+    /// `avar` mapping.
+    pub fn normalize(&self, value: f64) -> f64 {
+        let value = value.max(self.min_value).min(self.max_value);
+        if value < self.default_value {
+            (value - self.default_value) / (self.default_value - self.min_value)
+        } else if value > self.default_value {
+            (value - self.default_value) / (self.max_value - self.default_value)
+        } else {
+            0.
+        }
+    }
+}
+
+fn number(axis: &CFDictionary<CFString, CFType>, key: CFStringRef) -> Option<CFNumber> {
+    axis.find(key)?.downcast::<CFNumber>()
+}
+
+/// Reads an axis dictionary, given the keys of its name and of its minimum, default and maximum
+/// values.
+fn read_axis(
+    axis: &CFDictionary<CFString, CFType>,
+    tag: FourCharCode,
+    [name, min_value, default_value, max_value]: [CFStringRef; 4],
+) -> Option<VariationAxis> {
+    Some(VariationAxis {
+        tag,
+        name: axis
+            .find(name)
+            .and_then(|name| name.downcast::<CFString>())
+            .map(|name| name.to_string()),
+        name_id: None,
+        min_value: number(axis, min_value)?.to_f64()?,
+        default_value: number(axis, default_value)?.to_f64()?,
+        max_value: number(axis, max_value)?.to_f64()?,
+        hidden: false,
+    })
+}
+
+/// Returns the variation axes of a `CGFont`, or an empty vector if it is not a variable font.
+///
+/// The tags, name ids and hidden flags come from the `fvar` table, whose axes are in the same
+/// order as the font's axis dictionaries.
+pub fn cg_font_variation_axes(font: &CGFont) -> Vec<VariationAxis> {
+    let fvar = font
+        .copy_table_for_tag(FVAR)
+        .and_then(|data| FvarTable::parse(data.bytes()).ok());
+    let (Some(fvar), Some(axes)) = (fvar, font.copy_variation_axes()) else {
+        return Vec::new();
+    };
+    fvar.axes
+        .iter()
+        .zip(axes.iter())
+        .filter_map(|(record, axis)| {
+            let mut result = VariationAxis::from_cg_dictionary(&axis, record.tag)?;
+            result.name_id = record.name_id;
+            result.hidden = record.hidden;
+            Some(result)
+        })
+        .collect()
+}
+
+/// A named instance of a variable font, such as "Bold Condensed".
+#[derive(Clone, Debug, PartialEq)]
+pub struct NamedInstance {
+    pub subfamily_name_id: NameId,
+    /// The localized subfamily name, if it has been looked up.
+    pub subfamily_name: Option<String>,
+    pub postscript_name_id: Option<NameId>,
+    /// The PostScript name, if the instance has one and it has been looked up.
+    pub postscript_name: Option<String>,
+    /// The user-space value of each axis, in the order of the axes.
+    pub coordinates: Vec<f64>,
+}
+
+/// A parsed `fvar` table.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FvarTable {
+    pub axes: Vec<VariationAxis>,
+    pub instances: Vec<NamedInstance>,
+}
+
+impl FvarTable {
+    pub fn parse(data: &[u8]) -> Result<FvarTable, Error> {
+        let mut reader = Reader::new(data);
+        let version = reader.u32()?;
+        if version >> 16 != 1 {
+            return Err(Error::UnsupportedVersion {
+                table: FVAR,
+                version,
+            });
+        }
+        let axes_offset = reader.u16()? as usize;
+        reader.skip(2)?;
+        let axis_count = reader.u16()? as usize;
+        let axis_size = reader.u16()? as usize;
+        let instance_count = reader.u16()? as usize;
+        let instance_size = reader.u16()? as usize;
+        // Records can be larger than the fields read from them, but not smaller.
+        if axis_size < 20 || instance_size < 4 * axis_count + 4 {
+            return Err(Error::InvalidTable { tag: FVAR });
+        }
+
+        let mut axes = Vec::with_capacity(axis_count);
+        for i in 0..axis_count {
+            let mut reader = Reader::at(data, axes_offset + i * axis_size);
+            axes.push(VariationAxis {
+                tag: FourCharCode::from_u32(reader.u32()?),
+                min_value: reader.fixed()?,
+                default_value: reader.fixed()?,
+                max_value: reader.fixed()?,
+                hidden: reader.u16()? & HIDDEN_AXIS != 0,
+                name_id: Some(NameId(reader.u16()?)),
+                name: None,
+            });
+        }
+
+        let instances_offset = axes_offset + axis_count * axis_size;
+        let has_postscript_names = instance_size >= 4 * axis_count + 6;
+        let mut instances = Vec::with_capacity(instance_count);
+        for i in 0..instance_count {
+            let mut reader = Reader::at(data, instances_offset + i * instance_size);
+            let subfamily_name_id = NameId(reader.u16()?);
+            // The flags are reserved.
+            reader.skip(2)?;
+            let coordinates = (0..axis_count)
+                .map(|_| reader.fixed())
+                .collect::<Result<_, _>>()?;
+            let postscript_name_id = match has_postscript_names {
+                true => Some(reader.u16()?).filter(|&id| id != 0xFFFF).map(NameId),
+                false => None,
+            };
+            instances.push(NamedInstance {
+                subfamily_name_id,
+                subfamily_name: None,
+                postscript_name_id,
+                postscript_name: None,
+                coordinates,
+            });
+        }
+        Ok(FvarTable { axes, instances })
+    }
+
+    /// Fills in the axis and instance names from `names`, in the language that best matches
+    /// `language`, such as `en-US`.
+    pub fn resolve_names(&mut self, names: &NameTable, language: &str) {
+        for axis in &mut self.axes {
+            if let Some(id) = axis.name_id {
+                axis.name = names.best_name(id, language);
+            }
+        }
+        for instance in &mut self.instances {
+            instance.subfamily_name = names.best_name(instance.subfamily_name_id, language);
+            if let Some(id) = instance.postscript_name_id {
+                instance.postscript_name = names.best_name(id, language);
+            }
+        }
+    }
+}
+
+/// A parsed `avar` table, which adjusts normalized coordinates to make variations perceptually
+/// even.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AvarTable {
+    /// The (from, to) pairs of each axis, in the order of the `fvar` axes.
+    pub segment_maps: Vec<Vec<(f64, f64)>>,
+}
+
+impl AvarTable {
+    /// Parses a version 1 `avar` table.
+    pub fn parse(data: &[u8]) -> Result<AvarTable, Error> {
+        let mut reader = Reader::new(data);
+        let version = reader.u32()?;
+        if version >> 16 != 1 {
+            return Err(Error::UnsupportedVersion {
+                table: AVAR,
+                version,
+            });
+        }
+        reader.skip(2)?;
+        let axis_count = reader.u16()?;
+        let mut segment_maps = Vec::with_capacity(axis_count as usize);
+        for _ in 0..axis_count {
+            let count = reader.u16()?;
+            let pairs = (0..count)
+                .map(|_| Ok((reader.f2dot14()?, reader.f2dot14()?)))
+                .collect::<Result<_, Error>>()?;
+            segment_maps.push(pairs);
+        }
+        Ok(AvarTable { segment_maps })
+    }
+
+    /// Maps a normalized coordinate of the axis at `axis_index` by interpolating between the
+    /// pairs of its segment map.
+    ///
+    /// NaN is returned unchanged.
+    pub fn map(&self, axis_index: usize, value: f64) -> f64 {
+        let pairs = match self.segment_maps.get(axis_index) {
+            Some(pairs) if !pairs.is_empty() && !value.is_nan() => pairs,
+            _ => return value,
+        };
+        let (first, last) = (pairs[0], pairs[pairs.len() - 1]);
+        if value <= first.0 {
+            return value + first.1 - first.0;
+        }
+        if value >= last.0 {
+            return value + last.1 - last.0;
+        }
+        let upper = pairs.iter().position(|&(from, _)| from >= value).unwrap();
+        let (from_high, to_high) = pairs[upper];
+        if from_high == value {
+            return to_high;
+        }
+        let (from_low, to_low) = pairs[upper - 1];
+        to_low + (to_high - to_low) * (value - from_low) / (from_high - from_low)
+    }
+}
+
+/// Computes the normalized coordinates of a variation, one for each of `axes`, from user-space
+/// values such as `[(wght, 700.)]`.
+///
+/// Axes without a value are at their default. The coordinates are mapped with `avar`, if
+/// given, and rounded to the 2.14 fixed-point precision that fonts store them in.
+///
+/// ```
+/// use core_foundation::four_char_code::FourCharCode;
+/// use core_text::sfnt::{normalize_coordinates, VariationAxis};
+///
+/// const WGHT: FourCharCode = FourCharCode::new(b"wght");
+/// let weight = VariationAxis {
+///     tag: WGHT,
+///     name: None,
+///     name_id: None,
+///     min_value: 100.,
+///     default_value: 400.,
+///     max_value: 900.,
+///     hidden: false,
+/// };
+/// assert_eq!(normalize_coordinates(&[weight.clone()], None, &[(WGHT, 650.)]), [0.5]);
+/// assert_eq!(normalize_coordinates(&[weight], None, &[(WGHT, 50.)]), [-1.]);
+/// ```
+pub fn normalize_coordinates(
+    axes: &[VariationAxis],
+    avar: Option<&AvarTable>,
+    values: &[(FourCharCode, f64)],
+) -> Vec<f64> {
+    axes.iter()
+        .enumerate()
+        .map(|(i, axis)| {
+            let value = values
+                .iter()
+                .rev()
+                .find(|&&(tag, _)| tag == axis.tag)
+                .map_or(axis.default_value, |&(_, value)| value);
+            let mut normalized = axis.normalize(value);
+            if let Some(avar) = avar {
+                normalized = avar.map(i, normalized);
+            }
+            (normalized * 16384.).round() / 16384.
+        })
+        .collect()
+}
+
+/// A design axis listed in the `STAT` table, which may include axes the font does not vary
+/// along, such as `ital` in an upright font.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DesignAxis {
+    pub tag: FourCharCode,
+    pub name_id: NameId,
+    /// The position of the axis when building style names, lowest first.
+    pub ordering: u16,
+}
+
+/// The values an axis value record names. Axis indices refer to the `STAT` design axes.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AxisValueKind {
+    /// A single value on one axis, from a format 1 record.
+    Value { axis_index: u16, value: f64 },
+    /// A range of values on one axis, from a format 2 record.
+    Range {
+        axis_index: u16,
+        nominal: f64,
+        min: f64,
+        max: f64,
+    },
+    /// A single value on one axis that has a style-linked counterpart, such as bold for
+    /// regular, from a format 3 record.
+    Linked {
+        axis_index: u16,
+        value: f64,
+        linked_value: f64,
+    },
+    /// A combination of values on several axes, from a format 4 record.
+    Combination(Vec<(u16, f64)>),
+}
+
+/// A name for a value or combination of values in the `STAT` table, such as "Bold".
+#[derive(Clone, Debug, PartialEq)]
+pub struct AxisValue {
+    pub flags: u16,
+    pub name_id: NameId,
+    pub kind: AxisValueKind,
+}
+
+impl AxisValue {
+    /// The value applies to an older font in the family that uses different names.
+    pub const OLDER_SIBLING_FONT_ATTRIBUTE: u16 = 0x0001;
+    /// The name can be left out of style names, like "Regular".
+    pub const ELIDABLE_AXIS_VALUE_NAME: u16 = 0x0002;
+
+    pub fn is_elidable(&self) -> bool {
+        self.flags & AxisValue::ELIDABLE_AXIS_VALUE_NAME != 0
+    }
+}
+
+/// A parsed `STAT` (style attributes) table.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StatTable {
+    pub design_axes: Vec<DesignAxis>,
+    /// The axis values, leaving out records in formats this crate does not know.
+    pub axis_values: Vec<AxisValue>,
+    /// The name to use when every axis value name is elided, from version 1.1 on.
+    pub elided_fallback_name_id: Option<NameId>,
+}
+
+impl StatTable {
+    pub fn parse(data: &[u8]) -> Result<StatTable, Error> {
+        let mut reader = Reader::new(data);
+        let version = reader.u32()?;
+        if version >> 16 != 1 {
+            return Err(Error::UnsupportedVersion {
+                table: STAT,
+                version,
+            });
+        }
+        let axis_size = reader.u16()? as usize;
+        let axis_count = reader.u16()? as usize;
+        let axes_offset = reader.u32()? as usize;
+        let value_count = reader.u16()? as usize;
+        let values_offset = reader.u32()? as usize;
+        let elided_fallback_name_id = match version & 0xFFFF {
+            0 => None,
+            _ => Some(NameId(reader.u16()?)),
+        };
+
+        let mut design_axes = Vec::with_capacity(axis_count);
+        for i in 0..axis_count {
+            let mut reader = Reader::at(data, axes_offset + i * axis_size);
+            design_axes.push(DesignAxis {
+                tag: FourCharCode::from_u32(reader.u32()?),
+                name_id: NameId(reader.u16()?),
+                ordering: reader.u16()?,
+            });
+        }
+
+        let mut axis_values = Vec::with_capacity(value_count);
+        let mut offsets = Reader::at(data, values_offset);
+        for _ in 0..value_count {
+            let mut reader = Reader::at(data, values_offset + offsets.u16()? as usize);
+            let format = reader.u16()?;
+            if format == 4 {
+                let count = reader.u16()?;
+                let flags = reader.u16()?;
+                let name_id = NameId(reader.u16()?);
+                let values = (0..count)
+                    .map(|_| Ok((reader.u16()?, reader.fixed()?)))
+                    .collect::<Result<_, Error>>()?;
+                axis_values.push(AxisValue {
+                    flags,
+                    name_id,
+                    kind: AxisValueKind::Combination(values),
+                });
+                continue;
+            }
+            let axis_index = reader.u16()?;
+            let flags = reader.u16()?;
+            let name_id = NameId(reader.u16()?);
+            let kind = match format {
+                1 => AxisValueKind::Value {
+                    axis_index,
+                    value: reader.fixed()?,
+                },
+                2 => AxisValueKind::Range {
+                    axis_index,
+                    nominal: reader.fixed()?,
+                    min: reader.fixed()?,
+                    max: reader.fixed()?,
+                },
+                3 => AxisValueKind::Linked {
+                    axis_index,
+                    value: reader.fixed()?,
+                    linked_value: reader.fixed()?,
+                },
+                _ => continue,
+            };
+            axis_values.push(AxisValue {
+                flags,
+                name_id,
+                kind,
+            });
+        }
+        Ok(StatTable {
+            design_axes,
+            axis_values,
+            elided_fallback_name_id,
+        })
+    }
+}
@@ -0,0 +1,170 @@
+// Copyright 2026 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Decoding of WOFF web fonts into sfnt data that CoreText can load.
+
+use super::{slice, woff2, write_sfnt, Error, FontCollection, Reader};
+use super::{VERSION_APPLE_TRUETYPE, VERSION_OPENTYPE, VERSION_TRUETYPE, VERSION_TYPE1};
+use core_foundation::four_char_code::FourCharCode;
+use std::borrow::Cow;
+
+/// The signature at the start of a WOFF file.
+pub const SIGNATURE_WOFF: FourCharCode = FourCharCode::new(b"wOFF");
+
+/// The largest font that a WOFF or WOFF2 file is decoded to, as in the OpenType Sanitizer.
+///
+/// The sizes in the header are checked against it before anything is decompressed, so a small
+/// file cannot make the decoder allocate gigabytes.
+pub const MAX_DECODED_SIZE: usize = 30 << 20;
+
+const HEADER_LEN: usize = 44;
+const TABLE_ENTRY_LEN: usize = 20;
+
+/// Returns `data` as sfnt data, decoding it first if it is a WOFF or WOFF2 file.
+///
+/// Data that is not a web font is returned unchanged, after checking that it is a font or font
+/// collection.
+///
+/// ```
+/// use core_text::sfnt::woff::decode;
+/// use core_text::sfnt::Error;
+///
+/// assert_eq!(decode(b"wOFF").unwrap_err(), Error::Truncated { offset: 4 });
+/// assert_eq!(decode(b"GIF89a").unwrap_err(), Error::InvalidHeader);
+/// ```
+pub fn decode(data: &[u8]) -> Result<Cow<'_, [u8]>, Error> {
+    match Reader::new(data).u32().map(FourCharCode::from_u32) {
+        Ok(SIGNATURE_WOFF) => decode_woff(data).map(Cow::Owned),
+        Ok(woff2::SIGNATURE_WOFF2) => woff2::decode_woff2(data).map(Cow::Owned),
+        _ => FontCollection::parse(data).map(|_| Cow::Borrowed(data)),
+    }
+}
+
+/// Decodes a WOFF 1.0 file into a font file with the same tables.
+///
+/// The file is checked strictly: its length, the table directory and the extension blocks
+/// must all agree with each other, tables must be sorted, aligned and not overlap, and each
+/// table must decompress to exactly its original length. Extended metadata and private data
+/// are dropped.
+pub fn decode_woff(data: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut reader = Reader::new(data);
+    if FourCharCode::from_u32(reader.u32()?) != SIGNATURE_WOFF {
+        return Err(Error::InvalidHeader);
+    }
+    let flavor = FourCharCode::from_u32(reader.u32()?);
+    let length = reader.u32()? as usize;
+    let count = reader.u16()? as usize;
+    let reserved = reader.u16()?;
+    let total_sfnt_size = reader.u32()? as usize;
+    // The version of the font, for information only.
+    reader.skip(4)?;
+    let meta = (reader.u32()? as usize, reader.u32()? as usize);
+    // The decompressed length of the metadata.
+    reader.skip(4)?;
+    let private = (reader.u32()? as usize, reader.u32()? as usize);
+    let known_flavor = [
+        VERSION_TRUETYPE,
+        VERSION_OPENTYPE,
+        VERSION_APPLE_TRUETYPE,
+        VERSION_TYPE1,
+    ]
+    .contains(&flavor);
+    if !known_flavor || length != data.len() || count == 0 || reserved != 0 {
+        return Err(Error::InvalidHeader);
+    }
+
+    let directory_end = HEADER_LEN + count * TABLE_ENTRY_LEN;
+    let mut entries = Vec::with_capacity(count);
+    for _ in 0..count {
+        let tag = FourCharCode::from_u32(reader.u32()?);
+        let offset = reader.u32()? as usize;
+        let compressed_len = reader.u32()? as usize;
+        let original_len = reader.u32()? as usize;
+        // The checksum of the original table, which is recomputed for the output.
+        reader.skip(4)?;
+        let sorted = entries.last().map_or(true, |last: &Entry| last.tag < tag);
+        if !sorted
+            || offset % 4 != 0
+            || offset < directory_end
+            || offset
+                .checked_add(compressed_len)
+                .map_or(true, |end| end > length)
+            || compressed_len > original_len
+        {
+            return Err(Error::InvalidTable { tag });
+        }
+        entries.push(Entry {
+            tag,
+            offset,
+            compressed_len,
+            original_len,
+        });
+    }
+
+    // Every block of the file must lie after the directory, inside the file, without overlaps.
+    let mut blocks: Vec<_> = entries
+        .iter()
+        .map(|entry| (entry.offset, entry.compressed_len, Some(entry.tag)))
+        .collect();
+    for (offset, len) in [meta, private] {
+        if offset != 0 || len != 0 {
+            if offset % 4 != 0 || offset < directory_end || offset.saturating_add(len) > length {
+                return Err(Error::InvalidHeader);
+            }
+            blocks.push((offset, len, None));
+        }
+    }
+    blocks.sort_unstable_by_key(|&(offset, _, _)| offset);
+    for pair in blocks.windows(2) {
+        let (offset, len, _) = pair[0];
+        if offset + len > pair[1].0 {
+            return Err(pair[1]
+                .2
+                .map_or(Error::InvalidHeader, |tag| Error::InvalidTable { tag }));
+        }
+    }
+
+    // The original lengths bound the memory used for decompression, so they are checked
+    // before anything is decompressed.
+    let sfnt_size = entries.iter().fold(12 + 16 * count, |size, entry| {
+        size.saturating_add((entry.original_len + 3) & !3)
+    });
+    if sfnt_size != total_sfnt_size {
+        return Err(Error::InvalidHeader);
+    }
+    if sfnt_size > MAX_DECODED_SIZE {
+        return Err(Error::TooLarge);
+    }
+
+    let mut tables = Vec::with_capacity(count);
+    for entry in &entries {
+        let stored = slice(data, entry.offset, entry.compressed_len)?;
+        let table = if entry.compressed_len == entry.original_len {
+            Cow::Borrowed(stored)
+        } else {
+            let table =
+                miniz_oxide::inflate::decompress_to_vec_zlib_with_limit(stored, entry.original_len)
+                    .map_err(|_| Error::Decompression)?;
+            if table.len() != entry.original_len {
+                return Err(Error::InvalidTable { tag: entry.tag });
+            }
+            Cow::Owned(table)
+        };
+        tables.push((entry.tag, table));
+    }
+    let tables: Vec<_> = tables.iter().map(|(tag, table)| (*tag, &**table)).collect();
+    Ok(write_sfnt(flavor, &tables))
+}
+
+struct Entry {
+    tag: FourCharCode,
+    offset: usize,
+    compressed_len: usize,
+    original_len: usize,
+}
@@ -0,0 +1,682 @@
+// Copyright 2026 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Decoding of WOFF2 web fonts, including the `glyf`, `loca` and `hmtx` transforms.
+
+use super::collection::TAG_TTCF;
+use super::woff::MAX_DECODED_SIZE;
+use super::{slice, write_offset_table, write_sfnt, write_table_record, Error, Reader};
+use super::{VERSION_APPLE_TRUETYPE, VERSION_OPENTYPE, VERSION_TRUETYPE, VERSION_TYPE1};
+use core_foundation::four_char_code::FourCharCode;
+use std::borrow::Cow;
+use std::io::Read;
+
+/// The signature at the start of a WOFF2 file.
+pub const SIGNATURE_WOFF2: FourCharCode = FourCharCode::new(b"wOF2");
+
+const GLYF: FourCharCode = FourCharCode::new(b"glyf");
+const LOCA: FourCharCode = FourCharCode::new(b"loca");
+const HMTX: FourCharCode = FourCharCode::new(b"hmtx");
+const HHEA: FourCharCode = FourCharCode::new(b"hhea");
+
+/// The tags that table directory entries can refer to by index.
+const KNOWN_TAGS: [&[u8; 4]; 63] = [
+    b"cmap", b"head", b"hhea", b"hmtx", b"maxp", b"name", b"OS/2", b"post", b"cvt ", b"fpgm",
+    b"glyf", b"loca", b"prep", b"CFF ", b"VORG", b"EBDT", b"EBLC", b"gasp", b"hdmx", b"kern",
+    b"LTSH", b"PCLT", b"VDMX", b"vhea", b"vmtx", b"BASE", b"GDEF", b"GPOS", b"GSUB", b"EBSC",
+    b"JSTF", b"MATH", b"CBDT", b"CBLC", b"COLR", b"CPAL", b"SVG ", b"sbix", b"acnt", b"avar",
+    b"bdat", b"bloc", b"bsln", b"cvar", b"fdsc", b"feat", b"fmtx", b"fvar", b"gvar", b"hsty",
+    b"just", b"lcar", b"mort", b"morx", b"opbd", b"prop", b"trak", b"Zapf", b"Silf", b"Glat",
+    b"Gloc", b"Feat", b"Sill",
+];
+
+/// Decodes a WOFF2 file into a font file, or into a font collection if it holds one.
+///
+/// Transformed `glyf`, `loca` and `hmtx` tables are rebuilt, so the result has the same
+/// outlines and metrics as the original font but not necessarily the same bytes. The file is
+/// checked strictly: its length and blocks must agree with the header, the tables must
+/// decompress to exactly the sizes in the directory, and the rebuilt tables must have their
+/// original lengths. Collections are written as version 1.0 collections, without a `DSIG`
+/// table, and extended metadata and private data are dropped.
+pub fn decode_woff2(data: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut reader = Reader::new(data);
+    if FourCharCode::from_u32(reader.u32()?) != SIGNATURE_WOFF2 {
+        return Err(Error::InvalidHeader);
+    }
+    let flavor = FourCharCode::from_u32(reader.u32()?);
+    let length = reader.u32()? as usize;
+    let count = reader.u16()? as usize;
+    let reserved = reader.u16()?;
+    // totalSfntSize is only a hint, and the font version is for information only.
+    reader.skip(4)?;
+    let compressed_len = reader.u32()? as usize;
+    reader.skip(4)?;
+    let meta = (reader.u32()? as usize, reader.u32()? as usize);
+    // The decompressed length of the metadata.
+    reader.skip(4)?;
+    let private = (reader.u32()? as usize, reader.u32()? as usize);
+    if length != data.len() || count == 0 || reserved != 0 {
+        return Err(Error::InvalidHeader);
+    }
+
+    let mut entries = Vec::with_capacity(count);
+    let mut stream_len = 0usize;
+    for _ in 0..count {
+        let flags = reader.u8()?;
+        let tag = match flags & 0x3F {
+            63 => FourCharCode::from_u32(reader.u32()?),
+            index => FourCharCode::new(KNOWN_TAGS[index as usize]),
+        };
+        let version = flags >> 6;
+        let original_len = base128(&mut reader)?;
+        // Version 0 is the transform of `glyf` and `loca`, and version 3 leaves them alone.
+        // Other tables are left alone by version 0, and only `hmtx` has another transform.
+        let transformed = match (tag, version) {
+            (GLYF, 0) | (LOCA, 0) | (HMTX, 1) => true,
+            (GLYF, 3) | (LOCA, 3) | (_, 0) => false,
+            _ => return Err(Error::InvalidTable { tag }),
+        };
+        let stored_len = match transformed {
+            true => base128(&mut reader)?,
+            false => original_len,
+        };
+        if tag == LOCA && transformed && stored_len != 0 {
+            return Err(Error::InvalidTable { tag });
+        }
+        let offset = stream_len;
+        stream_len = stream_len
+            .checked_add(stored_len as usize)
+            .ok_or(Error::InvalidTable { tag })?;
+        entries.push(Entry {
+            tag,
+            transformed,
+            original_len: original_len as usize,
+            offset,
+            stored_len: stored_len as usize,
+        });
+    }
+
+    let fonts = match flavor {
+        TAG_TTCF => collection_directory(&mut reader, &entries)?,
+        _ => vec![Font {
+            flavor,
+            tables: (0..count).collect(),
+        }],
+    };
+    for font in &fonts {
+        check_font(font, &entries)?;
+    }
+
+    let compressed_start = reader.offset;
+    let compressed = slice(data, compressed_start, compressed_len)?;
+    let compressed_end = compressed_start + compressed_len;
+    let mut blocks = vec![(compressed_start, compressed_len)];
+    for (offset, len) in [meta, private] {
+        if offset != 0 || len != 0 {
+            if offset % 4 != 0 || offset < compressed_end || offset.saturating_add(len) > length {
+                return Err(Error::InvalidHeader);
+            }
+            blocks.push((offset, len));
+        }
+    }
+    blocks.sort_unstable();
+    if blocks
+        .windows(2)
+        .any(|pair| pair[0].0 + pair[0].1 > pair[1].0)
+    {
+        return Err(Error::InvalidHeader);
+    }
+
+    // The stream and the tables rebuilt from it are limited before anything is decompressed.
+    let sfnt_size = entries.iter().fold(12 + 16 * count, |size, entry| {
+        size.saturating_add((entry.original_len + 3) & !3)
+    });
+    if stream_len > MAX_DECODED_SIZE || sfnt_size > MAX_DECODED_SIZE {
+        return Err(Error::TooLarge);
+    }
+
+    // Reading one byte more than expected detects streams that are too long, without
+    // decompressing them in full.
+    let mut stream = Vec::new();
+    brotli_decompressor::Decompressor::new(compressed, 4096)
+        .take(stream_len as u64 + 1)
+        .read_to_end(&mut stream)
+        .map_err(|_| Error::Decompression)?;
+    if stream.len() != stream_len {
+        return Err(Error::Decompression);
+    }
+
+    let tables = reconstruct_tables(&stream, &entries, &fonts)?;
+    if flavor != TAG_TTCF {
+        let mut font: Vec<_> = entries
+            .iter()
+            .zip(&tables)
+            .map(|(entry, table)| (entry.tag, &**table))
+            .collect();
+        font.sort_unstable_by_key(|&(tag, _)| tag);
+        return Ok(write_sfnt(flavor, &font));
+    }
+    Ok(write_collection(&entries, &tables, &fonts))
+}
+
+/// A table directory entry.
+struct Entry {
+    tag: FourCharCode,
+    transformed: bool,
+    original_len: usize,
+    /// The offset of the stored table in the decompressed stream.
+    offset: usize,
+    stored_len: usize,
+}
+
+/// A font of the file, with indices of its table directory entries.
+struct Font {
+    flavor: FourCharCode,
+    tables: Vec<usize>,
+}
+
+impl Font {
+    fn table(&self, entries: &[Entry], tag: FourCharCode) -> Option<usize> {
+        self.tables
+            .iter()
+            .copied()
+            .find(|&index| entries[index].tag == tag)
+    }
+}
+
+fn collection_directory(reader: &mut Reader, entries: &[Entry]) -> Result<Vec<Font>, Error> {
+    let version = reader.u32()?;
+    if !matches!(version, 0x0001_0000 | 0x0002_0000) {
+        return Err(Error::UnsupportedVersion {
+            table: TAG_TTCF,
+            version,
+        });
+    }
+    let count = uint255(reader)?;
+    if count == 0 {
+        return Err(Error::InvalidHeader);
+    }
+    let mut fonts = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        let table_count = uint255(reader)?;
+        let flavor = FourCharCode::from_u32(reader.u32()?);
+        let tables = (0..table_count)
+            .map(|_| match uint255(reader)? as usize {
+                index if index < entries.len() => Ok(index),
+                _ => Err(Error::InvalidHeader),
+            })
+            .collect::<Result<_, _>>()?;
+        fonts.push(Font { flavor, tables });
+    }
+    Ok(fonts)
+}
+
+/// Checks the flavor of a font, that its tags are unique, and that transformed `glyf` and
+/// `loca` tables come in pairs.
+fn check_font(font: &Font, entries: &[Entry]) -> Result<(), Error> {
+    let known_flavor = [
+        VERSION_TRUETYPE,
+        VERSION_OPENTYPE,
+        VERSION_APPLE_TRUETYPE,
+        VERSION_TYPE1,
+    ]
+    .contains(&font.flavor);
+    if !known_flavor || font.tables.is_empty() {
+        return Err(Error::InvalidHeader);
+    }
+    let mut tags: Vec<_> = font.tables.iter().map(|&i| entries[i].tag).collect();
+    tags.sort_unstable();
+    if let Some(pair) = tags.windows(2).find(|pair| pair[0] == pair[1]) {
+        return Err(Error::InvalidTable { tag: pair[0] });
+    }
+    let glyf = font.table(entries, GLYF).map(|i| &entries[i]);
+    let loca = font.table(entries, LOCA).map(|i| &entries[i]);
+    match (glyf, loca) {
+        (Some(glyf), Some(loca)) if glyf.transformed == loca.transformed => {}
+        (Some(glyf), _) if glyf.transformed => return Err(Error::InvalidTable { tag: LOCA }),
+        (_, Some(loca)) if loca.transformed => return Err(Error::InvalidTable { tag: GLYF }),
+        _ => {}
+    }
+    if let Some(hmtx) = font.table(entries, HMTX) {
+        if entries[hmtx].transformed && !glyf.map_or(false, |glyf| glyf.transformed) {
+            return Err(Error::InvalidTable { tag: HMTX });
+        }
+    }
+    Ok(())
+}
+
+/// Returns the final data of every table directory entry.
+fn reconstruct_tables<'a>(
+    stream: &'a [u8],
+    entries: &[Entry],
+    fonts: &[Font],
+) -> Result<Vec<Cow<'a, [u8]>>, Error> {
+    let mut tables: Vec<Cow<[u8]>> = entries
+        .iter()
+        .map(|entry| Cow::Borrowed(&stream[entry.offset..entry.offset + entry.stored_len]))
+        .collect();
+    let mut x_mins: Vec<Option<Vec<i16>>> = entries.iter().map(|_| None).collect();
+    for font in fonts {
+        let Some(glyf) = font.table(entries, GLYF) else {
+            continue;
+        };
+        if !entries[glyf].transformed || x_mins[glyf].is_some() {
+            continue;
+        }
+        // check_font() has made sure that there is a transformed `loca`.
+        let loca = font.table(entries, LOCA).unwrap();
+        let outlines =
+            reconstruct_glyf(&tables[glyf]).map_err(|_| Error::InvalidTable { tag: GLYF })?;
+        if outlines.loca.len() != entries[loca].original_len {
+            return Err(Error::InvalidTable { tag: LOCA });
+        }
+        tables[glyf] = Cow::Owned(outlines.glyf);
+        tables[loca] = Cow::Owned(outlines.loca);
+        x_mins[glyf] = Some(outlines.x_mins);
+    }
+    for font in fonts {
+        let Some(hmtx) = font.table(entries, HMTX) else {
+            continue;
+        };
+        if !entries[hmtx].transformed || !matches!(tables[hmtx], Cow::Borrowed(_)) {
+            continue;
+        }
+        let invalid = Error::InvalidTable { tag: HMTX };
+        let x_mins = font
+            .table(entries, GLYF)
+            .and_then(|glyf| x_mins[glyf].as_deref())
+            .ok_or(invalid)?;
+        let hhea = font.table(entries, HHEA).ok_or(invalid)?;
+        let metrics_count = Reader::at(&tables[hhea], 34).u16().map_err(|_| invalid)?;
+        let table =
+            reconstruct_hmtx(&tables[hmtx], x_mins, metrics_count as usize).map_err(|_| invalid)?;
+        if table.len() != entries[hmtx].original_len {
+            return Err(invalid);
+        }
+        tables[hmtx] = Cow::Owned(table);
+    }
+    Ok(tables)
+}
+
+/// Writes a version 1.0 font collection, storing tables shared by several fonts once.
+fn write_collection(entries: &[Entry], tables: &[Cow<[u8]>], fonts: &[Font]) -> Vec<u8> {
+    let header_len = 12 + 4 * fonts.len();
+    let directories_len: usize = fonts.iter().map(|font| 12 + 16 * font.tables.len()).sum();
+    let mut out = Vec::new();
+    out.extend_from_slice(&TAG_TTCF.to_bytes());
+    out.extend_from_slice(&0x0001_0000u32.to_be_bytes());
+    out.extend_from_slice(&(fonts.len() as u32).to_be_bytes());
+    let mut directory_offset = header_len;
+    for font in fonts {
+        out.extend_from_slice(&(directory_offset as u32).to_be_bytes());
+        directory_offset += 12 + 16 * font.tables.len();
+    }
+
+    let mut offsets: Vec<Option<usize>> = vec![None; entries.len()];
+    let mut body = Vec::new();
+    for font in fonts {
+        let mut indices = font.tables.clone();
+        indices.sort_unstable_by_key(|&index| entries[index].tag);
+        write_offset_table(&mut out, font.flavor, indices.len());
+        for index in indices {
+            let offset = *offsets[index].get_or_insert_with(|| {
+                let offset = header_len + directories_len + body.len();
+                body.extend_from_slice(&tables[index]);
+                body.resize((body.len() + 3) & !3, 0);
+                offset
+            });
+            write_table_record(&mut out, entries[index].tag, &tables[index], offset);
+        }
+    }
+    out.extend_from_slice(&body);
+    out
+}
+
+/// Reads a `UIntBase128`: a big-endian number in up to five bytes of seven bits each.
+fn base128(reader: &mut Reader) -> Result<u32, Error> {
+    let mut value = 0u32;
+    for i in 0..5 {
+        let byte = reader.u8()?;
+        // Leading zeros and values that do not fit in 32 bits are not allowed.
+        if (i == 0 && byte == 0x80) || value & 0xFE00_0000 != 0 {
+            return Err(Error::InvalidHeader);
+        }
+        value = value << 7 | (byte & 0x7F) as u32;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(Error::InvalidHeader)
+}
+
+/// Reads a `255UInt16`, which stores small numbers in one byte.
+fn uint255(reader: &mut Reader) -> Result<u16, Error> {
+    Ok(match reader.u8()? {
+        253 => reader.u16()?,
+        254 => reader.u8()? as u16 + 506,
+        255 => reader.u8()? as u16 + 253,
+        code => code as u16,
+    })
+}
+
+struct Outlines {
+    glyf: Vec<u8>,
+    loca: Vec<u8>,
+    /// The minimum x coordinate of each glyph, which is its left side bearing.
+    x_mins: Vec<i16>,
+}
+
+// Flags of simple glyph points.
+const ON_CURVE_POINT: u8 = 0x01;
+const X_SHORT_VECTOR: u8 = 0x02;
+const Y_SHORT_VECTOR: u8 = 0x04;
+const REPEAT_FLAG: u8 = 0x08;
+const X_IS_SAME_OR_POSITIVE: u8 = 0x10;
+const Y_IS_SAME_OR_POSITIVE: u8 = 0x20;
+const OVERLAP_SIMPLE: u8 = 0x40;
+
+// Flags of composite glyph components.
+const ARG_1_AND_2_ARE_WORDS: u16 = 0x0001;
+const WE_HAVE_A_SCALE: u16 = 0x0008;
+const MORE_COMPONENTS: u16 = 0x0020;
+const WE_HAVE_AN_X_AND_Y_SCALE: u16 = 0x0040;
+const WE_HAVE_A_TWO_BY_TWO: u16 = 0x0080;
+const WE_HAVE_INSTRUCTIONS: u16 = 0x0100;
+
+/// Rebuilds the `glyf` and `loca` tables from a transformed `glyf` table.
+fn reconstruct_glyf(data: &[u8]) -> Result<Outlines, Error> {
+    let mut reader = Reader::new(data);
+    let reserved = reader.u16()?;
+    let option_flags = reader.u16()?;
+    let glyph_count = reader.u16()? as usize;
+    let index_format = reader.u16()?;
+    if reserved != 0 || index_format > 1 {
+        return Err(Error::InvalidHeader);
+    }
+    let mut stream_lens = [0; 7];
+    for len in &mut stream_lens {
+        *len = reader.u32()? as usize;
+    }
+    let mut streams = [&data[..0]; 7];
+    for (stream, &len) in streams.iter_mut().zip(&stream_lens) {
+        *stream = reader.bytes(len)?;
+    }
+    let [contour_counts, point_counts, flags, glyphs, composites, bboxes, instructions] = streams;
+    let mut contour_counts = Reader::new(contour_counts);
+    let mut point_counts = Reader::new(point_counts);
+    let mut flags = Reader::new(flags);
+    let mut glyphs = Reader::new(glyphs);
+    let mut composites = Reader::new(composites);
+    let mut instructions = Reader::new(instructions);
+    let bitmap_len = 4 * ((glyph_count + 31) / 32);
+    let bbox_bitmap = slice(bboxes, 0, bitmap_len)?;
+    let mut bboxes = Reader::at(bboxes, bitmap_len);
+    let overlap_bitmap = match option_flags & 1 {
+        0 => None,
+        _ => Some(reader.bytes((glyph_count + 7) / 8)?),
+    };
+    let bit = |bitmap: &[u8], glyph: usize| bitmap[glyph >> 3] & (0x80 >> (glyph & 7)) != 0;
+
+    let mut outlines = Outlines {
+        glyf: Vec::new(),
+        loca: Vec::new(),
+        x_mins: Vec::with_capacity(glyph_count),
+    };
+    let mut offsets = Vec::with_capacity(glyph_count + 1);
+    let mut points = Vec::new();
+    for glyph in 0..glyph_count {
+        offsets.push(outlines.glyf.len());
+        let has_bbox = bit(bbox_bitmap, glyph);
+        let contour_count = contour_counts.u16()? as i16;
+        let start = outlines.glyf.len();
+        let out = &mut outlines.glyf;
+        match contour_count {
+            0 if has_bbox => return Err(Error::InvalidHeader),
+            0 => {}
+            -1 if !has_bbox => return Err(Error::InvalidHeader),
+            -1 => {
+                out.extend_from_slice(&(-1i16).to_be_bytes());
+                out.extend_from_slice(bboxes.bytes(8)?);
+                let mut has_instructions = false;
+                loop {
+                    let mut component = composites.clone();
+                    let flags = composites.u16()?;
+                    composites.skip(2)?;
+                    composites.skip(match flags & ARG_1_AND_2_ARE_WORDS {
+                        0 => 2,
+                        _ => 4,
+                    })?;
+                    if flags & WE_HAVE_A_SCALE != 0 {
+                        composites.skip(2)?;
+                    } else if flags & WE_HAVE_AN_X_AND_Y_SCALE != 0 {
+                        composites.skip(4)?;
+                    } else if flags & WE_HAVE_A_TWO_BY_TWO != 0 {
+                        composites.skip(8)?;
+                    }
+                    out.extend_from_slice(component.bytes(composites.offset - component.offset)?);
+                    has_instructions |= flags & WE_HAVE_INSTRUCTIONS != 0;
+                    if flags & MORE_COMPONENTS == 0 {
+                        break;
+                    }
+                }
+                if has_instructions {
+                    let len = uint255(&mut glyphs)?;
+                    out.extend_from_slice(&len.to_be_bytes());
+                    out.extend_from_slice(instructions.bytes(len as usize)?);
+                }
+            }
+            count if count > 0 => {
+                let mut end_points = Vec::with_capacity(count as usize);
+                let mut point_count = 0usize;
+                for _ in 0..count {
+                    point_count += uint255(&mut point_counts)? as usize;
+                    if point_count == 0 || point_count > 0x10000 {
+                        return Err(Error::InvalidHeader);
+                    }
+                    end_points.push((point_count - 1) as u16);
+                }
+                points.clear();
+                let (mut x, mut y) = (0i32, 0i32);
+                for _ in 0..point_count {
+                    let flag = flags.u8()?;
+                    let (dx, dy) = triplet(flag & 0x7F, &mut glyphs)?;
+                    x = x.checked_add(dx).ok_or(Error::InvalidHeader)?;
+                    y = y.checked_add(dy).ok_or(Error::InvalidHeader)?;
+                    points.push((x, y, flag & 0x80 == 0));
+                }
+                let instruction_len = uint255(&mut glyphs)?;
+                let bbox = match has_bbox {
+                    true => bboxes.bytes(8)?.to_vec(),
+                    false => bounding_box(&points)?,
+                };
+                let overlap = overlap_bitmap.map_or(false, |bitmap| bit(bitmap, glyph));
+                out.extend_from_slice(&count.to_be_bytes());
+                out.extend_from_slice(&bbox);
+                for end in end_points {
+                    out.extend_from_slice(&end.to_be_bytes());
+                }
+                out.extend_from_slice(&instruction_len.to_be_bytes());
+                out.extend_from_slice(instructions.bytes(instruction_len as usize)?);
+                write_points(out, &points, overlap)?;
+            }
+            _ => return Err(Error::InvalidHeader),
+        }
+        outlines.x_mins.push(match out.len() > start {
+            true => i16::from_be_bytes([out[start + 2], out[start + 3]]),
+            false => 0,
+        });
+        out.resize((out.len() + 3) & !3, 0);
+    }
+    offsets.push(outlines.glyf.len());
+
+    for offset in offsets {
+        match index_format {
+            0 => {
+                let offset = u16::try_from(offset / 2).map_err(|_| Error::InvalidHeader)?;
+                outlines.loca.extend_from_slice(&offset.to_be_bytes());
+            }
+            _ => outlines
+                .loca
+                .extend_from_slice(&(offset as u32).to_be_bytes()),
+        }
+    }
+    Ok(outlines)
+}
+
+/// Decodes the coordinate deltas of a point from its flag, without the on-curve bit, and its
+/// bytes in the glyph stream.
+fn triplet(flag: u8, glyphs: &mut Reader) -> Result<(i32, i32), Error> {
+    let with_sign = |flag: u8, value: i32| match flag & 1 {
+        0 => -value,
+        _ => value,
+    };
+    let b = |reader: &mut Reader| reader.u8().map(i32::from);
+    Ok(match flag {
+        0..=9 => (0, with_sign(flag, ((flag as i32 & 14) << 7) + b(glyphs)?)),
+        10..=19 => (
+            with_sign(flag, (((flag as i32 - 10) & 14) << 7) + b(glyphs)?),
+            0,
+        ),
+        20..=83 => {
+            let b0 = flag as i32 - 20;
+            let b1 = b(glyphs)?;
+            (
+                with_sign(flag, 1 + (b0 & 0x30) + (b1 >> 4)),
+                with_sign(flag >> 1, 1 + ((b0 & 0x0C) << 2) + (b1 & 0x0F)),
+            )
+        }
+        84..=119 => {
+            let b0 = flag as i32 - 84;
+            (
+                with_sign(flag, 1 + ((b0 / 12) << 8) + b(glyphs)?),
+                with_sign(flag >> 1, 1 + (((b0 % 12) >> 2) << 8) + b(glyphs)?),
+            )
+        }
+        120..=123 => {
+            let (b0, b1, b2) = (b(glyphs)?, b(glyphs)?, b(glyphs)?);
+            (
+                with_sign(flag, (b0 << 4) + (b1 >> 4)),
+                with_sign(flag >> 1, ((b1 & 0x0F) << 8) + b2),
+            )
+        }
+        _ => {
+            let (dx, dy) = (glyphs.u16()? as i32, glyphs.u16()? as i32);
+            (with_sign(flag, dx), with_sign(flag >> 1, dy))
+        }
+    })
+}
+
+/// Returns the bounding box of the points, as it is stored in a glyph.
+fn bounding_box(points: &[(i32, i32, bool)]) -> Result<Vec<u8>, Error> {
+    let (mut x_min, mut y_min, mut x_max, mut y_max) = (i32::MAX, i32::MAX, i32::MIN, i32::MIN);
+    for &(x, y, _) in points {
+        x_min = x_min.min(x);
+        y_min = y_min.min(y);
+        x_max = x_max.max(x);
+        y_max = y_max.max(y);
+    }
+    let mut bbox = Vec::with_capacity(8);
+    for value in [x_min, y_min, x_max, y_max] {
+        let value = i16::try_from(value).map_err(|_| Error::InvalidHeader)?;
+        bbox.extend_from_slice(&value.to_be_bytes());
+    }
+    Ok(bbox)
+}
+
+/// Writes the flags and coordinates of a simple glyph, folding runs of equal flags.
+fn write_points(
+    out: &mut Vec<u8>,
+    points: &[(i32, i32, bool)],
+    overlap: bool,
+) -> Result<(), Error> {
+    let mut flags = Vec::with_capacity(points.len());
+    let mut xs = Vec::new();
+    let mut ys = Vec::new();
+    let mut last_flag = None;
+    let mut repeat = 0u8;
+    let (mut x, mut y) = (0, 0);
+    for (i, &(px, py, on_curve)) in points.iter().enumerate() {
+        let mut flag = if on_curve { ON_CURVE_POINT } else { 0 };
+        if i == 0 && overlap {
+            flag |= OVERLAP_SIMPLE;
+        }
+        flag |= write_coordinate(&mut xs, px - x, X_SHORT_VECTOR, X_IS_SAME_OR_POSITIVE)?;
+        flag |= write_coordinate(&mut ys, py - y, Y_SHORT_VECTOR, Y_IS_SAME_OR_POSITIVE)?;
+        (x, y) = (px, py);
+        if last_flag == Some(flag) && repeat != 255 {
+            *flags.last_mut().unwrap() |= REPEAT_FLAG;
+            repeat += 1;
+        } else {
+            if repeat != 0 {
+                flags.push(repeat);
+            }
+            flags.push(flag);
+            repeat = 0;
+        }
+        last_flag = Some(flag);
+    }
+    if repeat != 0 {
+        flags.push(repeat);
+    }
+    out.extend_from_slice(&flags);
+    out.extend_from_slice(&xs);
+    out.extend_from_slice(&ys);
+    Ok(())
+}
+
+/// Writes a coordinate delta in its shortest form and returns its point flags.
+fn write_coordinate(
+    out: &mut Vec<u8>,
+    delta: i32,
+    short: u8,
+    same_or_positive: u8,
+) -> Result<u8, Error> {
+    Ok(match delta {
+        0 => same_or_positive,
+        -255..=255 => {
+            out.push(delta.unsigned_abs() as u8);
+            short | if delta > 0 { same_or_positive } else { 0 }
+        }
+        _ => {
+            let delta = i16::try_from(delta).map_err(|_| Error::InvalidHeader)?;
+            out.extend_from_slice(&delta.to_be_bytes());
+            0
+        }
+    })
+}
+
+/// Rebuilds an `hmtx` table, taking left side bearings that were left out from the glyphs.
+fn reconstruct_hmtx(data: &[u8], x_mins: &[i16], metrics_count: usize) -> Result<Vec<u8>, Error> {
+    let mut reader = Reader::new(data);
+    let flags = reader.u8()?;
+    let has_proportional_lsbs = flags & 1 == 0;
+    let has_monospaced_lsbs = flags & 2 == 0;
+    if flags & 0xFC != 0
+        || (has_proportional_lsbs && has_monospaced_lsbs)
+        || metrics_count == 0
+        || metrics_count > x_mins.len()
+    {
+        return Err(Error::InvalidHeader);
+    }
+    let advances = reader.bytes(2 * metrics_count)?;
+    let mut side_bearing = |glyph: usize| match glyph < metrics_count {
+        true if has_proportional_lsbs => reader.bytes(2).map(|b| [b[0], b[1]]),
+        false if has_monospaced_lsbs => reader.bytes(2).map(|b| [b[0], b[1]]),
+        _ => Ok(x_mins[glyph].to_be_bytes()),
+    };
+    let mut table = Vec::with_capacity(2 * metrics_count + 2 * x_mins.len());
+    for glyph in 0..x_mins.len() {
+        let lsb = side_bearing(glyph)?;
+        if glyph < metrics_count {
+            table.extend_from_slice(&advances[2 * glyph..2 * glyph + 2]);
+        }
+        table.extend_from_slice(&lsb);
+    }
+    Ok(table)
+}
@@ -0,0 +1,849 @@
+#!/usr/bin/env python3
+"""Generates the synthetic fonts used by the sfnt tests.
+
+The fonts have empty glyphs and only the tables the tests need, plus the ones required for a
+valid TrueType file. Run from this directory; the output is deterministic.
+"""
+
+import struct
+import zlib
+
+
+def pad4(data):
+    return data + b"\0" * (-len(data) % 4)
+
+
+def checksum(data):
+    data = pad4(data)
+    return sum(struct.unpack(">%dI" % (len(data) // 4), data)) & 0xFFFFFFFF
+
+
+def sfnt(tables, version=b"\0\1\0\0"):
+    """Returns a font file with `tables`, a dict from tag to bytes, in tag order."""
+    tags = sorted(tables)
+    count = len(tags)
+    entry_selector = max(i for i in range(16) if 1 << i <= count)
+    search_range = (1 << entry_selector) * 16
+    header = version + struct.pack(
+        ">HHHH", count, search_range, entry_selector, count * 16 - search_range
+    )
+    offset = len(header) + 16 * count
+    directory = b""
+    body = b""
+    for tag in tags:
+        data = tables[tag]
+        directory += struct.pack(">4sIII", tag, checksum(data), offset + len(body), len(data))
+        body += pad4(data)
+    font = bytearray(header + directory + body)
+    if b"head" in tables:
+        entry = len(header) + 16 * tags.index(b"head")
+        head_offset = struct.unpack(">I", font[entry + 8 : entry + 12])[0]
+        adjustment = (0xB1B0AFBA - checksum(bytes(font))) & 0xFFFFFFFF
+        font[head_offset + 8 : head_offset + 12] = struct.pack(">I", adjustment)
+    return bytes(font)
+
+
+def table_checksum(tag, data):
+    """The checksum of a table, which for `head` is taken with checksumAdjustment set to zero."""
+    if tag == b"head":
+        data = data[:8] + b"\0\0\0\0" + data[12:]
+    return checksum(data)
+
+
+def tables_of(font):
+    """Returns the version and tables of a font file made by sfnt()."""
+    count = struct.unpack(">H", font[4:6])[0]
+    tables = {}
+    for i in range(count):
+        tag, _, offset, length = struct.unpack(">4sIII", font[12 + 16 * i : 28 + 16 * i])
+        tables[tag] = font[offset : offset + length]
+    return font[:4], tables
+
+
+def collection(fonts):
+    """Returns a version 1.0 font collection of `fonts`, storing tables with the same bytes
+    once."""
+    faces = [tables_of(font) for font in fonts]
+    header_len = 12 + 4 * len(faces)
+    directories_len = sum(12 + 16 * len(tables) for _, tables in faces)
+    shared = {}
+    body = b""
+    directories = b""
+    offsets = b""
+    for version, tables in faces:
+        offsets += struct.pack(">I", header_len + len(directories))
+        tags = sorted(tables)
+        count = len(tags)
+        entry_selector = max(i for i in range(16) if 1 << i <= count)
+        search_range = (1 << entry_selector) * 16
+        directories += version + struct.pack(
+            ">HHHH", count, search_range, entry_selector, count * 16 - search_range
+        )
+        for tag in tags:
+            data = tables[tag]
+            if data not in shared:
+                shared[data] = header_len + directories_len + len(body)
+                body += pad4(data)
+            directories += struct.pack(">4sIII", tag, table_checksum(tag, data), shared[data], len(data))
+    return b"ttcf" + struct.pack(">HHI", 1, 0, len(faces)) + offsets + directories + body
+
+
+def head():
+    return struct.pack(
+        ">IIIIHHqqhhhhHHhhh",
+        0x00010000,  # version
+        0x00010000,  # fontRevision
+        0,  # checksumAdjustment, filled in by sfnt()
+        0x5F0F3CF5,  # magicNumber
+        0x000B,  # flags
+        1000,  # unitsPerEm
+        0,  # created
+        0,  # modified
+        0, 0, 0, 0,  # xMin, yMin, xMax, yMax
+        0,  # macStyle
+        8,  # lowestRecPPEM
+        2,  # fontDirectionHint
+        0,  # indexToLocFormat
+        0,  # glyphDataFormat
+    )
+
+
+def hhea(num_glyphs):
+    return struct.pack(
+        ">IhhhHhhhhhhhhhhhH",
+        0x00010000, 800, -200, 0, 500, 0, 0, 500, 1, 0, 0, 0, 0, 0, 0, 0, num_glyphs
+    )
+
+
+def maxp(num_glyphs):
+    return struct.pack(">IH13H", 0x00010000, num_glyphs, *([0] * 13))
+
+
+def hmtx(num_glyphs):
+    return struct.pack(">%dH" % (2 * num_glyphs), *([500, 0] * num_glyphs))
+
+
+def post():
+    return struct.pack(">IIhhIIIII", 0x00030000, 0, -100, 50, 0, 0, 0, 0, 0)
+
+
+def os2():
+    return struct.pack(
+        ">HhHHHhhhhhhhhhhh10s16s4sHHHhhhHH",
+        1, 500, 400, 5, 0, 650, 700, 0, 140, 650, 700, 0, 480, 50, 250, 0,
+        b"\0" * 10, b"\0" * 16, b"NONE", 0x40, 0x20, 0x7E, 800, -200, 0, 800, 200,
+    ) + struct.pack(">II", 1, 0)
+
+
+def empty_glyphs(num_glyphs):
+    return {b"glyf": b"", b"loca": struct.pack(">%dH" % (num_glyphs + 1), *([0] * (num_glyphs + 1)))}
+
+
+def cmap_format4_segments(segments):
+    """A format 4 subtable from (start, end, glyphs) segments.
+
+    `glyphs` is either the glyph of `start`, mapped with idDelta, or a list of glyphs for every
+    code in the segment, mapped with idRangeOffset.
+    """
+    segments = list(segments) + [(0xFFFF, 0xFFFF, 0)]
+    count = len(segments)
+    entry_selector = max(i for i in range(16) if 1 << i <= count)
+    search_range = 2 << entry_selector
+    deltas = []
+    range_offsets = []
+    glyph_array = []
+    for i, (start, end, glyphs) in enumerate(segments):
+        if isinstance(glyphs, list):
+            deltas.append(0)
+            # From this idRangeOffset entry to the glyph in glyphIdArray.
+            range_offsets.append(2 * (count - i + len(glyph_array)))
+            glyph_array += glyphs
+        else:
+            deltas.append((glyphs - start) & 0xFFFF if glyphs else 1)
+            range_offsets.append(0)
+    body = struct.pack(">%dH" % count, *[s[1] for s in segments]) + b"\0\0"
+    body += struct.pack(">%dH" % count, *[s[0] for s in segments])
+    body += struct.pack(">%dH" % count, *deltas)
+    body += struct.pack(">%dH" % count, *range_offsets)
+    body += struct.pack(">%dH" % len(glyph_array), *glyph_array)
+    header = struct.pack(
+        ">HHHHHHH", 4, 14 + len(body), 0, count * 2, search_range,
+        entry_selector, count * 2 - search_range,
+    )
+    return header + body
+
+
+def cmap_format6(first, glyphs):
+    return struct.pack(">HHHHH%dH" % len(glyphs), 6, 10 + 2 * len(glyphs), 0, first, len(glyphs), *glyphs)
+
+
+def cmap_groups(format, groups):
+    """A format 12 or 13 subtable from (start, end, glyph) groups."""
+    body = b"".join(struct.pack(">III", *group) for group in groups)
+    return struct.pack(">HHIII", format, 0, 16 + len(body), 0, len(groups)) + body
+
+
+def cmap_format14(selectors):
+    """A format 14 subtable from (selector, default ranges, non-default mappings) tuples."""
+    header_len = 10 + 11 * len(selectors)
+    records = b""
+    body = b""
+    for selector, default, mappings in selectors:
+        default_offset = 0
+        if default:
+            default_offset = header_len + len(body)
+            body += struct.pack(">I", len(default))
+            for start, additional in default:
+                body += struct.pack(">I", start)[1:] + struct.pack(">B", additional)
+        mappings_offset = 0
+        if mappings:
+            mappings_offset = header_len + len(body)
+            body += struct.pack(">I", len(mappings))
+            for code, glyph in mappings:
+                body += struct.pack(">I", code)[1:] + struct.pack(">H", glyph)
+        records += struct.pack(">I", selector)[1:] + struct.pack(">II", default_offset, mappings_offset)
+    return struct.pack(">HII", 14, header_len + len(body), len(selectors)) + records + body
+
+
+def cmap(subtables):
+    """A cmap table from (platform, encoding, subtable bytes) tuples."""
+    header = struct.pack(">HH", 0, len(subtables))
+    offset = 4 + 8 * len(subtables)
+    records = b""
+    body = b""
+    for platform, encoding, data in subtables:
+        records += struct.pack(">HHI", platform, encoding, offset + len(body))
+        body += data
+    return header + records + body
+
+
+def name(records, language_tags=()):
+    """A name table from (platform, encoding, language, name ID, bytes) tuples."""
+    version = 1 if language_tags else 0
+    records = sorted(records, key=lambda r: r[:4])
+    storage = b""
+    encoded = b""
+    for platform, encoding, language, name_id, string in records:
+        encoded += struct.pack(
+            ">HHHHHH", platform, encoding, language, name_id, len(string), len(storage)
+        )
+        storage += string
+    tags = b""
+    if version == 1:
+        tags += struct.pack(">H", len(language_tags))
+        for tag in language_tags:
+            string = tag.encode("utf-16-be")
+            tags += struct.pack(">HH", len(string), len(storage))
+            storage += string
+    storage_offset = 6 + len(encoded) + len(tags)
+    return struct.pack(">HHH", version, len(records), storage_offset) + encoded + tags + storage
+
+
+def windows(language, name_id, string):
+    return (3, 1, language, name_id, string.encode("utf-16-be"))
+
+
+def mac(language, name_id, string):
+    return (1, 0, language, name_id, string.encode("mac_roman"))
+
+
+def font(names, cmap_table, num_glyphs):
+    tables = {
+        b"head": head(),
+        b"hhea": hhea(num_glyphs),
+        b"maxp": maxp(num_glyphs),
+        b"hmtx": hmtx(num_glyphs),
+        b"post": post(),
+        b"OS/2": os2(),
+        b"name": names,
+        b"cmap": cmap_table,
+    }
+    tables.update(empty_glyphs(num_glyphs))
+    return tables
+
+
+def names_font():
+    records = [
+        windows(0x0409, 1, "Fixture Sans"),
+        windows(0x0409, 2, "Regular"),
+        windows(0x0409, 4, "Fixture Sans Regular"),
+        windows(0x0409, 6, "FixtureSans-Regular"),
+        windows(0x0409, 16, "Fixture"),
+        windows(0x0409, 17, "Sans Regular"),
+        windows(0x0407, 1, "Fixture Grotesk"),
+        windows(0x0407, 2, "Standard"),
+        windows(0x0C07, 2, "Normal"),
+        windows(0x0411, 1, "フィクスチャ サンズ"),
+        windows(0x8000, 1, "Fixture Sans Canadienne"),
+        windows(0x0409, 19, "Sphinx 😀"),
+        mac(0, 1, "Fixture Sans"),
+        mac(0, 0, "© Fixture Café"),
+        mac(2, 1, "Fixture Grotesk"),  # German
+        (3, 2, 0x0411, 1, "フィクスチャ".encode("shift_jis")),
+        (0, 3, 0, 7, "Fixture™".encode("utf-16-be")),
+    ]
+    segments = [(ord(c), ord(c), i + 1) for i, c in enumerate("ABC")]
+    table = cmap([(3, 1, cmap_format4_segments(segments))])
+    return sfnt(font(name(records, ["fr-CA"]), table, 4))
+
+
+def fixed(value):
+    return struct.pack(">i", round(value * 65536))
+
+
+def f2dot14(value):
+    return struct.pack(">h", round(value * 16384))
+
+
+def fvar(axes, instances):
+    """An fvar table from (tag, min, default, max, flags, name ID) axes and
+    (subfamily name ID, coordinates, PostScript name ID) instances."""
+    instance_size = 4 * len(axes) + 6
+    header = struct.pack(">HHHHHHHH", 1, 0, 16, 2, len(axes), 20, len(instances), instance_size)
+    body = b""
+    for tag, minimum, default, maximum, flags, name_id in axes:
+        body += tag + fixed(minimum) + fixed(default) + fixed(maximum)
+        body += struct.pack(">HH", flags, name_id)
+    for name_id, coordinates, postscript_name_id in instances:
+        body += struct.pack(">HH", name_id, 0) + b"".join(fixed(c) for c in coordinates)
+        body += struct.pack(">H", postscript_name_id)
+    return header + body
+
+
+def avar(segment_maps):
+    """An avar table from a list of (from, to) lists, one per axis."""
+    data = struct.pack(">HHHH", 1, 0, 0, len(segment_maps))
+    for pairs in segment_maps:
+        data += struct.pack(">H", len(pairs))
+        data += b"".join(f2dot14(a) + f2dot14(b) for a, b in pairs)
+    return data
+
+
+def stat(axes, values, elided_fallback_name_id):
+    """A version 1.2 STAT table from (tag, name ID, ordering) axes and encoded axis values."""
+    design_axes = b"".join(tag + struct.pack(">HH", name_id, ordering) for tag, name_id, ordering in axes)
+    offsets = b""
+    tables = b""
+    for value in values:
+        offsets += struct.pack(">H", 2 * len(values) + len(tables))
+        tables += value
+    header_len = 20
+    return (
+        struct.pack(
+            ">HHHHIHIH", 1, 2, 8, len(axes), header_len, len(values),
+            header_len + len(design_axes), elided_fallback_name_id,
+        )
+        + design_axes + offsets + tables
+    )
+
+
+def stat_value(format, axis_index, flags, name_id, *values):
+    if format == 4:
+        pairs = axis_index
+        data = struct.pack(">HHHH", 4, len(pairs), flags, name_id)
+        return data + b"".join(struct.pack(">H", i) + fixed(v) for i, v in pairs)
+    data = struct.pack(">HHHH", format, axis_index, flags, name_id)
+    return data + b"".join(fixed(v) for v in values)
+
+
+def simple_names(family):
+    return name([windows(0x0409, 1, family), windows(0x0409, 2, "Regular")])
+
+
+def cmap_font():
+    bmp = [
+        (ord("A"), ord("Z"), 1),
+        (ord("a"), ord("c"), [30, 0, 31]),
+        (0xE9, 0xE9, 32),
+    ]
+    groups = [
+        (ord("A"), ord("Z"), 1),
+        (ord("a"), ord("a"), 30),
+        (ord("c"), ord("c"), 31),
+        (0xE9, 0xE9, 32),
+        (0x1F600, 0x1F602, 40),
+        # Starting at glyph 0 leaves only the first character unmapped.
+        (0x1F910, 0x1F912, 0),
+    ]
+    variations = [
+        (0xFE0E, [], [(ord("A"), 50)]),
+        (0xFE0F, [(0x1F600, 1)], [(0x1F602, 43)]),
+    ]
+    table = cmap(
+        [
+            (0, 3, cmap_format4_segments(bmp)),
+            (0, 5, cmap_format14(variations)),
+            (1, 0, cmap_format6(ord("A"), [1, 2, 3])),
+            (3, 1, cmap_format4_segments(bmp)),
+            (3, 10, cmap_groups(12, groups)),
+        ]
+    )
+    return sfnt(font(simple_names("Fixture Cmap"), table, 60))
+
+
+def last_resort_font():
+    groups = [
+        (0x20, 0x7E, 1),
+        (0xD000, 0xFFFF, 2),
+        (0x10000, 0x10FFFF, 3),
+    ]
+    table = cmap([(0, 6, cmap_groups(13, groups))])
+    return sfnt(font(simple_names("Fixture Last Resort"), table, 4))
+
+
+def variable_font():
+    names = [
+        windows(0x0409, 1, "Fixture Variable"),
+        windows(0x0409, 2, "Regular"),
+        windows(0x0409, 256, "Weight"),
+        windows(0x0409, 257, "Width"),
+        windows(0x0409, 258, "Optical Contrast"),
+        windows(0x0409, 259, "Thin"),
+        windows(0x0409, 260, "Regular"),
+        windows(0x0409, 264, "Bold"),
+        windows(0x0409, 265, "Black Condensed"),
+        windows(0x0409, 266, "Italic"),
+        windows(0x0409, 267, "Semi Bold"),
+        windows(0x0409, 268, "Roman"),
+        windows(0x0409, 270, "FixtureVariable-Thin"),
+        windows(0x0409, 271, "FixtureVariable-BlackCondensed"),
+        windows(0x0407, 256, "Gewicht"),
+    ]
+    tables = font(name(names), cmap([(3, 1, cmap_format4_segments([]))]), 1)
+    tables[b"fvar"] = fvar(
+        [
+            (b"wght", 100, 400, 900, 0, 256),
+            (b"wdth", 75, 100, 125, 0, 257),
+            (b"XOPQ", 20, 88, 200, 1, 258),
+        ],
+        [
+            (259, [100, 100, 88], 270),
+            (260, [400, 100, 88], 0xFFFF),
+            (265, [900, 75, 88], 271),
+        ],
+    )
+    tables[b"avar"] = avar(
+        [
+            [(-1, -1), (0, 0), (0.5, 0.25), (1, 1)],
+            [(-1, -1), (0, 0), (1, 1)],
+            [],
+        ]
+    )
+    tables[b"STAT"] = stat(
+        [(b"wght", 256, 0), (b"wdth", 257, 1), (b"ital", 266, 2)],
+        [
+            stat_value(1, 0, 0, 259, 100),
+            stat_value(3, 0, 2, 260, 400, 700),
+            stat_value(2, 0, 0, 267, 600, 550, 650),
+            stat_value(1, 2, 2, 268, 0),
+            stat_value(4, [(0, 900), (1, 75)], 0, 265),
+        ],
+        2,
+    )
+    return sfnt(tables)
+
+# Glyph outlines, in the encoding the WOFF2 decoder reproduces.
+
+ON_CURVE = 0x01
+X_SHORT = 0x02
+Y_SHORT = 0x04
+REPEAT = 0x08
+X_SAME_OR_POSITIVE = 0x10
+Y_SAME_OR_POSITIVE = 0x20
+OVERLAP_SIMPLE = 0x40
+
+
+def bbox_of(contours):
+    points = [(x, y) for contour in contours for x, y, _ in contour]
+    if not points:
+        return (0, 0, 0, 0)
+    xs = [x for x, _ in points]
+    ys = [y for _, y in points]
+    return (min(xs), min(ys), max(xs), max(ys))
+
+
+def simple_glyph(contours, instructions=b"", bbox=None, overlap=False):
+    """A simple glyph from contours of (x, y, on curve) points, with repeated flags folded."""
+    bbox = bbox or bbox_of(contours)
+    data = struct.pack(">hhhhh", len(contours), *bbox)
+    end = -1
+    for contour in contours:
+        end += len(contour)
+        data += struct.pack(">H", end)
+    data += struct.pack(">H", len(instructions)) + instructions
+    flags = b""
+    xs = b""
+    ys = b""
+    last_flag = None
+    repeat = 0
+    x = y = 0
+    for i, (px, py, on_curve) in enumerate(p for contour in contours for p in contour):
+        dx, dy = px - x, py - y
+        x, y = px, py
+        flag = ON_CURVE if on_curve else 0
+        if i == 0 and overlap:
+            flag |= OVERLAP_SIMPLE
+        if dx == 0:
+            flag |= X_SAME_OR_POSITIVE
+        elif -256 < dx < 256:
+            flag |= X_SHORT | (X_SAME_OR_POSITIVE if dx > 0 else 0)
+            xs += struct.pack(">B", abs(dx))
+        else:
+            xs += struct.pack(">h", dx)
+        if dy == 0:
+            flag |= Y_SAME_OR_POSITIVE
+        elif -256 < dy < 256:
+            flag |= Y_SHORT | (Y_SAME_OR_POSITIVE if dy > 0 else 0)
+            ys += struct.pack(">B", abs(dy))
+        else:
+            ys += struct.pack(">h", dy)
+        if flag == last_flag and repeat != 255:
+            flags = flags[:-1] + bytes([flags[-1] | REPEAT])
+            repeat += 1
+        else:
+            if repeat:
+                flags += bytes([repeat])
+            flags += bytes([flag])
+            repeat = 0
+        last_flag = flag
+    if repeat:
+        flags += bytes([repeat])
+    return data + flags + xs + ys
+
+
+ARG_1_AND_2_ARE_WORDS = 0x0001
+ARGS_ARE_XY_VALUES = 0x0002
+WE_HAVE_A_SCALE = 0x0008
+MORE_COMPONENTS = 0x0020
+WE_HAVE_A_TWO_BY_TWO = 0x0080
+WE_HAVE_INSTRUCTIONS = 0x0100
+
+
+def component(flags, glyph, args, transform=()):
+    """A composite glyph component; MORE_COMPONENTS is set by composite_glyph()."""
+    data = struct.pack(">HH", flags, glyph)
+    data += struct.pack(">hh" if flags & ARG_1_AND_2_ARE_WORDS else ">bb", *args)
+    return data + b"".join(f2dot14(v) for v in transform)
+
+
+def composite_glyph(bbox, components, instructions=b""):
+    data = struct.pack(">hhhhh", -1, *bbox)
+    for i, c in enumerate(components):
+        flags = struct.unpack(">H", c[:2])[0]
+        if i < len(components) - 1:
+            flags |= MORE_COMPONENTS
+        if instructions:
+            flags |= WE_HAVE_INSTRUCTIONS
+        data += struct.pack(">H", flags) + c[2:]
+    if instructions:
+        data += struct.pack(">H", len(instructions)) + instructions
+    return data
+
+
+# Each glyph is (simple glyph arguments or composite glyph arguments, advance width).
+OUTLINE_GLYPHS = [
+    # .notdef: a box with a hole.
+    (("simple", [[(50, 0, True), (450, 0, True), (450, 700, True), (50, 700, True)],
+                 [(100, 50, True), (100, 650, True), (400, 650, True), (400, 50, True)]],
+      b"\xb0\x01"), 500),
+    # space
+    (("simple", []), 250),
+    # A curve exercising every point encoding: zero, one-byte, two-byte and large deltas.
+    (("simple", [[(0, 0, True), (0, 900, True), (1200, 900, False), (1200, 0, True),
+                  (1190, 3, False), (1130, 40, False), (700, 300, True), (-300, 5000, True),
+                  (-300, 5000, True), (-300, 5000, True), (-5000, -4000, False)]],
+      b"", None, True), 1200),
+    # A glyph whose stored bounding box is larger than its points.
+    (("simple", [[(10, 10, True), (20, 30, True), (30, 10, True)]], b"\x00" * 300,
+      (-20, -20, 60, 60)), 600),
+    # A composite glyph with byte and word offsets, a scale and a 2x2 transform.
+    (("composite", (-100, -100, 1400, 1000), [
+        component(ARGS_ARE_XY_VALUES | WE_HAVE_A_SCALE, 0, (10, -10), (0.5,)),
+        component(ARGS_ARE_XY_VALUES | ARG_1_AND_2_ARE_WORDS | WE_HAVE_A_TWO_BY_TWO, 3,
+                  (1000, -300), (1, 0.25, 0, -1)),
+    ], b"\x01\x02\x03"), 1400),
+]
+
+
+def outline_glyph(spec):
+    if spec[0] == "simple":
+        return simple_glyph(*spec[1:]) if spec[1] else b""
+    return composite_glyph(*spec[1:])
+
+
+def outline_tables():
+    """glyf, loca and hmtx tables for OUTLINE_GLYPHS, with three long metrics."""
+    glyf = b""
+    loca = [0]
+    hmtx = b""
+    for i, (spec, advance) in enumerate(OUTLINE_GLYPHS):
+        glyph = outline_glyph(spec)
+        x_min = struct.unpack(">h", glyph[2:4])[0] if glyph else 0
+        glyf += pad4(glyph)
+        loca.append(len(glyf))
+        hmtx += struct.pack(">Hh", advance, x_min) if i < 3 else struct.pack(">h", x_min)
+    loca = struct.pack(">%dH" % len(loca), *[offset // 2 for offset in loca])
+    return {b"glyf": glyf, b"loca": loca, b"hmtx": hmtx}
+
+
+def outlines_font():
+    tables = font(simple_names("Fixture Outlines"), cmap([(3, 1, cmap_format4_segments([]))]), 5)
+    num_glyphs = len(OUTLINE_GLYPHS)
+    tables[b"hhea"] = tables[b"hhea"][:-2] + struct.pack(">H", 3)
+    tables[b"maxp"] = maxp(num_glyphs)
+    tables.update(outline_tables())
+    return sfnt(tables)
+
+
+# WOFF and WOFF2.
+
+def woff(font):
+    """A WOFF file for `font`, compressing the tables that get smaller."""
+    version, tables = tables_of(font)
+    tags = sorted(tables)
+    offset = 44 + 20 * len(tags)
+    directory = b""
+    body = b""
+    for tag in tags:
+        data = tables[tag]
+        compressed = zlib.compress(data, 9)
+        if len(compressed) >= len(data):
+            compressed = data
+        directory += struct.pack(
+            ">4sIIII", tag, offset + len(body), len(compressed), len(data),
+            table_checksum(tag, data),
+        )
+        body += pad4(compressed)
+    length = offset + len(body)
+    header = b"wOFF" + version + struct.pack(
+        ">IHHIHHIIIII", length, len(tags), 0, len(font), 1, 0, 0, 0, 0, 0, 0
+    )
+    return header + directory + body
+
+
+def brotli_stored(data):
+    """A Brotli stream holding `data` in uncompressed meta-blocks."""
+    bits = [0]  # WBITS = 16
+
+    def write(value, count):
+        bits.extend((value >> i) & 1 for i in range(count))
+
+    def align():
+        bits.extend([0] * (-len(bits) % 8))
+
+    chunks = []
+    for start in range(0, len(data), 1 << 16):
+        chunk = data[start : start + (1 << 16)]
+        write(0, 1)  # ISLAST
+        write(0, 2)  # MNIBBLES = 4
+        write(len(chunk) - 1, 16)
+        write(1, 1)  # ISUNCOMPRESSED
+        align()
+        chunks.append((len(bits) // 8, chunk))
+        bits.extend([0] * (8 * len(chunk)))
+    write(1, 1)  # ISLAST
+    write(1, 1)  # ISLASTEMPTY
+    align()
+    stream = bytearray(sum(bit << (i % 8) for i, bit in enumerate(bits[j : j + 8])) for j in range(0, len(bits), 8))
+    for offset, chunk in chunks:
+        stream[offset : offset + len(chunk)] = chunk
+    return bytes(stream)
+
+
+WOFF2_KNOWN_TAGS = [
+    b"cmap", b"head", b"hhea", b"hmtx", b"maxp", b"name", b"OS/2", b"post", b"cvt ", b"fpgm",
+    b"glyf", b"loca", b"prep", b"CFF ", b"VORG", b"EBDT", b"EBLC", b"gasp", b"hdmx", b"kern",
+    b"LTSH", b"PCLT", b"VDMX", b"vhea", b"vmtx", b"BASE", b"GDEF", b"GPOS", b"GSUB", b"EBSC",
+    b"JSTF", b"MATH", b"CBDT", b"CBLC", b"COLR", b"CPAL", b"SVG ", b"sbix", b"acnt", b"avar",
+    b"bdat", b"bloc", b"bsln", b"cvar", b"fdsc", b"feat", b"fmtx", b"fvar", b"gvar", b"hsty",
+    b"just", b"lcar", b"mort", b"morx", b"opbd", b"prop", b"trak", b"Zapf", b"Silf", b"Glat",
+    b"Gloc", b"Feat", b"Sill",
+]
+
+
+def base128(value):
+    data = bytes([value & 0x7F])
+    value >>= 7
+    while value:
+        data = bytes([0x80 | (value & 0x7F)]) + data
+        value >>= 7
+    return data
+
+
+def uint255(value):
+    if value < 253:
+        return bytes([value])
+    if value < 506:
+        return bytes([255, value - 253])
+    if value < 762:
+        return bytes([254, value - 506])
+    return bytes([253]) + struct.pack(">H", value)
+
+
+def triplet(on_curve, dx, dy):
+    """The flag and data bytes of a point in the WOFF2 glyph stream."""
+    flag = 0 if on_curve else 128
+    ax, ay = abs(dx), abs(dy)
+    x_sign = 0 if dx < 0 else 1
+    y_sign = 0 if dy < 0 else 1
+    signs = x_sign + 2 * y_sign
+    if dx == 0 and ay < 1280:
+        return flag + ((ay & 0xF00) >> 7) + y_sign, bytes([ay & 0xFF])
+    if dy == 0 and ax < 1280:
+        return flag + 10 + ((ax & 0xF00) >> 7) + x_sign, bytes([ax & 0xFF])
+    if ax < 65 and ay < 65:
+        return (
+            flag + 20 + ((ax - 1) & 0x30) + (((ay - 1) & 0x30) >> 2) + signs,
+            bytes([(((ax - 1) & 0xF) << 4) | ((ay - 1) & 0xF)]),
+        )
+    if ax < 769 and ay < 769:
+        return (
+            flag + 84 + 12 * (((ax - 1) & 0x300) >> 8) + (((ay - 1) & 0x300) >> 6) + signs,
+            bytes([(ax - 1) & 0xFF, (ay - 1) & 0xFF]),
+        )
+    if ax < 4096 and ay < 4096:
+        return flag + 120 + signs, bytes([ax >> 4, ((ax & 0xF) << 4) | (ay >> 8), ay & 0xFF])
+    return flag + 124 + signs, struct.pack(">HH", ax, ay)
+
+
+def transformed_glyf(glyphs, index_format):
+    """The WOFF2 transform of a glyf table from OUTLINE_GLYPHS-style specs."""
+    n_contours = n_points = flags = stream = composites = bboxes = instructions = b""
+    bbox_bitmap = bytearray(4 * ((len(glyphs) + 31) // 32))
+    overlap_bitmap = bytearray((len(glyphs) + 7) // 8)
+    for i, spec in enumerate(glyphs):
+        if spec[0] == "composite":
+            bbox, components, glyph_instructions = spec[1:]
+            n_contours += struct.pack(">h", -1)
+            glyph = composite_glyph(bbox, components, glyph_instructions)
+            end = len(glyph) - (2 + len(glyph_instructions) if glyph_instructions else 0)
+            composites += glyph[10:end]
+            if glyph_instructions:
+                stream += uint255(len(glyph_instructions))
+                instructions += glyph_instructions
+            bbox_bitmap[i >> 3] |= 0x80 >> (i & 7)
+            bboxes += struct.pack(">hhhh", *bbox)
+            continue
+        contours = spec[1]
+        glyph_instructions = spec[2] if len(spec) > 2 else b""
+        bbox = spec[3] if len(spec) > 3 else None
+        if len(spec) > 4 and spec[4]:
+            overlap_bitmap[i >> 3] |= 0x80 >> (i & 7)
+        n_contours += struct.pack(">h", len(contours))
+        if not contours:
+            continue
+        x = y = 0
+        for contour in contours:
+            n_points += uint255(len(contour))
+            for px, py, on_curve in contour:
+                flag, data = triplet(on_curve, px - x, py - y)
+                x, y = px, py
+                flags += bytes([flag])
+                stream += data
+        stream += uint255(len(glyph_instructions))
+        instructions += glyph_instructions
+        if bbox and bbox != bbox_of(contours):
+            bbox_bitmap[i >> 3] |= 0x80 >> (i & 7)
+            bboxes += struct.pack(">hhhh", *bbox)
+    has_overlap = any(overlap_bitmap)
+    bbox_stream = bytes(bbox_bitmap) + bboxes
+    header = struct.pack(
+        ">HHHHIIIIIII", 0, 1 if has_overlap else 0, len(glyphs), index_format,
+        len(n_contours), len(n_points), len(flags), len(stream), len(composites),
+        len(bbox_stream), len(instructions),
+    )
+    data = header + n_contours + n_points + flags + stream + composites + bbox_stream
+    data += instructions
+    if has_overlap:
+        data += bytes(overlap_bitmap)
+    return data
+
+
+def transformed_hmtx(advances):
+    """The WOFF2 transform of an hmtx table whose side bearings all equal the glyph xMin."""
+    return b"\x03" + struct.pack(">%dH" % len(advances), *advances)
+
+
+def woff2(flavor, entries, collection=None):
+    """A WOFF2 file from (tag, transform version, original length, data) table entries.
+
+    The tables are stored in uncompressed Brotli meta-blocks. `collection` is the collection
+    directory, for a `ttcf` flavor.
+    """
+    directory = b""
+    stream = b""
+    for tag, version, length, data in entries:
+        index = WOFF2_KNOWN_TAGS.index(tag) if tag in WOFF2_KNOWN_TAGS else 63
+        directory += bytes([index | version << 6])
+        if index == 63:
+            directory += tag
+        directory += base128(length)
+        transformed = version != 0 if tag not in (b"glyf", b"loca") else version != 3
+        if transformed:
+            directory += base128(len(data))
+        stream += data
+    directory += collection or b""
+    compressed = brotli_stored(stream)
+    length = 48 + len(directory) + len(compressed)
+    padding = -length % 4
+    header = b"wOF2" + flavor + struct.pack(
+        ">IHHIIHHIIIII", length + padding, len(entries), 0, 0, len(compressed), 1, 0, 0, 0, 0, 0,
+        0,
+    )
+    return header + directory + compressed + b"\0" * padding
+
+
+def outlines_woff2():
+    font = outlines_font()
+    _, tables = tables_of(font)
+    specs = [spec for spec, _ in OUTLINE_GLYPHS]
+    entries = []
+    for tag in sorted(tables):
+        data = tables[tag]
+        if tag == b"glyf":
+            entries.append((tag, 0, len(data), transformed_glyf(specs, 0)))
+        elif tag == b"loca":
+            entries.append((tag, 0, len(data), b""))
+        elif tag == b"hmtx":
+            entries.append((tag, 1, len(data), transformed_hmtx([500, 250, 1200])))
+        else:
+            entries.append((tag, 0, len(data), data))
+    header = woff2(b"\0\1\0\0", entries)
+    # totalSfntSize, at offset 16, is only informative; fill it in anyway.
+    return header[:16] + struct.pack(">I", len(font)) + header[20:]
+
+
+def collection_woff2(fonts):
+    """A WOFF2 collection of `fonts` with untransformed tables, storing shared tables once."""
+    faces = [tables_of(font) for font in fonts]
+    entries = []
+    indices = {}
+    collection = struct.pack(">I", 0x00010000) + uint255(len(faces))
+    for version, tables in faces:
+        collection += uint255(len(tables)) + version
+        for tag in sorted(tables):
+            key = (tag, tables[tag])
+            if key not in indices:
+                indices[key] = len(entries)
+                null = 3 if tag in (b"glyf", b"loca") else 0
+                entries.append((tag, null, len(tables[tag]), tables[tag]))
+            collection += uint255(indices[key])
+    return woff2(b"ttcf", entries, collection)
+
+
+FONTS = {
+    "names.ttf": names_font,
+    "cmap.ttf": cmap_font,
+    "last-resort.ttf": last_resort_font,
+    "variable.ttf": variable_font,
+    "collection.ttc": lambda: collection([names_font(), cmap_font()]),
+    "collection.woff2": lambda: collection_woff2([names_font(), cmap_font()]),
+    "outlines.ttf": outlines_font,
+    "outlines.woff": lambda: woff(outlines_font()),
+    "outlines.woff2": outlines_woff2,
+}
+
+if __name__ == "__main__":
+    for path, build in FONTS.items():
+        with open(path, "wb") as f:
+            f.write(build())
@@ -0,0 +1,258 @@
+use core_text::font_matcher::{
+    css_oblique_angle, css_weight, css_width, FaceDescriptor, FamilyName, FontMatcher, FontQuery,
+    FontSlant, GenericFamily,
+};
+
+fn face(
+    family_name: &str,
+    style_name: &str,
+    weight: f64,
+    width: f64,
+    slant: FontSlant,
+) -> FaceDescriptor {
+    FaceDescriptor {
+        family_name: family_name.to_owned(),
+        style_name: style_name.to_owned(),
+        weight,
+        width,
+        slant,
+        monospace: false,
+        path: None,
+    }
+}
+
+fn best(matcher: &FontMatcher, family: &str, weight: f64, width: f64, slant: FontSlant) -> String {
+    let query = FontQuery {
+        families: vec![FamilyName::Named(family.to_owned())],
+        weight,
+        width,
+        slant,
+    };
+    matcher.find_best_match(&query).unwrap().style_name.clone()
+}
+
+fn weights(weights: &[f64]) -> FontMatcher {
+    FontMatcher::new(
+        weights
+            .iter()
+            .map(|&weight| {
+                face(
+                    "Family",
+                    &weight.to_string(),
+                    weight,
+                    100.,
+                    FontSlant::Normal,
+                )
+            })
+            .collect(),
+    )
+}
+
+#[test]
+fn weight() {
+    let normal = FontSlant::Normal;
+    // Between 400 and 500, heavier weights up to 500 come first, then lighter ones.
+    assert_eq!(
+        best(&weights(&[300., 500., 600.]), "Family", 450., 100., normal),
+        "500"
+    );
+    assert_eq!(
+        best(&weights(&[300., 600.]), "Family", 400., 100., normal),
+        "300"
+    );
+    assert_eq!(
+        best(&weights(&[600., 700.]), "Family", 500., 100., normal),
+        "600"
+    );
+    // Below 400 lighter weights come first.
+    assert_eq!(
+        best(&weights(&[200., 400.]), "Family", 300., 100., normal),
+        "200"
+    );
+    assert_eq!(
+        best(&weights(&[400., 500.]), "Family", 300., 100., normal),
+        "400"
+    );
+    // Above 500 heavier weights come first.
+    assert_eq!(
+        best(&weights(&[600., 800.]), "Family", 700., 100., normal),
+        "800"
+    );
+    assert_eq!(
+        best(&weights(&[400., 600.]), "Family", 700., 100., normal),
+        "600"
+    );
+}
+
+#[test]
+fn width() {
+    let matcher = FontMatcher::new(vec![
+        face("Family", "Condensed", 400., 75., FontSlant::Normal),
+        face("Family", "Expanded", 400., 125., FontSlant::Normal),
+    ]);
+    let normal = FontSlant::Normal;
+    assert_eq!(best(&matcher, "Family", 400., 100., normal), "Condensed");
+    assert_eq!(best(&matcher, "Family", 400., 112.5, normal), "Expanded");
+    assert_eq!(best(&matcher, "Family", 400., 150., normal), "Expanded");
+    assert_eq!(best(&matcher, "Family", 400., 50., normal), "Condensed");
+}
+
+#[test]
+fn slant() {
+    let matcher = FontMatcher::new(vec![
+        face("Family", "Regular", 400., 100., FontSlant::Normal),
+        face("Family", "Italic", 400., 100., FontSlant::Italic),
+        face("Family", "Oblique", 400., 100., FontSlant::Oblique(10.)),
+        face("Family", "Backslant", 400., 100., FontSlant::Oblique(-12.)),
+    ]);
+    assert_eq!(
+        best(&matcher, "Family", 400., 100., FontSlant::Normal),
+        "Regular"
+    );
+    assert_eq!(
+        best(&matcher, "Family", 400., 100., FontSlant::Italic),
+        "Italic"
+    );
+    // Large angles prefer smaller positive angles, then italic faces.
+    assert_eq!(
+        best(&matcher, "Family", 400., 100., FontSlant::Oblique(20.)),
+        "Oblique"
+    );
+    assert_eq!(
+        best(&matcher, "Family", 400., 100., FontSlant::Oblique(-14.)),
+        "Backslant"
+    );
+    assert_eq!(
+        best(&matcher, "Family", 400., 100., FontSlant::Oblique(-6.)),
+        "Backslant"
+    );
+
+    let matcher = FontMatcher::new(vec![
+        face("Family", "Regular", 400., 100., FontSlant::Normal),
+        face("Family", "Italic", 400., 100., FontSlant::Italic),
+    ]);
+    assert_eq!(
+        best(&matcher, "Family", 400., 100., FontSlant::Oblique(20.)),
+        "Italic"
+    );
+    // Small angles accept upright faces before italic ones.
+    assert_eq!(
+        best(&matcher, "Family", 400., 100., FontSlant::Oblique(8.)),
+        "Regular"
+    );
+    assert_eq!(
+        best(&matcher, "Family", 400., 100., FontSlant::Oblique(-8.)),
+        "Regular"
+    );
+
+    // Italic queries fall back to obliques, then upright faces.
+    let matcher = FontMatcher::new(vec![
+        face("Family", "Regular", 400., 100., FontSlant::Normal),
+        face("Family", "Oblique", 400., 100., FontSlant::Oblique(20.)),
+    ]);
+    assert_eq!(
+        best(&matcher, "Family", 400., 100., FontSlant::Italic),
+        "Oblique"
+    );
+    // Normal queries prefer positive obliques to italic faces.
+    let matcher = FontMatcher::new(vec![
+        face("Family", "Italic", 400., 100., FontSlant::Italic),
+        face("Family", "Oblique", 400., 100., FontSlant::Oblique(20.)),
+    ]);
+    assert_eq!(
+        best(&matcher, "Family", 400., 100., FontSlant::Normal),
+        "Oblique"
+    );
+}
+
+#[test]
+fn narrowing_order() {
+    let matcher = FontMatcher::new(vec![
+        face("Family", "Condensed Bold", 700., 75., FontSlant::Normal),
+        face("Family", "Bold Italic", 700., 100., FontSlant::Italic),
+        face("Family", "Light", 300., 100., FontSlant::Normal),
+    ]);
+    // Width is matched first, then slant, then weight.
+    assert_eq!(
+        best(&matcher, "Family", 700., 100., FontSlant::Normal),
+        "Light"
+    );
+    assert_eq!(
+        best(&matcher, "Family", 300., 90., FontSlant::Italic),
+        "Condensed Bold"
+    );
+}
+
+#[test]
+fn families() {
+    let mut mono = face("Fixture Mono", "Regular", 400., 100., FontSlant::Normal);
+    mono.monospace = true;
+    let mut matcher = FontMatcher::new(vec![
+        face("Fixture Sans", "Regular", 400., 100., FontSlant::Normal),
+        face("Fixture Serif", "Regular", 400., 100., FontSlant::Normal),
+        mono,
+    ]);
+    let query = |families| FontQuery {
+        families,
+        ..FontQuery::default()
+    };
+    let family_of = |matcher: &FontMatcher, families| {
+        matcher
+            .find_best_match(&query(families))
+            .map(|face| face.family_name.clone())
+    };
+
+    let named = |name: &str| FamilyName::Named(name.to_owned());
+    assert_eq!(
+        family_of(&matcher, vec![named("Missing"), named("FIXTURE serif")]),
+        Some("Fixture Serif".to_owned())
+    );
+    assert_eq!(family_of(&matcher, vec![named("Missing")]), None);
+    assert_eq!(
+        family_of(
+            &matcher,
+            vec![FamilyName::Generic(GenericFamily::Monospace)]
+        ),
+        Some("Fixture Mono".to_owned())
+    );
+    assert_eq!(
+        family_of(
+            &matcher,
+            vec![
+                FamilyName::Generic(GenericFamily::SansSerif),
+                named("Fixture Serif")
+            ]
+        ),
+        Some("Fixture Serif".to_owned())
+    );
+
+    matcher.set_generic_family(
+        GenericFamily::SansSerif,
+        vec!["Missing".to_owned(), "fixture sans".to_owned()],
+    );
+    assert_eq!(
+        family_of(
+            &matcher,
+            vec![FamilyName::Generic(GenericFamily::SansSerif)]
+        ),
+        Some("Fixture Sans".to_owned())
+    );
+    assert_eq!(matcher.family_faces(&named("fixture mono")).len(), 1);
+}
+
+#[test]
+fn normalized_values() {
+    assert_eq!(css_weight(-1.), 1.);
+    assert_eq!(css_weight(-0.4), 300.);
+    assert_eq!(css_weight(0.), 400.);
+    assert_eq!(css_weight(0.3), 600.);
+    assert_eq!(css_weight(0.62), 900.);
+    assert_eq!(css_weight(2.), 1000.);
+    assert!((css_weight(0.35) - 650.).abs() < 1e-9);
+    assert_eq!(css_width(-1.), 50.);
+    assert_eq!(css_width(0.), 100.);
+    assert_eq!(css_width(-0.5), 75.);
+    assert_eq!(css_width(1.), 200.);
+    assert_eq!(css_oblique_angle(0.5), 15.);
+    assert_eq!(css_oblique_angle(-1.), -30.);
+}
@@ -0,0 +1,284 @@
+use core_text::font::kCTFontTableCmap;
+use core_text::sfnt::cmap::EncodingRecord;
+use core_text::sfnt::{CharacterMap, CmapTable, Coverage, Error, FontFile, PlatformId};
+
+fn cmap_data(font: &[u8]) -> &[u8] {
+    FontFile::parse(font)
+        .unwrap()
+        .table_data(kCTFontTableCmap)
+        .unwrap()
+}
+
+const CMAP: &[u8] = include_bytes!("fixtures/cmap.ttf");
+const LAST_RESORT: &[u8] = include_bytes!("fixtures/last-resort.ttf");
+
+#[test]
+fn encoding_records() {
+    let table = CmapTable::parse(cmap_data(CMAP)).unwrap();
+    let records: Vec<_> = table
+        .records()
+        .iter()
+        .map(|r| (r.platform_id, r.encoding_id, r.format))
+        .collect();
+    assert_eq!(
+        records,
+        [
+            (PlatformId::UNICODE, 3, 4),
+            (PlatformId::UNICODE, 5, 14),
+            (PlatformId::MACINTOSH, 0, 6),
+            (PlatformId::WINDOWS, 1, 4),
+            (PlatformId::WINDOWS, 10, 12),
+        ]
+    );
+}
+
+#[test]
+fn subtables() {
+    let table = CmapTable::parse(cmap_data(CMAP)).unwrap();
+    let maps: Vec<_> = table
+        .records()
+        .iter()
+        .map(|record| table.subtable(record))
+        .collect();
+
+    // Format 4, with idDelta and idRangeOffset segments.
+    let bmp = maps[0].as_ref().unwrap();
+    assert_eq!(maps[3].as_ref(), Ok(bmp));
+    assert_eq!(bmp.len(), 26 + 2 + 1);
+    assert_eq!(bmp.glyph('A'), Some(1));
+    assert_eq!(bmp.glyph('Z'), Some(26));
+    assert_eq!(bmp.glyph('a'), Some(30));
+    assert_eq!(bmp.glyph('b'), None);
+    assert_eq!(bmp.glyph('c'), Some(31));
+    assert_eq!(bmp.glyph('é'), Some(32));
+    assert_eq!(bmp.glyph('😀'), None);
+
+    // Format 6.
+    let mac = maps[2].as_ref().unwrap();
+    let glyphs: Vec<_> = mac.iter().collect();
+    assert_eq!(glyphs, [('A', 1), ('B', 2), ('C', 3)]);
+
+    // Format 12.
+    let full = maps[4].as_ref().unwrap();
+    assert_eq!(full.len(), bmp.len() + 5);
+    assert!(bmp.iter().all(|(c, glyph)| full.glyph(c) == Some(glyph)));
+    assert_eq!(full.glyph('😂'), Some(42));
+    // A group starting at glyph 0 only leaves its first character unmapped.
+    assert_eq!(full.glyph('🤐'), None);
+    assert_eq!(full.glyph('🤑'), Some(1));
+    assert_eq!(full.glyph('🤒'), Some(2));
+
+    assert_eq!(
+        maps[1],
+        Err(Error::UnsupportedFormat {
+            table: "cmap".parse().unwrap(),
+            format: 14
+        })
+    );
+}
+
+#[test]
+fn character_map() {
+    let map = CharacterMap::parse(cmap_data(CMAP)).unwrap();
+    assert_eq!(map.len(), 34);
+    assert_eq!(map.glyph('😁'), Some(41));
+
+    let selectors: Vec<_> = map.variation_selectors().collect();
+    assert_eq!(selectors, ['\u{FE0E}', '\u{FE0F}']);
+    assert_eq!(map.variation_glyph('A', '\u{FE0E}'), Some(50));
+    assert_eq!(map.variation_glyph('A', '\u{FE0F}'), None);
+    // Default variation sequences use the glyph of the base character.
+    assert_eq!(map.variation_glyph('😀', '\u{FE0F}'), Some(40));
+    assert_eq!(map.variation_glyph('😁', '\u{FE0F}'), Some(41));
+    assert_eq!(map.variation_glyph('😂', '\u{FE0F}'), Some(43));
+    assert_eq!(map.variation_glyph('😂', '\u{FE00}'), None);
+}
+
+#[test]
+fn coverage() {
+    let coverage = CharacterMap::parse(cmap_data(CMAP)).unwrap().coverage();
+    assert_eq!(
+        coverage.ranges(),
+        [
+            'A'..='Z',
+            'a'..='a',
+            'c'..='c',
+            'é'..='é',
+            '😀'..='😂',
+            '🤑'..='🤒'
+        ]
+    );
+    assert_eq!(coverage.len(), 34);
+    assert!(coverage.contains_all("Zac😀"));
+    assert_eq!(coverage.missing("abcd").collect::<String>(), "bd");
+    assert_eq!(coverage.chars().nth(26), Some('a'));
+
+    let other: Coverage = "bdB".chars().collect();
+    let union = coverage.union(&other);
+    assert_eq!(
+        union.ranges(),
+        ['A'..='Z', 'a'..='d', 'é'..='é', '😀'..='😂', '🤑'..='🤒']
+    );
+    assert_eq!(union.len(), 36);
+    assert!(Coverage::default().is_empty());
+}
+
+#[test]
+fn format13() {
+    let map = CharacterMap::parse(cmap_data(LAST_RESORT)).unwrap();
+    assert_eq!(map.glyph(' '), Some(1));
+    assert_eq!(map.glyph('~'), Some(1));
+    assert_eq!(map.glyph('\u{7F}'), None);
+    assert_eq!(map.glyph('\u{D7FF}'), Some(2));
+    assert_eq!(map.glyph('\u{E000}'), Some(2));
+    assert_eq!(map.glyph('\u{10FFFF}'), Some(3));
+
+    // Surrogates are left out.
+    let coverage = map.coverage();
+    assert_eq!(
+        coverage.ranges(),
+        [
+            ' '..='~',
+            '\u{D000}'..='\u{D7FF}',
+            '\u{E000}'..='\u{10FFFF}'
+        ]
+    );
+    assert_eq!(coverage.len(), 95 + 0x2800 + 0x100000);
+    assert_eq!(map.len(), coverage.len());
+}
+
+#[test]
+fn malformed() {
+    let data = cmap_data(CMAP);
+    assert_eq!(
+        CmapTable::parse(&[0, 1, 0, 0]).unwrap_err(),
+        Error::UnsupportedVersion {
+            table: "cmap".parse().unwrap(),
+            version: 1
+        }
+    );
+    assert_eq!(
+        CmapTable::parse(&data[..10]).unwrap_err(),
+        Error::Truncated { offset: 8 }
+    );
+    let table = CmapTable::parse(data).unwrap();
+    let record = EncodingRecord {
+        format: 2,
+        ..table.records()[0]
+    };
+    assert!(matches!(
+        table.subtable(&record),
+        Err(Error::UnsupportedFormat { format: 2, .. })
+    ));
+    // A table with no Unicode subtable maps nothing.
+    assert_eq!(
+        CharacterMap::parse(&[0, 0, 0, 0]),
+        Ok(CharacterMap::default())
+    );
+}
+
+/// A cmap table with one Windows Unicode format 4 subtable of (start, end, idDelta,
+/// idRangeOffset) segments, followed by `glyphs` as the glyph array.
+fn format4(segments: &[(u16, u16, u16, u16)], glyphs: &[u16]) -> Vec<u8> {
+    let count = segments.len() as u16 + 1;
+    let mut columns = [vec![], vec![], vec![], vec![]];
+    for &(start, end, delta, range_offset) in segments {
+        columns[0].push(end);
+        columns[1].push(start);
+        columns[2].push(delta);
+        columns[3].push(range_offset);
+    }
+    columns[0].push(0xFFFF);
+    columns[1].push(0xFFFF);
+    columns[2].push(1);
+    columns[3].push(0);
+    let mut subtable = vec![0, 4, 0, 0, 0, 0];
+    subtable.extend_from_slice(&(count * 2).to_be_bytes());
+    subtable.extend_from_slice(&[0; 6]);
+    for (i, column) in columns.iter().enumerate() {
+        if i == 1 {
+            subtable.extend_from_slice(&[0, 0]);
+        }
+        column
+            .iter()
+            .for_each(|value| subtable.extend_from_slice(&value.to_be_bytes()));
+    }
+    glyphs
+        .iter()
+        .for_each(|glyph| subtable.extend_from_slice(&glyph.to_be_bytes()));
+    let mut data = vec![0, 0, 0, 1, 0, 3, 0, 1, 0, 0, 0, 12];
+    data.extend_from_slice(&subtable);
+    data
+}
+
+#[test]
+fn format4_delta_wraps() {
+    // Glyphs count up from 0xFFFE and wrap around to 0, which is left unmapped.
+    let map = CharacterMap::parse(&format4(&[(0x41, 0x44, 0xFFFE - 0x41, 0)], &[])).unwrap();
+    let glyphs: Vec<_> = map.iter().collect();
+    assert_eq!(glyphs, [('A', 0xFFFE), ('B', 0xFFFF), ('D', 1)]);
+}
+
+#[test]
+fn format4_overlapping_segments() {
+    // Thousands of segments covering the whole range, alternating between idDelta and a glyph
+    // array, are read in one pass over the characters. Earlier segments take precedence.
+    let count = 8000u16;
+    let segments: Vec<_> = (0..count)
+        .map(|i| match i % 2 {
+            0 => (0, 0xFFFE, i + 1, 0),
+            // From this idRangeOffset entry to the glyph array.
+            _ => (0, 0xFFFE, 0, 2 * (count + 1 - i)),
+        })
+        .collect();
+    let glyphs = vec![7; 0xFFFF];
+    let map = CharacterMap::parse(&format4(&segments, &glyphs)).unwrap();
+    assert_eq!(map.glyph('\0'), Some(1));
+    assert_eq!(map.glyph('A'), Some(0x42));
+    assert_eq!(map.glyph('\u{FFFE}'), Some(0xFFFF));
+    assert_eq!(map.len(), 0xFFFF - 0x800);
+}
+
+/// A cmap table with one format 14 subtable whose `selectors` all share one default table of
+/// `ranges` and one non-default table of `glyphs`.
+fn format14_shared(selectors: u32, ranges: u32, glyphs: u32) -> Vec<u8> {
+    let default_offset = 10 + 11 * selectors;
+    let glyphs_offset = default_offset + 4 + 4 * ranges;
+    let length = glyphs_offset + 4 + 5 * glyphs;
+    let mut data = vec![0, 0, 0, 1, 0, 0, 0, 5, 0, 0, 0, 12, 0, 14];
+    data.extend_from_slice(&length.to_be_bytes());
+    data.extend_from_slice(&selectors.to_be_bytes());
+    for selector in 0..selectors {
+        data.extend_from_slice(&(0xE0100 + selector).to_be_bytes()[1..]);
+        data.extend_from_slice(&default_offset.to_be_bytes());
+        data.extend_from_slice(&glyphs_offset.to_be_bytes());
+    }
+    data.extend_from_slice(&ranges.to_be_bytes());
+    for range in 0..ranges {
+        data.extend_from_slice(&(0x10000 + 2 * range).to_be_bytes()[1..]);
+        data.push(0);
+    }
+    data.extend_from_slice(&glyphs.to_be_bytes());
+    for code in 0..glyphs {
+        data.extend_from_slice(&(0x40 + code).to_be_bytes()[1..]);
+        data.extend_from_slice(&(code as u16).to_be_bytes());
+    }
+    data
+}
+
+#[test]
+fn format14_shared_tables() {
+    // Every selector points at the same tables, which are read once.
+    let map = CharacterMap::parse(&format14_shared(20000, 4000, 4000)).unwrap();
+    assert_eq!(map.variation_selectors().count(), 20000);
+    assert_eq!(map.variation_glyph('A', '\u{E0100}'), Some(1));
+    assert_eq!(map.variation_glyph('B', '\u{E4F1F}'), Some(2));
+
+    // Tables with more entries than the subtable has room for are rejected.
+    let mut data = format14_shared(1, 4, 0);
+    data[33..37].copy_from_slice(&1000u32.to_be_bytes());
+    assert_eq!(
+        CharacterMap::parse(&data).unwrap_err(),
+        Error::Truncated { offset: 33 }
+    );
+}
@@ -0,0 +1,116 @@
+use core_foundation::four_char_code::FourCharCode;
+use core_text::sfnt::collection::TAG_TTCF;
+use core_text::sfnt::{checksum, Error, FontCollection, FontFile, NameId, VERSION_TRUETYPE};
+
+const COLLECTION: &[u8] = include_bytes!("fixtures/collection.ttc");
+const NAMES: &[u8] = include_bytes!("fixtures/names.ttf");
+const CMAP: &[u8] = include_bytes!("fixtures/cmap.ttf");
+
+#[test]
+fn faces() {
+    let collection = FontCollection::parse(COLLECTION).unwrap();
+    assert!(collection.is_collection());
+    assert_eq!(collection.len(), 2);
+    assert!(collection.face(2).is_none());
+    let families: Vec<_> = collection
+        .faces()
+        .map(|face| {
+            face.unwrap()
+                .name_table()
+                .unwrap()
+                .best_name(NameId::FAMILY, "en-US")
+        })
+        .collect();
+    assert_eq!(
+        families,
+        [
+            Some("Fixture Sans".to_owned()),
+            Some("Fixture Cmap".to_owned())
+        ]
+    );
+}
+
+#[test]
+fn shared_tables() {
+    let collection = FontCollection::parse(COLLECTION).unwrap();
+    let (first, second) = (
+        &collection.face(0).unwrap().unwrap(),
+        &collection.face(1).unwrap().unwrap(),
+    );
+    assert_eq!(first.version(), VERSION_TRUETYPE);
+    let offset = |face: &FontFile, tag: &[u8; 4]| {
+        let tag = FourCharCode::new(tag);
+        face.tables().iter().find(|r| r.tag == tag).unwrap().offset
+    };
+    assert_eq!(offset(first, b"post"), offset(second, b"post"));
+    assert_ne!(offset(first, b"name"), offset(second, b"name"));
+}
+
+#[test]
+fn extract_faces() {
+    let collection = FontCollection::parse(COLLECTION).unwrap();
+    assert_eq!(collection.face(0).unwrap().unwrap().to_sfnt(), NAMES);
+    assert_eq!(collection.face(1).unwrap().unwrap().to_sfnt(), CMAP);
+    assert_eq!(checksum(CMAP), 0xB1B0AFBA);
+}
+
+#[test]
+fn single_font() {
+    let collection = FontCollection::parse(NAMES).unwrap();
+    assert!(!collection.is_collection());
+    assert_eq!(collection.len(), 1);
+    assert_eq!(collection.face(0).unwrap().unwrap().to_sfnt(), NAMES);
+}
+
+#[test]
+fn errors() {
+    let mut data = COLLECTION.to_vec();
+    data[4] = 3;
+    assert_eq!(
+        FontCollection::parse(&data).unwrap_err(),
+        Error::UnsupportedVersion {
+            table: TAG_TTCF,
+            version: 0x0301_0000,
+        }
+    );
+    assert_eq!(
+        FontCollection::parse(&COLLECTION[..14]).unwrap_err(),
+        Error::Truncated { offset: 12 }
+    );
+    // The second face's offset points past the end of the data.
+    let mut data = COLLECTION.to_vec();
+    data[16..20].copy_from_slice(&0xFFFFu32.to_be_bytes());
+    assert_eq!(
+        FontCollection::parse(&data).unwrap_err(),
+        Error::InvalidFace { index: 1 }
+    );
+    // Both faces start at the same offset.
+    let mut data = COLLECTION.to_vec();
+    data.copy_within(12..16, 16);
+    assert_eq!(
+        FontCollection::parse(&data).unwrap_err(),
+        Error::InvalidFace { index: 1 }
+    );
+    // More faces than the data has room for.
+    let mut data = COLLECTION.to_vec();
+    data[8..12].copy_from_slice(&u32::MAX.to_be_bytes());
+    assert_eq!(
+        FontCollection::parse(&data).unwrap_err(),
+        Error::Truncated { offset: 12 }
+    );
+}
+
+#[test]
+fn broken_face() {
+    // A face whose directory is not an sfnt does not prevent reading the other faces.
+    let mut data = COLLECTION.to_vec();
+    let second = u32::from_be_bytes([data[16], data[17], data[18], data[19]]) as usize;
+    data[second..second + 4].copy_from_slice(b"wOFF");
+    let collection = FontCollection::parse(&data).unwrap();
+    assert_eq!(collection.len(), 2);
+    assert_eq!(collection.face(0).unwrap().unwrap().to_sfnt(), NAMES);
+    assert_eq!(
+        collection.face(1).unwrap().unwrap_err(),
+        Error::InvalidHeader
+    );
+}
@@ -0,0 +1,156 @@
+use core_text::font::kCTFontTableName;
+use core_text::sfnt::{Error, FontFile, NameId, NameTable, PlatformId, VERSION_TRUETYPE};
+
+const FONT: &[u8] = include_bytes!("fixtures/names.ttf");
+
+fn name_table() -> NameTable {
+    let font = FontFile::parse(FONT).unwrap();
+    NameTable::parse(font.table_data(kCTFontTableName).unwrap()).unwrap()
+}
+
+#[test]
+fn table_directory() {
+    let font = FontFile::parse(FONT).unwrap();
+    assert_eq!(font.version(), VERSION_TRUETYPE);
+    let tags: Vec<_> = font.tables().iter().map(|t| t.tag.to_string()).collect();
+    assert_eq!(
+        tags,
+        ["OS/2", "cmap", "glyf", "head", "hhea", "hmtx", "loca", "maxp", "name", "post"]
+    );
+    assert_eq!(font.table_data(b"glyf"), Some(&[][..]));
+    assert_eq!(font.table_data(b"GSUB"), None);
+
+    assert_eq!(
+        FontFile::parse(&FONT[..100]).unwrap_err(),
+        Error::Truncated { offset: 100 }
+    );
+    let mut truncated = FONT.to_vec();
+    truncated.truncate(truncated.len() - 4);
+    assert!(matches!(
+        FontFile::parse(&truncated),
+        Err(Error::InvalidTable { .. })
+    ));
+}
+
+#[test]
+fn records() {
+    let table = name_table();
+    assert_eq!(table.records().len(), 17);
+    assert_eq!(table.language_tags(), ["fr-CA"]);
+
+    let summary: Vec<_> = table
+        .records()
+        .iter()
+        .map(|r| {
+            (
+                r.platform_id,
+                r.encoding_id,
+                r.name_id,
+                table.language_tag(r),
+                r.decode(),
+            )
+        })
+        .collect();
+    let mac = |language, name_id, s: &str| {
+        (
+            PlatformId::MACINTOSH,
+            0,
+            name_id,
+            Some(language),
+            Some(s.to_owned()),
+        )
+    };
+    assert!(summary.contains(&mac("en", NameId::COPYRIGHT, "© Fixture Café")));
+    assert!(summary.contains(&mac("de", NameId::FAMILY, "Fixture Grotesk")));
+    assert!(summary.contains(&(
+        PlatformId::UNICODE,
+        3,
+        NameId::TRADEMARK,
+        None,
+        Some("Fixture™".to_owned())
+    )));
+    // Shift JIS is not decoded.
+    assert!(summary.contains(&(PlatformId::WINDOWS, 2, NameId::FAMILY, Some("ja-JP"), None)));
+    assert!(summary.contains(&(
+        PlatformId::WINDOWS,
+        1,
+        NameId::FAMILY,
+        Some("fr-CA"),
+        Some("Fixture Sans Canadienne".to_owned())
+    )));
+}
+
+#[test]
+fn best_names() {
+    let table = name_table();
+    let family = |language| table.best_name(NameId::FAMILY, language).unwrap();
+    assert_eq!(family("en-US"), "Fixture Sans");
+    assert_eq!(family("de-DE"), "Fixture Grotesk");
+    assert_eq!(family("DE"), "Fixture Grotesk");
+    assert_eq!(family("ja"), "フィクスチャ サンズ");
+    assert_eq!(family("fr-CA"), "Fixture Sans Canadienne");
+    assert_eq!(family("fr-FR"), "Fixture Sans Canadienne");
+    assert_eq!(family("nl"), "Fixture Sans");
+
+    let subfamily = |language| table.best_name(NameId::SUBFAMILY, language).unwrap();
+    assert_eq!(subfamily("de-AT"), "Normal");
+    assert_eq!(subfamily("de-CH"), "Standard");
+    assert_eq!(subfamily("sv"), "Regular");
+
+    assert_eq!(
+        table.best_name(NameId::SAMPLE_TEXT, "en").as_deref(),
+        Some("Sphinx 😀")
+    );
+    assert_eq!(
+        table.best_name(NameId::TRADEMARK, "en").as_deref(),
+        Some("Fixture™")
+    );
+    assert_eq!(table.best_name(NameId::LICENSE, "en"), None);
+}
+
+#[test]
+fn localized_names() {
+    let names = name_table().localized_names(NameId::FAMILY);
+    let names: Vec<_> = names
+        .iter()
+        .map(|(k, v)| (k.as_str(), v.as_str()))
+        .collect();
+    assert_eq!(
+        names,
+        [
+            ("de", "Fixture Grotesk"),
+            ("de-DE", "Fixture Grotesk"),
+            ("en", "Fixture Sans"),
+            ("en-US", "Fixture Sans"),
+            ("fr-CA", "Fixture Sans Canadienne"),
+            ("ja-JP", "フィクスチャ サンズ"),
+        ]
+    );
+}
+
+#[test]
+fn malformed() {
+    let table = FontFile::parse(FONT)
+        .unwrap()
+        .table_data(kCTFontTableName)
+        .unwrap();
+    assert_eq!(
+        NameTable::parse(&[0, 2, 0, 0, 0, 6]),
+        Err(Error::UnsupportedVersion {
+            table: "name".parse().unwrap(),
+            version: 2
+        })
+    );
+    assert_eq!(
+        NameTable::parse(&table[..10]),
+        Err(Error::Truncated { offset: 10 })
+    );
+    assert!(matches!(
+        NameTable::parse(&table[..table.len() - 1]),
+        Err(Error::Truncated { .. })
+    ));
+    assert_eq!(
+        NameTable::parse(&[0, 0, 0, 0, 0, 6]),
+        Ok(NameTable::default())
+    );
+}
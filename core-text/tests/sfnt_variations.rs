@@ -0,0 +1,199 @@
+use core_foundation::four_char_code::FourCharCode;
+use core_text::font::{kCTFontTableAvar, kCTFontTableFvar, kCTFontTableName, kCTFontTableSTAT};
+use core_text::sfnt::variations::{AxisValue, AxisValueKind, DesignAxis};
+use core_text::sfnt::{
+    normalize_coordinates, AvarTable, Error, FontFile, FvarTable, NameId, NameTable, StatTable,
+};
+
+const VARIABLE: &[u8] = include_bytes!("fixtures/variable.ttf");
+
+const WGHT: FourCharCode = FourCharCode::new(b"wght");
+const WDTH: FourCharCode = FourCharCode::new(b"wdth");
+const XOPQ: FourCharCode = FourCharCode::new(b"XOPQ");
+
+fn table(tag: u32) -> &'static [u8] {
+    FontFile::parse(VARIABLE).unwrap().table_data(tag).unwrap()
+}
+
+fn fvar() -> FvarTable {
+    FvarTable::parse(table(kCTFontTableFvar)).unwrap()
+}
+
+#[test]
+fn fvar_axes() {
+    let axes: Vec<_> = fvar()
+        .axes
+        .iter()
+        .map(|a| {
+            (
+                a.tag,
+                a.min_value,
+                a.default_value,
+                a.max_value,
+                a.hidden,
+                a.name_id,
+            )
+        })
+        .collect();
+    assert_eq!(
+        axes,
+        [
+            (WGHT, 100., 400., 900., false, Some(NameId(256))),
+            (WDTH, 75., 100., 125., false, Some(NameId(257))),
+            (XOPQ, 20., 88., 200., true, Some(NameId(258))),
+        ]
+    );
+}
+
+#[test]
+fn fvar_instances_and_names() {
+    let mut fvar = fvar();
+    let instance = &fvar.instances[1];
+    assert_eq!(instance.subfamily_name_id, NameId(260));
+    assert_eq!(instance.postscript_name_id, None);
+    assert_eq!(instance.coordinates, [400., 100., 88.]);
+
+    let names = NameTable::parse(table(kCTFontTableName)).unwrap();
+    fvar.resolve_names(&names, "de-DE");
+    let axis_names: Vec<_> = fvar.axes.iter().map(|a| a.name.as_deref()).collect();
+    assert_eq!(
+        axis_names,
+        [Some("Gewicht"), Some("Width"), Some("Optical Contrast")]
+    );
+    let instances: Vec<_> = fvar
+        .instances
+        .iter()
+        .map(|i| (i.subfamily_name.as_deref(), i.postscript_name.as_deref()))
+        .collect();
+    assert_eq!(
+        instances,
+        [
+            (Some("Thin"), Some("FixtureVariable-Thin")),
+            (Some("Regular"), None),
+            (
+                Some("Black Condensed"),
+                Some("FixtureVariable-BlackCondensed")
+            ),
+        ]
+    );
+}
+
+#[test]
+fn fvar_errors() {
+    let data = table(kCTFontTableFvar);
+    assert_eq!(
+        FvarTable::parse(&data[..30]).unwrap_err(),
+        Error::Truncated { offset: 28 }
+    );
+    let mut data = data.to_vec();
+    data[1] = 2;
+    assert_eq!(
+        FvarTable::parse(&data).unwrap_err(),
+        Error::UnsupportedVersion {
+            table: FourCharCode::from_u32(kCTFontTableFvar),
+            version: 0x0002_0000,
+        }
+    );
+
+    // Axis and instance records must be large enough for their fields.
+    let fvar = FourCharCode::from_u32(kCTFontTableFvar);
+    let mut data = table(kCTFontTableFvar).to_vec();
+    data[10..12].copy_from_slice(&16u16.to_be_bytes());
+    assert_eq!(
+        FvarTable::parse(&data).unwrap_err(),
+        Error::InvalidTable { tag: fvar }
+    );
+    let mut data = table(kCTFontTableFvar).to_vec();
+    let axis_count = u16::from_be_bytes([data[8], data[9]]);
+    data[14..16].copy_from_slice(&(4 * axis_count + 2).to_be_bytes());
+    assert_eq!(
+        FvarTable::parse(&data).unwrap_err(),
+        Error::InvalidTable { tag: fvar }
+    );
+}
+
+#[test]
+fn avar_mapping() {
+    let avar = AvarTable::parse(table(kCTFontTableAvar)).unwrap();
+    assert_eq!(avar.segment_maps.len(), 3);
+    assert_eq!(avar.map(0, 0.5), 0.25);
+    assert_eq!(avar.map(0, 0.75), 0.625);
+    assert_eq!(avar.map(0, -0.5), -0.5);
+    assert_eq!(avar.map(1, 0.3), 0.3);
+    // Axes with an empty or missing map are left alone.
+    assert_eq!(avar.map(2, 0.3), 0.3);
+    assert_eq!(avar.map(7, 0.3), 0.3);
+    assert!(avar.map(0, f64::NAN).is_nan());
+    assert_eq!(avar.map(0, f64::INFINITY), f64::INFINITY);
+}
+
+#[test]
+fn normalized_coordinates() {
+    let axes = fvar().axes;
+    let avar = AvarTable::parse(table(kCTFontTableAvar)).unwrap();
+    assert_eq!(normalize_coordinates(&axes, None, &[]), [0., 0., 0.]);
+    assert_eq!(
+        normalize_coordinates(&axes, None, &[(WGHT, 650.), (WDTH, 50.), (XOPQ, 144.)]),
+        [0.5, -1., 0.5]
+    );
+    assert_eq!(
+        normalize_coordinates(&axes, Some(&avar), &[(WGHT, 650.), (WGHT, 775.)]),
+        [0.625, 0., 0.]
+    );
+    // Coordinates are rounded to 2.14 fixed point.
+    assert_eq!(
+        normalize_coordinates(&axes, None, &[(WGHT, 500.)]),
+        [3277. / 16384., 0., 0.]
+    );
+}
+
+#[test]
+fn stat_table() {
+    let stat = StatTable::parse(table(kCTFontTableSTAT)).unwrap();
+    assert_eq!(stat.elided_fallback_name_id, Some(NameId::SUBFAMILY));
+    assert_eq!(
+        stat.design_axes[2],
+        DesignAxis {
+            tag: FourCharCode::new(b"ital"),
+            name_id: NameId(266),
+            ordering: 2,
+        }
+    );
+    let kinds: Vec<_> = stat.axis_values.iter().map(|v| &v.kind).collect();
+    assert_eq!(
+        kinds,
+        [
+            &AxisValueKind::Value {
+                axis_index: 0,
+                value: 100.
+            },
+            &AxisValueKind::Linked {
+                axis_index: 0,
+                value: 400.,
+                linked_value: 700.
+            },
+            &AxisValueKind::Range {
+                axis_index: 0,
+                nominal: 600.,
+                min: 550.,
+                max: 650.
+            },
+            &AxisValueKind::Value {
+                axis_index: 2,
+                value: 0.
+            },
+            &AxisValueKind::Combination(vec![(0, 900.), (1, 75.)]),
+        ]
+    );
+    let elidable: Vec<_> = stat
+        .axis_values
+        .iter()
+        .filter(|v| v.is_elidable())
+        .map(|v| v.name_id)
+        .collect();
+    assert_eq!(elidable, [NameId(260), NameId(268)]);
+    assert_eq!(
+        stat.axis_values[1].flags,
+        AxisValue::ELIDABLE_AXIS_VALUE_NAME
+    );
+}
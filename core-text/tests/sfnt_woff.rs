@@ -0,0 +1,155 @@
+use core_foundation::four_char_code::FourCharCode;
+use core_text::sfnt::woff::{decode, decode_woff};
+use core_text::sfnt::woff2::decode_woff2;
+use core_text::sfnt::{checksum, Error, FontCollection};
+use std::borrow::Cow;
+
+const OUTLINES: &[u8] = include_bytes!("fixtures/outlines.ttf");
+const OUTLINES_WOFF: &[u8] = include_bytes!("fixtures/outlines.woff");
+const OUTLINES_WOFF2: &[u8] = include_bytes!("fixtures/outlines.woff2");
+const COLLECTION: &[u8] = include_bytes!("fixtures/collection.ttc");
+const COLLECTION_WOFF2: &[u8] = include_bytes!("fixtures/collection.woff2");
+
+fn set_u32(data: &mut [u8], offset: usize, value: u32) {
+    data[offset..offset + 4].copy_from_slice(&value.to_be_bytes());
+}
+
+#[test]
+fn woff() {
+    let font = decode_woff(OUTLINES_WOFF).unwrap();
+    assert_eq!(font, OUTLINES);
+    assert_eq!(checksum(&font), 0xB1B0AFBA);
+}
+
+#[test]
+fn woff_validation() {
+    let glyf = FourCharCode::new(b"glyf");
+    assert_eq!(
+        decode_woff(&OUTLINES_WOFF[..100]).unwrap_err(),
+        Error::InvalidHeader
+    );
+
+    // The header's length must be the file length, and totalSfntSize the decoded length.
+    let mut data = OUTLINES_WOFF.to_vec();
+    data.push(0);
+    assert_eq!(decode_woff(&data).unwrap_err(), Error::InvalidHeader);
+    let mut data = OUTLINES_WOFF.to_vec();
+    set_u32(&mut data, 12, OUTLINES.len() as u32 + 4);
+    assert_eq!(decode_woff(&data).unwrap_err(), Error::InvalidHeader);
+
+    // The third table is glyf. Its offset must be aligned and inside the file.
+    let entry = 44 + 2 * 20;
+    let mut data = OUTLINES_WOFF.to_vec();
+    set_u32(&mut data, entry + 4, 102);
+    assert_eq!(
+        decode_woff(&data).unwrap_err(),
+        Error::InvalidTable { tag: glyf }
+    );
+    let mut data = OUTLINES_WOFF.to_vec();
+    set_u32(&mut data, entry + 4, OUTLINES_WOFF.len() as u32 - 4);
+    assert_eq!(
+        decode_woff(&data).unwrap_err(),
+        Error::InvalidTable { tag: glyf }
+    );
+
+    // Tables must not overlap.
+    let mut data = OUTLINES_WOFF.to_vec();
+    let previous_offset = u32::from_be_bytes(data[entry - 16..entry - 12].try_into().unwrap());
+    set_u32(&mut data, entry + 4, previous_offset);
+    assert_eq!(
+        decode_woff(&data).unwrap_err(),
+        Error::InvalidTable { tag: glyf }
+    );
+
+    // Compressed tables must decompress to their original length.
+    let mut data = OUTLINES_WOFF.to_vec();
+    let offset = u32::from_be_bytes(data[entry + 4..entry + 8].try_into().unwrap()) as usize;
+    data[offset + 2] ^= 0xFF;
+    assert_eq!(decode_woff(&data).unwrap_err(), Error::Decompression);
+
+    // Fonts larger than the decoded size limit are rejected before decompressing anything,
+    // even when the header agrees with the directory.
+    let mut data = OUTLINES_WOFF.to_vec();
+    let original_len = u32::from_be_bytes(data[entry + 12..entry + 16].try_into().unwrap());
+    let total = OUTLINES.len() as u32 - ((original_len + 3) & !3) + (1 << 30);
+    set_u32(&mut data, entry + 12, 1 << 30);
+    set_u32(&mut data, 16, total);
+    assert_eq!(decode_woff(&data).unwrap_err(), Error::TooLarge);
+}
+
+#[test]
+fn woff2_transforms() {
+    let font = decode_woff2(OUTLINES_WOFF2).unwrap();
+    assert_eq!(font, OUTLINES);
+}
+
+#[test]
+fn woff2_collection() {
+    let collection = decode_woff2(COLLECTION_WOFF2).unwrap();
+    assert_eq!(collection, COLLECTION);
+    assert_eq!(FontCollection::parse(&collection).unwrap().len(), 2);
+}
+
+#[test]
+fn woff2_validation() {
+    assert_eq!(
+        decode_woff2(&OUTLINES_WOFF2[..40]).unwrap_err(),
+        Error::Truncated { offset: 40 }
+    );
+
+    let mut data = OUTLINES_WOFF2.to_vec();
+    data.extend_from_slice(&[0; 4]);
+    assert_eq!(decode_woff2(&data).unwrap_err(), Error::InvalidHeader);
+
+    // The reserved field must be zero.
+    let mut data = OUTLINES_WOFF2.to_vec();
+    data[15] = 1;
+    assert_eq!(decode_woff2(&data).unwrap_err(), Error::InvalidHeader);
+
+    // The compressed stream must fit in the file.
+    let mut data = OUTLINES_WOFF2.to_vec();
+    set_u32(&mut data, 20, OUTLINES_WOFF2.len() as u32);
+    assert!(matches!(
+        decode_woff2(&data).unwrap_err(),
+        Error::Truncated { .. }
+    ));
+
+    // The first table is OS/2, known tag 6. Only hmtx has transform version 1.
+    let mut data = OUTLINES_WOFF2.to_vec();
+    assert_eq!(data[48], 6);
+    data[48] |= 1 << 6;
+    assert_eq!(
+        decode_woff2(&data).unwrap_err(),
+        Error::InvalidTable {
+            tag: FourCharCode::new(b"OS/2")
+        }
+    );
+
+    // UIntBase128 numbers must not have leading zeros.
+    let mut data = OUTLINES_WOFF2.to_vec();
+    data.splice(49..49, [0x80]);
+    let length = data.len() as u32;
+    set_u32(&mut data, 8, length);
+    assert_eq!(decode_woff2(&data).unwrap_err(), Error::InvalidHeader);
+
+    // The tables must decompress to the lengths in the directory.
+    let mut data = OUTLINES_WOFF2.to_vec();
+    data[49] += 1;
+    assert_eq!(decode_woff2(&data).unwrap_err(), Error::Decompression);
+
+    // Streams larger than the decoded size limit are rejected before decompressing anything.
+    let mut data = OUTLINES_WOFF2.to_vec();
+    assert!(data[49] < 0x80);
+    data.splice(49..50, [0x84, 0x80, 0x80, 0x80, 0x00]);
+    let length = data.len() as u32;
+    set_u32(&mut data, 8, length);
+    assert_eq!(decode_woff2(&data).unwrap_err(), Error::TooLarge);
+}
+
+#[test]
+fn decode_any() {
+    assert!(matches!(decode(OUTLINES).unwrap(), Cow::Borrowed(_)));
+    assert!(matches!(decode(COLLECTION).unwrap(), Cow::Borrowed(_)));
+    assert_eq!(decode(OUTLINES_WOFF).unwrap(), OUTLINES);
+    assert_eq!(decode(OUTLINES_WOFF2).unwrap(), OUTLINES);
+}
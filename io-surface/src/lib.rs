@@ -18,6 +18,7 @@ use cgl::{kCGLNoError, CGLErrorString, CGLGetCurrentContext, CGLTexImageIOSurfac
 use core::ffi::{c_int, c_void};
 use core_foundation::base::{CFRelease, CFRetain, CFType, CFTypeID, CFTypeRef, TCFType};
 use core_foundation::dictionary::{CFDictionary, CFDictionaryRef};
+use core_foundation::four_char_code::FourCharCode;
 use core_foundation::string::{CFString, CFStringRef};
 use core_foundation_sys::base::mach_port_t;
 use leaky_cow::LeakyCow;
@@ -110,6 +111,14 @@ impl IOSurface {
         unsafe { IOSurfaceGetID(self.as_concrete_TypeRef()) }
     }
 
+    /// Returns the pixel format, such as `BGRA`.
+    pub fn pixel_format(&self) -> FourCharCode {
+        // The pixel format is an `OSType`, declared here as signed.
+        unsafe {
+            FourCharCode::from_u32(IOSurfaceGetPixelFormat(self.as_concrete_TypeRef()) as u32)
+        }
+    }
+
     /// Binds to the current GL texture.
     pub fn bind_to_gl_texture(&self, width: i32, height: i32, has_alpha: bool) {
         unsafe {